@@ -0,0 +1,43 @@
+//! Build script: exposes the git commit and compiler version to the operator
+//! for the `kafka_backup_operator_build_info` metric.
+
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=GIT_SHA");
+    // HEAD only changes on checkout; a new commit on the same branch updates
+    // the branch ref, stored loose under refs/ or in packed-refs. Paths that
+    // don't exist are skipped, since cargo would rerun on every build.
+    for path in [".git/HEAD", ".git/refs", ".git/packed-refs"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={}", path);
+        }
+    }
+
+    // Container builds have no .git directory, so GIT_SHA can be passed in
+    let git_sha = std::env::var("GIT_SHA")
+        .ok()
+        .filter(|sha| !sha.trim().is_empty())
+        .or_else(|| command_output("git", &["rev-parse", "--short=12", "HEAD"]))
+        .unwrap_or_else(|| "unknown".to_string());
+
+    // `rustc 1.88.0 (6b00bc388 2025-06-23)` -> `1.88.0`
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rust_version = command_output(&rustc, &["--version"])
+        .and_then(|out| out.split_whitespace().nth(1).map(str::to_string))
+        .unwrap_or_else(|| "unknown".to_string());
+
+    println!("cargo:rustc-env=GIT_SHA={}", git_sha.trim());
+    println!("cargo:rustc-env=RUSTC_VERSION={}", rust_version);
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let trimmed = stdout.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
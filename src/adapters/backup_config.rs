@@ -2,11 +2,15 @@
 //!
 //! Converts KafkaBackup CRD spec to kafka-backup-core configuration.
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+
 use kube::Client;
+use tracing::{info, warn};
 
 use crate::crd::{
     CheckpointSpec, CircuitBreakerSpec, KafkaBackup, KafkaClusterSpec, KafkaConnectionSpec,
-    MetricsSpec, RateLimitingSpec,
+    MetricsSpec, OffsetStorageBackendKind, RateLimitingSpec,
 };
 use crate::error::Result;
 
@@ -24,6 +28,8 @@ pub struct ResolvedBackupConfig {
     pub storage: ResolvedStorage,
     /// Compression settings
     pub compression: CompressionConfig,
+    /// Per-topic compression overrides
+    pub topic_compression: HashMap<String, CompressionConfig>,
     /// Checkpoint settings
     pub checkpoint: Option<ResolvedCheckpointConfig>,
     /// Rate limiting settings
@@ -34,6 +40,34 @@ pub struct ResolvedBackupConfig {
     pub metrics: Option<ResolvedMetricsConfig>,
     /// Backup segment and mode settings
     pub backup_options: ResolvedBackupOptionsConfig,
+    /// Offset storage backend
+    pub offset_storage_backend: OffsetStorageBackendKind,
+}
+
+impl ResolvedBackupConfig {
+    /// Compression for `topic`: its override, else the global setting
+    pub fn compression_for_topic(&self, topic: &str) -> &CompressionConfig {
+        self.topic_compression
+            .get(topic)
+            .unwrap_or(&self.compression)
+    }
+
+    /// Compression shared by every backed-up topic, if they all resolve to
+    /// the same setting
+    pub fn uniform_compression(&self) -> Option<&CompressionConfig> {
+        let mut topics = self.topics.iter();
+        let first = self.compression_for_topic(topics.next()?);
+        topics
+            .all(|topic| self.compression_for_topic(topic) == first)
+            .then_some(first)
+    }
+
+    /// Compression for the engine run. The engine applies one setting to
+    /// every topic, which validation keeps overrides consistent with; should
+    /// resolved topics still disagree, the global setting is used.
+    pub fn run_compression(&self) -> &CompressionConfig {
+        self.uniform_compression().unwrap_or(&self.compression)
+    }
 }
 
 /// Resolved Kafka cluster configuration with credentials
@@ -42,10 +76,74 @@ pub struct ResolvedKafkaConfig {
     pub bootstrap_servers: Vec<String>,
     pub security_protocol: String,
     pub tls: Option<TlsCredentials>,
+    /// Directory of mounted TLS files, used instead of `tls` when set
+    pub tls_cert_dir: Option<PathBuf>,
     pub sasl: Option<SaslCredentials>,
     pub connection: ResolvedKafkaConnectionConfig,
 }
 
+/// Client properties from `kafkaCluster.clientConfig` the operator applies.
+/// The core client takes no free-form properties, so these are mapped onto
+/// its connection settings and every other key is ignored.
+pub const SUPPORTED_CLIENT_CONFIG: &[&str] = &["socket.keepalive.enable", "socket.nagle.disable"];
+
+/// Boolean value of a supported clientConfig property, `None` if unparsable
+pub fn client_config_bool(value: &str) -> Option<bool> {
+    value.trim().to_ascii_lowercase().parse().ok()
+}
+
+/// Apply the supported clientConfig overrides to the connection settings.
+/// Unparsable values are rejected by validation and skipped here.
+fn apply_client_config(
+    connection: &mut ResolvedKafkaConnectionConfig,
+    client_config: &HashMap<String, String>,
+) {
+    for (key, value) in client_config {
+        let Some(enabled) = client_config_bool(value) else {
+            continue;
+        };
+        match key.as_str() {
+            "socket.keepalive.enable" => connection.tcp_keepalive = enabled,
+            "socket.nagle.disable" => connection.tcp_nodelay = enabled,
+            _ => {}
+        }
+    }
+}
+
+/// Client properties (exact keys, or prefixes ending in '.') the operator
+/// manages through the bootstrap and security fields of `kafkaCluster`
+pub const MANAGED_CLIENT_CONFIG: &[&str] =
+    &["bootstrap.servers", "security.protocol", "sasl.", "ssl."];
+
+/// Whether `key` would override a bootstrap or security setting
+pub fn is_managed_client_config(key: &str) -> bool {
+    MANAGED_CLIENT_CONFIG.iter().any(|managed| {
+        if managed.ends_with('.') {
+            key.starts_with(managed)
+        } else {
+            key == *managed
+        }
+    })
+}
+
+/// SASL mechanism in the form the core client expects: trimmed and uppercased
+pub fn normalize_sasl_mechanism(mechanism: &str) -> String {
+    mechanism.trim().to_uppercase()
+}
+
+/// clientConfig keys that are neither passed through nor managed, sorted
+pub fn ignored_client_config(client_config: &HashMap<String, String>) -> Vec<String> {
+    let mut ignored: Vec<String> = client_config
+        .keys()
+        .filter(|key| {
+            !SUPPORTED_CLIENT_CONFIG.contains(&key.as_str()) && !is_managed_client_config(key)
+        })
+        .cloned()
+        .collect();
+    ignored.sort();
+    ignored
+}
+
 /// Resolved Kafka connection tuning
 #[derive(Debug, Clone)]
 pub struct ResolvedKafkaConnectionConfig {
@@ -77,7 +175,7 @@ pub struct SaslCredentials {
 }
 
 /// Compression configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompressionConfig {
     pub algorithm: String,
     pub level: i32,
@@ -97,6 +195,7 @@ pub struct ResolvedRateLimitingConfig {
     pub records_per_sec: u64,
     pub bytes_per_sec: u64,
     pub max_concurrent_partitions: usize,
+    pub ramp_up_secs: Option<u64>,
 }
 
 /// Resolved circuit breaker configuration
@@ -131,6 +230,7 @@ pub struct ResolvedBackupOptionsConfig {
     pub source_cluster_id: Option<String>,
     pub poll_interval_ms: u64,
     pub consumer_group_snapshot: bool,
+    pub max_message_bytes: Option<u64>,
 }
 
 /// Build fully resolved backup configuration from CRD
@@ -150,6 +250,20 @@ pub async fn build_backup_config(
         algorithm: backup.spec.compression.clone(),
         level: backup.spec.compression_level,
     };
+    let topic_compression = backup
+        .spec
+        .topic_compression
+        .iter()
+        .map(|(topic, topic_override)| {
+            (
+                topic.clone(),
+                CompressionConfig {
+                    algorithm: topic_override.compression.clone(),
+                    level: topic_override.compression_level,
+                },
+            )
+        })
+        .collect();
 
     // Build checkpoint config
     let checkpoint = backup.spec.checkpoint.as_ref().map(build_checkpoint_config);
@@ -180,6 +294,7 @@ pub async fn build_backup_config(
         source_cluster_id: backup.spec.source_cluster_id.clone(),
         poll_interval_ms: backup.spec.poll_interval_ms,
         consumer_group_snapshot: backup.spec.consumer_group_snapshot,
+        max_message_bytes: backup.spec.max_message_bytes,
     };
 
     Ok(ResolvedBackupConfig {
@@ -187,11 +302,13 @@ pub async fn build_backup_config(
         topics: backup.spec.topics.clone(),
         storage,
         compression,
+        topic_compression,
         checkpoint,
         rate_limiting,
         circuit_breaker,
         metrics,
         backup_options,
+        offset_storage_backend: backup.spec.offset_storage_backend,
     })
 }
 
@@ -231,17 +348,10 @@ pub async fn build_kafka_config(
 
     // Resolve SASL credentials if configured
     let sasl = if let Some(sasl_ref) = &kafka.sasl_secret {
-        let (username, password) = get_sasl_credentials(
-            client,
-            namespace,
-            &sasl_ref.name,
-            &sasl_ref.username_key,
-            &sasl_ref.password_key,
-        )
-        .await?;
+        let (username, password) = get_sasl_credentials(client, namespace, sasl_ref).await?;
 
         Some(SaslCredentials {
-            mechanism: sasl_ref.mechanism.clone(),
+            mechanism: normalize_sasl_mechanism(&sasl_ref.mechanism),
             username,
             password,
         })
@@ -249,16 +359,24 @@ pub async fn build_kafka_config(
         None
     };
 
+    let ignored = ignored_client_config(&kafka.client_config);
+    if !ignored.is_empty() {
+        warn!(keys = ?ignored, "Ignoring unsupported clientConfig properties");
+    }
+    let mut connection = kafka
+        .connection
+        .as_ref()
+        .map(build_connection_config)
+        .unwrap_or_default();
+    apply_client_config(&mut connection, &kafka.client_config);
+
     Ok(ResolvedKafkaConfig {
         bootstrap_servers: kafka.bootstrap_servers.clone(),
         security_protocol: kafka.security_protocol.clone(),
         tls,
+        tls_cert_dir: kafka.tls_cert_dir.as_ref().map(PathBuf::from),
         sasl,
-        connection: kafka
-            .connection
-            .as_ref()
-            .map(build_connection_config)
-            .unwrap_or_default(),
+        connection,
     })
 }
 
@@ -288,15 +406,26 @@ fn build_checkpoint_config(checkpoint: &CheckpointSpec) -> ResolvedCheckpointCon
     }
 }
 
-fn build_rate_limiting_config(rate_limiting: &RateLimitingSpec) -> ResolvedRateLimitingConfig {
+/// Resolve rate limiting. Zero record and byte limits both mean unlimited,
+/// which is easy to mistake for a configured limit, so it is called out.
+pub fn build_rate_limiting_config(rate_limiting: &RateLimitingSpec) -> ResolvedRateLimitingConfig {
+    if rate_limiting.records_per_sec == 0 && rate_limiting.bytes_per_sec == 0 {
+        info!(
+            max_concurrent_partitions = rate_limiting.max_concurrent_partitions,
+            "rateLimiting sets no record or byte limit; throughput is unlimited and only partition concurrency is capped"
+        );
+    }
+
     ResolvedRateLimitingConfig {
         records_per_sec: rate_limiting.records_per_sec,
         bytes_per_sec: rate_limiting.bytes_per_sec,
         max_concurrent_partitions: rate_limiting.max_concurrent_partitions,
+        ramp_up_secs: rate_limiting.ramp_up_secs,
     }
 }
 
-fn build_circuit_breaker_config(
+/// Resolve circuit breaker settings
+pub fn build_circuit_breaker_config(
     circuit_breaker: &CircuitBreakerSpec,
 ) -> ResolvedCircuitBreakerConfig {
     ResolvedCircuitBreakerConfig {
@@ -318,3 +447,60 @@ fn build_metrics_config(metrics: &MetricsSpec) -> ResolvedMetricsConfig {
         max_partition_labels: metrics.max_partition_labels,
     }
 }
+
+#[cfg(test)]
+mod client_config_tests {
+    use super::*;
+
+    #[test]
+    fn security_and_bootstrap_keys_are_managed() {
+        assert!(is_managed_client_config("security.protocol"));
+        assert!(is_managed_client_config("sasl.mechanism"));
+        assert!(is_managed_client_config("ssl.ca.location"));
+        assert!(is_managed_client_config("bootstrap.servers"));
+        assert!(!is_managed_client_config("socket.timeout.ms"));
+        assert!(!is_managed_client_config("security.protocol.extra"));
+    }
+
+    #[test]
+    fn sasl_mechanisms_are_trimmed_and_uppercased() {
+        assert_eq!(normalize_sasl_mechanism(" scram-sha-512 "), "SCRAM-SHA-512");
+        assert_eq!(normalize_sasl_mechanism("PLAIN"), "PLAIN");
+    }
+
+    #[test]
+    fn supported_keys_override_connection_settings() {
+        let mut connection = ResolvedKafkaConnectionConfig::default();
+        let client_config = HashMap::from([
+            ("socket.keepalive.enable".to_string(), "false".to_string()),
+            ("socket.nagle.disable".to_string(), "False".to_string()),
+        ]);
+        apply_client_config(&mut connection, &client_config);
+        assert!(!connection.tcp_keepalive);
+        assert!(!connection.tcp_nodelay);
+
+        let invalid = HashMap::from([("socket.keepalive.enable".to_string(), "yes".to_string())]);
+        apply_client_config(&mut connection, &invalid);
+        assert!(!connection.tcp_keepalive);
+    }
+
+    #[test]
+    fn unsupported_keys_are_ignored() {
+        let client_config = HashMap::from([
+            ("socket.nagle.disable".to_string(), "true".to_string()),
+            ("fetch.max.bytes".to_string(), "52428800".to_string()),
+            ("sasl.username".to_string(), "admin".to_string()),
+            ("queue.buffering.max.ms".to_string(), "5".to_string()),
+            ("enable.idempotence".to_string(), "true".to_string()),
+        ]);
+
+        assert_eq!(
+            ignored_client_config(&client_config),
+            vec![
+                "enable.idempotence",
+                "fetch.max.bytes",
+                "queue.buffering.max.ms"
+            ]
+        );
+    }
+}
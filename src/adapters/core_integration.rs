@@ -20,12 +20,22 @@ use kafka_backup_core::validation::{
     ValidationConfig as CoreValidationConfig, WebhookConfig as CoreWebhookConfig,
 };
 
-use super::backup_config::{ResolvedBackupConfig, ResolvedKafkaConfig, ResolvedMetricsConfig};
+use crate::crd::{OffsetStorageBackendKind, RestoreOffsetStrategy};
+
+use super::backup_config::{
+    ResolvedBackupConfig, ResolvedKafkaConfig, ResolvedMetricsConfig, ResolvedRateLimitingConfig,
+};
 use super::restore_config::ResolvedRestoreConfig;
 use super::storage_config::ResolvedStorage;
 use super::tls_files::TlsFileManager;
 use super::validation_config::{ResolvedEvidenceConfig, ResolvedValidationConfig};
 
+/// Partition concurrency of a backup without a rateLimiting section
+const DEFAULT_BACKUP_CONCURRENT_PARTITIONS: usize = 8;
+
+/// Scratch directory used when `WORK_DIR` is unset
+const DEFAULT_WORK_DIR: &str = "/tmp/kafka-backup-operator";
+
 /// Convert resolved backup configuration to kafka-backup-core Config
 pub fn to_core_backup_config(
     resolved: &ResolvedBackupConfig,
@@ -34,11 +44,14 @@ pub fn to_core_backup_config(
 ) -> kafka_backup_core::Result<Config> {
     let kafka_config =
         to_core_kafka_config_with_tls(&resolved.kafka, &resolved.topics, tls_manager);
-    let storage_config = to_core_storage_config(&resolved.storage);
+    // With a date layout the backup is written below its date partition
+    let storage = resolved.storage.for_backup(backup_id);
+    let storage_config = to_core_storage_config(&storage);
     let backup_options = to_core_backup_options(resolved);
 
     // Build offset storage config with proper path inside the backup storage directory
-    let offset_storage = build_offset_storage_config(&resolved.storage, backup_id);
+    let offset_storage =
+        build_offset_storage_config(&storage, backup_id, resolved.offset_storage_backend);
 
     // Build metrics config
     let metrics = resolved.metrics.as_ref().map(to_core_metrics_config);
@@ -91,6 +104,7 @@ pub fn to_core_restore_config(
 /// Return true when a restore should collect an offset mapping report.
 pub fn restore_needs_offset_report(resolved: &ResolvedRestoreConfig) -> bool {
     resolved.auto_consumer_groups
+        || !resolved.offset_report_formats.is_empty()
         || resolved
             .offset_reset
             .as_ref()
@@ -107,14 +121,50 @@ pub fn restore_offset_report_path(
         return None;
     }
 
-    let base_path = match storage {
+    Some(
+        restore_working_dir(storage)
+            .join(backup_id)
+            .join("offset-mapping.json"),
+    )
+}
+
+/// Checkpoint file the restore engine persists its position to, so a restore
+/// interrupted by an operator restart resumes instead of starting over. Keyed
+/// by namespace and name, one directory level each, so restores of the same
+/// backup never share a checkpoint.
+pub fn restore_checkpoint_path(
+    storage: &ResolvedStorage,
+    backup_id: &str,
+    namespace: &str,
+    restore_name: &str,
+) -> PathBuf {
+    restore_working_dir(storage)
+        .join(backup_id)
+        .join("restore-checkpoints")
+        .join(namespace)
+        .join(format!("{}.json", restore_name))
+}
+
+/// Local directory restore artifacts are written under: the PVC itself for
+/// local storage, the operator work directory for object stores.
+fn restore_working_dir(storage: &ResolvedStorage) -> PathBuf {
+    match storage {
         ResolvedStorage::Local(local) => PathBuf::from(&local.path),
         ResolvedStorage::S3(_) | ResolvedStorage::Azure(_) | ResolvedStorage::Gcs(_) => {
-            PathBuf::from("/tmp/kafka-backup-operator")
+            operator_work_dir()
         }
-    };
+    }
+}
 
-    Some(base_path.join(backup_id).join("offset-mapping.json"))
+/// Directory object-store backups and restores keep local state in: backup
+/// offset databases and restore checkpoints. Set `WORK_DIR` to a persistent
+/// volume for that state to survive an operator pod restart.
+pub fn operator_work_dir() -> PathBuf {
+    std::env::var("WORK_DIR")
+        .ok()
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_WORK_DIR))
 }
 
 /// Convert resolved Kafka configuration to kafka-backup-core KafkaConfig
@@ -123,7 +173,7 @@ fn to_core_kafka_config(resolved: &ResolvedKafkaConfig, topics: &[String]) -> Ka
 }
 
 /// Convert resolved Kafka configuration to kafka-backup-core KafkaConfig with TLS
-fn to_core_kafka_config_with_tls(
+pub fn to_core_kafka_config_with_tls(
     resolved: &ResolvedKafkaConfig,
     topics: &[String],
     tls_manager: Option<&TlsFileManager>,
@@ -166,7 +216,7 @@ pub fn to_core_security_config_with_tls(
 
     let (sasl_mechanism, sasl_username, sasl_password) = match &resolved.sasl {
         Some(sasl) => {
-            let mechanism = match sasl.mechanism.to_uppercase().as_str() {
+            let mechanism = match sasl.mechanism.as_str() {
                 "PLAIN" => Some(SaslMechanism::Plain),
                 "SCRAM-SHA-256" => Some(SaslMechanism::ScramSha256),
                 "SCRAM-SHA-512" => Some(SaslMechanism::ScramSha512),
@@ -305,31 +355,32 @@ fn to_core_metrics_config(resolved: &ResolvedMetricsConfig) -> MetricsConfig {
     config
 }
 
-/// Convert backup options
-fn to_core_backup_options(resolved: &ResolvedBackupConfig) -> BackupOptions {
-    let compression = match resolved.compression.algorithm.to_lowercase().as_str() {
+/// Segment compression for a `compression` setting
+pub fn to_core_compression(algorithm: &str) -> CompressionType {
+    match algorithm.to_lowercase().as_str() {
         "none" => CompressionType::None,
         "lz4" => CompressionType::Lz4,
-        "zstd" => CompressionType::Zstd,
         _ => CompressionType::Zstd,
-    };
+    }
+}
+
+/// Convert backup options
+fn to_core_backup_options(resolved: &ResolvedBackupConfig) -> BackupOptions {
+    let run_compression = resolved.run_compression();
+    let compression = to_core_compression(&run_compression.algorithm);
 
     let (checkpoint_interval_secs, sync_interval_secs) = match &resolved.checkpoint {
         Some(cp) if cp.enabled => (cp.interval_secs, cp.interval_secs * 2),
         _ => (5, 30),
     };
 
-    let max_concurrent_partitions = resolved
-        .rate_limiting
-        .as_ref()
-        .map(|rl| rl.max_concurrent_partitions)
-        .unwrap_or(8);
+    let max_concurrent_partitions = backup_concurrent_partitions(resolved.rate_limiting.as_ref());
 
     BackupOptions {
         segment_max_bytes: resolved.backup_options.segment_max_bytes,
         segment_max_interval_ms: resolved.backup_options.segment_max_interval_ms,
         compression,
-        compression_level: resolved.compression.level,
+        compression_level: run_compression.level,
         start_offset: kafka_backup_core::config::StartOffset::Earliest,
         continuous: resolved.backup_options.continuous,
         include_internal_topics: false,
@@ -345,6 +396,14 @@ fn to_core_backup_options(resolved: &ResolvedBackupConfig) -> BackupOptions {
     }
 }
 
+/// Partitions a backup reads at once: `rateLimiting.maxConcurrentPartitions`,
+/// or [`DEFAULT_BACKUP_CONCURRENT_PARTITIONS`] without a rateLimiting section
+fn backup_concurrent_partitions(rate_limiting: Option<&ResolvedRateLimitingConfig>) -> usize {
+    rate_limiting.map_or(DEFAULT_BACKUP_CONCURRENT_PARTITIONS, |rl| {
+        rl.max_concurrent_partitions
+    })
+}
+
 /// Convert restore options
 fn to_core_restore_options(resolved: &ResolvedRestoreConfig) -> RestoreOptions {
     let reset_strategy = resolved
@@ -355,7 +414,14 @@ fn to_core_restore_options(resolved: &ResolvedRestoreConfig) -> RestoreOptions {
         .offset_reset
         .as_ref()
         .is_some_and(|offset_reset| offset_reset.enabled);
+    // Only header-based translation runs inside the engine; earliest and
+    // latest offsets are committed by the operator once the restore is done
+    let engine_translates_offsets = matches!(
+        resolved.offset_strategy,
+        None | Some(RestoreOffsetStrategy::HeaderBased)
+    );
     let reset_consumer_offsets = reset_enabled
+        && engine_translates_offsets
         && !matches!(
             reset_strategy.as_deref(),
             Some("manual") | Some("dry-run") | Some("dry_run")
@@ -367,7 +433,14 @@ fn to_core_restore_options(resolved: &ResolvedRestoreConfig) -> RestoreOptions {
         .map(|offset_reset| offset_reset.consumer_groups.clone())
         .unwrap_or_default();
 
-    let consumer_group_strategy = if reset_enabled || resolved.auto_consumer_groups {
+    let consumer_group_strategy = if let Some(strategy) = resolved.offset_strategy {
+        match strategy {
+            RestoreOffsetStrategy::HeaderBased => OffsetStrategy::HeaderBased,
+            RestoreOffsetStrategy::Skip
+            | RestoreOffsetStrategy::Earliest
+            | RestoreOffsetStrategy::Latest => OffsetStrategy::Skip,
+        }
+    } else if reset_enabled || resolved.auto_consumer_groups {
         match reset_strategy.as_deref() {
             Some("manual") => OffsetStrategy::Manual,
             Some("timestamp") | Some("timestamp-based") | Some("timestamp_based") => {
@@ -456,7 +529,17 @@ fn to_core_restore_options(resolved: &ResolvedRestoreConfig) -> RestoreOptions {
 fn build_offset_storage_config(
     storage: &ResolvedStorage,
     backup_id: &str,
+    backend: OffsetStorageBackendKind,
 ) -> Option<OffsetStorageConfig> {
+    if backend == OffsetStorageBackendKind::Memory {
+        return Some(OffsetStorageConfig {
+            backend: OffsetStorageBackend::Memory,
+            db_path: PathBuf::new(),
+            s3_key: None,
+            sync_interval_secs: 60,
+        });
+    }
+
     match storage {
         ResolvedStorage::Local(local) => {
             // Create offset database path inside the backup storage directory
@@ -665,3 +748,41 @@ fn to_core_notifications_config(
             }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limiting(max_concurrent_partitions: usize) -> ResolvedRateLimitingConfig {
+        ResolvedRateLimitingConfig {
+            records_per_sec: 0,
+            bytes_per_sec: 0,
+            max_concurrent_partitions,
+            ramp_up_secs: None,
+        }
+    }
+
+    #[test]
+    fn backup_concurrency_follows_rate_limiting() {
+        assert_eq!(backup_concurrent_partitions(Some(&rate_limiting(2))), 2);
+        assert_eq!(
+            backup_concurrent_partitions(None),
+            DEFAULT_BACKUP_CONCURRENT_PARTITIONS
+        );
+    }
+
+    #[test]
+    fn restore_checkpoints_are_keyed_by_namespace_and_name() {
+        let storage = ResolvedStorage::Local(crate::adapters::LocalStorageConfig {
+            path: "/data".to_string(),
+        });
+        let team_a = restore_checkpoint_path(&storage, "nightly", "team-a", "orders");
+        let team_b = restore_checkpoint_path(&storage, "nightly", "team-b", "orders");
+
+        assert_eq!(
+            team_a,
+            PathBuf::from("/data/nightly/restore-checkpoints/team-a/orders.json")
+        );
+        assert_ne!(team_a, team_b);
+    }
+}
@@ -2,6 +2,7 @@
 
 mod backup_config;
 mod core_integration;
+mod pvc;
 mod restore_config;
 mod secrets;
 mod storage_config;
@@ -10,6 +11,7 @@ mod validation_config;
 
 pub use backup_config::*;
 pub use core_integration::*;
+pub use pvc::*;
 pub use restore_config::*;
 pub use secrets::*;
 pub use storage_config::*;
@@ -0,0 +1,131 @@
+//! PersistentVolumeClaim provisioning for PVC-backed storage
+
+use std::collections::BTreeMap;
+
+use k8s_openapi::api::core::v1::{
+    PersistentVolumeClaim, PersistentVolumeClaimSpec, VolumeResourceRequirements,
+};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::api::{ObjectMeta, PostParams};
+use kube::{Api, Client};
+use tracing::info;
+
+use crate::crd::PvcCreateSpec;
+use crate::error::{Error, Result};
+
+/// Make sure `claim_name` exists in `namespace`, creating it from `create`
+/// when missing. Returns whether the claim is bound to a volume.
+///
+/// The claim holds backup data, so it is created without an owner and
+/// outlives the resource that asked for it.
+pub async fn ensure_pvc(
+    client: &Client,
+    namespace: &str,
+    claim_name: &str,
+    create: &PvcCreateSpec,
+) -> Result<bool> {
+    let pvcs: Api<PersistentVolumeClaim> = Api::namespaced(client.clone(), namespace);
+
+    let pvc = match pvcs.get_opt(claim_name).await? {
+        Some(pvc) => pvc,
+        None => {
+            info!(
+                namespace = %namespace,
+                claim = %claim_name,
+                size = %create.size,
+                "Creating PersistentVolumeClaim"
+            );
+            let pvc = build_pvc(claim_name, create);
+            match pvcs.create(&PostParams::default(), &pvc).await {
+                Ok(created) => created,
+                // Lost a race with another reconcile; use whatever exists now
+                Err(kube::Error::Api(api_err)) if api_err.code == 409 => {
+                    pvcs.get(claim_name).await?
+                }
+                Err(e) => return Err(Error::Kube(e)),
+            }
+        }
+    };
+
+    Ok(is_bound(&pvc))
+}
+
+/// Whether a claim has been bound to a volume
+pub fn is_bound(pvc: &PersistentVolumeClaim) -> bool {
+    pvc.status
+        .as_ref()
+        .and_then(|status| status.phase.as_deref())
+        == Some("Bound")
+}
+
+/// Build the PersistentVolumeClaim requested by a `PvcCreateSpec`
+pub fn build_pvc(claim_name: &str, create: &PvcCreateSpec) -> PersistentVolumeClaim {
+    let mut labels = BTreeMap::new();
+    labels.insert(
+        "app.kubernetes.io/managed-by".to_string(),
+        "kafka-backup-operator".to_string(),
+    );
+
+    PersistentVolumeClaim {
+        metadata: ObjectMeta {
+            name: Some(claim_name.to_string()),
+            labels: Some(labels),
+            ..Default::default()
+        },
+        spec: Some(PersistentVolumeClaimSpec {
+            access_modes: Some(create.access_modes.clone()),
+            storage_class_name: create.storage_class_name.clone(),
+            resources: Some(VolumeResourceRequirements {
+                requests: Some(BTreeMap::from([(
+                    "storage".to_string(),
+                    Quantity(create.size.clone()),
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::PersistentVolumeClaimStatus;
+
+    fn create_spec() -> PvcCreateSpec {
+        PvcCreateSpec {
+            enabled: true,
+            storage_class_name: Some("fast".to_string()),
+            size: "50Gi".to_string(),
+            access_modes: vec!["ReadWriteOnce".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_build_pvc_from_create_spec() {
+        let pvc = build_pvc("backups", &create_spec());
+
+        assert_eq!(pvc.metadata.name.as_deref(), Some("backups"));
+        assert!(pvc.metadata.owner_references.is_none());
+        let spec = pvc.spec.unwrap();
+        assert_eq!(spec.storage_class_name.as_deref(), Some("fast"));
+        assert_eq!(spec.access_modes, Some(vec!["ReadWriteOnce".to_string()]));
+        assert_eq!(
+            spec.resources.unwrap().requests.unwrap()["storage"],
+            Quantity("50Gi".to_string())
+        );
+    }
+
+    #[test]
+    fn test_is_bound() {
+        let mut pvc = build_pvc("backups", &create_spec());
+        assert!(!is_bound(&pvc));
+
+        pvc.status = Some(PersistentVolumeClaimStatus {
+            phase: Some("Bound".to_string()),
+            ..Default::default()
+        });
+        assert!(is_bound(&pvc));
+    }
+}
@@ -7,13 +7,14 @@ use std::collections::HashMap;
 use kube::Client;
 
 use crate::crd::{
-    BackupRef, KafkaRestore, OffsetResetSpec, PitrSpec, RollbackSpec, TopicRepartitioningSpec,
+    BackupRef, KafkaRestore, OffsetReportFormat, OffsetResetSpec, PartitionStrategy, PitrSpec,
+    RestoreOffsetStrategy, RollbackSpec, StorageSpec, TopicRepartitioningSpec,
 };
 use crate::error::Result;
 
 use super::backup_config::{
-    build_kafka_config, ResolvedCircuitBreakerConfig, ResolvedKafkaConfig,
-    ResolvedRateLimitingConfig,
+    build_circuit_breaker_config, build_kafka_config, build_rate_limiting_config,
+    ResolvedCircuitBreakerConfig, ResolvedKafkaConfig, ResolvedRateLimitingConfig,
 };
 use super::storage_config::{build_storage_config, ResolvedStorage};
 
@@ -22,6 +23,10 @@ use super::storage_config::{build_storage_config, ResolvedStorage};
 pub struct ResolvedRestoreConfig {
     /// Source backup location
     pub backup_source: ResolvedBackupSource,
+    /// Locations holding copies of the backup, tried in order after the
+    /// primary one. Left unresolved so a fallback's credentials are only
+    /// read once it is tried.
+    pub storage_fallbacks: Vec<StorageSpec>,
     /// Target Kafka cluster
     pub kafka: ResolvedKafkaConfig,
     /// Topics to restore
@@ -32,6 +37,8 @@ pub struct ResolvedRestoreConfig {
     pub partition_mapping: HashMap<i32, i32>,
     /// Per-topic repartitioning
     pub repartitioning: HashMap<String, ResolvedTopicRepartitioningConfig>,
+    /// How records without a repartitioning entry are assigned partitions
+    pub partition_strategy: PartitionStrategy,
     /// PITR configuration
     pub pitr: Option<ResolvedPitrConfig>,
     /// Rollback configuration
@@ -54,10 +61,14 @@ pub struct ResolvedRestoreConfig {
     pub auto_consumer_groups: bool,
     /// Post-restore consumer offset reset configuration
     pub offset_reset: Option<ResolvedOffsetResetConfig>,
+    /// Explicit consumer group offset strategy, if set
+    pub offset_strategy: Option<RestoreOffsetStrategy>,
     /// Create missing topics during restore
     pub create_topics: bool,
     /// Default replication factor for auto-created topics
     pub default_replication_factor: Option<i16>,
+    /// Offset report formats to export after the restore (empty for none)
+    pub offset_report_formats: Vec<OffsetReportFormat>,
 }
 
 /// Resolved backup source
@@ -76,6 +87,18 @@ pub enum ResolvedBackupSource {
     },
 }
 
+impl ResolvedBackupSource {
+    /// Select exactly `backup_id`, replacing any requested ID or keyword
+    pub fn pin_backup_id(&mut self, id: &str) {
+        match self {
+            ResolvedBackupSource::BackupResource { backup_id, .. }
+            | ResolvedBackupSource::Storage { backup_id, .. } => {
+                *backup_id = Some(id.to_string());
+            }
+        }
+    }
+}
+
 /// Resolved PITR configuration
 #[derive(Debug, Clone)]
 pub struct ResolvedPitrConfig {
@@ -132,28 +155,18 @@ pub async fn build_restore_config(
         .spec
         .rate_limiting
         .as_ref()
-        .map(|r| ResolvedRateLimitingConfig {
-            records_per_sec: r.records_per_sec,
-            bytes_per_sec: r.bytes_per_sec,
-            max_concurrent_partitions: r.max_concurrent_partitions,
-        });
+        .map(build_rate_limiting_config);
 
     // Build circuit breaker
-    let circuit_breaker =
-        restore
-            .spec
-            .circuit_breaker
-            .as_ref()
-            .map(|c| ResolvedCircuitBreakerConfig {
-                enabled: c.enabled,
-                failure_threshold: c.failure_threshold,
-                reset_timeout_secs: c.reset_timeout_secs,
-                success_threshold: c.success_threshold,
-                operation_timeout_ms: c.operation_timeout_ms,
-            });
+    let circuit_breaker = restore
+        .spec
+        .circuit_breaker
+        .as_ref()
+        .map(build_circuit_breaker_config);
 
     Ok(ResolvedRestoreConfig {
         backup_source,
+        storage_fallbacks: restore.spec.backup_ref.storage_fallbacks.clone(),
         kafka,
         topics: restore.spec.topics.clone(),
         topic_mapping: restore.spec.topic_mapping.clone(),
@@ -166,6 +179,7 @@ pub async fn build_restore_config(
                 (topic.clone(), build_repartitioning_config(repartitioning))
             })
             .collect(),
+        partition_strategy: restore.spec.partition_strategy,
         pitr,
         rollback,
         rate_limiting,
@@ -181,8 +195,15 @@ pub async fn build_restore_config(
             .offset_reset
             .as_ref()
             .map(build_offset_reset_config),
+        offset_strategy: restore.spec.offset_strategy,
         create_topics: restore.spec.create_topics,
         default_replication_factor: restore.spec.default_replication_factor,
+        offset_report_formats: restore
+            .spec
+            .offset_report
+            .as_ref()
+            .map(|report| report.formats.clone())
+            .unwrap_or_default(),
     })
 }
 
@@ -192,12 +213,19 @@ async fn resolve_backup_ref(
     client: &Client,
     namespace: &str,
 ) -> Result<ResolvedBackupSource> {
+    // No backup ID resolves to the newest backup, which useLatest always wants
+    let backup_id = if backup_ref.use_latest {
+        None
+    } else {
+        backup_ref.backup_id.clone()
+    };
+
     // If direct storage is specified, use that (preserving backup_id if provided)
     if let Some(storage) = &backup_ref.storage {
         let resolved = build_storage_config(storage, client, namespace).await?;
         return Ok(ResolvedBackupSource::Storage {
             storage: resolved,
-            backup_id: backup_ref.backup_id.clone(),
+            backup_id,
         });
     }
 
@@ -210,7 +238,7 @@ async fn resolve_backup_ref(
     Ok(ResolvedBackupSource::BackupResource {
         name: backup_ref.name.clone(),
         namespace: backup_namespace,
-        backup_id: backup_ref.backup_id.clone(),
+        backup_id,
     })
 }
 
@@ -230,7 +258,8 @@ fn build_pitr_config(pitr: &PitrSpec) -> ResolvedPitrConfig {
     }
 }
 
-fn build_rollback_config(rollback: &RollbackSpec) -> ResolvedRollbackConfig {
+/// Resolve rollback settings, mapping snapshot storage onto its mount path
+pub fn build_rollback_config(rollback: &RollbackSpec) -> ResolvedRollbackConfig {
     let snapshot_path = rollback.snapshot_storage.as_ref().map(|s| {
         let base = format!("/snapshots/{}", s.pvc_name);
         match &s.sub_path {
@@ -1,8 +1,16 @@
 //! Secret fetching utilities for Kubernetes secrets
+//!
+//! Storage and SASL credential refs may instead name environment variables
+//! of the operator pod, for credentials injected by an external secret
+//! manager rather than stored as Kubernetes secrets.
 
 use k8s_openapi::api::core::v1::Secret;
 use kube::{Api, Client};
 
+use crate::crd::{
+    AzureCredentialsRef, AzureSasTokenRef, AzureServicePrincipalRef, GcsCredentialsRef,
+    S3CredentialsRef, SaslSecretRef,
+};
 use crate::error::{Error, Result};
 
 /// Fetch a secret from Kubernetes
@@ -35,40 +43,100 @@ pub fn get_secret_string(secret: &Secret, key: &str) -> Result<String> {
         .map_err(|e| Error::Config(format!("Invalid UTF-8 in secret key '{}': {}", key, e)))
 }
 
-/// Fetch S3 credentials from a Kubernetes secret
+/// Prefix a credential ref's `*Env` variable must carry. Resources can only
+/// read variables set aside for credentials, not the rest of the operator's
+/// environment (e.g. its own service account settings).
+pub const CREDENTIAL_ENV_PREFIX: &str = "KAFKA_BACKUP_CRED_";
+
+/// Read a credential from one of the operator's own environment variables
+pub fn get_env_string(var: &str) -> Result<String> {
+    if !var.starts_with(CREDENTIAL_ENV_PREFIX) {
+        return Err(Error::Config(format!(
+            "Environment variable '{}' cannot hold credentials: the name must start with {}",
+            var, CREDENTIAL_ENV_PREFIX
+        )));
+    }
+    std::env::var(var).map_err(|e| {
+        Error::Config(format!(
+            "Failed to read credential from environment variable '{}': {}",
+            var, e
+        ))
+    })
+}
+
+/// Fetch the secret a credential ref points at, or `None` when the ref
+/// sources its values from environment variables instead
+async fn get_credential_secret(
+    client: &Client,
+    namespace: &str,
+    secret_name: Option<&str>,
+) -> Result<Option<Secret>> {
+    match secret_name {
+        Some(name) => get_secret(client, name, namespace).await.map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Resolve one credential value from `env` when set, otherwise from `key`
+/// in the credential's secret
+fn get_credential_value(secret: Option<&Secret>, key: &str, env: Option<&str>) -> Result<String> {
+    match (env, secret) {
+        (Some(var), _) => get_env_string(var),
+        (None, Some(secret)) => get_secret_string(secret, key),
+        (None, None) => Err(Error::Config(format!(
+            "Credential '{}' has neither a secret name nor an environment variable",
+            key
+        ))),
+    }
+}
+
+/// Fetch S3 credentials from a Kubernetes secret or environment variables
 pub async fn get_s3_credentials(
     client: &Client,
     namespace: &str,
-    secret_name: &str,
-    access_key_id_key: &str,
-    secret_access_key_key: &str,
+    creds: &S3CredentialsRef,
 ) -> Result<(String, String)> {
-    let secret = get_secret(client, secret_name, namespace).await?;
-    let access_key_id = get_secret_string(&secret, access_key_id_key)?;
-    let secret_access_key = get_secret_string(&secret, secret_access_key_key)?;
+    let secret = get_credential_secret(client, namespace, creds.name.as_deref()).await?;
+    let access_key_id = get_credential_value(
+        secret.as_ref(),
+        &creds.access_key_id_key,
+        creds.access_key_id_env.as_deref(),
+    )?;
+    let secret_access_key = get_credential_value(
+        secret.as_ref(),
+        &creds.secret_access_key_key,
+        creds.secret_access_key_env.as_deref(),
+    )?;
     Ok((access_key_id, secret_access_key))
 }
 
-/// Fetch Azure account key credentials from a Kubernetes secret
+/// Fetch Azure account key credentials from a Kubernetes secret or
+/// environment variable
 pub async fn get_azure_credentials(
     client: &Client,
     namespace: &str,
-    secret_name: &str,
-    account_key_key: &str,
+    creds: &AzureCredentialsRef,
 ) -> Result<String> {
-    let secret = get_secret(client, secret_name, namespace).await?;
-    get_secret_string(&secret, account_key_key)
+    let secret = get_credential_secret(client, namespace, creds.name.as_deref()).await?;
+    get_credential_value(
+        secret.as_ref(),
+        &creds.account_key_key,
+        creds.account_key_env.as_deref(),
+    )
 }
 
-/// Fetch Azure SAS token from a Kubernetes secret
+/// Fetch Azure SAS token from a Kubernetes secret or environment variable
 pub async fn get_azure_sas_token(
     client: &Client,
     namespace: &str,
-    secret_name: &str,
-    sas_token_key: &str,
+    sas: &AzureSasTokenRef,
 ) -> Result<String> {
-    let secret = get_secret(client, secret_name, namespace).await?;
-    get_secret_string(&secret, sas_token_key)
+    let secret = get_credential_secret(client, namespace, sas.name.as_deref()).await?;
+    get_credential_value(
+        secret.as_ref(),
+        &sas.sas_token_key,
+        sas.sas_token_env.as_deref(),
+    )
 }
 
 /// Azure Service Principal credentials
@@ -79,19 +147,29 @@ pub struct AzureServicePrincipalCredentials {
     pub client_secret: String,
 }
 
-/// Fetch Azure Service Principal credentials from a Kubernetes secret
+/// Fetch Azure Service Principal credentials from a Kubernetes secret or
+/// environment variables
 pub async fn get_azure_service_principal_credentials(
     client: &Client,
     namespace: &str,
-    secret_name: &str,
-    client_id_key: &str,
-    tenant_id_key: &str,
-    client_secret_key: &str,
+    sp: &AzureServicePrincipalRef,
 ) -> Result<AzureServicePrincipalCredentials> {
-    let secret = get_secret(client, secret_name, namespace).await?;
-    let client_id = get_secret_string(&secret, client_id_key)?;
-    let tenant_id = get_secret_string(&secret, tenant_id_key)?;
-    let client_secret = get_secret_string(&secret, client_secret_key)?;
+    let secret = get_credential_secret(client, namespace, sp.name.as_deref()).await?;
+    let client_id = get_credential_value(
+        secret.as_ref(),
+        &sp.client_id_key,
+        sp.client_id_env.as_deref(),
+    )?;
+    let tenant_id = get_credential_value(
+        secret.as_ref(),
+        &sp.tenant_id_key,
+        sp.tenant_id_env.as_deref(),
+    )?;
+    let client_secret = get_credential_value(
+        secret.as_ref(),
+        &sp.client_secret_key,
+        sp.client_secret_env.as_deref(),
+    )?;
     Ok(AzureServicePrincipalCredentials {
         client_id,
         tenant_id,
@@ -99,15 +177,18 @@ pub async fn get_azure_service_principal_credentials(
     })
 }
 
-/// Fetch GCS credentials from a Kubernetes secret
+/// Fetch GCS credentials from a Kubernetes secret or environment variable
 pub async fn get_gcs_credentials(
     client: &Client,
     namespace: &str,
-    secret_name: &str,
-    service_account_json_key: &str,
+    creds: &GcsCredentialsRef,
 ) -> Result<String> {
-    let secret = get_secret(client, secret_name, namespace).await?;
-    get_secret_string(&secret, service_account_json_key)
+    let secret = get_credential_secret(client, namespace, creds.name.as_deref()).await?;
+    get_credential_value(
+        secret.as_ref(),
+        &creds.service_account_json_key,
+        creds.service_account_json_env.as_deref(),
+    )
 }
 
 /// Fetch TLS credentials from a Kubernetes secret
@@ -200,16 +281,61 @@ pub async fn get_tls_credentials_split(
     })
 }
 
-/// Fetch SASL credentials from a Kubernetes secret
+/// Fetch SASL credentials from a Kubernetes secret or environment variables
 pub async fn get_sasl_credentials(
     client: &Client,
     namespace: &str,
-    secret_name: &str,
-    username_key: &str,
-    password_key: &str,
+    sasl: &SaslSecretRef,
 ) -> Result<(String, String)> {
-    let secret = get_secret(client, secret_name, namespace).await?;
-    let username = get_secret_string(&secret, username_key)?;
-    let password = get_secret_string(&secret, password_key)?;
+    let secret = get_credential_secret(client, namespace, sasl.name.as_deref()).await?;
+    let username = get_credential_value(
+        secret.as_ref(),
+        &sasl.username_key,
+        sasl.username_env.as_deref(),
+    )?;
+    let password = get_credential_value(
+        secret.as_ref(),
+        &sasl.password_key,
+        sasl.password_env.as_deref(),
+    )?;
     Ok((username, password))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_sourced_credential_ignores_the_secret() {
+        std::env::set_var("KAFKA_BACKUP_TEST_SASL_PASSWORD", "from-vault");
+
+        let value = get_credential_value(None, "password", Some("KAFKA_BACKUP_TEST_SASL_PASSWORD"))
+            .unwrap();
+        assert_eq!(value, "from-vault");
+    }
+
+    #[test]
+    fn unset_env_var_is_a_config_error() {
+        let err = get_credential_value(None, "password", Some("KAFKA_BACKUP_TEST_UNSET_VAR"))
+            .unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+        assert!(err.to_string().contains("KAFKA_BACKUP_TEST_UNSET_VAR"));
+    }
+
+    #[test]
+    fn secret_sourced_credential_reads_the_key() {
+        let secret = Secret {
+            data: Some(
+                [(
+                    "password".to_string(),
+                    k8s_openapi::ByteString(b"from-secret".to_vec()),
+                )]
+                .into(),
+            ),
+            ..Default::default()
+        };
+
+        let value = get_credential_value(Some(&secret), "password", None).unwrap();
+        assert_eq!(value, "from-secret");
+    }
+}
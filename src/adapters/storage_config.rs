@@ -2,6 +2,9 @@
 //!
 //! Converts CRD StorageSpec to kafka-backup-core storage configuration.
 
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDateTime;
 use kube::Client;
 
 use crate::crd::{AzureStorageSpec, GcsStorageSpec, PvcStorageSpec, S3StorageSpec, StorageSpec};
@@ -25,6 +28,205 @@ pub enum ResolvedStorage {
     Gcs(GcsStorageConfig),
 }
 
+/// Key prefix listed by [`ResolvedStorage::probe`]. Nothing is stored under
+/// it, so the listing stays cheap however large the bucket is.
+const PROBE_PREFIX: &str = ".kafka-backup-operator-probe/";
+
+/// HTTP statuses object stores answer with when they reject credentials
+const AUTH_REJECTION_STATUSES: [&str; 2] = ["401", "403"];
+
+/// Fragments of S3, Azure and GCS error responses that mean the backend
+/// rejected the credentials rather than could not be reached
+const AUTH_REJECTION_MARKERS: [&str; 10] = [
+    "unauthorized",
+    "forbidden",
+    "accessdenied",
+    "access denied",
+    "invalidaccesskeyid",
+    "signaturedoesnotmatch",
+    "expiredtoken",
+    "authenticationfailed",
+    "authorizationfailure",
+    "invalid_grant",
+];
+
+impl ResolvedStorage {
+    /// Backend name used in metric labels
+    pub fn backend_name(&self) -> &'static str {
+        match self {
+            ResolvedStorage::Local(_) => "pvc",
+            ResolvedStorage::S3(_) => "s3",
+            ResolvedStorage::Azure(_) => "azure",
+            ResolvedStorage::Gcs(_) => "gcs",
+        }
+    }
+
+    /// Whether backups are written below `YYYY/MM/DD/` date partitions
+    pub fn date_layout(&self) -> bool {
+        match self {
+            ResolvedStorage::Local(_) => false,
+            ResolvedStorage::S3(s3) => s3.date_layout,
+            ResolvedStorage::Azure(azure) => azure.date_layout,
+            ResolvedStorage::Gcs(gcs) => gcs.date_layout,
+        }
+    }
+
+    /// Storage rooted at the date partition `backup_id` is written under
+    /// when `dateLayout` is set. Unchanged for the flat layout, and for IDs
+    /// that do not end in a generated `YYYYMMDD-HHMMSS` timestamp.
+    pub fn for_backup(&self, backup_id: &str) -> ResolvedStorage {
+        match backup_date_partition(backup_id).filter(|_| self.date_layout()) {
+            Some(partition) => self.with_partition(&partition),
+            None => self.clone(),
+        }
+    }
+
+    /// Storage rooted at `partition` (e.g. `2026/01/31/`) below the
+    /// configured prefix. The result is already scoped, so its date layout
+    /// is cleared and [`ResolvedStorage::for_backup`] leaves it as is.
+    pub fn with_partition(&self, partition: &str) -> ResolvedStorage {
+        let partition = partition.trim_matches('/');
+        let join = |prefix: &Option<String>| {
+            if partition.is_empty() {
+                return prefix.clone();
+            }
+            match prefix.as_deref().map(|prefix| prefix.trim_end_matches('/')) {
+                Some(prefix) if !prefix.is_empty() => Some(format!("{}/{}", prefix, partition)),
+                _ => Some(partition.to_string()),
+            }
+        };
+        match self {
+            ResolvedStorage::Local(local) => ResolvedStorage::Local(LocalStorageConfig {
+                path: match partition {
+                    "" => local.path.clone(),
+                    partition => format!("{}/{}", local.path.trim_end_matches('/'), partition),
+                },
+            }),
+            ResolvedStorage::S3(s3) => ResolvedStorage::S3(S3StorageConfig {
+                prefix: join(&s3.prefix),
+                date_layout: false,
+                ..s3.clone()
+            }),
+            ResolvedStorage::Azure(azure) => ResolvedStorage::Azure(AzureStorageConfig {
+                prefix: join(&azure.prefix),
+                date_layout: false,
+                ..azure.clone()
+            }),
+            ResolvedStorage::Gcs(gcs) => ResolvedStorage::Gcs(GcsStorageConfig {
+                prefix: join(&gcs.prefix),
+                date_layout: false,
+                ..gcs.clone()
+            }),
+        }
+    }
+
+    /// Check the storage can be reached, so an outage surfaces before any
+    /// Kafka work starts. Local storage needs a readable directory, or a
+    /// sub-path not created yet whose nearest existing ancestor is a mounted
+    /// volume (an ancestor such as `/` on the pod's own filesystem proves
+    /// nothing about the storage);
+    /// object stores must answer a listing under the configured prefix. A
+    /// listing the backend refuses with the resolved credentials is an
+    /// [`Error::StorageAuth`] rather than an [`Error::Storage`].
+    pub async fn probe(&self) -> Result<()> {
+        match self {
+            ResolvedStorage::Local(local) => probe_local(Path::new(&local.path)).await,
+            ResolvedStorage::S3(_) | ResolvedStorage::Azure(_) | ResolvedStorage::Gcs(_) => {
+                let backend = kafka_backup_core::storage::create_backend(
+                    &super::to_core_storage_config_for_validation(self),
+                )
+                .map_err(|e| {
+                    Error::Storage(format!(
+                        "Failed to create {} storage backend: {}",
+                        self.backend_name(),
+                        e
+                    ))
+                })?;
+                backend
+                    .list(PROBE_PREFIX)
+                    .await
+                    .map_err(|e| classify_probe_error(self.backend_name(), &e.to_string()))?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Date partition (`YYYY/MM/DD/`) of a backup ID ending in a generated
+/// `-YYYYMMDD-HHMMSS` timestamp
+pub fn backup_date_partition(backup_id: &str) -> Option<String> {
+    let split = backup_id.len().checked_sub("-YYYYMMDD-HHMMSS".len())?;
+    let timestamp = backup_id.get(split..)?.strip_prefix('-')?;
+    let started = NaiveDateTime::parse_from_str(timestamp, "%Y%m%d-%H%M%S").ok()?;
+    Some(started.format("%Y/%m/%d/").to_string())
+}
+
+/// Split a storage key into its leading `YYYY/MM/DD/` date partition, empty
+/// for keys laid out flat, and the rest of the key
+pub fn split_date_partition(key: &str) -> (&str, &str) {
+    let is_partition = |partition: &str| {
+        partition.len() == "YYYY/MM/DD/".len()
+            && partition
+                .bytes()
+                .enumerate()
+                .all(|(index, byte)| match index {
+                    4 | 7 | 10 => byte == b'/',
+                    _ => byte.is_ascii_digit(),
+                })
+    };
+    match key.get(.."YYYY/MM/DD/".len()) {
+        Some(partition) if is_partition(partition) => key.split_at(partition.len()),
+        _ => ("", key),
+    }
+}
+
+/// Whether a backend error message reports rejected credentials
+pub fn is_auth_rejection(message: &str) -> bool {
+    let message = message.to_ascii_lowercase();
+    // Whole numbers only, so a port or byte count containing 403 is not a match
+    message
+        .split(|c: char| !c.is_ascii_digit())
+        .any(|number| AUTH_REJECTION_STATUSES.contains(&number))
+        || AUTH_REJECTION_MARKERS
+            .iter()
+            .any(|marker| message.contains(marker))
+}
+
+fn classify_probe_error(backend_name: &str, message: &str) -> Error {
+    if is_auth_rejection(message) {
+        Error::StorageAuth(format!(
+            "{} storage rejected the configured credentials: {}",
+            backend_name, message
+        ))
+    } else {
+        Error::Storage(format!(
+            "{} storage is unreachable: {}",
+            backend_name, message
+        ))
+    }
+}
+
+async fn probe_local(path: &Path) -> Result<()> {
+    let existing = path
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .unwrap_or(path);
+    if existing != path && !is_mount_point(existing) {
+        return Err(Error::Storage(format!(
+            "Storage directory '{}' does not exist",
+            path.display()
+        )));
+    }
+    tokio::fs::read_dir(existing).await.map_err(|e| {
+        Error::Storage(format!(
+            "Storage directory '{}' is unreachable: {}",
+            existing.display(),
+            e
+        ))
+    })?;
+    Ok(())
+}
+
 /// Local/PVC storage configuration
 #[derive(Debug, Clone)]
 pub struct LocalStorageConfig {
@@ -40,6 +242,7 @@ pub struct S3StorageConfig {
     pub path_style: bool,
     pub allow_http: bool,
     pub prefix: Option<String>,
+    pub date_layout: bool,
     pub access_key_id: String,
     pub secret_access_key: String,
 }
@@ -72,6 +275,7 @@ pub struct AzureStorageConfig {
     pub account_name: String,
     pub auth: AzureAuthMethod,
     pub prefix: Option<String>,
+    pub date_layout: bool,
     /// Custom endpoint URL (for Azure Government, China, or private endpoints)
     pub endpoint: Option<String>,
 }
@@ -81,6 +285,7 @@ pub struct AzureStorageConfig {
 pub struct GcsStorageConfig {
     pub bucket: String,
     pub prefix: Option<String>,
+    pub date_layout: bool,
     pub service_account_json: String,
 }
 
@@ -108,7 +313,7 @@ async fn build_pvc_storage(pvc: Option<&PvcStorageSpec>) -> Result<ResolvedStora
         pvc.ok_or_else(|| Error::config("PVC configuration is required for pvc storage type"))?;
 
     // Build the path from claim name and optional sub-path
-    let base_path = format!("/data/{}", pvc.claim_name);
+    let base_path = pvc_mount_path(&pvc.claim_name).display().to_string();
     let path = match &pvc.sub_path {
         Some(sub) => format!("{}/{}", base_path, sub),
         None => base_path,
@@ -117,6 +322,22 @@ async fn build_pvc_storage(pvc: Option<&PvcStorageSpec>) -> Result<ResolvedStora
     Ok(ResolvedStorage::Local(LocalStorageConfig { path }))
 }
 
+/// Where the operator pod must mount a storage PVC
+pub fn pvc_mount_path(claim_name: &str) -> PathBuf {
+    Path::new("/data").join(claim_name)
+}
+
+/// Whether a volume is mounted at `path`, i.e. it lives on a different device
+/// than its parent directory
+pub fn is_mount_point(path: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    let (Ok(metadata), Some(parent)) = (std::fs::metadata(path), path.parent()) else {
+        return false;
+    };
+    std::fs::metadata(parent).is_ok_and(|parent| parent.dev() != metadata.dev())
+}
+
 /// Build S3 storage configuration with resolved credentials
 async fn build_s3_storage(
     s3: Option<&S3StorageSpec>,
@@ -125,15 +346,9 @@ async fn build_s3_storage(
 ) -> Result<ResolvedStorage> {
     let s3 = s3.ok_or_else(|| Error::config("S3 configuration is required for s3 storage type"))?;
 
-    // Fetch credentials from Kubernetes secret
-    let (access_key_id, secret_access_key) = get_s3_credentials(
-        client,
-        namespace,
-        &s3.credentials_secret.name,
-        &s3.credentials_secret.access_key_id_key,
-        &s3.credentials_secret.secret_access_key_key,
-    )
-    .await?;
+    // Fetch credentials from Kubernetes secret or environment variables
+    let (access_key_id, secret_access_key) =
+        get_s3_credentials(client, namespace, &s3.credentials_secret).await?;
 
     let path_style = s3.path_style.unwrap_or(false);
     let allow_http = s3.allow_http.unwrap_or(false);
@@ -153,6 +368,7 @@ async fn build_s3_storage(
         path_style,
         allow_http,
         prefix: s3.prefix.clone(),
+        date_layout: s3.date_layout,
         access_key_id,
         secret_access_key,
     }))
@@ -186,15 +402,8 @@ async fn build_azure_storage(
         AzureAuthMethod::WorkloadIdentity
     } else if let Some(sp_secret) = &azure.service_principal_secret {
         // 2. Service Principal credentials
-        let sp_creds = get_azure_service_principal_credentials(
-            client,
-            namespace,
-            &sp_secret.name,
-            &sp_secret.client_id_key,
-            &sp_secret.tenant_id_key,
-            &sp_secret.client_secret_key,
-        )
-        .await?;
+        let sp_creds =
+            get_azure_service_principal_credentials(client, namespace, sp_secret).await?;
 
         tracing::info!(
             account_name = %azure.account_name,
@@ -210,13 +419,7 @@ async fn build_azure_storage(
         }
     } else if let Some(sas_secret) = &azure.sas_token_secret {
         // 3. SAS token
-        let sas_token = get_azure_sas_token(
-            client,
-            namespace,
-            &sas_secret.name,
-            &sas_secret.sas_token_key,
-        )
-        .await?;
+        let sas_token = get_azure_sas_token(client, namespace, sas_secret).await?;
 
         tracing::info!(
             account_name = %azure.account_name,
@@ -227,8 +430,7 @@ async fn build_azure_storage(
         AzureAuthMethod::SasToken(sas_token)
     } else if let Some(creds) = &azure.credentials_secret {
         // 4. Account key
-        let account_key =
-            get_azure_credentials(client, namespace, &creds.name, &creds.account_key_key).await?;
+        let account_key = get_azure_credentials(client, namespace, creds).await?;
 
         tracing::info!(
             account_name = %azure.account_name,
@@ -260,6 +462,7 @@ async fn build_azure_storage(
         account_name: azure.account_name.clone(),
         auth,
         prefix: azure.prefix.clone(),
+        date_layout: azure.date_layout,
         endpoint: azure.endpoint.clone(),
     }))
 }
@@ -273,18 +476,14 @@ async fn build_gcs_storage(
     let gcs =
         gcs.ok_or_else(|| Error::config("GCS configuration is required for gcs storage type"))?;
 
-    // Fetch credentials from Kubernetes secret
-    let service_account_json = get_gcs_credentials(
-        client,
-        namespace,
-        &gcs.credentials_secret.name,
-        &gcs.credentials_secret.service_account_json_key,
-    )
-    .await?;
+    // Fetch credentials from Kubernetes secret or environment variable
+    let service_account_json =
+        get_gcs_credentials(client, namespace, &gcs.credentials_secret).await?;
 
     Ok(ResolvedStorage::Gcs(GcsStorageConfig {
         bucket: gcs.bucket.clone(),
         prefix: gcs.prefix.clone(),
+        date_layout: gcs.date_layout,
         service_account_json,
     }))
 }
@@ -298,4 +497,144 @@ mod tests {
     fn test_pvc_path_construction() {
         // This would require mocking - placeholder for future tests
     }
+
+    #[test]
+    fn plain_directories_are_not_mount_points() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!is_mount_point(dir.path()));
+        assert!(!is_mount_point(&dir.path().join("missing")));
+        assert_eq!(
+            pvc_mount_path("kafka-backups"),
+            Path::new("/data/kafka-backups")
+        );
+    }
+
+    #[tokio::test]
+    async fn local_probe_accepts_an_existing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = ResolvedStorage::Local(LocalStorageConfig {
+            path: dir.path().display().to_string(),
+        });
+        assert!(storage.probe().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn local_probe_rejects_a_missing_path_outside_a_volume() {
+        // A tempdir is not a mount point, so its existence says nothing
+        // about whether the storage volume is there
+        let dir = tempfile::tempdir().unwrap();
+        let storage = ResolvedStorage::Local(LocalStorageConfig {
+            path: dir.path().join("nightly").display().to_string(),
+        });
+        assert!(matches!(storage.probe().await, Err(Error::Storage(_))));
+    }
+
+    #[tokio::test]
+    async fn local_probe_rejects_an_unreadable_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("not-a-directory");
+        std::fs::write(&file, b"").unwrap();
+        let storage = ResolvedStorage::Local(LocalStorageConfig {
+            path: file.display().to_string(),
+        });
+        assert!(matches!(storage.probe().await, Err(Error::Storage(_))));
+    }
+
+    #[test]
+    fn rejected_credentials_are_classified_as_auth_errors() {
+        for message in [
+            "Generic S3 error: response error \"AccessDenied\", status 403",
+            "Client error with status 401 Unauthorized",
+            "The AWS Access Key Id you provided does not exist (InvalidAccessKeyId)",
+            "Server failed to authenticate the request: AuthenticationFailed",
+        ] {
+            assert!(
+                matches!(classify_probe_error("s3", message), Error::StorageAuth(_)),
+                "{}",
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn connectivity_failures_stay_storage_errors() {
+        for message in [
+            "error sending request: connection refused",
+            "dns error: failed to lookup address information",
+            "operation timed out after 4030ms",
+        ] {
+            assert!(
+                matches!(classify_probe_error("s3", message), Error::Storage(_)),
+                "{}",
+                message
+            );
+        }
+    }
+
+    fn s3_storage(prefix: Option<&str>, date_layout: bool) -> ResolvedStorage {
+        ResolvedStorage::S3(S3StorageConfig {
+            bucket: "backups".to_string(),
+            region: "us-east-1".to_string(),
+            endpoint: None,
+            path_style: false,
+            allow_http: false,
+            prefix: prefix.map(str::to_string),
+            date_layout,
+            access_key_id: "key".to_string(),
+            secret_access_key: "secret".to_string(),
+        })
+    }
+
+    fn s3_prefix(storage: &ResolvedStorage) -> Option<&str> {
+        match storage {
+            ResolvedStorage::S3(s3) => s3.prefix.as_deref(),
+            _ => panic!("expected s3 storage"),
+        }
+    }
+
+    #[test]
+    fn backup_date_partition_comes_from_the_generated_timestamp() {
+        assert_eq!(
+            backup_date_partition("nightly-20260131-020000").as_deref(),
+            Some("2026/01/31/")
+        );
+        assert_eq!(backup_date_partition("nightly-manual"), None);
+        assert_eq!(backup_date_partition("20260131-020000"), None);
+    }
+
+    #[test]
+    fn split_date_partition_only_matches_a_leading_date() {
+        assert_eq!(
+            split_date_partition("2026/01/31/nightly-20260131-020000/manifest.json"),
+            ("2026/01/31/", "nightly-20260131-020000/manifest.json")
+        );
+        assert_eq!(
+            split_date_partition("nightly-20260131-020000/manifest.json"),
+            ("", "nightly-20260131-020000/manifest.json")
+        );
+    }
+
+    #[test]
+    fn date_layout_scopes_storage_below_the_prefix() {
+        let storage = s3_storage(Some("kafka/"), true);
+        let scoped = storage.for_backup("nightly-20260131-020000");
+        assert_eq!(s3_prefix(&scoped), Some("kafka/2026/01/31"));
+        // Already scoped storage is not partitioned again
+        assert_eq!(
+            s3_prefix(&scoped.for_backup("nightly-20260131-020000")),
+            Some("kafka/2026/01/31")
+        );
+
+        let unprefixed = s3_storage(None, true).for_backup("nightly-20260131-020000");
+        assert_eq!(s3_prefix(&unprefixed), Some("2026/01/31"));
+    }
+
+    #[test]
+    fn flat_layout_leaves_the_prefix_alone() {
+        let storage = s3_storage(Some("kafka"), false);
+        assert_eq!(
+            s3_prefix(&storage.for_backup("nightly-20260131-020000")),
+            Some("kafka")
+        );
+    }
 }
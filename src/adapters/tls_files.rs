@@ -9,6 +9,7 @@ use std::path::{Path, PathBuf};
 
 use crate::error::{Error, Result};
 
+use super::backup_config::ResolvedKafkaConfig;
 use super::secrets::TlsCredentials;
 
 /// Manages TLS credential files
@@ -109,6 +110,36 @@ impl TlsFileManager {
         }
     }
 
+    /// Create a TlsFileManager for a directory of mounted certificates
+    ///
+    /// Expects `ca.crt` and picks up `tls.crt`/`tls.key` when both are present,
+    /// matching the layout written by cert-manager and Kubernetes TLS secrets.
+    /// Mounted files are never deleted.
+    pub fn from_cert_dir(cert_dir: &Path) -> Result<Self> {
+        let ca_cert_path = cert_dir.join("ca.crt");
+        if !ca_cert_path.is_file() {
+            return Err(Error::Config(format!(
+                "tlsCertDir {:?} does not contain ca.crt",
+                cert_dir
+            )));
+        }
+
+        let client_cert_path = cert_dir.join("tls.crt");
+        let client_key_path = cert_dir.join("tls.key");
+        let (client_cert_path, client_key_path) =
+            if client_cert_path.is_file() && client_key_path.is_file() {
+                (Some(client_cert_path), Some(client_key_path))
+            } else {
+                (None, None)
+            };
+
+        Ok(Self::from_paths(
+            ca_cert_path,
+            client_cert_path,
+            client_key_path,
+        ))
+    }
+
     /// Disable cleanup on drop (useful for debugging)
     pub fn keep_files(mut self) -> Self {
         self.cleanup_on_drop = false;
@@ -159,6 +190,24 @@ fn write_file(path: &Path, content: &str) -> Result<()> {
     Ok(())
 }
 
+/// Create the TLS file manager for a resolved Kafka config, if TLS is configured
+///
+/// Mounted certificate directories take precedence and are used in place;
+/// secret-sourced credentials are written under [`default_tls_dir`].
+pub fn create_tls_manager(
+    kafka: &ResolvedKafkaConfig,
+    operation_id: &str,
+) -> Result<Option<TlsFileManager>> {
+    if let Some(cert_dir) = &kafka.tls_cert_dir {
+        return TlsFileManager::from_cert_dir(cert_dir).map(Some);
+    }
+
+    match &kafka.tls {
+        Some(tls) => TlsFileManager::new(tls, &default_tls_dir(operation_id)).map(Some),
+        None => Ok(None),
+    }
+}
+
 /// Get the default TLS directory for an operation
 pub fn default_tls_dir(operation_id: &str) -> PathBuf {
     PathBuf::from(format!("/tmp/kafka-backup-tls/{}", operation_id))
@@ -234,4 +283,32 @@ mod tests {
         // After drop, file should still exist because we called keep_files()
         assert!(ca_path.exists());
     }
+
+    #[test]
+    fn test_from_cert_dir_uses_mounted_files_without_cleanup() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("ca.crt"), "test-ca").unwrap();
+        fs::write(dir.path().join("tls.crt"), "test-client").unwrap();
+        fs::write(dir.path().join("tls.key"), "test-key").unwrap();
+
+        let ca_path;
+        {
+            let manager = TlsFileManager::from_cert_dir(dir.path()).unwrap();
+            ca_path = manager.ca_location();
+            assert_eq!(ca_path, dir.path().join("ca.crt"));
+            assert_eq!(
+                manager.certificate_location(),
+                Some(dir.path().join("tls.crt"))
+            );
+            assert_eq!(manager.key_location(), Some(dir.path().join("tls.key")));
+        }
+        // Mounted files must survive drop
+        assert!(ca_path.exists());
+    }
+
+    #[test]
+    fn test_from_cert_dir_requires_ca() {
+        let dir = tempdir().unwrap();
+        assert!(TlsFileManager::from_cert_dir(dir.path()).is_err());
+    }
 }
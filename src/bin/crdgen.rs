@@ -3,13 +3,88 @@
 //! This binary generates Kubernetes CRD manifests for all custom resources
 //! defined by the kafka-backup-operator.
 //!
-//! Usage: cargo run --bin crdgen > deploy/crds/all.yaml
+//! Usage:
+//!   cargo run --bin crdgen > deploy/crds/all.yaml
+//!   cargo run --bin crdgen -- --output-dir deploy/crds
+//!   cargo run --bin crdgen -- --format json --output-dir deploy/crds
 
-use kafka_backup_operator::crd::generate_crds;
+use std::path::PathBuf;
+use std::process::ExitCode;
 
-fn main() {
-    for crd in generate_crds() {
-        println!("---");
-        print!("{}", crd);
+use kafka_backup_operator::crd::{generate_crds_as, CrdFormat};
+
+const USAGE: &str = "Usage: crdgen [--format yaml|json] [--output-dir <dir>]
+
+Prints all CRDs to stdout, or writes one <kind>.<format> file per CRD to <dir>.";
+
+/// Parsed command line
+#[derive(Debug, Default)]
+struct Args {
+    format: CrdFormat,
+    output_dir: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let args = match parse_args(args.into_iter()) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let crds = generate_crds_as(args.format);
+
+    let Some(output_dir) = args.output_dir else {
+        for (_, crd) in crds {
+            // `---` separates documents only in a YAML stream
+            if args.format == CrdFormat::Yaml {
+                println!("---");
+            }
+            print!("{}", crd);
+        }
+        return ExitCode::SUCCESS;
+    };
+
+    if let Err(e) = std::fs::create_dir_all(&output_dir) {
+        eprintln!("Failed to create '{}': {}", output_dir.display(), e);
+        return ExitCode::FAILURE;
+    }
+    for (name, crd) in crds {
+        let path = output_dir.join(format!("{}.{}", name, args.format.extension()));
+        if let Err(e) = std::fs::write(&path, crd) {
+            eprintln!("Failed to write '{}': {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+        eprintln!("Wrote {}", path.display());
+    }
+    ExitCode::SUCCESS
+}
+
+/// Parse the command line flags
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args::default();
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("{} requires a value", flag))
+        };
+        match flag.as_str() {
+            "--output-dir" => parsed.output_dir = Some(PathBuf::from(value()?)),
+            "--format" => parsed.format = value()?.parse()?,
+            other => return Err(format!("Unknown argument '{}'", other)),
+        }
     }
+    Ok(parsed)
 }
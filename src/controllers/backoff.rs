@@ -0,0 +1,127 @@
+//! Per-object exponential backoff for reconcile retries
+//!
+//! Tracks consecutive reconcile failures per resource and turns the
+//! controller's base retry interval into an exponentially growing, jittered
+//! delay. Jitter spreads retries out so a shared outage (e.g. brokers
+//! unreachable) does not requeue every resource at the same instant.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Upper bound on any backoff delay
+pub const MAX_BACKOFF: Duration = Duration::from_secs(900);
+
+/// Largest exponent applied to the base delay; keeps the shift from overflowing
+const MAX_EXPONENT: u32 = 16;
+
+/// Consecutive failure counts keyed by `{kind}/{namespace}/{name}`
+#[derive(Debug, Default)]
+pub struct Backoff {
+    failures: Mutex<HashMap<String, u32>>,
+}
+
+impl Backoff {
+    /// Create an empty backoff tracker
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a failure and return the jittered delay before the next retry
+    pub fn next_delay(&self, key: &str, base: Duration) -> Duration {
+        let failures = {
+            let mut failures = self.failures.lock().unwrap();
+            let count = failures.entry(key.to_string()).or_insert(0);
+            *count = count.saturating_add(1);
+            *count
+        };
+        backoff_delay(base, failures, jitter_fraction(key))
+    }
+
+    /// Number of consecutive failures recorded for `key`
+    pub fn failures(&self, key: &str) -> u32 {
+        self.failures.lock().unwrap().get(key).copied().unwrap_or(0)
+    }
+
+    /// Clear the failure count after a successful reconcile
+    pub fn reset(&self, key: &str) {
+        self.failures.lock().unwrap().remove(key);
+    }
+}
+
+/// Build the backoff key for a resource
+pub fn object_key(kind: &str, namespace: &str, name: &str) -> String {
+    format!("{}/{}/{}", kind, namespace, name)
+}
+
+/// Compute `base * 2^(failures - 1)`, capped at [`MAX_BACKOFF`], then apply
+/// "equal jitter": half the delay is fixed and the other half is scaled by
+/// `jitter` (expected in `[0, 1)`).
+fn backoff_delay(base: Duration, failures: u32, jitter: f64) -> Duration {
+    let exponent = failures.saturating_sub(1).min(MAX_EXPONENT);
+    let delay = base
+        .checked_mul(1u32 << exponent)
+        .unwrap_or(MAX_BACKOFF)
+        .min(MAX_BACKOFF);
+
+    let half = delay / 2;
+    half + half.mul_f64(jitter.clamp(0.0, 1.0))
+}
+
+/// Cheap jitter source without pulling in an RNG crate: hash the key with a
+/// randomly seeded hasher together with the current time.
+fn jitter_fraction(key: &str) -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    key.hash(&mut hasher);
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0)
+        .hash(&mut hasher);
+    (hasher.finish() % 10_000) as f64 / 10_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_doubles_per_consecutive_failure() {
+        let base = Duration::from_secs(30);
+        assert_eq!(backoff_delay(base, 1, 1.0), Duration::from_secs(30));
+        assert_eq!(backoff_delay(base, 2, 1.0), Duration::from_secs(60));
+        assert_eq!(backoff_delay(base, 3, 1.0), Duration::from_secs(120));
+    }
+
+    #[test]
+    fn delay_is_capped() {
+        let base = Duration::from_secs(300);
+        assert_eq!(backoff_delay(base, 10, 1.0), MAX_BACKOFF);
+        assert_eq!(backoff_delay(base, u32::MAX, 1.0), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn jitter_keeps_at_least_half_the_delay() {
+        let base = Duration::from_secs(60);
+        assert_eq!(backoff_delay(base, 1, 0.0), Duration::from_secs(30));
+        for _ in 0..100 {
+            let delay = backoff_delay(base, 1, jitter_fraction("ns/name"));
+            assert!(delay >= Duration::from_secs(30) && delay <= base);
+        }
+    }
+
+    #[test]
+    fn reset_clears_failure_count() {
+        let backoff = Backoff::new();
+        let key = object_key("KafkaBackup", "ns", "name");
+
+        backoff.next_delay(&key, Duration::from_secs(30));
+        backoff.next_delay(&key, Duration::from_secs(30));
+        assert_eq!(backoff.failures(&key), 2);
+
+        backoff.reset(&key);
+        assert_eq!(backoff.failures(&key), 0);
+    }
+}
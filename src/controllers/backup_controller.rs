@@ -5,6 +5,8 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::Utc;
+use futures::future::join_all;
 use futures::StreamExt;
 use kube::{
     api::ListParams,
@@ -17,52 +19,102 @@ use kube::{
 };
 use tracing::{error, info, instrument, warn};
 
-use crate::controllers::Context;
+use crate::controllers::{backoff, Context};
 use crate::crd::KafkaBackup;
 use crate::error::{Error, Result};
 use crate::metrics;
 use crate::reconcilers::backup as backup_reconciler;
+use crate::reconcilers::{
+    recover_orphaned, scoped_apis, set_validating, watch_namespaces, PHASE_INTERRUPTED,
+    PHASE_VALIDATING,
+};
 
 /// Finalizer name for KafkaBackup resources
 const FINALIZER_NAME: &str = "kafka.oso.sh/backup-finalizer";
 
 /// Run the KafkaBackup controller
 pub async fn run(client: Client, context: Arc<Context>) {
-    let api: Api<KafkaBackup> = Api::all(client.clone());
+    let apis: Vec<Api<KafkaBackup>> = scoped_apis(&client, &watch_namespaces());
 
     // Verify CRD is installed
-    if let Err(e) = api.list(&ListParams::default().limit(1)).await {
-        error!("KafkaBackup CRD not installed: {}", e);
-        return;
+    for api in &apis {
+        if let Err(e) = api.list(&ListParams::default().limit(1)).await {
+            error!("KafkaBackup CRD not installed: {}", e);
+            return;
+        }
     }
 
+    // Nothing runs in this process yet, so anything still Running was left
+    // behind by the previous instance
+    recover_orphaned(
+        &client,
+        &apis,
+        "KafkaBackup",
+        |backup: &KafkaBackup| backup.status.as_ref()?.phase.clone(),
+        |backup: &KafkaBackup| backup.status.as_ref()?.heartbeat_time,
+        |backup: &KafkaBackup| {
+            backup
+                .status
+                .as_ref()
+                .map(|status| status.conditions.clone())
+                .unwrap_or_default()
+        },
+        PHASE_INTERRUPTED,
+        "Backup was orphaned by an operator restart; resuming from checkpoint",
+    )
+    .await;
+    backup_reconciler::sweep_incomplete_backups(&client, &apis).await;
+
     info!("Starting KafkaBackup controller");
 
-    Controller::new(api, WatcherConfig::default())
-        .shutdown_on_signal()
-        .run(reconcile, error_policy, context)
-        .for_each(|result| async move {
-            match result {
-                Ok((obj, _action)) => {
-                    info!(
-                        name = %obj.name,
-                        namespace = obj.namespace.as_deref().unwrap_or("default"),
-                        "Reconciled KafkaBackup"
-                    );
-                }
-                Err(e) => {
-                    error!(error = %e, "Reconciliation error");
-                    metrics::RECONCILIATION_ERRORS
-                        .with_label_values(&["KafkaBackup"])
-                        .inc();
+    // One controller per watched namespace, or a single cluster-wide one
+    let controllers: Vec<_> = apis
+        .into_iter()
+        .map(|api| Controller::new(api, WatcherConfig::default()))
+        .collect();
+    let managed_gauge = super::spawn_managed_resources_gauge(
+        "KafkaBackup",
+        controllers.iter().map(Controller::store).collect(),
+    );
+    join_all(controllers.into_iter().map(|controller| {
+        controller
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, context.clone())
+            .for_each(|result| async move {
+                match result {
+                    Ok((obj, _action)) => {
+                        info!(
+                            name = %obj.name,
+                            namespace = obj.namespace.as_deref().unwrap_or("default"),
+                            "Reconciled KafkaBackup"
+                        );
+                        metrics::record_reconcile_success();
+                    }
+                    Err(e) => {
+                        error!(error = %e, "Reconciliation error");
+                        metrics::RECONCILIATION_ERRORS
+                            .with_label_values(&["KafkaBackup"])
+                            .inc();
+                        if !super::is_user_config_error(&e) {
+                            metrics::record_reconcile_failure();
+                        }
+                    }
                 }
-            }
-        })
-        .await;
+            })
+    }))
+    .await;
+    managed_gauge.abort();
 }
 
 /// Main reconciliation function
-#[instrument(skip(ctx, obj), fields(name = %obj.name_any(), namespace = obj.namespace()))]
+#[instrument(
+    skip(ctx, obj),
+    fields(
+        name = %obj.name_any(),
+        namespace = obj.namespace(),
+        log_level = super::log_level_override(obj.as_ref()),
+    )
+)]
 async fn reconcile(obj: Arc<KafkaBackup>, ctx: Arc<Context>) -> Result<Action> {
     let _timer = metrics::RECONCILE_DURATION
         .with_label_values(&["KafkaBackup"])
@@ -73,16 +125,37 @@ async fn reconcile(obj: Arc<KafkaBackup>, ctx: Arc<Context>) -> Result<Action> {
 
     let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
     let api: Api<KafkaBackup> = Api::namespaced(ctx.client.clone(), &namespace);
+    let backoff_key = backoff::object_key("KafkaBackup", &namespace, &obj.name_any());
+
+    // A paused resource is left untouched, finalizer included
+    if super::sync_paused_condition(&api, obj.as_ref()).await? {
+        info!("Reconciliation paused by annotation");
+        return Ok(Action::await_change());
+    }
 
     // Use finalizer for proper cleanup handling
-    finalizer(&api, FINALIZER_NAME, obj, |event| async {
+    let result = finalizer(&api, FINALIZER_NAME, obj, |event| async {
         match event {
             FinalizerEvent::Apply(backup) => apply(backup, ctx.clone()).await,
-            FinalizerEvent::Cleanup(backup) => cleanup(backup, ctx.clone()).await,
+            FinalizerEvent::Cleanup(backup) => {
+                super::bounded_cleanup(
+                    "KafkaBackup",
+                    &ctx.client,
+                    backup.as_ref(),
+                    cleanup(backup.clone(), ctx.clone()),
+                )
+                .await
+            }
         }
     })
     .await
-    .map_err(|e| Error::Finalizer(Box::new(e)))
+    .map_err(|e| Error::Finalizer(Box::new(e)));
+
+    if result.is_ok() {
+        ctx.backoff.reset(&backoff_key);
+    }
+
+    result
 }
 
 /// Apply reconciliation (create/update)
@@ -98,20 +171,69 @@ async fn apply(backup: Arc<KafkaBackup>, ctx: Arc<Context>) -> Result<Action> {
         "Reconciling KafkaBackup"
     );
 
-    // Check if we've already processed this generation
+    // Every reconcile, scheduled requeues included, advances the backup age
+    backup_reconciler::record_backup_age(&backup, &namespace, Utc::now());
+
+    // Check if we've already processed this generation. A Validating one was
+    // picked up but never got through validation, so it is validated again.
     if let Some(status) = &backup.status {
-        if status.observed_generation == Some(generation) {
+        if status.observed_generation == Some(generation)
+            && status.phase.as_deref() != Some(PHASE_VALIDATING)
+        {
             // Generation unchanged, check if backup should run based on schedule
             if backup.spec.suspend {
                 info!("Backup is suspended, skipping");
                 return Ok(Action::requeue(Duration::from_secs(60)));
             }
+            if let Some(remaining) = backup_reconciler::suspension_remaining(&backup, Utc::now()) {
+                info!(
+                    resume_in_secs = remaining.as_secs(),
+                    "Backup is suspended until suspendUntil, skipping"
+                );
+                return Ok(Action::requeue(remaining));
+            }
+
+            // A Running backup is either still in flight or was orphaned; an
+            // Interrupted one was stopped by an operator shutdown
+            if matches!(
+                status.phase.as_deref(),
+                Some("Running") | Some(PHASE_INTERRUPTED)
+            ) {
+                return backup_reconciler::reconcile_running(
+                    &backup,
+                    &ctx.client,
+                    &namespace,
+                    &ctx.shutdown,
+                )
+                .await;
+            }
+
+            // Manual trigger annotation runs a backup regardless of schedule
+            if let Some(token) = backup_reconciler::pending_trigger(&backup) {
+                return backup_reconciler::execute_triggered_backup(
+                    &backup,
+                    &ctx.client,
+                    &namespace,
+                    &token,
+                    &ctx.shutdown,
+                )
+                .await;
+            }
 
             // Check schedule for next backup
-            return backup_reconciler::check_schedule(&backup, &ctx.client, &namespace).await;
+            return backup_reconciler::check_schedule(
+                &backup,
+                &ctx.client,
+                &namespace,
+                &ctx.shutdown,
+            )
+            .await;
         }
     }
 
+    let api: Api<KafkaBackup> = Api::namespaced(ctx.client.clone(), &namespace);
+    set_validating(&api, &name, backup.metadata.generation).await?;
+
     // Validate the spec
     if let Err(e) = backup_reconciler::validate(&backup) {
         warn!(error = %e, "Validation failed");
@@ -120,43 +242,110 @@ async fn apply(backup: Arc<KafkaBackup>, ctx: Arc<Context>) -> Result<Action> {
         return Ok(Action::requeue(Duration::from_secs(300)));
     }
 
+    let now = Utc::now();
+    if let Some(until) = backup.spec.suspend_until.filter(|until| *until <= now) {
+        warn!(suspend_until = %until, "suspendUntil is in the past and has no effect");
+    }
+
     // Update status to Ready and calculate next scheduled backup
     backup_reconciler::update_status_ready(&backup, &ctx.client, &namespace).await?;
 
+    if let Some(remaining) = backup_reconciler::suspension_remaining(&backup, now) {
+        info!(
+            resume_in_secs = remaining.as_secs(),
+            "Backup is suspended until suspendUntil, skipping"
+        );
+        return Ok(Action::requeue(remaining));
+    }
+
     // Check if backup should run now
-    backup_reconciler::check_schedule(&backup, &ctx.client, &namespace).await
+    backup_reconciler::check_schedule(&backup, &ctx.client, &namespace, &ctx.shutdown).await
 }
 
 /// Cleanup when resource is being deleted
-async fn cleanup(backup: Arc<KafkaBackup>, _ctx: Arc<Context>) -> Result<Action> {
+async fn cleanup(backup: Arc<KafkaBackup>, ctx: Arc<Context>) -> Result<Action> {
     let name = backup.name_any();
+    let namespace = backup.namespace().unwrap_or_else(|| "default".to_string());
     info!(name = %name, "Cleaning up KafkaBackup");
 
+    // Restores still reading from this backup resolve their storage through
+    // it, so the finalizer stays until they reach a terminal phase
+    backup_reconciler::check_deletion_allowed(&backup, &ctx.client, &namespace).await?;
+
     // Cancel any running backup operations.
     // Backup data is not deleted when the KafkaBackup resource is removed.
     // Operator-managed retention only runs after successful backups when enabled.
 
     metrics::CLEANUPS.with_label_values(&["KafkaBackup"]).inc();
+    // A deleted backup would otherwise keep reporting an ever-growing age
+    let _ = metrics::SECONDS_SINCE_LAST_BACKUP.remove_label_values(&[&namespace, &name]);
+    crate::reconcilers::forget_storage_probe("KafkaBackup", &namespace, &name);
+    crate::reconcilers::circuit_breaker::forget("KafkaBackup", &namespace, &name);
 
     Ok(Action::await_change())
 }
 
 /// Error policy for the controller
-fn error_policy(obj: Arc<KafkaBackup>, error: &Error, _ctx: Arc<Context>) -> Action {
+fn error_policy(obj: Arc<KafkaBackup>, error: &Error, ctx: Arc<Context>) -> Action {
     let name = obj.name_any();
+    let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
     error!(
         name = %name,
         error = %error,
         "Reconciliation failed, scheduling retry"
     );
 
-    // Exponential backoff based on error type
-    let requeue_duration = match error {
+    // Base interval by error type, grown exponentially per consecutive failure
+    let base = retry_base(error);
+
+    let requeue_duration = ctx
+        .backoff
+        .next_delay(&backoff::object_key("KafkaBackup", &namespace, &name), base);
+
+    Action::requeue(requeue_duration)
+}
+
+/// Base retry interval for the error a reconcile failed with
+fn retry_base(error: &Error) -> Duration {
+    match error.unwrap_finalizer() {
         Error::Kube(_) => Duration::from_secs(30),
         Error::Config(_) | Error::Validation(_) => Duration::from_secs(300),
+        Error::KafkaConnection(_) => Duration::from_secs(15),
         Error::Storage(_) => Duration::from_secs(60),
+        Error::StorageAuth(_) => Duration::from_secs(300),
+        Error::CredentialsRotated(_) => Duration::from_secs(10),
         _ => Duration::from_secs(30),
-    };
+    }
+}
 
-    Action::requeue(requeue_duration)
+#[cfg(test)]
+mod tests {
+    use kube::runtime::finalizer::Error as FinalizerError;
+
+    use super::*;
+
+    /// How `reconcile` hands errors to `error_policy`
+    fn applied(error: Error) -> Error {
+        Error::Finalizer(Box::new(FinalizerError::ApplyFailed(error)))
+    }
+
+    #[test]
+    fn unreachable_cluster_retries_quickly() {
+        let error = applied(Error::KafkaConnection("brokers unreachable".to_string()));
+        assert_eq!(retry_base(&error), Duration::from_secs(15));
+    }
+
+    #[test]
+    fn rejected_storage_credentials_back_off_slowly() {
+        let error = applied(Error::StorageAuth("access denied".to_string()));
+        assert_eq!(retry_base(&error), Duration::from_secs(300));
+    }
+
+    #[test]
+    fn rotated_credentials_retry_fast() {
+        let error = applied(Error::CredentialsRotated(
+            "SASL authentication failed".to_string(),
+        ));
+        assert_eq!(retry_base(&error), Duration::from_secs(10));
+    }
 }
@@ -3,6 +3,7 @@
 //! This module contains the controller implementations that watch for CRD changes
 //! and trigger reconciliation.
 
+pub mod backoff;
 mod backup_controller;
 mod offset_reset_controller;
 mod offset_rollback_controller;
@@ -15,17 +16,350 @@ pub use offset_rollback_controller::run as run_offset_rollback_controller;
 pub use restore_controller::run as run_restore_controller;
 pub use validation_controller::run as run_validation_controller;
 
-use kube::Client;
+use std::fmt::Debug;
+use std::future::Future;
+use std::hash::Hash;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use kube::api::{Api, Patch, PatchParams};
+use kube::runtime::controller::{self, Action};
+use kube::runtime::reflector::Store;
+use kube::{Client, Resource, ResourceExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::error;
+
+use self::backoff::Backoff;
+use crate::crd::Condition;
+use crate::error::{Error, Result};
+use crate::metrics;
+use crate::reconcilers::events;
 
 /// Shared context for all controllers
 pub struct Context {
     /// Kubernetes client
     pub client: Client,
+    /// Per-object reconcile failure tracking for retry backoff
+    pub backoff: Backoff,
+    /// Cancelled on SIGTERM/SIGINT; running backups and restores stop and
+    /// record the Interrupted phase
+    pub shutdown: CancellationToken,
 }
 
 impl Context {
     /// Create a new context
     pub fn new(client: Client) -> Self {
-        Self { client }
+        Self {
+            client,
+            backoff: Backoff::new(),
+            shutdown: CancellationToken::new(),
+        }
+    }
+}
+
+/// How often the managed-resources gauge is recomputed
+const MANAGED_RESOURCES_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Number of resources across the watcher caches of a kind's controllers
+fn cached_resources<K>(stores: &[Store<K>]) -> usize
+where
+    K: Resource + Clone + 'static,
+    K::DynamicType: Eq + Hash + Clone,
+{
+    stores.iter().map(|store| store.state().len()).sum()
+}
+
+/// Keep the managed-resources gauge of `kind` at the number of resources in
+/// its controllers' watcher caches. Counting the caches rather than
+/// reconciles and cleanups also drops resources that disappear without a
+/// cleanup, such as ones deleted while paused.
+pub fn spawn_managed_resources_gauge<K>(kind: &'static str, stores: Vec<Store<K>>) -> JoinHandle<()>
+where
+    K: Resource + Clone + Send + Sync + 'static,
+    K::DynamicType: Eq + Hash + Clone + Send + Sync,
+{
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(MANAGED_RESOURCES_INTERVAL);
+        loop {
+            interval.tick().await;
+            metrics::MANAGED_RESOURCES
+                .with_label_values(&[kind])
+                .set(cached_resources(&stores) as f64);
+        }
+    })
+}
+
+/// Whether a failed reconcile is down to the resource's own configuration.
+/// Those are reported on the resource and do not count against operator
+/// health, so one misconfigured CR cannot get the operator restarted.
+pub(crate) fn is_user_config_error<Q: 'static>(error: &controller::Error<Error, Q>) -> bool {
+    matches!(error, controller::Error::ReconcilerFailed(error, _) if error.is_user_config())
+}
+
+/// Default bound on a finalizer cleanup, in seconds
+const DEFAULT_CLEANUP_TIMEOUT_SECS: u64 = 60;
+
+/// Environment variable overriding [`DEFAULT_CLEANUP_TIMEOUT_SECS`]
+const CLEANUP_TIMEOUT_ENV: &str = "CLEANUP_TIMEOUT_SECS";
+
+/// Resolve the cleanup timeout from `CLEANUP_TIMEOUT_SECS`, ignoring values
+/// that are not a positive number of seconds
+fn cleanup_timeout(value: Option<&str>) -> Duration {
+    let secs = value
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_CLEANUP_TIMEOUT_SECS);
+    Duration::from_secs(secs)
+}
+
+/// Run a finalizer cleanup for at most the cleanup timeout. A cleanup that
+/// is still running by then is abandoned with a `CleanupTimedOut` warning
+/// event and treated as done, so a hung storage or Kafka call cannot leave
+/// the resource stuck in deletion. Errors the cleanup returns in time, such
+/// as a blocked deletion, are passed through unchanged.
+pub async fn bounded_cleanup<K, F>(
+    kind: &'static str,
+    client: &Client,
+    obj: &K,
+    cleanup: F,
+) -> Result<Action>
+where
+    K: Resource<DynamicType = ()>,
+    F: Future<Output = Result<Action>>,
+{
+    let timeout = cleanup_timeout(std::env::var(CLEANUP_TIMEOUT_ENV).ok().as_deref());
+    match tokio::time::timeout(timeout, cleanup).await {
+        Ok(result) => result,
+        Err(_) => {
+            let message = format!(
+                "Cleanup did not finish within {}s and was abandoned; the finalizer \
+                 was removed and cleanup may be incomplete",
+                timeout.as_secs()
+            );
+            error!(kind = %kind, name = %obj.name_any(), timeout_secs = timeout.as_secs(), "{}", message);
+            events::publish_warning(client, obj, "CleanupTimedOut", "Cleanup", &message).await;
+            Ok(Action::await_change())
+        }
+    }
+}
+
+/// Annotation raising the log level of a single resource's reconciles, e.g.
+/// `kafka.oso.sh/log-level: debug`
+pub const LOG_LEVEL_ANNOTATION: &str = "kafka.oso.sh/log-level";
+
+/// Levels [`LOG_LEVEL_ANNOTATION`] accepts. The tracing filter enables each
+/// inside reconcile spans whose `log_level` field carries it.
+pub const LOG_LEVEL_OVERRIDES: [&str; 2] = ["debug", "trace"];
+
+/// Log level requested by the resource's [`LOG_LEVEL_ANNOTATION`], if it is
+/// one of [`LOG_LEVEL_OVERRIDES`]
+pub fn log_level_override<K: Resource>(obj: &K) -> Option<&'static str> {
+    let requested = obj
+        .meta()
+        .annotations
+        .as_ref()?
+        .get(LOG_LEVEL_ANNOTATION)?
+        .trim()
+        .to_lowercase();
+    LOG_LEVEL_OVERRIDES
+        .into_iter()
+        .find(|level| *level == requested)
+}
+
+/// Annotation freezing operator actions on a resource from its next
+/// reconcile, e.g. `kafka.oso.sh/paused: "true"`. An operation already
+/// running finishes first. Status is left as is apart from the Paused
+/// condition.
+pub const PAUSED_ANNOTATION: &str = "kafka.oso.sh/paused";
+
+/// Condition type recorded while [`PAUSED_ANNOTATION`] is set
+pub const PAUSED_CONDITION: &str = "Paused";
+
+/// Whether the resource carries `kafka.oso.sh/paused: "true"`
+pub fn is_paused<K: Resource>(obj: &K) -> bool {
+    obj.meta()
+        .annotations
+        .as_ref()
+        .and_then(|annotations| annotations.get(PAUSED_ANNOTATION))
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+}
+
+/// Add or drop the Paused condition to match [`PAUSED_ANNOTATION`] and return
+/// whether the resource is paused. Reconciles call this before the finalizer
+/// so a paused resource is left entirely alone, deletion included.
+pub async fn sync_paused_condition<K>(api: &Api<K>, obj: &K) -> Result<bool>
+where
+    K: Resource + Clone + DeserializeOwned + Serialize + Debug,
+{
+    let paused = is_paused(obj);
+    // Each kind has its own status type, so read the conditions generically
+    let conditions: Vec<Condition> = serde_json::to_value(obj)
+        .ok()
+        .and_then(|value| value.pointer("/status/conditions").cloned())
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default();
+
+    if let Some(conditions) = paused_conditions(&conditions, paused, Utc::now()) {
+        let name = obj.meta().name.clone().unwrap_or_default();
+        api.patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(json!({ "status": { "conditions": conditions } })),
+        )
+        .await?;
+    }
+    Ok(paused)
+}
+
+/// Conditions to patch so the Paused condition matches `paused`, or `None`
+/// when they already do. Patching only on a change keeps the status update
+/// from triggering another one.
+fn paused_conditions(
+    conditions: &[Condition],
+    paused: bool,
+    now: DateTime<Utc>,
+) -> Option<Vec<serde_json::Value>> {
+    let recorded = conditions
+        .iter()
+        .any(|condition| condition.type_ == PAUSED_CONDITION);
+    if paused == recorded {
+        return None;
+    }
+
+    let mut updated: Vec<serde_json::Value> = conditions
+        .iter()
+        .filter(|condition| condition.type_ != PAUSED_CONDITION)
+        .filter_map(|condition| serde_json::to_value(condition).ok())
+        .collect();
+    if paused {
+        updated.push(json!({
+            "type": PAUSED_CONDITION,
+            "status": "True",
+            "lastTransitionTime": now,
+            "reason": "PausedByAnnotation",
+            "message": format!("Reconciliation is paused by the {} annotation", PAUSED_ANNOTATION)
+        }));
+    }
+    Some(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use kube::runtime::{reflector, watcher};
+
+    use crate::crd::KafkaBackup;
+
+    use super::*;
+
+    fn backup(annotation: Option<&str>) -> KafkaBackup {
+        let spec = serde_json::from_value(serde_json::json!({
+            "kafkaCluster": {"bootstrapServers": ["localhost:9092"]},
+            "topics": ["orders"],
+            "storage": {
+                "storageType": "pvc",
+                "pvc": {"claimName": "backups"}
+            },
+        }))
+        .unwrap();
+        let mut backup = KafkaBackup::new("nightly", spec);
+        backup.metadata.annotations = annotation
+            .map(|level| BTreeMap::from([(LOG_LEVEL_ANNOTATION.to_string(), level.to_string())]));
+        backup
+    }
+
+    #[test]
+    fn log_level_annotation_raises_supported_levels() {
+        assert_eq!(log_level_override(&backup(Some("debug"))), Some("debug"));
+        assert_eq!(log_level_override(&backup(Some(" TRACE "))), Some("trace"));
+    }
+
+    #[test]
+    fn paused_annotation_must_be_true() {
+        let mut paused = backup(None);
+        assert!(!is_paused(&paused));
+
+        for (value, expected) in [
+            ("true", true),
+            (" True ", true),
+            ("false", false),
+            ("yes", false),
+        ] {
+            paused.metadata.annotations = Some(BTreeMap::from([(
+                PAUSED_ANNOTATION.to_string(),
+                value.to_string(),
+            )]));
+            assert_eq!(is_paused(&paused), expected, "value {:?}", value);
+        }
+    }
+
+    #[test]
+    fn paused_condition_is_added_and_removed_once() {
+        let now = Utc::now();
+        let ready = Condition {
+            type_: "Ready".to_string(),
+            status: "True".to_string(),
+            last_transition_time: now,
+            reason: None,
+            message: None,
+        };
+
+        let added = paused_conditions(std::slice::from_ref(&ready), true, now).unwrap();
+        assert_eq!(added.len(), 2);
+        assert_eq!(added[1]["type"], PAUSED_CONDITION);
+        assert_eq!(added[1]["status"], "True");
+
+        let recorded: Vec<Condition> =
+            serde_json::from_value(serde_json::Value::Array(added)).unwrap();
+        assert!(paused_conditions(&recorded, true, now).is_none());
+
+        let cleared = paused_conditions(&recorded, false, now).unwrap();
+        assert_eq!(cleared.len(), 1);
+        assert_eq!(cleared[0]["type"], "Ready");
+        assert!(paused_conditions(std::slice::from_ref(&ready), false, now).is_none());
+    }
+
+    #[test]
+    fn managed_resources_are_the_cached_resources() {
+        let (first, mut first_writer) = reflector::store::<KafkaBackup>();
+        let (second, mut second_writer) = reflector::store::<KafkaBackup>();
+        let mut other = backup(None);
+        other.metadata.name = Some("hourly".to_string());
+
+        first_writer.apply_watcher_event(&watcher::Event::Apply(backup(None)));
+        first_writer.apply_watcher_event(&watcher::Event::Apply(backup(None)));
+        second_writer.apply_watcher_event(&watcher::Event::Apply(other));
+        let stores = [first, second];
+        assert_eq!(cached_resources(&stores), 2);
+
+        // Deleted without a cleanup, e.g. while paused
+        first_writer.apply_watcher_event(&watcher::Event::Delete(backup(None)));
+        assert_eq!(cached_resources(&stores), 1);
+    }
+
+    #[test]
+    fn cleanup_timeout_falls_back_to_default() {
+        assert_eq!(cleanup_timeout(Some("15")), Duration::from_secs(15));
+        assert_eq!(cleanup_timeout(Some(" 120 ")), Duration::from_secs(120));
+        for value in [None, Some(""), Some("0"), Some("-5"), Some("1m")] {
+            assert_eq!(
+                cleanup_timeout(value),
+                Duration::from_secs(DEFAULT_CLEANUP_TIMEOUT_SECS),
+                "value {:?}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn missing_or_unsupported_log_level_is_ignored() {
+        assert_eq!(log_level_override(&backup(None)), None);
+        assert_eq!(log_level_override(&backup(Some("warn"))), None);
+        assert_eq!(log_level_override(&backup(Some("verbose"))), None);
     }
 }
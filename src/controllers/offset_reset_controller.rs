@@ -5,6 +5,7 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures::future::join_all;
 use futures::StreamExt;
 use kube::{
     api::ListParams,
@@ -17,53 +18,117 @@ use kube::{
 };
 use tracing::{error, info, instrument, warn};
 
-use crate::controllers::Context;
+use crate::controllers::{backoff, Context};
 use crate::crd::KafkaOffsetReset;
 use crate::error::{Error, Result};
 use crate::metrics;
 use crate::reconcilers::offset_reset as offset_reset_reconciler;
+use crate::reconcilers::{recover_orphaned, scoped_apis, watch_namespaces};
 
 /// Finalizer name for KafkaOffsetReset resources
 const FINALIZER_NAME: &str = "kafka.oso.sh/offset-reset-finalizer";
 
 /// Run the KafkaOffsetReset controller
 pub async fn run(client: Client, context: Arc<Context>) {
-    let api: Api<KafkaOffsetReset> = Api::all(client.clone());
+    let apis: Vec<Api<KafkaOffsetReset>> = scoped_apis(&client, &watch_namespaces());
 
     // Verify CRD is installed
-    if let Err(e) = api.list(&ListParams::default().limit(1)).await {
-        error!("KafkaOffsetReset CRD not installed: {}", e);
-        return;
+    for api in &apis {
+        if let Err(e) = api.list(&ListParams::default().limit(1)).await {
+            error!("KafkaOffsetReset CRD not installed: {}", e);
+            return;
+        }
     }
 
+    // Nothing runs in this process yet, so anything still Running was left
+    // behind by the previous instance
+    recover_orphaned(
+        &client,
+        &apis,
+        "KafkaOffsetReset",
+        |reset: &KafkaOffsetReset| reset.status.as_ref()?.phase.clone(),
+        |_: &KafkaOffsetReset| None,
+        |reset: &KafkaOffsetReset| {
+            reset
+                .status
+                .as_ref()
+                .map(|status| status.conditions.clone())
+                .unwrap_or_default()
+        },
+        "Failed",
+        "Offset reset was orphaned by an operator restart",
+    )
+    .await;
+
     info!("Starting KafkaOffsetReset controller");
 
-    Controller::new(api, WatcherConfig::default())
-        .shutdown_on_signal()
-        .run(reconcile, error_policy, context)
-        .for_each(|result| async move {
-            match result {
-                Ok((obj, _action)) => {
-                    info!(
-                        name = %obj.name,
-                        namespace = obj.namespace.as_deref().unwrap_or("default"),
-                        "Reconciled KafkaOffsetReset"
-                    );
-                }
-                Err(e) => {
-                    error!(error = %e, "Reconciliation error");
-                    metrics::RECONCILIATION_ERRORS
-                        .with_label_values(&["KafkaOffsetReset"])
-                        .inc();
+    // One controller per watched namespace, or a single cluster-wide one
+    let controllers: Vec<_> = apis
+        .into_iter()
+        .map(|api| Controller::new(api, WatcherConfig::default()))
+        .collect();
+    let managed_gauge = super::spawn_managed_resources_gauge(
+        "KafkaOffsetReset",
+        controllers.iter().map(Controller::store).collect(),
+    );
+    join_all(controllers.into_iter().map(|controller| {
+        controller
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, context.clone())
+            .for_each(|result| async move {
+                match result {
+                    Ok((obj, _action)) => {
+                        info!(
+                            name = %obj.name,
+                            namespace = obj.namespace.as_deref().unwrap_or("default"),
+                            "Reconciled KafkaOffsetReset"
+                        );
+                        metrics::record_reconcile_success();
+                    }
+                    Err(e) => {
+                        error!(error = %e, "Reconciliation error");
+                        metrics::RECONCILIATION_ERRORS
+                            .with_label_values(&["KafkaOffsetReset"])
+                            .inc();
+                        if !super::is_user_config_error(&e) {
+                            metrics::record_reconcile_failure();
+                        }
+                    }
                 }
-            }
-        })
-        .await;
+            })
+    }))
+    .await;
+    managed_gauge.abort();
 }
 
 /// Main reconciliation function
-#[instrument(skip(ctx, obj), fields(name = %obj.name_any(), namespace = obj.namespace()))]
+#[instrument(
+    skip(ctx, obj),
+    fields(
+        name = %obj.name_any(),
+        namespace = obj.namespace(),
+        log_level = super::log_level_override(obj.as_ref()),
+    )
+)]
 async fn reconcile(obj: Arc<KafkaOffsetReset>, ctx: Arc<Context>) -> Result<Action> {
+    let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<KafkaOffsetReset> = Api::namespaced(ctx.client.clone(), &namespace);
+
+    // A paused resource is left untouched, finalizer included
+    if super::sync_paused_condition(&api, obj.as_ref()).await? {
+        info!("Reconciliation paused by annotation");
+        return Ok(Action::await_change());
+    }
+
+    // One-shot resources that already finished this generation need no work
+    // beyond the snapshot sweep; return before touching metrics so thousands
+    // of completed objects stay quiet. Checked after the paused sync so
+    // settled resources still report Paused.
+    if let Some(action) = offset_reset_reconciler::settled_action(&obj) {
+        sweep_snapshots(&obj, &ctx, false).await;
+        return Ok(action);
+    }
+
     let _timer = metrics::RECONCILE_DURATION
         .with_label_values(&["KafkaOffsetReset"])
         .start_timer();
@@ -71,17 +136,30 @@ async fn reconcile(obj: Arc<KafkaOffsetReset>, ctx: Arc<Context>) -> Result<Acti
         .with_label_values(&["KafkaOffsetReset"])
         .inc();
 
-    let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
-    let api: Api<KafkaOffsetReset> = Api::namespaced(ctx.client.clone(), &namespace);
+    let backoff_key = backoff::object_key("KafkaOffsetReset", &namespace, &obj.name_any());
 
-    finalizer(&api, FINALIZER_NAME, obj, |event| async {
+    let result = finalizer(&api, FINALIZER_NAME, obj, |event| async {
         match event {
             FinalizerEvent::Apply(reset) => apply(reset, ctx.clone()).await,
-            FinalizerEvent::Cleanup(reset) => cleanup(reset, ctx.clone()).await,
+            FinalizerEvent::Cleanup(reset) => {
+                super::bounded_cleanup(
+                    "KafkaOffsetReset",
+                    &ctx.client,
+                    reset.as_ref(),
+                    cleanup(reset.clone(), ctx.clone()),
+                )
+                .await
+            }
         }
     })
     .await
-    .map_err(|e| Error::Finalizer(Box::new(e)))
+    .map_err(|e| Error::Finalizer(Box::new(e)));
+
+    if result.is_ok() {
+        ctx.backoff.reset(&backoff_key);
+    }
+
+    result
 }
 
 /// Apply reconciliation (create/update)
@@ -135,10 +213,12 @@ async fn apply(reset: Arc<KafkaOffsetReset>, ctx: Arc<Context>) -> Result<Action
 }
 
 /// Cleanup when resource is being deleted
-async fn cleanup(reset: Arc<KafkaOffsetReset>, _ctx: Arc<Context>) -> Result<Action> {
+async fn cleanup(reset: Arc<KafkaOffsetReset>, ctx: Arc<Context>) -> Result<Action> {
     let name = reset.name_any();
     info!(name = %name, "Cleaning up KafkaOffsetReset");
 
+    sweep_snapshots(&reset, &ctx, true).await;
+
     metrics::CLEANUPS
         .with_label_values(&["KafkaOffsetReset"])
         .inc();
@@ -146,20 +226,46 @@ async fn cleanup(reset: Arc<KafkaOffsetReset>, _ctx: Arc<Context>) -> Result<Act
     Ok(Action::await_change())
 }
 
+/// Remove expired offset reset snapshots. Best effort; a storage problem must
+/// neither block deletion nor fail a finished reset.
+async fn sweep_snapshots(reset: &KafkaOffsetReset, ctx: &Context, finalizing: bool) {
+    let name = reset.name_any();
+    match offset_reset_reconciler::cleanup_snapshots(reset, &ctx.client, finalizing).await {
+        Ok(removed) if removed > 0 => {
+            info!(name = %name, removed, "Removed expired offset reset snapshots");
+        }
+        Ok(_) => {}
+        Err(e) => warn!(name = %name, error = %e, "Failed to clean up offset reset snapshots"),
+    }
+}
+
 /// Error policy for the controller
-fn error_policy(obj: Arc<KafkaOffsetReset>, error: &Error, _ctx: Arc<Context>) -> Action {
+fn error_policy(obj: Arc<KafkaOffsetReset>, error: &Error, ctx: Arc<Context>) -> Action {
     let name = obj.name_any();
+    let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
     error!(
         name = %name,
         error = %error,
         "Reconciliation failed, scheduling retry"
     );
 
-    let requeue_duration = match error {
+    // Base interval by error type, grown exponentially per consecutive failure
+    let base = retry_base(error);
+
+    let requeue_duration = ctx.backoff.next_delay(
+        &backoff::object_key("KafkaOffsetReset", &namespace, &name),
+        base,
+    );
+
+    Action::requeue(requeue_duration)
+}
+
+/// Base retry interval for the error a reconcile failed with
+fn retry_base(error: &Error) -> Duration {
+    match error.unwrap_finalizer() {
         Error::Kube(_) => Duration::from_secs(30),
         Error::Config(_) | Error::Validation(_) => Duration::from_secs(300),
+        Error::KafkaConnection(_) => Duration::from_secs(15),
         _ => Duration::from_secs(30),
-    };
-
-    Action::requeue(requeue_duration)
+    }
 }
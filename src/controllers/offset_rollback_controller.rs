@@ -5,6 +5,7 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures::future::join_all;
 use futures::StreamExt;
 use kube::{
     api::ListParams,
@@ -17,53 +18,115 @@ use kube::{
 };
 use tracing::{error, info, instrument, warn};
 
-use crate::controllers::Context;
+use crate::controllers::{backoff, Context};
 use crate::crd::KafkaOffsetRollback;
 use crate::error::{Error, Result};
 use crate::metrics;
 use crate::reconcilers::offset_rollback as offset_rollback_reconciler;
+use crate::reconcilers::{recover_orphaned, scoped_apis, watch_namespaces};
 
 /// Finalizer name for KafkaOffsetRollback resources
 const FINALIZER_NAME: &str = "kafka.oso.sh/offset-rollback-finalizer";
 
 /// Run the KafkaOffsetRollback controller
 pub async fn run(client: Client, context: Arc<Context>) {
-    let api: Api<KafkaOffsetRollback> = Api::all(client.clone());
+    let apis: Vec<Api<KafkaOffsetRollback>> = scoped_apis(&client, &watch_namespaces());
 
     // Verify CRD is installed
-    if let Err(e) = api.list(&ListParams::default().limit(1)).await {
-        error!("KafkaOffsetRollback CRD not installed: {}", e);
-        return;
+    for api in &apis {
+        if let Err(e) = api.list(&ListParams::default().limit(1)).await {
+            error!("KafkaOffsetRollback CRD not installed: {}", e);
+            return;
+        }
     }
 
+    // Nothing runs in this process yet, so anything still Running was left
+    // behind by the previous instance
+    recover_orphaned(
+        &client,
+        &apis,
+        "KafkaOffsetRollback",
+        |rollback: &KafkaOffsetRollback| rollback.status.as_ref()?.phase.clone(),
+        |_: &KafkaOffsetRollback| None,
+        |rollback: &KafkaOffsetRollback| {
+            rollback
+                .status
+                .as_ref()
+                .map(|status| status.conditions.clone())
+                .unwrap_or_default()
+        },
+        "Failed",
+        "Offset rollback was orphaned by an operator restart",
+    )
+    .await;
+
     info!("Starting KafkaOffsetRollback controller");
 
-    Controller::new(api, WatcherConfig::default())
-        .shutdown_on_signal()
-        .run(reconcile, error_policy, context)
-        .for_each(|result| async move {
-            match result {
-                Ok((obj, _action)) => {
-                    info!(
-                        name = %obj.name,
-                        namespace = obj.namespace.as_deref().unwrap_or("default"),
-                        "Reconciled KafkaOffsetRollback"
-                    );
-                }
-                Err(e) => {
-                    error!(error = %e, "Reconciliation error");
-                    metrics::RECONCILIATION_ERRORS
-                        .with_label_values(&["KafkaOffsetRollback"])
-                        .inc();
+    // One controller per watched namespace, or a single cluster-wide one
+    let controllers: Vec<_> = apis
+        .into_iter()
+        .map(|api| Controller::new(api, WatcherConfig::default()))
+        .collect();
+    let managed_gauge = super::spawn_managed_resources_gauge(
+        "KafkaOffsetRollback",
+        controllers.iter().map(Controller::store).collect(),
+    );
+    join_all(controllers.into_iter().map(|controller| {
+        controller
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, context.clone())
+            .for_each(|result| async move {
+                match result {
+                    Ok((obj, _action)) => {
+                        info!(
+                            name = %obj.name,
+                            namespace = obj.namespace.as_deref().unwrap_or("default"),
+                            "Reconciled KafkaOffsetRollback"
+                        );
+                        metrics::record_reconcile_success();
+                    }
+                    Err(e) => {
+                        error!(error = %e, "Reconciliation error");
+                        metrics::RECONCILIATION_ERRORS
+                            .with_label_values(&["KafkaOffsetRollback"])
+                            .inc();
+                        if !super::is_user_config_error(&e) {
+                            metrics::record_reconcile_failure();
+                        }
+                    }
                 }
-            }
-        })
-        .await;
+            })
+    }))
+    .await;
+    managed_gauge.abort();
 }
 
 /// Main reconciliation function
-#[instrument(skip(ctx, obj), fields(name = %obj.name_any(), namespace = obj.namespace()))]
+#[instrument(
+    skip(ctx, obj),
+    fields(
+        name = %obj.name_any(),
+        namespace = obj.namespace(),
+        log_level = super::log_level_override(obj.as_ref()),
+    )
+)]
 async fn reconcile(obj: Arc<KafkaOffsetRollback>, ctx: Arc<Context>) -> Result<Action> {
+    let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<KafkaOffsetRollback> = Api::namespaced(ctx.client.clone(), &namespace);
+
+    // A paused resource is left untouched, finalizer included
+    if super::sync_paused_condition(&api, obj.as_ref()).await? {
+        info!("Reconciliation paused by annotation");
+        return Ok(Action::await_change());
+    }
+
+    // One-shot resources that already finished this generation need no work;
+    // return before touching metrics so thousands of completed objects stay quiet.
+    // Checked after the paused sync so settled resources still report Paused.
+    if offset_rollback_reconciler::is_settled(&obj) {
+        return Ok(Action::await_change());
+    }
+
     let _timer = metrics::RECONCILE_DURATION
         .with_label_values(&["KafkaOffsetRollback"])
         .start_timer();
@@ -71,17 +134,30 @@ async fn reconcile(obj: Arc<KafkaOffsetRollback>, ctx: Arc<Context>) -> Result<A
         .with_label_values(&["KafkaOffsetRollback"])
         .inc();
 
-    let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
-    let api: Api<KafkaOffsetRollback> = Api::namespaced(ctx.client.clone(), &namespace);
+    let backoff_key = backoff::object_key("KafkaOffsetRollback", &namespace, &obj.name_any());
 
-    finalizer(&api, FINALIZER_NAME, obj, |event| async {
+    let result = finalizer(&api, FINALIZER_NAME, obj, |event| async {
         match event {
             FinalizerEvent::Apply(rollback) => apply(rollback, ctx.clone()).await,
-            FinalizerEvent::Cleanup(rollback) => cleanup(rollback, ctx.clone()).await,
+            FinalizerEvent::Cleanup(rollback) => {
+                super::bounded_cleanup(
+                    "KafkaOffsetRollback",
+                    &ctx.client,
+                    rollback.as_ref(),
+                    cleanup(rollback.clone(), ctx.clone()),
+                )
+                .await
+            }
         }
     })
     .await
-    .map_err(|e| Error::Finalizer(Box::new(e)))
+    .map_err(|e| Error::Finalizer(Box::new(e)));
+
+    if result.is_ok() {
+        ctx.backoff.reset(&backoff_key);
+    }
+
+    result
 }
 
 /// Apply reconciliation (create/update)
@@ -149,20 +225,33 @@ async fn cleanup(rollback: Arc<KafkaOffsetRollback>, _ctx: Arc<Context>) -> Resu
 }
 
 /// Error policy for the controller
-fn error_policy(obj: Arc<KafkaOffsetRollback>, error: &Error, _ctx: Arc<Context>) -> Action {
+fn error_policy(obj: Arc<KafkaOffsetRollback>, error: &Error, ctx: Arc<Context>) -> Action {
     let name = obj.name_any();
+    let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
     error!(
         name = %name,
         error = %error,
         "Reconciliation failed, scheduling retry"
     );
 
-    let requeue_duration = match error {
+    // Base interval by error type, grown exponentially per consecutive failure
+    let base = retry_base(error);
+
+    let requeue_duration = ctx.backoff.next_delay(
+        &backoff::object_key("KafkaOffsetRollback", &namespace, &name),
+        base,
+    );
+
+    Action::requeue(requeue_duration)
+}
+
+/// Base retry interval for the error a reconcile failed with
+fn retry_base(error: &Error) -> Duration {
+    match error.unwrap_finalizer() {
         Error::Kube(_) => Duration::from_secs(30),
         Error::Config(_) | Error::Validation(_) => Duration::from_secs(300),
+        Error::KafkaConnection(_) => Duration::from_secs(15),
         Error::SnapshotNotFound(_) => Duration::from_secs(60),
         _ => Duration::from_secs(30),
-    };
-
-    Action::requeue(requeue_duration)
+    }
 }
@@ -5,6 +5,7 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures::future::join_all;
 use futures::StreamExt;
 use kube::{
     api::ListParams,
@@ -17,52 +18,103 @@ use kube::{
 };
 use tracing::{error, info, instrument, warn};
 
-use crate::controllers::Context;
+use crate::controllers::{backoff, Context};
 use crate::crd::KafkaRestore;
 use crate::error::{Error, Result};
 use crate::metrics;
 use crate::reconcilers::restore as restore_reconciler;
+use crate::reconcilers::{
+    recover_orphaned, scoped_apis, set_validating, watch_namespaces, PHASE_INTERRUPTED,
+};
 
 /// Finalizer name for KafkaRestore resources
 const FINALIZER_NAME: &str = "kafka.oso.sh/restore-finalizer";
 
+/// How often a completed `rerunOnNewBackup` restore checks for a newer backup
+const RERUN_POLL_INTERVAL: Duration = Duration::from_secs(300);
+
 /// Run the KafkaRestore controller
 pub async fn run(client: Client, context: Arc<Context>) {
-    let api: Api<KafkaRestore> = Api::all(client.clone());
+    let apis: Vec<Api<KafkaRestore>> = scoped_apis(&client, &watch_namespaces());
 
     // Verify CRD is installed
-    if let Err(e) = api.list(&ListParams::default().limit(1)).await {
-        error!("KafkaRestore CRD not installed: {}", e);
-        return;
+    for api in &apis {
+        if let Err(e) = api.list(&ListParams::default().limit(1)).await {
+            error!("KafkaRestore CRD not installed: {}", e);
+            return;
+        }
     }
 
+    // Nothing runs in this process yet, so anything still Running was left
+    // behind by the previous instance
+    recover_orphaned(
+        &client,
+        &apis,
+        "KafkaRestore",
+        |restore: &KafkaRestore| restore.status.as_ref()?.phase.clone(),
+        |restore: &KafkaRestore| restore.status.as_ref()?.heartbeat_time,
+        |restore: &KafkaRestore| {
+            restore
+                .status
+                .as_ref()
+                .map(|status| status.conditions.clone())
+                .unwrap_or_default()
+        },
+        PHASE_INTERRUPTED,
+        "Restore was orphaned by an operator restart; resuming from checkpoint",
+    )
+    .await;
+
     info!("Starting KafkaRestore controller");
 
-    Controller::new(api, WatcherConfig::default())
-        .shutdown_on_signal()
-        .run(reconcile, error_policy, context)
-        .for_each(|result| async move {
-            match result {
-                Ok((obj, _action)) => {
-                    info!(
-                        name = %obj.name,
-                        namespace = obj.namespace.as_deref().unwrap_or("default"),
-                        "Reconciled KafkaRestore"
-                    );
-                }
-                Err(e) => {
-                    error!(error = %e, "Reconciliation error");
-                    metrics::RECONCILIATION_ERRORS
-                        .with_label_values(&["KafkaRestore"])
-                        .inc();
+    // One controller per watched namespace, or a single cluster-wide one
+    let controllers: Vec<_> = apis
+        .into_iter()
+        .map(|api| Controller::new(api, WatcherConfig::default()))
+        .collect();
+    let managed_gauge = super::spawn_managed_resources_gauge(
+        "KafkaRestore",
+        controllers.iter().map(Controller::store).collect(),
+    );
+    join_all(controllers.into_iter().map(|controller| {
+        controller
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, context.clone())
+            .for_each(|result| async move {
+                match result {
+                    Ok((obj, _action)) => {
+                        info!(
+                            name = %obj.name,
+                            namespace = obj.namespace.as_deref().unwrap_or("default"),
+                            "Reconciled KafkaRestore"
+                        );
+                        metrics::record_reconcile_success();
+                    }
+                    Err(e) => {
+                        error!(error = %e, "Reconciliation error");
+                        metrics::RECONCILIATION_ERRORS
+                            .with_label_values(&["KafkaRestore"])
+                            .inc();
+                        if !super::is_user_config_error(&e) {
+                            metrics::record_reconcile_failure();
+                        }
+                    }
                 }
-            }
-        })
-        .await;
+            })
+    }))
+    .await;
+    managed_gauge.abort();
 }
 
 /// Main reconciliation function
-#[instrument(skip(ctx, obj), fields(name = %obj.name_any(), namespace = obj.namespace()))]
+#[instrument(
+    skip(ctx, obj),
+    fields(
+        name = %obj.name_any(),
+        namespace = obj.namespace(),
+        log_level = super::log_level_override(obj.as_ref()),
+    )
+)]
 async fn reconcile(obj: Arc<KafkaRestore>, ctx: Arc<Context>) -> Result<Action> {
     let _timer = metrics::RECONCILE_DURATION
         .with_label_values(&["KafkaRestore"])
@@ -73,15 +125,36 @@ async fn reconcile(obj: Arc<KafkaRestore>, ctx: Arc<Context>) -> Result<Action>
 
     let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
     let api: Api<KafkaRestore> = Api::namespaced(ctx.client.clone(), &namespace);
+    let backoff_key = backoff::object_key("KafkaRestore", &namespace, &obj.name_any());
 
-    finalizer(&api, FINALIZER_NAME, obj, |event| async {
+    // A paused resource is left untouched, finalizer included
+    if super::sync_paused_condition(&api, obj.as_ref()).await? {
+        info!("Reconciliation paused by annotation");
+        return Ok(Action::await_change());
+    }
+
+    let result = finalizer(&api, FINALIZER_NAME, obj, |event| async {
         match event {
             FinalizerEvent::Apply(restore) => apply(restore, ctx.clone()).await,
-            FinalizerEvent::Cleanup(restore) => cleanup(restore, ctx.clone()).await,
+            FinalizerEvent::Cleanup(restore) => {
+                super::bounded_cleanup(
+                    "KafkaRestore",
+                    &ctx.client,
+                    restore.as_ref(),
+                    cleanup(restore.clone(), ctx.clone()),
+                )
+                .await
+            }
         }
     })
     .await
-    .map_err(|e| Error::Finalizer(Box::new(e)))
+    .map_err(|e| Error::Finalizer(Box::new(e)));
+
+    if result.is_ok() {
+        ctx.backoff.reset(&backoff_key);
+    }
+
+    result
 }
 
 /// Apply reconciliation (create/update)
@@ -102,20 +175,81 @@ async fn apply(restore: Arc<KafkaRestore>, ctx: Arc<Context>) -> Result<Action>
         if status.observed_generation == Some(generation) {
             // Check current phase
             match status.phase.as_deref() {
+                Some("Completed")
+                    if restore.spec.backup_ref.rerun_on_new_backup && !restore.spec.dry_run =>
+                {
+                    // Follow the backup: restore again once a newer one completes
+                    if !restore_reconciler::newer_backup_available(
+                        &restore,
+                        &ctx.client,
+                        &namespace,
+                    )
+                    .await?
+                    {
+                        return Ok(Action::requeue(RERUN_POLL_INTERVAL));
+                    }
+                }
                 Some("Completed") | Some("Failed") | Some("RolledBack") => {
                     // Terminal states - no action needed
                     return Ok(Action::await_change());
                 }
                 Some("Running") => {
                     // Monitor progress
-                    return restore_reconciler::monitor_progress(&restore, &ctx.client, &namespace)
-                        .await;
+                    return restore_reconciler::monitor_progress(
+                        &restore,
+                        &ctx.client,
+                        &namespace,
+                        &ctx.shutdown,
+                    )
+                    .await;
+                }
+                Some("TimedOut") | Some("Stalled") => {
+                    // Retry once the timeout retry delay has elapsed
+                    if let Some(wait) = restore_reconciler::timeout_retry_wait(&restore) {
+                        return Ok(Action::requeue(wait));
+                    }
                 }
                 _ => {}
             }
         }
     }
 
+    // backupRef, kafkaCluster and topics are fixed until an in-flight restore
+    // reaches a terminal phase
+    let changed = restore_reconciler::changed_locked_fields(&restore);
+    if !changed.is_empty() {
+        restore_reconciler::reject_locked_field_change(&restore, &ctx.client, &namespace, &changed)
+            .await?;
+        return restore_reconciler::follow_in_flight(
+            &restore,
+            &ctx.client,
+            &namespace,
+            &ctx.shutdown,
+        )
+        .await;
+    }
+    if restore_reconciler::locked_field_change_reverted(&restore) {
+        restore_reconciler::clear_locked_field_change(&restore, &ctx.client, &namespace).await?;
+        return restore_reconciler::follow_in_flight(
+            &restore,
+            &ctx.client,
+            &namespace,
+            &ctx.shutdown,
+        )
+        .await;
+    }
+
+    // A new generation shows up as Validating before any slow checks run
+    if restore
+        .status
+        .as_ref()
+        .and_then(|status| status.observed_generation)
+        != Some(generation)
+    {
+        let api: Api<KafkaRestore> = Api::namespaced(ctx.client.clone(), &namespace);
+        set_validating(&api, &name, restore.metadata.generation).await?;
+    }
+
     // Validate the spec
     if let Err(e) = restore_reconciler::validate(&restore) {
         warn!(error = %e, "Validation failed");
@@ -125,7 +259,9 @@ async fn apply(restore: Arc<KafkaRestore>, ctx: Arc<Context>) -> Result<Action>
     }
 
     // Execute restore operation
-    restore_reconciler::execute(&restore, &ctx.client, &namespace).await
+    // Restores stopped by an operator shutdown (Interrupted) land here and
+    // resume from their checkpoint
+    restore_reconciler::execute(&restore, &ctx.client, &namespace, &ctx.shutdown).await
 }
 
 /// Cleanup when resource is being deleted
@@ -137,26 +273,70 @@ async fn cleanup(restore: Arc<KafkaRestore>, _ctx: Arc<Context>) -> Result<Actio
     // Clean up rollback snapshots if no longer needed
 
     metrics::CLEANUPS.with_label_values(&["KafkaRestore"]).inc();
+    let namespace = restore.namespace().unwrap_or_else(|| "default".to_string());
+    crate::reconcilers::forget_storage_probe("KafkaRestore", &namespace, &name);
+    crate::reconcilers::circuit_breaker::forget("KafkaRestore", &namespace, &name);
 
     Ok(Action::await_change())
 }
 
 /// Error policy for the controller
-fn error_policy(obj: Arc<KafkaRestore>, error: &Error, _ctx: Arc<Context>) -> Action {
+fn error_policy(obj: Arc<KafkaRestore>, error: &Error, ctx: Arc<Context>) -> Action {
     let name = obj.name_any();
+    let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
     error!(
         name = %name,
         error = %error,
         "Reconciliation failed, scheduling retry"
     );
 
-    let requeue_duration = match error {
+    // Base interval by error type, grown exponentially per consecutive failure
+    let base = retry_base(error);
+
+    let requeue_duration = ctx.backoff.next_delay(
+        &backoff::object_key("KafkaRestore", &namespace, &name),
+        base,
+    );
+
+    Action::requeue(requeue_duration)
+}
+
+/// Base retry interval for the error a reconcile failed with
+fn retry_base(error: &Error) -> Duration {
+    match error.unwrap_finalizer() {
         Error::Kube(_) => Duration::from_secs(30),
         Error::Config(_) | Error::Validation(_) => Duration::from_secs(300),
+        Error::KafkaConnection(_) => Duration::from_secs(15),
         Error::Storage(_) | Error::BackupNotFound(_) => Duration::from_secs(60),
+        Error::StorageAuth(_) => Duration::from_secs(300),
+        Error::CredentialsRotated(_) => Duration::from_secs(10),
         Error::Rollback(_) => Duration::from_secs(30),
         _ => Duration::from_secs(30),
-    };
+    }
+}
 
-    Action::requeue(requeue_duration)
+#[cfg(test)]
+mod tests {
+    use kube::runtime::finalizer::Error as FinalizerError;
+
+    use super::*;
+
+    /// How `reconcile` hands errors to `error_policy`
+    fn applied(error: Error) -> Error {
+        Error::Finalizer(Box::new(FinalizerError::ApplyFailed(error)))
+    }
+
+    #[test]
+    fn rejected_storage_credentials_back_off_slowly() {
+        let error = applied(Error::StorageAuth("access denied".to_string()));
+        assert_eq!(retry_base(&error), Duration::from_secs(300));
+    }
+
+    #[test]
+    fn rotated_credentials_retry_fast() {
+        let error = applied(Error::CredentialsRotated(
+            "SASL authentication failed".to_string(),
+        ));
+        assert_eq!(retry_base(&error), Duration::from_secs(10));
+    }
 }
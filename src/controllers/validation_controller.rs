@@ -5,6 +5,7 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures::future::join_all;
 use futures::StreamExt;
 use kube::{
     api::ListParams,
@@ -17,52 +18,98 @@ use kube::{
 };
 use tracing::{error, info, instrument, warn};
 
-use crate::controllers::Context;
+use crate::controllers::{backoff, Context};
 use crate::crd::KafkaBackupValidation;
 use crate::error::{Error, Result};
 use crate::metrics;
 use crate::reconcilers::validation as validation_reconciler;
+use crate::reconcilers::{recover_orphaned, scoped_apis, watch_namespaces};
 
 /// Finalizer name for KafkaBackupValidation resources
 const FINALIZER_NAME: &str = "kafka.oso.sh/validation-finalizer";
 
 /// Run the KafkaBackupValidation controller
 pub async fn run(client: Client, context: Arc<Context>) {
-    let api: Api<KafkaBackupValidation> = Api::all(client.clone());
+    let apis: Vec<Api<KafkaBackupValidation>> = scoped_apis(&client, &watch_namespaces());
 
     // Verify CRD is installed
-    if let Err(e) = api.list(&ListParams::default().limit(1)).await {
-        error!("KafkaBackupValidation CRD not installed: {}", e);
-        return;
+    for api in &apis {
+        if let Err(e) = api.list(&ListParams::default().limit(1)).await {
+            error!("KafkaBackupValidation CRD not installed: {}", e);
+            return;
+        }
     }
 
+    // Nothing runs in this process yet, so anything still Running was left
+    // behind by the previous instance
+    recover_orphaned(
+        &client,
+        &apis,
+        "KafkaBackupValidation",
+        |validation: &KafkaBackupValidation| validation.status.as_ref()?.phase.clone(),
+        |_: &KafkaBackupValidation| None,
+        |validation: &KafkaBackupValidation| {
+            validation
+                .status
+                .as_ref()
+                .map(|status| status.conditions.clone())
+                .unwrap_or_default()
+        },
+        "Failed",
+        "Validation was orphaned by an operator restart",
+    )
+    .await;
+
     info!("Starting KafkaBackupValidation controller");
 
-    Controller::new(api, WatcherConfig::default())
-        .shutdown_on_signal()
-        .run(reconcile, error_policy, context)
-        .for_each(|result| async move {
-            match result {
-                Ok((obj, _action)) => {
-                    info!(
-                        name = %obj.name,
-                        namespace = obj.namespace.as_deref().unwrap_or("default"),
-                        "Reconciled KafkaBackupValidation"
-                    );
-                }
-                Err(e) => {
-                    error!(error = %e, "Reconciliation error");
-                    metrics::RECONCILIATION_ERRORS
-                        .with_label_values(&["KafkaBackupValidation"])
-                        .inc();
+    // One controller per watched namespace, or a single cluster-wide one
+    let controllers: Vec<_> = apis
+        .into_iter()
+        .map(|api| Controller::new(api, WatcherConfig::default()))
+        .collect();
+    let managed_gauge = super::spawn_managed_resources_gauge(
+        "KafkaBackupValidation",
+        controllers.iter().map(Controller::store).collect(),
+    );
+    join_all(controllers.into_iter().map(|controller| {
+        controller
+            .shutdown_on_signal()
+            .run(reconcile, error_policy, context.clone())
+            .for_each(|result| async move {
+                match result {
+                    Ok((obj, _action)) => {
+                        info!(
+                            name = %obj.name,
+                            namespace = obj.namespace.as_deref().unwrap_or("default"),
+                            "Reconciled KafkaBackupValidation"
+                        );
+                        metrics::record_reconcile_success();
+                    }
+                    Err(e) => {
+                        error!(error = %e, "Reconciliation error");
+                        metrics::RECONCILIATION_ERRORS
+                            .with_label_values(&["KafkaBackupValidation"])
+                            .inc();
+                        if !super::is_user_config_error(&e) {
+                            metrics::record_reconcile_failure();
+                        }
+                    }
                 }
-            }
-        })
-        .await;
+            })
+    }))
+    .await;
+    managed_gauge.abort();
 }
 
 /// Main reconciliation function
-#[instrument(skip(ctx, obj), fields(name = %obj.name_any(), namespace = obj.namespace()))]
+#[instrument(
+    skip(ctx, obj),
+    fields(
+        name = %obj.name_any(),
+        namespace = obj.namespace(),
+        log_level = super::log_level_override(obj.as_ref()),
+    )
+)]
 async fn reconcile(obj: Arc<KafkaBackupValidation>, ctx: Arc<Context>) -> Result<Action> {
     let _timer = metrics::RECONCILE_DURATION
         .with_label_values(&["KafkaBackupValidation"])
@@ -73,15 +120,36 @@ async fn reconcile(obj: Arc<KafkaBackupValidation>, ctx: Arc<Context>) -> Result
 
     let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
     let api: Api<KafkaBackupValidation> = Api::namespaced(ctx.client.clone(), &namespace);
+    let backoff_key = backoff::object_key("KafkaBackupValidation", &namespace, &obj.name_any());
 
-    finalizer(&api, FINALIZER_NAME, obj, |event| async {
+    // A paused resource is left untouched, finalizer included
+    if super::sync_paused_condition(&api, obj.as_ref()).await? {
+        info!("Reconciliation paused by annotation");
+        return Ok(Action::await_change());
+    }
+
+    let result = finalizer(&api, FINALIZER_NAME, obj, |event| async {
         match event {
             FinalizerEvent::Apply(validation) => apply(validation, ctx.clone()).await,
-            FinalizerEvent::Cleanup(validation) => cleanup(validation, ctx.clone()).await,
+            FinalizerEvent::Cleanup(validation) => {
+                super::bounded_cleanup(
+                    "KafkaBackupValidation",
+                    &ctx.client,
+                    validation.as_ref(),
+                    cleanup(validation.clone(), ctx.clone()),
+                )
+                .await
+            }
         }
     })
     .await
-    .map_err(|e| Error::Finalizer(Box::new(e)))
+    .map_err(|e| Error::Finalizer(Box::new(e)));
+
+    if result.is_ok() {
+        ctx.backoff.reset(&backoff_key);
+    }
+
+    result
 }
 
 /// Apply reconciliation (create/update)
@@ -153,22 +221,36 @@ async fn cleanup(validation: Arc<KafkaBackupValidation>, _ctx: Arc<Context>) ->
 }
 
 /// Error policy for the controller
-fn error_policy(obj: Arc<KafkaBackupValidation>, error: &Error, _ctx: Arc<Context>) -> Action {
+fn error_policy(obj: Arc<KafkaBackupValidation>, error: &Error, ctx: Arc<Context>) -> Action {
     let name = obj.name_any();
+    let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
     error!(
         name = %name,
         error = %error,
         "Reconciliation failed, scheduling retry"
     );
 
-    let requeue_duration = match error {
+    // Base interval by error type, grown exponentially per consecutive failure
+    let base = retry_base(error);
+
+    let requeue_duration = ctx.backoff.next_delay(
+        &backoff::object_key("KafkaBackupValidation", &namespace, &name),
+        base,
+    );
+
+    Action::requeue(requeue_duration)
+}
+
+/// Base retry interval for the error a reconcile failed with
+fn retry_base(error: &Error) -> Duration {
+    match error.unwrap_finalizer() {
         Error::Kube(_) => Duration::from_secs(30),
         Error::Config(_) | Error::Validation(_) => Duration::from_secs(300),
+        Error::KafkaConnection(_) => Duration::from_secs(15),
         Error::Storage(_) => Duration::from_secs(60),
+        Error::StorageAuth(_) => Duration::from_secs(300),
         Error::Evidence(_) => Duration::from_secs(60),
         Error::Notification(_) => Duration::from_secs(30),
         _ => Duration::from_secs(30),
-    };
-
-    Action::requeue(requeue_duration)
+    }
 }
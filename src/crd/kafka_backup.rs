@@ -1,5 +1,7 @@
 //! KafkaBackup Custom Resource Definition
 
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use kube::CustomResource;
 use schemars::JsonSchema;
@@ -19,7 +21,9 @@ use serde::{Deserialize, Serialize};
     printcolumn = r#"{"name": "Phase", "type": "string", "jsonPath": ".status.phase"}"#,
     printcolumn = r#"{"name": "Last Backup", "type": "string", "jsonPath": ".status.lastBackupTime"}"#,
     printcolumn = r#"{"name": "Records", "type": "integer", "jsonPath": ".status.recordsProcessed"}"#,
+    printcolumn = r#"{"name": "Topics", "type": "integer", "jsonPath": ".status.topicsBackedUp"}"#,
     printcolumn = r#"{"name": "Resumable", "type": "boolean", "jsonPath": ".status.resumable"}"#,
+    printcolumn = r#"{"name": "Summary", "type": "string", "priority": 1, "jsonPath": ".status.summary"}"#,
     printcolumn = r#"{"name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp"}"#
 )]
 #[serde(rename_all = "camelCase")]
@@ -41,6 +45,13 @@ pub struct KafkaBackupSpec {
     #[serde(default = "default_compression_level")]
     pub compression_level: i32,
 
+    /// Per-topic compression overrides, keyed by topic name. Unlisted topics
+    /// use `compression` and `compressionLevel`. The engine applies one
+    /// setting per run, so overrides must agree and, when they differ from
+    /// `compression`, list every topic in `topics`.
+    #[serde(default)]
+    pub topic_compression: std::collections::HashMap<String, CompressionOverride>,
+
     /// Maximum segment size in bytes before rotating
     #[serde(default = "default_segment_max_bytes")]
     pub segment_max_bytes: u64,
@@ -61,22 +72,60 @@ pub struct KafkaBackupSpec {
     #[serde(default = "default_true")]
     pub include_offset_headers: bool,
 
-    /// Source cluster identifier recorded in manifests and offset headers
+    /// Source cluster identifier recorded in manifests and offset headers.
+    /// Detected from cluster metadata when unset.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_cluster_id: Option<String>,
 
+    /// Ownership labels such as `team: payments`, stored with every backup
+    /// in `labels.json` next to its manifest and shown on restores of it.
+    /// Keys and values follow Kubernetes label syntax.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, String>,
+
+    /// Record a CRC32 of every segment in `checksums.json` next to the
+    /// manifest once a run finishes, read back from storage, so restores with
+    /// `verifyChecksums` catch corruption that leaves sizes unchanged. Each
+    /// segment is read once more after it is written.
+    #[serde(default)]
+    pub segment_checksums: bool,
+
     /// Poll interval for continuous mode in milliseconds
     #[serde(default = "default_poll_interval_ms")]
     pub poll_interval_ms: u64,
 
-    /// Snapshot consumer group offsets to storage after each backup cycle
+    /// Snapshot consumer group offsets to storage after each backup cycle.
+    /// The operator also stores the offsets committed on the backed-up topics
+    /// in `consumer-offsets.json`, for restores with `restoreOffsetsOnly`.
     #[serde(default)]
     pub consumer_group_snapshot: bool,
 
+    /// Drop records whose key and value together exceed this many bytes.
+    /// kafka-backup-core has no per-record hook, so once a run finishes its
+    /// segments holding larger records are rewritten without them; dropped
+    /// records are counted in `status.skippedRecords`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_message_bytes: Option<u64>,
+
     /// Cron schedule for automated backups
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schedule: Option<String>,
 
+    /// Shortest allowed gap in seconds between scheduled runs. Defaults to
+    /// 60; runs closer together than this tend to overlap and queue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_schedule_interval_secs: Option<u64>,
+
+    /// Accept a schedule that fires more often than
+    /// `minScheduleIntervalSecs`, logging a warning instead of rejecting it
+    #[serde(default)]
+    pub allow_frequent_schedule: bool,
+
+    /// Upper bound in seconds between schedule checks. Falls back to the
+    /// `BACKUP_MAX_REQUEUE_SECS` environment variable, then 300.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_requeue_secs: Option<u64>,
+
     /// Checkpoint configuration for resumable backups
     #[serde(skip_serializing_if = "Option::is_none")]
     pub checkpoint: Option<CheckpointSpec>,
@@ -97,11 +146,59 @@ pub struct KafkaBackupSpec {
     #[serde(default)]
     pub suspend: bool,
 
+    /// Suspend backups until this time (RFC 3339). Backups resume
+    /// automatically once it has passed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspend_until: Option<DateTime<Utc>>,
+
+    /// Abort a backup run that takes longer than this many seconds. The
+    /// resource moves to the TimedOut phase and is retried later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+
+    /// Abort a backup run once it has written more than this many bytes. The
+    /// resource moves to the AbortedSizeLimit phase. Data already written is
+    /// kept in storage, marked with an `aborted.json` file so garbage
+    /// collection leaves it alone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_total_bytes: Option<u64>,
+
+    /// Where the backup engine tracks consumed offsets (sqlite, memory).
+    /// `memory` avoids writing an offset database for one-shot backups but
+    /// requires `checkpoint.enabled: false`, since nothing survives to resume
+    /// from.
+    #[serde(default)]
+    pub offset_storage_backend: OffsetStorageBackendKind,
+
     /// Metrics configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<MetricsSpec>,
 }
 
+/// Offset storage backend for a backup
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OffsetStorageBackendKind {
+    /// SQLite database kept with the backup, allowing interrupted runs to resume
+    #[default]
+    Sqlite,
+    /// In-process only; lost when the run ends
+    Memory,
+}
+
+/// Compression settings for a single topic
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompressionOverride {
+    /// Compression algorithm (none, lz4, zstd)
+    #[serde(default = "default_compression")]
+    pub compression: String,
+
+    /// Compression level (1-22 for zstd)
+    #[serde(default = "default_compression_level")]
+    pub compression_level: i32,
+}
+
 fn default_compression() -> String {
     "zstd".to_string()
 }
@@ -142,6 +239,12 @@ pub struct KafkaClusterSpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ca_secret: Option<CaSecretRef>,
 
+    /// Directory of mounted TLS files (e.g. a cert-manager CSI or projected
+    /// volume) containing `ca.crt` and optionally `tls.crt`/`tls.key`.
+    /// Mutually exclusive with tlsSecret and caSecret.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_cert_dir: Option<String>,
+
     /// SASL configuration secret reference
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sasl_secret: Option<SaslSecretRef>,
@@ -149,6 +252,13 @@ pub struct KafkaClusterSpec {
     /// Kafka TCP connection tuning
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connection: Option<KafkaConnectionSpec>,
+
+    /// Client property overrides. `socket.keepalive.enable` and
+    /// `socket.nagle.disable` ("true" or "false") are applied to the
+    /// connection settings. Security and bootstrap properties are rejected;
+    /// other properties are ignored and listed in status.ignoredClientConfig.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub client_config: HashMap<String, String>,
 }
 
 fn default_security_protocol() -> String {
@@ -225,11 +335,15 @@ pub struct CaSecretRef {
 }
 
 /// SASL secret reference
+///
+/// Credentials come from either the secret `name` or the `*Env` operator
+/// environment variables (e.g. injected by a Vault agent), never both.
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SaslSecretRef {
     /// Secret name
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /// SASL mechanism (PLAIN, SCRAM-SHA-256, SCRAM-SHA-512)
     pub mechanism: String,
     /// Username key in secret
@@ -238,6 +352,12 @@ pub struct SaslSecretRef {
     /// Password key in secret
     #[serde(default = "default_password_key")]
     pub password_key: String,
+    /// Operator environment variable (`KAFKA_BACKUP_CRED_*`) holding the username
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username_env: Option<String>,
+    /// Operator environment variable (`KAFKA_BACKUP_CRED_*`) holding the password
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_env: Option<String>,
 }
 
 fn default_username_key() -> String {
@@ -281,14 +401,17 @@ fn default_storage_type() -> String {
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PvcStorageSpec {
-    /// PVC claim name
+    /// PVC claim name. The claim must be mounted into the operator pod at
+    /// `/data/<claimName>`.
     pub claim_name: String,
 
     /// Sub-path within the PVC
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sub_path: Option<String>,
 
-    /// Auto-create PVC if not exists
+    /// Auto-create PVC if not exists. The claim is not owned by the
+    /// KafkaBackup, so its backups outlive the resource. Backups only run
+    /// once it is mounted into the operator pod at `/data/<claimName>`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub create: Option<PvcCreateSpec>,
 }
@@ -348,16 +471,25 @@ pub struct S3StorageSpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prefix: Option<String>,
 
+    /// Write each backup under `<prefix>/YYYY/MM/DD/` for the day it started,
+    /// so lifecycle policies can expire backups by date
+    #[serde(default)]
+    pub date_layout: bool,
+
     /// Credentials secret reference
     pub credentials_secret: S3CredentialsRef,
 }
 
 /// S3 credentials secret reference
+///
+/// Credentials come from either the secret `name` or the `*Env` operator
+/// environment variables, never both.
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct S3CredentialsRef {
     /// Secret name
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 
     /// Access key ID key in secret
     #[serde(default = "default_aws_access_key_id")]
@@ -366,6 +498,14 @@ pub struct S3CredentialsRef {
     /// Secret access key key in secret
     #[serde(default = "default_aws_secret_access_key")]
     pub secret_access_key_key: String,
+
+    /// Operator environment variable (`KAFKA_BACKUP_CRED_*`) holding the access key ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access_key_id_env: Option<String>,
+
+    /// Operator environment variable (`KAFKA_BACKUP_CRED_*`) holding the secret access key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_access_key_env: Option<String>,
 }
 
 fn default_aws_access_key_id() -> String {
@@ -390,7 +530,14 @@ pub struct AzureStorageSpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prefix: Option<String>,
 
+    /// Write each backup under `<prefix>/YYYY/MM/DD/` for the day it started,
+    /// so lifecycle policies can expire backups by date
+    #[serde(default)]
+    pub date_layout: bool,
+
     /// Custom endpoint URL (for Azure Government, China, or private endpoints)
+    /// where the default `blob.core.windows.net` suffix does not apply, e.g.
+    /// `https://<account>.blob.core.usgovcloudapi.net`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub endpoint: Option<String>,
 
@@ -416,15 +563,22 @@ pub struct AzureStorageSpec {
 }
 
 /// Azure credentials secret reference (account key)
+///
+/// The key comes from either the secret `name` or `accountKeyEnv`, never both.
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AzureCredentialsRef {
     /// Secret name
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 
     /// Account key key in secret
     #[serde(default = "default_azure_account_key")]
     pub account_key_key: String,
+
+    /// Operator environment variable (`KAFKA_BACKUP_CRED_*`) holding the account key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_key_env: Option<String>,
 }
 
 fn default_azure_account_key() -> String {
@@ -432,15 +586,22 @@ fn default_azure_account_key() -> String {
 }
 
 /// Azure SAS token secret reference
+///
+/// The token comes from either the secret `name` or `sasTokenEnv`, never both.
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AzureSasTokenRef {
     /// Secret name
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 
     /// SAS token key in secret
     #[serde(default = "default_azure_sas_token")]
     pub sas_token_key: String,
+
+    /// Operator environment variable (`KAFKA_BACKUP_CRED_*`) holding the SAS token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sas_token_env: Option<String>,
 }
 
 fn default_azure_sas_token() -> String {
@@ -448,11 +609,15 @@ fn default_azure_sas_token() -> String {
 }
 
 /// Azure Service Principal secret reference
+///
+/// Credentials come from either the secret `name` or the `*Env` operator
+/// environment variables, never both.
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AzureServicePrincipalRef {
     /// Secret name
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 
     /// Client ID key in secret
     #[serde(default = "default_azure_client_id")]
@@ -465,6 +630,18 @@ pub struct AzureServicePrincipalRef {
     /// Client secret key in secret
     #[serde(default = "default_azure_client_secret")]
     pub client_secret_key: String,
+
+    /// Operator environment variable (`KAFKA_BACKUP_CRED_*`) holding the client ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id_env: Option<String>,
+
+    /// Operator environment variable (`KAFKA_BACKUP_CRED_*`) holding the tenant ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id_env: Option<String>,
+
+    /// Operator environment variable (`KAFKA_BACKUP_CRED_*`) holding the client secret
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret_env: Option<String>,
 }
 
 fn default_azure_client_id() -> String {
@@ -490,20 +667,33 @@ pub struct GcsStorageSpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prefix: Option<String>,
 
+    /// Write each backup under `<prefix>/YYYY/MM/DD/` for the day it started,
+    /// so lifecycle policies can expire backups by date
+    #[serde(default)]
+    pub date_layout: bool,
+
     /// Credentials secret reference
     pub credentials_secret: GcsCredentialsRef,
 }
 
 /// GCS credentials secret reference
+///
+/// The service account JSON comes from either the secret `name` or
+/// `serviceAccountJsonEnv`, never both.
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GcsCredentialsRef {
     /// Secret name
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 
     /// Service account JSON key in secret
     #[serde(default = "default_gcs_service_account")]
     pub service_account_json_key: String,
+
+    /// Operator environment variable (`KAFKA_BACKUP_CRED_*`) holding the service account JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_account_json_env: Option<String>,
 }
 
 fn default_gcs_service_account() -> String {
@@ -559,9 +749,19 @@ pub struct RateLimitingSpec {
     #[serde(default)]
     pub bytes_per_sec: u64,
 
-    /// Maximum concurrent partitions
+    /// Maximum partitions read (backups) or written (restores) at once; at
+    /// least 1. Each partition in flight can hold up to a full segment
+    /// (`segmentMaxBytes`) in memory, so peak memory grows with this value:
+    /// size the operator's memory limit for roughly this many segments.
     #[serde(default = "default_max_concurrent_partitions")]
     pub max_concurrent_partitions: usize,
+
+    /// Restores only: raise the records-per-second limit linearly from 10%
+    /// to 100% of `recordsPerSec` over this many seconds (at least 60), so a
+    /// fresh topic is not hit at full rate straight away. While the ramp
+    /// lasts topics are restored one at a time, the limit rising between them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ramp_up_secs: Option<u64>,
 }
 
 fn default_max_concurrent_partitions() -> usize {
@@ -687,7 +887,8 @@ pub struct RetentionSpec {
 #[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KafkaBackupStatus {
-    /// Current phase (Pending, Running, Completed, Failed)
+    /// Current phase (Pending, Validating, PvcNotBound, Running, Completed, Failed, TimedOut,
+    /// AbortedSizeLimit)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phase: Option<String>,
 
@@ -695,6 +896,10 @@ pub struct KafkaBackupStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 
+    /// One-line summary of the last completed run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
     /// Last backup timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_backup_time: Option<DateTime<Utc>>,
@@ -711,6 +916,11 @@ pub struct KafkaBackupStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_scheduled_backup: Option<DateTime<Utc>>,
 
+    /// Last `kafka.oso.sh/trigger` annotation value acted on. A manual backup
+    /// runs only when the annotation differs from this token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_trigger_token: Option<String>,
+
     /// Records processed in current/last backup
     #[serde(skip_serializing_if = "Option::is_none")]
     pub records_processed: Option<u64>,
@@ -723,6 +933,18 @@ pub struct KafkaBackupStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub segments_completed: Option<u64>,
 
+    /// Records the last backup dropped for exceeding maxMessageBytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped_records: Option<u64>,
+
+    /// Topics included in the last backup
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topics_backed_up: Option<u32>,
+
+    /// Partitions included in the last backup, summed across topics
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partitions_backed_up: Option<u32>,
+
     /// Whether checkpointing is enabled
     #[serde(skip_serializing_if = "Option::is_none")]
     pub checkpoint_enabled: Option<bool>,
@@ -735,6 +957,11 @@ pub struct KafkaBackupStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resumable: Option<bool>,
 
+    /// Last heartbeat from the running backup. A Running backup whose
+    /// heartbeat goes stale was interrupted and is resumed when resumable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heartbeat_time: Option<DateTime<Utc>>,
+
     /// Throughput (records per second)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub throughput_records_per_sec: Option<f64>,
@@ -775,6 +1002,19 @@ pub struct KafkaBackupStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retention_error: Option<String>,
 
+    /// Most recent failures, oldest first (at most 5)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_errors: Vec<ErrorEntry>,
+
+    /// kafkaCluster.clientConfig keys the Kafka client does not support
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignored_client_config: Vec<String>,
+
+    /// Concrete topics the last run backed up, as recorded in its manifest,
+    /// with `topics` wildcards expanded
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resolved_topics: Vec<String>,
+
     /// Observed generation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub observed_generation: Option<i64>,
@@ -805,3 +1045,17 @@ pub struct Condition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
+
+/// A failure recorded in `status.recentErrors`
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorEntry {
+    /// When the failure was recorded
+    pub timestamp: DateTime<Utc>,
+
+    /// Phase the resource moved to (Failed, TimedOut)
+    pub phase: String,
+
+    /// Failure message
+    pub message: String,
+}
@@ -5,7 +5,7 @@ use kube::CustomResource;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::{Condition, KafkaClusterSpec, StorageSpec};
+use super::{Condition, ErrorEntry, KafkaClusterSpec, StorageSpec};
 
 /// KafkaBackupValidation resource specification
 #[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
@@ -21,6 +21,7 @@ use super::{Condition, KafkaClusterSpec, StorageSpec};
     printcolumn = r#"{"name": "Phase", "type": "string", "jsonPath": ".status.phase"}"#,
     printcolumn = r#"{"name": "Result", "type": "string", "jsonPath": ".status.validationResult"}"#,
     printcolumn = r#"{"name": "Checks", "type": "integer", "jsonPath": ".status.checksCompleted"}"#,
+    printcolumn = r#"{"name": "Summary", "type": "string", "priority": 1, "jsonPath": ".status.summary"}"#,
     printcolumn = r#"{"name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp"}"#
 )]
 #[serde(rename_all = "camelCase")]
@@ -288,6 +289,10 @@ pub struct KafkaBackupValidationStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 
+    /// One-line summary of the last completed run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
     /// Overall validation result (Pass, Fail, Warn)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub validation_result: Option<String>,
@@ -340,6 +345,10 @@ pub struct KafkaBackupValidationStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_scheduled_validation: Option<DateTime<Utc>>,
 
+    /// Most recent failures, oldest first (at most 5)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_errors: Vec<ErrorEntry>,
+
     /// Observed generation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub observed_generation: Option<i64>,
@@ -5,7 +5,7 @@ use kube::CustomResource;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::{Condition, KafkaClusterSpec};
+use super::{Condition, ErrorEntry, KafkaClusterSpec, SnapshotRef};
 
 /// KafkaOffsetReset resource specification
 #[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
@@ -21,6 +21,7 @@ use super::{Condition, KafkaClusterSpec};
     printcolumn = r#"{"name": "Phase", "type": "string", "jsonPath": ".status.phase"}"#,
     printcolumn = r#"{"name": "Groups", "type": "integer", "jsonPath": ".status.groupsReset"}"#,
     printcolumn = r#"{"name": "Failed", "type": "integer", "jsonPath": ".status.groupsFailed"}"#,
+    printcolumn = r#"{"name": "Summary", "type": "string", "priority": 1, "jsonPath": ".status.summary"}"#,
     printcolumn = r#"{"name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp"}"#
 )]
 #[serde(rename_all = "camelCase")]
@@ -28,9 +29,14 @@ pub struct KafkaOffsetResetSpec {
     /// Target Kafka cluster
     pub kafka_cluster: KafkaClusterSpec,
 
-    /// Consumer groups to reset
+    /// Consumer groups to reset (leave empty with `allGroups`)
+    #[serde(default)]
     pub consumer_groups: Vec<String>,
 
+    /// Reset every consumer group on the cluster instead of `consumerGroups`
+    #[serde(default)]
+    pub all_groups: bool,
+
     /// Reset strategy
     pub reset_strategy: OffsetResetStrategy,
 
@@ -46,6 +52,12 @@ pub struct KafkaOffsetResetSpec {
     #[serde(default)]
     pub topics: Vec<String>,
 
+    /// Restrict the reset to specific partitions. Every topic listed here
+    /// must also appear in `topics`; topics without an entry, and entries
+    /// without partitions, are reset on all partitions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub partitions: Vec<TopicPartitions>,
+
     /// Parallelism for bulk reset
     #[serde(default = "default_parallelism")]
     pub parallelism: usize,
@@ -65,16 +77,74 @@ pub struct KafkaOffsetResetSpec {
     /// Snapshot before reset for rollback
     #[serde(default = "default_true")]
     pub snapshot_before_reset: bool,
+
+    /// Hours to keep each of this reset's snapshots after it is taken. Expired
+    /// snapshots are swept while the reset exists and when it is deleted;
+    /// those of resets deleted earlier use the sweeping reset's value.
+    #[serde(default = "default_snapshot_retention_hours")]
+    pub snapshot_retention_hours: u32,
+
+    /// Retry and timeout settings for resetting each consumer group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetrySpec>,
+
+    /// Re-read each group's committed offsets after the commit and fail the
+    /// group, listing the partitions, if any does not hold its target offset
+    #[serde(default)]
+    pub verify_after_reset: bool,
 }
 
 fn default_parallelism() -> usize {
     50
 }
 
+fn default_snapshot_retention_hours() -> u32 {
+    24
+}
+
 fn default_true() -> bool {
     true
 }
 
+/// Retry settings for the offset fetches and commits of each consumer group
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RetrySpec {
+    /// Attempts per consumer group, including the first (minimum 1)
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Delay before the first retry in milliseconds; doubles on each retry
+    #[serde(default = "default_base_delay_ms")]
+    pub base_delay_ms: u64,
+
+    /// Timeout of each attempt in milliseconds (minimum 1000)
+    #[serde(default = "default_request_timeout_ms")]
+    pub request_timeout_ms: u64,
+}
+
+impl Default for RetrySpec {
+    fn default() -> Self {
+        Self {
+            max_attempts: default_max_attempts(),
+            base_delay_ms: default_base_delay_ms(),
+            request_timeout_ms: default_request_timeout_ms(),
+        }
+    }
+}
+
+fn default_max_attempts() -> u32 {
+    3
+}
+
+fn default_base_delay_ms() -> u64 {
+    100
+}
+
+fn default_request_timeout_ms() -> u64 {
+    30000
+}
+
 /// Offset reset strategy
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
@@ -120,6 +190,10 @@ pub struct KafkaOffsetResetStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 
+    /// One-line summary of the last completed run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
     /// Start time
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start_time: Option<DateTime<Utc>>,
@@ -152,10 +226,19 @@ pub struct KafkaOffsetResetStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snapshot_path: Option<String>,
 
+    /// Pre-reset snapshot as a reference that can be copied verbatim into a
+    /// KafkaOffsetRollback's snapshotRef
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_ref: Option<SnapshotRef>,
+
     /// Per-group results
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub group_results: Vec<GroupResetResult>,
 
+    /// Most recent failures, oldest first (at most 5)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_errors: Vec<ErrorEntry>,
+
     /// Observed generation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub observed_generation: Option<i64>,
@@ -165,6 +248,18 @@ pub struct KafkaOffsetResetStatus {
     pub conditions: Vec<Condition>,
 }
 
+/// A topic and a set of its partitions
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicPartitions {
+    /// Topic name
+    pub topic: String,
+
+    /// Partition numbers (empty = all partitions)
+    #[serde(default)]
+    pub partitions: Vec<i32>,
+}
+
 /// Per-group reset result
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -182,4 +277,31 @@ pub struct GroupResetResult {
     /// Number of partitions reset
     #[serde(skip_serializing_if = "Option::is_none")]
     pub partitions_reset: Option<usize>,
+
+    /// Partitions whose offsets were committed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub partitions: Vec<TopicPartitions>,
+
+    /// Old and new offset of each partition the reset committed (in dry run,
+    /// the changes it would commit)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub partition_deltas: Vec<PartitionDelta>,
+}
+
+/// Committed offset of one partition before and after a reset
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PartitionDelta {
+    /// Topic name
+    pub topic: String,
+
+    /// Partition number
+    pub partition: i32,
+
+    /// Offset committed before the reset, if the group had one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_offset: Option<i64>,
+
+    /// Offset the reset commits
+    pub new_offset: i64,
 }
@@ -5,7 +5,7 @@ use kube::CustomResource;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::{Condition, KafkaClusterSpec};
+use super::{Condition, ErrorEntry, KafkaClusterSpec};
 
 /// KafkaOffsetRollback resource specification
 #[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
@@ -20,6 +20,7 @@ use super::{Condition, KafkaClusterSpec};
     status = "KafkaOffsetRollbackStatus",
     printcolumn = r#"{"name": "Phase", "type": "string", "jsonPath": ".status.phase"}"#,
     printcolumn = r#"{"name": "Groups", "type": "integer", "jsonPath": ".status.groupsRolledBack"}"#,
+    printcolumn = r#"{"name": "Summary", "type": "string", "priority": 1, "jsonPath": ".status.summary"}"#,
     printcolumn = r#"{"name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp"}"#
 )]
 #[serde(rename_all = "camelCase")]
@@ -69,6 +70,10 @@ pub struct SnapshotRef {
     /// Reference to KafkaOffsetReset that created the snapshot
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset_reset_ref: Option<String>,
+
+    /// Bootstrap servers of the cluster the snapshot was taken from
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cluster: Vec<String>,
 }
 
 /// KafkaOffsetRollback status
@@ -83,6 +88,10 @@ pub struct KafkaOffsetRollbackStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 
+    /// One-line summary of the last completed run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
     /// Start time
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start_time: Option<DateTime<Utc>>,
@@ -103,6 +112,10 @@ pub struct KafkaOffsetRollbackStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verification: Option<VerificationResult>,
 
+    /// Most recent failures, oldest first (at most 5)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_errors: Vec<ErrorEntry>,
+
     /// Observed generation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub observed_generation: Option<i64>,
@@ -1,11 +1,15 @@
 //! KafkaRestore Custom Resource Definition
 
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use kube::CustomResource;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::{CircuitBreakerSpec, Condition, KafkaClusterSpec, RateLimitingSpec, StorageSpec};
+use super::{
+    CircuitBreakerSpec, Condition, ErrorEntry, KafkaClusterSpec, RateLimitingSpec, StorageSpec,
+};
 
 /// KafkaRestore resource specification
 #[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
@@ -21,6 +25,7 @@ use super::{CircuitBreakerSpec, Condition, KafkaClusterSpec, RateLimitingSpec, S
     printcolumn = r#"{"name": "Phase", "type": "string", "jsonPath": ".status.phase"}"#,
     printcolumn = r#"{"name": "Progress", "type": "string", "jsonPath": ".status.progressPercent"}"#,
     printcolumn = r#"{"name": "Records", "type": "integer", "jsonPath": ".status.recordsRestored"}"#,
+    printcolumn = r#"{"name": "Summary", "type": "string", "priority": 1, "jsonPath": ".status.summary"}"#,
     printcolumn = r#"{"name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp"}"#
 )]
 #[serde(rename_all = "camelCase")]
@@ -51,10 +56,37 @@ pub struct KafkaRestoreSpec {
     #[serde(default)]
     pub repartitioning: std::collections::HashMap<String, TopicRepartitioningSpec>,
 
+    /// How records are assigned to target partitions: `preserve` keeps each
+    /// record's backed-up partition, `key_hash` routes it by key across the
+    /// target topic's partitions like Kafka's default producer partitioner.
+    /// Topics listed in `repartitioning` keep their own settings.
+    #[serde(default)]
+    pub partition_strategy: PartitionStrategy,
+
     /// Consumer offset reset configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset_reset: Option<OffsetResetSpec>,
 
+    /// How consumer group offsets are set after the restore. Defaults to
+    /// `header_based` when `offsetReset` is enabled or `rollback` is set, and
+    /// `skip` otherwise.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_strategy: Option<RestoreOffsetStrategy>,
+
+    /// Consumer group names to use on the target cluster (source -> target).
+    /// Applied to offsets the operator commits and to KafkaOffsetResets that
+    /// use this restore's offset mapping. The restore engine commits
+    /// `header_based` offsets under the source names, so an enabled
+    /// `offsetReset` with that strategy is rejected; reset the renamed groups
+    /// with a KafkaOffsetReset using `from-mapping` instead.
+    #[serde(default)]
+    pub consumer_group_mapping: std::collections::HashMap<String, String>,
+
+    /// Prefix for target consumer group names not in `consumerGroupMapping`,
+    /// e.g. `dr-` maps group `app` to `dr-app`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer_group_prefix: Option<String>,
+
     /// Rollback safety configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rollback: Option<RollbackSpec>,
@@ -71,7 +103,8 @@ pub struct KafkaRestoreSpec {
     #[serde(default)]
     pub dry_run: bool,
 
-    /// Batch size for producing to the target cluster
+    /// Records per produce batch to the target cluster (1-100000, default
+    /// 1000). Lower it for large records, raise it for small ones.
     #[serde(default = "default_produce_batch_size")]
     pub produce_batch_size: usize,
 
@@ -99,9 +132,80 @@ pub struct KafkaRestoreSpec {
     pub create_topics: bool,
 
     /// Default replication factor for auto-created topics
-    /// If not specified, the broker's default replication factor is used.
+    /// If not specified, or -1, the broker's default replication factor is
+    /// used. An explicit factor fails the restore up front if it exceeds the
+    /// target's broker count, unless allowReplicationFactorDownscale is set.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_replication_factor: Option<i16>,
+
+    /// Create topics with the target's broker count as replication factor when
+    /// defaultReplicationFactor doesn't fit on the target cluster (e.g. a DR
+    /// restore into a smaller test cluster). Reduced topics are listed in a
+    /// ReplicationFactorReduced condition. The broker default isn't known to
+    /// the operator, so it is never reduced.
+    #[serde(default)]
+    pub allow_replication_factor_downscale: bool,
+
+    /// Fold backed-up partitions that the target topic lacks into its existing
+    /// partitions (partition modulo target partition count). Without it, a
+    /// target topic with fewer partitions than the backup fails the restore.
+    #[serde(default)]
+    pub allow_partition_downscale: bool,
+
+    /// Check every segment of the restored topics against the manifest before
+    /// producing, failing on the first segment that is missing, whose stored
+    /// size differs (truncated or partial uploads), or whose CRC32 differs
+    /// from the one recorded in `checksums.json`. Only backups written with
+    /// `segmentChecksums` have checksums; others are checked by size alone.
+    #[serde(default)]
+    pub verify_checksums: bool,
+
+    /// Check before restoring that the Kafka principal may describe and
+    /// write every target topic, and create the missing ones when
+    /// `createTopics` is set. Create is checked at cluster level, since
+    /// topic ACLs can't be read for topics that don't exist yet.
+    #[serde(default)]
+    pub acl_preflight: bool,
+
+    /// Refuse to restore from a backup created more than this many hours ago
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_backup_age_hours: Option<u32>,
+
+    /// Abort a restore that takes longer than this many seconds. The resource
+    /// moves to the TimedOut phase and is retried later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+
+    /// Abort a restore whose restored record count doesn't advance for this
+    /// many seconds. The resource moves to the Stalled phase and is retried
+    /// later from its checkpoint. Cannot be combined with `autoConsumerGroups`
+    /// or an `offsetReset` run by the restore engine: those three-phase
+    /// restores report no progress.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stall_timeout_secs: Option<u64>,
+
+    /// Restore every topic again when retrying a failed restore. By default a
+    /// retry against the same backup skips topics listed in
+    /// status.completedTopics.
+    #[serde(default)]
+    pub force_full_restore: bool,
+
+    /// Export the source-to-target offset mapping to backup storage once the
+    /// restore completes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_report: Option<OffsetReportSpec>,
+
+    /// With `dryRun`, read the first records of one backed-up topic into
+    /// status.preview to check what the backup holds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview: Option<PreviewSpec>,
+
+    /// Skip producing records and only commit the consumer group offsets
+    /// stored with the backup (KafkaBackup `consumerGroupSnapshot`) to the
+    /// target cluster, under the names from `consumerGroupMapping`,
+    /// `consumerGroupPrefix` and `topicMapping`
+    #[serde(default)]
+    pub restore_offsets_only: bool,
 }
 
 fn default_produce_batch_size() -> usize {
@@ -143,13 +247,31 @@ pub struct BackupRef {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub namespace: Option<String>,
 
-    /// Specific backup ID (if multiple backups exist)
+    /// Backup ID to restore: a specific ID, `latest` or `oldest`. Defaults to
+    /// the most recent backup in storage; an ID not found there is rejected.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub backup_id: Option<String>,
 
+    /// Always restore the newest completed backup, ignoring `backupId`
+    #[serde(default)]
+    pub use_latest: bool,
+
+    /// With `useLatest`, run the completed restore again whenever a newer
+    /// backup completes, which keeps a standby cluster following its source.
+    /// Requires `purgeTopics` so each run replaces the target topics instead
+    /// of appending every record again.
+    #[serde(default)]
+    pub rerun_on_new_backup: bool,
+
     /// Alternative: Direct storage reference (for external backups)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub storage: Option<StorageSpec>,
+
+    /// Further locations holding copies of the backup, such as a replica
+    /// bucket in another region. Tried in order when the primary location
+    /// is unreachable or does not contain the backup.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub storage_fallbacks: Vec<StorageSpec>,
 }
 
 /// Point-in-time recovery specification
@@ -193,6 +315,109 @@ fn default_offset_strategy() -> String {
     "manual".to_string()
 }
 
+/// Sample of a backed-up topic shown by a dry run
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewSpec {
+    /// Backed-up topic to sample
+    pub topic: String,
+
+    /// Records to sample (1-100, default 10)
+    #[serde(default = "default_preview_max_records")]
+    pub max_records: u32,
+
+    /// Include the start of each value, not just its size. Values may hold
+    /// sensitive data that then becomes readable from status.
+    #[serde(default)]
+    pub include_values: bool,
+}
+
+fn default_preview_max_records() -> u32 {
+    10
+}
+
+/// A backed-up record as shown by a dry-run preview
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewRecord {
+    /// Source partition
+    pub partition: i32,
+
+    /// Source offset
+    pub offset: i64,
+
+    /// Record timestamp in milliseconds since the epoch
+    pub timestamp: i64,
+
+    /// Key as text (invalid UTF-8 replaced), truncated; absent for null keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+
+    /// Target partition the record's key routes it to when its topic is
+    /// repartitioned by key; absent for null keys and preserved partitions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_partition: Option<i32>,
+
+    /// Value size in bytes
+    pub value_bytes: usize,
+
+    /// Start of the value as text, with `includeValues`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// Offset report export for a restore
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OffsetReportSpec {
+    /// Formats to write (defaults to both json and csv)
+    #[serde(default = "default_offset_report_formats")]
+    pub formats: Vec<OffsetReportFormat>,
+}
+
+fn default_offset_report_formats() -> Vec<OffsetReportFormat> {
+    vec![OffsetReportFormat::Json, OffsetReportFormat::Csv]
+}
+
+/// Offset report file format
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OffsetReportFormat {
+    /// JSON document with a `mappings` array
+    Json,
+    /// CSV with a header row
+    Csv,
+}
+
+/// Target partition assignment for restored records
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionStrategy {
+    /// Restore each record to the partition it was backed up from
+    #[default]
+    Preserve,
+    /// Route each record by the murmur2 hash of its key over the target
+    /// topic's partition count
+    KeyHash,
+}
+
+/// Consumer group offset handling for a restore
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RestoreOffsetStrategy {
+    /// Leave consumer group offsets untouched
+    Skip,
+    /// Translate offsets using the original-offset headers written at backup
+    /// time (requires `includeOffsetHeaders` on the backup)
+    HeaderBased,
+    /// Commit the earliest offset of every restored partition for the groups
+    /// in `offsetReset.consumerGroups`
+    Earliest,
+    /// Commit the latest offset of every restored partition for the groups in
+    /// `offsetReset.consumerGroups`
+    Latest,
+}
+
 /// Rollback safety specification
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -238,7 +463,8 @@ pub struct SnapshotStorageSpec {
 #[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KafkaRestoreStatus {
-    /// Current phase (Pending, Running, Completed, Failed, RolledBack)
+    /// Current phase (Pending, Validating, Running, Completed, Failed, RolledBack, TimedOut,
+    /// Stalled)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phase: Option<String>,
 
@@ -246,6 +472,10 @@ pub struct KafkaRestoreStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 
+    /// One-line summary of the last completed run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
     /// Start time
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start_time: Option<DateTime<Utc>>,
@@ -254,6 +484,11 @@ pub struct KafkaRestoreStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub completion_time: Option<DateTime<Utc>>,
 
+    /// Last heartbeat from the running restore. A Running restore whose
+    /// heartbeat goes stale was orphaned and is resumed from its checkpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heartbeat_time: Option<DateTime<Utc>>,
+
     /// Progress percentage (0-100)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub progress_percent: Option<f64>,
@@ -270,6 +505,12 @@ pub struct KafkaRestoreStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub segments_processed: Option<u64>,
 
+    /// Source topics fully restored by the current attempt, or carried over
+    /// from an earlier attempt at the same backup. Retries skip them unless
+    /// forceFullRestore is set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub completed_topics: Vec<String>,
+
     /// Current topic being restored
     #[serde(skip_serializing_if = "Option::is_none")]
     pub current_topic: Option<String>,
@@ -278,6 +519,11 @@ pub struct KafkaRestoreStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub throughput_records_per_sec: Option<f64>,
 
+    /// Records-per-second limit currently applied, which climbs to
+    /// `rateLimiting.recordsPerSec` while `rampUpSecs` is in effect
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_records_per_sec: Option<u64>,
+
     /// ETA (milliseconds)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub eta_ms: Option<u64>,
@@ -290,6 +536,84 @@ pub struct KafkaRestoreStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset_mapping_path: Option<String>,
 
+    /// Storage keys of the exported offset reports
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub offset_report_paths: Vec<String>,
+
+    /// Segments that passed verifyChecksums
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segments_verified: Option<u64>,
+
+    /// Segments checked by verifyChecksums
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segments_total: Option<u64>,
+
+    /// Backup ID the backupRef resolved to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup_id: Option<String>,
+
+    /// Labels the KafkaBackup stored with the resolved backup
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub backup_labels: BTreeMap<String, String>,
+
+    /// Storage location the backup was read from: `primary`, or
+    /// `storageFallbacks[N]` when the primary location was unavailable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_location: Option<String>,
+
+    /// Number of backed-up topics the restore covers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topics_to_restore: Option<usize>,
+
+    /// Differences a dry run found between the backed-up topics and their
+    /// target topics, e.g. "orders: target partitions=3, backup=6"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topic_drift: Vec<String>,
+
+    /// `retention.ms` and `cleanup.policy` of each existing target topic, as
+    /// read by a dry run. Backups record no topic configs, so these are the
+    /// target's values only, not a comparison.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub target_topic_configs: BTreeMap<String, BTreeMap<String, String>>,
+
+    /// Partition strategy the restore ran with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition_strategy: Option<PartitionStrategy>,
+
+    /// Records sampled by a dry run with `preview`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub preview: Vec<PreviewRecord>,
+
+    /// Schema version of the backup manifest being restored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest_version: Option<u32>,
+
+    /// Cluster ID recorded in the backup manifest
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_cluster_id: Option<String>,
+
+    /// Cluster ID reported by the restore target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_cluster_id: Option<String>,
+
+    /// Partitions folded by allowPartitionDownscale, keyed by target topic
+    /// (source partition -> target partition)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub partition_remapping: BTreeMap<String, BTreeMap<i32, i32>>,
+
+    /// Most recent failures, oldest first (at most 5)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_errors: Vec<ErrorEntry>,
+
+    /// Fields the restore started running with. They can't change until the
+    /// restore reaches a terminal phase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked_spec: Option<LockedRestoreSpec>,
+
+    /// kafkaCluster.clientConfig keys the Kafka client does not support
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignored_client_config: Vec<String>,
+
     /// Observed generation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub observed_generation: Option<i64>,
@@ -299,6 +623,32 @@ pub struct KafkaRestoreStatus {
     pub conditions: Vec<Condition>,
 }
 
+/// Spec fields that are immutable once a restore starts running
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRestoreSpec {
+    /// Backup the restore reads from
+    pub backup_ref: BackupRef,
+
+    /// Target Kafka cluster
+    pub kafka_cluster: KafkaClusterSpec,
+
+    /// Topics being restored (empty = all topics from backup)
+    #[serde(default)]
+    pub topics: Vec<String>,
+}
+
+impl LockedRestoreSpec {
+    /// The immutable fields of `spec`
+    pub fn from_spec(spec: &KafkaRestoreSpec) -> Self {
+        Self {
+            backup_ref: spec.backup_ref.clone(),
+            kafka_cluster: spec.kafka_cluster.clone(),
+            topics: spec.topics.clone(),
+        }
+    }
+}
+
 /// Rollback status information
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
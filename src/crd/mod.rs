@@ -12,15 +12,65 @@ pub use kafka_offset_reset::*;
 pub use kafka_offset_rollback::*;
 pub use kafka_restore::*;
 
-use kube::CustomResourceExt;
+use std::str::FromStr;
 
-/// Generate all CRD YAML manifests
-pub fn generate_crds() -> Vec<String> {
+use kube::{CustomResourceExt, Resource};
+
+/// Serialization format for generated CRD manifests
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CrdFormat {
+    #[default]
+    Yaml,
+    Json,
+}
+
+impl CrdFormat {
+    /// File extension for manifests in this format
+    pub fn extension(self) -> &'static str {
+        match self {
+            CrdFormat::Yaml => "yaml",
+            CrdFormat::Json => "json",
+        }
+    }
+}
+
+impl FromStr for CrdFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "yaml" | "yml" => Ok(CrdFormat::Yaml),
+            "json" => Ok(CrdFormat::Json),
+            other => Err(format!("Unknown format '{}': must be yaml or json", other)),
+        }
+    }
+}
+
+/// Generate all CRD YAML manifests as `(name, yaml)` pairs, where `name` is
+/// the lowercased kind (e.g. `kafkabackup`)
+pub fn generate_crds() -> Vec<(String, String)> {
+    generate_crds_as(CrdFormat::Yaml)
+}
+
+/// Generate all CRD manifests in `format` as `(name, manifest)` pairs
+pub fn generate_crds_as(format: CrdFormat) -> Vec<(String, String)> {
     vec![
-        serde_yaml::to_string(&KafkaBackup::crd()).unwrap(),
-        serde_yaml::to_string(&KafkaRestore::crd()).unwrap(),
-        serde_yaml::to_string(&KafkaOffsetReset::crd()).unwrap(),
-        serde_yaml::to_string(&KafkaOffsetRollback::crd()).unwrap(),
-        serde_yaml::to_string(&KafkaBackupValidation::crd()).unwrap(),
+        crd_manifest::<KafkaBackup>(format),
+        crd_manifest::<KafkaRestore>(format),
+        crd_manifest::<KafkaOffsetReset>(format),
+        crd_manifest::<KafkaOffsetRollback>(format),
+        crd_manifest::<KafkaBackupValidation>(format),
     ]
 }
+
+fn crd_manifest<K>(format: CrdFormat) -> (String, String)
+where
+    K: CustomResourceExt + Resource<DynamicType = ()>,
+{
+    let crd = K::crd();
+    let manifest = match format {
+        CrdFormat::Yaml => serde_yaml::to_string(&crd).unwrap(),
+        CrdFormat::Json => serde_json::to_string_pretty(&crd).unwrap() + "\n",
+    };
+    (K::kind(&()).to_lowercase(), manifest)
+}
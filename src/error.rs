@@ -16,6 +16,10 @@ pub enum Error {
     #[error("Kafka backup core error: {0}")]
     Core(String),
 
+    /// Kafka cluster could not be reached
+    #[error("Kafka connection error: {0}")]
+    KafkaConnection(String),
+
     /// Configuration error
     #[error("Configuration error: {0}")]
     Config(String),
@@ -24,6 +28,15 @@ pub enum Error {
     #[error("Storage error: {0}")]
     Storage(String),
 
+    /// Storage backend rejected the resolved credentials
+    #[error("Storage authentication error: {0}")]
+    StorageAuth(String),
+
+    /// Kafka or storage rejected credentials that were accepted when the
+    /// operation started, typically because a secret was rotated mid-run
+    #[error("Credentials rejected mid-operation: {0}")]
+    CredentialsRotated(String),
+
     /// Validation error
     #[error("Validation error: {0}")]
     Validation(String),
@@ -60,6 +73,26 @@ pub enum Error {
     #[error("Notification error: {0}")]
     Notification(String),
 
+    /// Operation exceeded its configured timeout
+    #[error("Operation timed out: {0}")]
+    Timeout(String),
+
+    /// Operation made no progress for its configured stall timeout
+    #[error("Operation stalled: {0}")]
+    Stalled(String),
+
+    /// Operation wrote more data than its configured size limit
+    #[error("Size limit exceeded: {0}")]
+    SizeLimitExceeded(String),
+
+    /// Operation stopped by operator shutdown before it finished
+    #[error("Operation interrupted: {0}")]
+    Interrupted(String),
+
+    /// Deletion refused while other resources still depend on the data
+    #[error("Deletion blocked: {0}")]
+    DeletionBlocked(String),
+
     /// Serialization error
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
@@ -103,4 +136,67 @@ impl Error {
     pub fn notification(msg: impl Into<String>) -> Self {
         Error::Notification(msg.into())
     }
+
+    /// The error a reconcile failed with. Every reconcile runs inside
+    /// `finalizer()`, which wraps apply and cleanup errors in
+    /// [`Error::Finalizer`]; retry policies match on the error underneath.
+    pub fn unwrap_finalizer(&self) -> &Error {
+        use kube::runtime::finalizer::Error as FinalizerError;
+
+        match self {
+            Error::Finalizer(error) => match error.as_ref() {
+                FinalizerError::ApplyFailed(error) | FinalizerError::CleanupFailed(error) => {
+                    error.unwrap_finalizer()
+                }
+                _ => self,
+            },
+            _ => self,
+        }
+    }
+
+    /// Whether the error stems from a resource's own configuration (an
+    /// invalid spec, a missing secret, an unreachable cluster or rejected
+    /// credentials) rather than from the operator itself
+    pub fn is_user_config(&self) -> bool {
+        matches!(
+            self.unwrap_finalizer(),
+            Error::Validation(_)
+                | Error::Config(_)
+                | Error::KafkaConnection(_)
+                | Error::StorageAuth(_)
+                | Error::SecretNotFound(_)
+                | Error::SecretKeyNotFound { .. }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kube::runtime::finalizer::Error as FinalizerError;
+
+    use super::*;
+
+    #[test]
+    fn finalizer_errors_are_unwrapped() {
+        let applied = Error::Finalizer(Box::new(FinalizerError::ApplyFailed(
+            Error::KafkaConnection("brokers unreachable".to_string()),
+        )));
+        assert!(matches!(
+            applied.unwrap_finalizer(),
+            Error::KafkaConnection(_)
+        ));
+        assert!(applied.is_user_config());
+
+        let cleaned = Error::Finalizer(Box::new(FinalizerError::CleanupFailed(Error::storage(
+            "bucket unavailable",
+        ))));
+        assert!(matches!(cleaned.unwrap_finalizer(), Error::Storage(_)));
+        assert!(!cleaned.is_user_config());
+
+        let unnamed = Error::Finalizer(Box::new(FinalizerError::UnnamedObject));
+        assert!(matches!(unnamed.unwrap_finalizer(), Error::Finalizer(_)));
+
+        let plain = Error::validation("bad spec");
+        assert!(matches!(plain.unwrap_finalizer(), Error::Validation(_)));
+    }
 }
@@ -3,23 +3,52 @@
 //! Main entry point for the operator. Sets up the Kubernetes client,
 //! registers CRD controllers, and runs the reconciliation loops.
 
+use std::io::Read;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::process::ExitCode;
 use std::sync::Arc;
+use std::time::Duration;
 
 use kube::Client;
 use tokio::signal;
-use tracing::{error, info};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+use tracing::{error, info, warn};
+use tracing_subscriber::{
+    layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer, Registry,
+};
 
 use kafka_backup_operator::{
-    controllers::{self, Context},
+    controllers::{self, Context, LOG_LEVEL_OVERRIDES},
     metrics,
+    reconcilers::manifests,
 };
 
 /// Default metrics port
-const METRICS_PORT: u16 = 8080;
+const DEFAULT_METRICS_PORT: u16 = 8080;
+
+/// Environment variable overriding the metrics port
+const METRICS_PORT_ENV: &str = "METRICS_PORT";
+
+/// Default metrics bind address
+const DEFAULT_METRICS_BIND_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+
+/// Environment variable overriding the metrics bind address
+const METRICS_BIND_ADDR_ENV: &str = "METRICS_BIND_ADDR";
+
+/// Default time in-flight operations get to stop after a shutdown signal,
+/// inside Kubernetes' default 30s termination grace period
+const DEFAULT_SHUTDOWN_GRACE_SECS: u64 = 25;
+
+/// Environment variable overriding the shutdown grace period
+const SHUTDOWN_GRACE_ENV: &str = "SHUTDOWN_GRACE_PERIOD_SECS";
 
 #[tokio::main]
-async fn main() -> anyhow::Result<()> {
+async fn main() -> anyhow::Result<ExitCode> {
+    // `--validate [PATH]` checks manifests offline instead of running the operator
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("--validate") {
+        return Ok(validate_manifests(args.get(1).map(String::as_str)));
+    }
+
     // Initialize tracing
     init_tracing();
 
@@ -33,8 +62,9 @@ async fn main() -> anyhow::Result<()> {
     let context = Arc::new(Context::new(client.clone()));
 
     // Start metrics server
-    let metrics_handle = tokio::spawn(metrics::serve(METRICS_PORT));
-    info!("Metrics server starting on port {}", METRICS_PORT);
+    let metrics_addr = metrics_addr();
+    let metrics_handle = tokio::spawn(metrics::serve(metrics_addr));
+    info!("Metrics server starting on {}", metrics_addr);
 
     // Run all controllers concurrently
     let backup_controller = controllers::run_backup_controller(client.clone(), context.clone());
@@ -46,44 +76,192 @@ async fn main() -> anyhow::Result<()> {
     let validation_controller =
         controllers::run_validation_controller(client.clone(), context.clone());
 
+    tokio::pin!(
+        backup_controller,
+        restore_controller,
+        offset_reset_controller,
+        offset_rollback_controller,
+        validation_controller
+    );
+
     // Handle graceful shutdown
-    tokio::select! {
-        _ = backup_controller => {
+    let shutdown_requested = tokio::select! {
+        _ = &mut backup_controller => {
             error!("Backup controller exited unexpectedly");
+            false
         }
-        _ = restore_controller => {
+        _ = &mut restore_controller => {
             error!("Restore controller exited unexpectedly");
+            false
         }
-        _ = offset_reset_controller => {
+        _ = &mut offset_reset_controller => {
             error!("Offset reset controller exited unexpectedly");
+            false
         }
-        _ = offset_rollback_controller => {
+        _ = &mut offset_rollback_controller => {
             error!("Offset rollback controller exited unexpectedly");
+            false
         }
-        _ = validation_controller => {
+        _ = &mut validation_controller => {
             error!("Validation controller exited unexpectedly");
+            false
         }
         _ = metrics_handle => {
             error!("Metrics server exited unexpectedly");
+            false
         }
         _ = shutdown_signal() => {
             info!("Received shutdown signal, stopping operator");
+            true
+        }
+    };
+
+    if shutdown_requested {
+        // Running backups and restores stop, record the Interrupted phase and
+        // resume from their checkpoint on the next start. The controllers
+        // finish once their in-flight reconciles have.
+        context.shutdown.cancel();
+        let grace_period = shutdown_grace_period();
+        let drained = tokio::time::timeout(grace_period, async {
+            tokio::join!(
+                &mut backup_controller,
+                &mut restore_controller,
+                &mut offset_reset_controller,
+                &mut offset_rollback_controller,
+                &mut validation_controller
+            )
+        })
+        .await;
+        if drained.is_err() {
+            warn!(
+                grace_period = ?grace_period,
+                "In-flight operations did not finish within the shutdown grace period"
+            );
         }
     }
 
     info!("OSO Kafka Backup Operator stopped");
-    Ok(())
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Validate the operator resources in the YAML file at `path` (stdin when
+/// absent or `-`), printing one line per resource. Fails if any is invalid.
+fn validate_manifests(path: Option<&str>) -> ExitCode {
+    let source = path.filter(|path| *path != "-").unwrap_or("<stdin>");
+    let read = match path.filter(|path| *path != "-") {
+        Some(path) => std::fs::read_to_string(path),
+        None => {
+            let mut yaml = String::new();
+            std::io::stdin().read_to_string(&mut yaml).map(|_| yaml)
+        }
+    };
+    let yaml = match read {
+        Ok(yaml) => yaml,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", source, e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let checks = manifests::validate_manifests(&yaml);
+    if checks.is_empty() {
+        eprintln!("No kafka.oso.sh resources found in {}", source);
+    }
+    let mut failed = 0;
+    for check in &checks {
+        match &check.error {
+            None => println!("ok      {}", check.resource),
+            Some(error) => {
+                failed += 1;
+                println!("invalid {}: {}", check.resource, error);
+            }
+        }
+    }
+    if failed > 0 {
+        eprintln!("{} of {} resources failed validation", failed, checks.len());
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
 }
 
 /// Initialize tracing subscriber
+///
+/// `LOG_FORMAT` selects the output format: `json` (default, newline-delimited
+/// JSON for in-cluster log shipping), `pretty` or `compact` (human-readable,
+/// for local development). Reconciles of resources annotated
+/// `kafka.oso.sh/log-level` log at that level on top of the filter.
 fn init_tracing() {
-    let env_filter = EnvFilter::try_from_default_env()
+    let mut env_filter = EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| EnvFilter::new("info,kube=warn,hyper=warn"));
+    for level in LOG_LEVEL_OVERRIDES {
+        let directive = format!("[{{log_level={0}}}]={0}", level)
+            .parse()
+            .expect("Invalid log level override directive");
+        env_filter = env_filter.add_directive(directive);
+    }
+
+    let log_format = std::env::var("LOG_FORMAT").unwrap_or_default();
+    let fmt_layer: Box<dyn Layer<Registry> + Send + Sync> =
+        match log_format.trim().to_lowercase().as_str() {
+            "pretty" => tracing_subscriber::fmt::layer().pretty().boxed(),
+            "compact" => tracing_subscriber::fmt::layer().compact().boxed(),
+            // One JSON object per line (NDJSON), without ANSI colour codes
+            // that would break the documents for log shippers
+            _ => tracing_subscriber::fmt::layer()
+                .json()
+                .with_ansi(false)
+                .with_writer(std::io::stdout)
+                .boxed(),
+        };
 
     tracing_subscriber::registry()
+        .with(fmt_layer)
         .with(env_filter)
-        .with(tracing_subscriber::fmt::layer().json())
         .init();
+
+    if !matches!(
+        log_format.trim().to_lowercase().as_str(),
+        "" | "json" | "pretty" | "compact"
+    ) {
+        warn!(log_format = %log_format, "Unknown LOG_FORMAT, falling back to json");
+    }
+}
+
+/// Resolve the metrics listen address from METRICS_BIND_ADDR and
+/// METRICS_PORT, falling back to the defaults on unparseable values
+fn metrics_addr() -> SocketAddr {
+    let ip = match std::env::var(METRICS_BIND_ADDR_ENV) {
+        Ok(value) if !value.trim().is_empty() => value.trim().parse().unwrap_or_else(|_| {
+            warn!(
+                value = %value,
+                "Invalid METRICS_BIND_ADDR, falling back to {}",
+                DEFAULT_METRICS_BIND_ADDR
+            );
+            DEFAULT_METRICS_BIND_ADDR
+        }),
+        _ => DEFAULT_METRICS_BIND_ADDR,
+    };
+    let port = match std::env::var(METRICS_PORT_ENV) {
+        Ok(value) if !value.trim().is_empty() => value.trim().parse().unwrap_or_else(|_| {
+            warn!(
+                value = %value,
+                "Invalid METRICS_PORT, falling back to {}",
+                DEFAULT_METRICS_PORT
+            );
+            DEFAULT_METRICS_PORT
+        }),
+        _ => DEFAULT_METRICS_PORT,
+    };
+    SocketAddr::new(ip, port)
+}
+
+/// Resolve the shutdown grace period: env, then default
+fn shutdown_grace_period() -> Duration {
+    let secs = std::env::var(SHUTDOWN_GRACE_ENV)
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_SHUTDOWN_GRACE_SECS);
+    Duration::from_secs(secs)
 }
 
 /// Wait for shutdown signal (SIGTERM or SIGINT)
@@ -1,6 +1,9 @@
 //! Prometheus metrics definitions and HTTP server
 
+use std::collections::VecDeque;
 use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use http_body_util::Full;
 use hyper::body::Bytes;
@@ -67,6 +70,55 @@ lazy_static::lazy_static! {
         &["namespace", "name"]
     ).unwrap();
 
+    /// Records left out of backups, by reason
+    pub static ref SKIPPED_RECORDS: CounterVec = register_counter_vec!(
+        "kafka_backup_operator_skipped_records_total",
+        "Total number of records left out of backups by reason",
+        &["namespace", "name", "reason"]
+    ).unwrap();
+
+    /// Compression ratio achieved by the last backup (uncompressed / written bytes)
+    pub static ref BACKUP_COMPRESSION_RATIO: GaugeVec = register_gauge_vec!(
+        "kafka_backup_operator_backup_compression_ratio",
+        "Compression ratio of last backup (uncompressed bytes / bytes written)",
+        &["namespace", "name"]
+    ).unwrap();
+
+    /// Backup sets left in storage after the last retention run
+    pub static ref RETAINED_BACKUPS: GaugeVec = register_gauge_vec!(
+        "kafka_backup_operator_retained_backups",
+        "Number of backup sets retained in storage after the last retention run",
+        &["namespace", "name"]
+    ).unwrap();
+
+    /// Seconds since the last successful backup, refreshed on every reconcile
+    pub static ref SECONDS_SINCE_LAST_BACKUP: GaugeVec = register_gauge_vec!(
+        "kafka_backup_operator_seconds_since_last_backup",
+        "Seconds since the last successful backup",
+        &["namespace", "name"]
+    ).unwrap();
+
+    /// Run-level circuit breaker state (0 = closed, 1 = open, 2 = half-open)
+    pub static ref CIRCUIT_BREAKER_STATE: GaugeVec = register_gauge_vec!(
+        "kafka_backup_operator_circuit_breaker_state",
+        "Circuit breaker state (0 = closed, 1 = open, 2 = half-open)",
+        &["kind", "namespace", "name"]
+    ).unwrap();
+
+    /// Whether the last storage probe before a backup or restore succeeded
+    pub static ref STORAGE_REACHABLE: GaugeVec = register_gauge_vec!(
+        "kafka_backup_operator_storage_reachable",
+        "Storage reachability from the last probe (1 = reachable, 0 = unreachable)",
+        &["kind", "backend", "namespace", "name"]
+    ).unwrap();
+
+    /// Bytes reclaimed by removing artifacts of incomplete backups
+    pub static ref GC_BYTES_RECLAIMED: CounterVec = register_counter_vec!(
+        "kafka_backup_operator_gc_bytes_reclaimed_total",
+        "Total bytes reclaimed by removing artifacts of failed or aborted backups",
+        &["namespace", "name"]
+    ).unwrap();
+
     /// Total number of restores
     pub static ref RESTORES_TOTAL: CounterVec = register_counter_vec!(
         "kafka_backup_operator_restores_total",
@@ -131,16 +183,120 @@ lazy_static::lazy_static! {
         "kafka_backup_operator_health",
         "Operator health status (1 = healthy, 0 = unhealthy)"
     ).unwrap();
+
+    /// Operator build information (always 1; the labels carry the values)
+    pub static ref BUILD_INFO: GaugeVec = register_gauge_vec!(
+        "kafka_backup_operator_build_info",
+        "Operator build information (always 1)",
+        &["version", "git_sha", "rust_version"]
+    ).unwrap();
+
+    /// Recent reconcile outcomes across all controllers, driving `OPERATOR_HEALTH`
+    static ref RECENT_FAILURES: Mutex<FailureWindow> =
+        Mutex::new(FailureWindow::new(HEALTH_FAILURE_WINDOW, HEALTH_FAILURE_THRESHOLD));
+}
+
+/// Window over which reconcile failures count against operator health
+pub const HEALTH_FAILURE_WINDOW: Duration = Duration::from_secs(300);
+
+/// Number of failures within the window above which the operator is
+/// unhealthy, unless a reconcile also succeeded within it
+pub const HEALTH_FAILURE_THRESHOLD: usize = 20;
+
+/// Sliding window of reconcile failure timestamps, plus the last success.
+/// Failures only make the operator unhealthy while nothing succeeds: a
+/// handful of resources failing over and over says nothing about whether
+/// the operator itself is stuck, as long as other reconciles get through.
+#[derive(Debug)]
+struct FailureWindow {
+    window: Duration,
+    threshold: usize,
+    failures: VecDeque<Instant>,
+    last_success: Option<Instant>,
+}
+
+impl FailureWindow {
+    fn new(window: Duration, threshold: usize) -> Self {
+        Self {
+            window,
+            threshold,
+            failures: VecDeque::new(),
+            last_success: None,
+        }
+    }
+
+    fn record(&mut self, now: Instant) {
+        self.failures.push_back(now);
+        self.prune(now);
+    }
+
+    fn record_success(&mut self, now: Instant) {
+        self.last_success = Some(now);
+    }
+
+    /// Drop failures that have aged out of the window
+    fn prune(&mut self, now: Instant) {
+        while let Some(oldest) = self.failures.front() {
+            if now.duration_since(*oldest) > self.window {
+                self.failures.pop_front();
+            } else {
+                break;
+            }
+        }
+        // Nothing past the threshold can change the verdict, so cap memory use
+        while self.failures.len() > self.threshold + 1 {
+            self.failures.pop_front();
+        }
+    }
+
+    fn healthy(&mut self, now: Instant) -> bool {
+        self.prune(now);
+        let progressing = self
+            .last_success
+            .is_some_and(|success| now.duration_since(success) <= self.window);
+        progressing || self.failures.len() <= self.threshold
+    }
 }
 
-/// Start the metrics HTTP server
-pub async fn serve(port: u16) -> anyhow::Result<()> {
-    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+/// Record a failed reconcile and update `OPERATOR_HEALTH`. Callers leave
+/// out failures caused by a resource's own configuration.
+pub fn record_reconcile_failure() {
+    let now = Instant::now();
+    let mut failures = RECENT_FAILURES.lock().unwrap();
+    failures.record(now);
+    OPERATOR_HEALTH.set(if failures.healthy(now) { 1.0 } else { 0.0 });
+}
+
+/// Record a successful reconcile, which keeps the operator healthy for
+/// [`HEALTH_FAILURE_WINDOW`]
+pub fn record_reconcile_success() {
+    let now = Instant::now();
+    let mut failures = RECENT_FAILURES.lock().unwrap();
+    failures.record_success(now);
+    OPERATOR_HEALTH.set(if failures.healthy(now) { 1.0 } else { 0.0 });
+}
+
+/// Re-evaluate `OPERATOR_HEALTH` so it recovers once failures age out of the window
+pub fn refresh_health() -> bool {
+    let healthy = RECENT_FAILURES.lock().unwrap().healthy(Instant::now());
+    OPERATOR_HEALTH.set(if healthy { 1.0 } else { 0.0 });
+    healthy
+}
+
+/// Start the metrics HTTP server on `addr`
+pub async fn serve(addr: SocketAddr) -> anyhow::Result<()> {
     let listener = TcpListener::bind(addr).await?;
     info!("Metrics server listening on {}", addr);
 
     // Set initial health
-    OPERATOR_HEALTH.set(1.0);
+    refresh_health();
+    BUILD_INFO
+        .with_label_values(&[
+            env!("CARGO_PKG_VERSION"),
+            env!("GIT_SHA"),
+            env!("RUSTC_VERSION"),
+        ])
+        .set(1.0);
 
     loop {
         let (stream, _) = listener.accept().await?;
@@ -192,8 +348,16 @@ fn metrics_response() -> Response<Full<Bytes>> {
         .unwrap()
 }
 
-/// Health check response
+/// Health check response, failing once reconciles keep erroring and none
+/// succeed, so the liveness probe can restart a stuck operator
 fn health_response() -> Response<Full<Bytes>> {
+    if !refresh_health() {
+        return Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .body(Full::new(Bytes::from("too many reconcile failures")))
+            .unwrap();
+    }
+
     Response::builder()
         .status(StatusCode::OK)
         .body(Full::new(Bytes::from("ok")))
@@ -215,3 +379,60 @@ fn not_found_response() -> Response<Full<Bytes>> {
         .body(Full::new(Bytes::from("Not Found")))
         .unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failure_window_goes_unhealthy_past_threshold() {
+        let mut window = FailureWindow::new(Duration::from_secs(300), 3);
+        let now = Instant::now();
+        for _ in 0..3 {
+            window.record(now);
+        }
+        assert!(window.healthy(now));
+
+        window.record(now);
+        assert!(!window.healthy(now));
+    }
+
+    #[test]
+    fn failure_window_recovers_once_failures_age_out() {
+        let mut window = FailureWindow::new(Duration::from_secs(300), 1);
+        let start = Instant::now();
+        window.record(start);
+        window.record(start + Duration::from_secs(10));
+        assert!(!window.healthy(start + Duration::from_secs(10)));
+
+        // The first failure leaves the window, bringing the count back to the threshold
+        assert!(window.healthy(start + Duration::from_secs(301)));
+        assert!(window.healthy(start + Duration::from_secs(311)));
+    }
+
+    #[test]
+    fn failure_window_caps_retained_failures() {
+        let mut window = FailureWindow::new(Duration::from_secs(300), 2);
+        let now = Instant::now();
+        for _ in 0..100 {
+            window.record(now);
+        }
+        assert_eq!(window.failures.len(), 3);
+    }
+
+    #[test]
+    fn failure_window_stays_healthy_while_reconciles_succeed() {
+        let mut window = FailureWindow::new(Duration::from_secs(300), 1);
+        let start = Instant::now();
+        window.record_success(start);
+        for _ in 0..10 {
+            window.record(start + Duration::from_secs(10));
+        }
+        assert!(window.healthy(start + Duration::from_secs(10)));
+
+        // Without another success the failures count once the success ages out
+        window.record(start + Duration::from_secs(301));
+        window.record(start + Duration::from_secs(302));
+        assert!(!window.healthy(start + Duration::from_secs(302)));
+    }
+}
@@ -6,7 +6,8 @@
 //! - Backup execution
 //! - Status updates
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
 use std::path::Path;
 use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
@@ -14,22 +15,39 @@ use std::time::Duration;
 use chrono::{DateTime, Utc};
 use cron::Schedule;
 use kafka_backup_core::backup::BackupEngine;
+use kafka_backup_core::config::CompressionType;
+use kafka_backup_core::manifest::{BackupManifest, BackupRecord};
+use kafka_backup_core::segment::SegmentWriter;
 use kube::{
-    api::{Patch, PatchParams},
+    api::{ListParams, Patch, PatchParams},
     runtime::controller::Action,
     Api, Client, ResourceExt,
 };
 use serde_json::json;
 use std::str::FromStr;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
 use crate::adapters::{
-    build_backup_config, default_tls_dir, to_core_backup_config, ResolvedStorage, TlsFileManager,
+    build_backup_config, build_circuit_breaker_config, build_kafka_config, build_storage_config,
+    create_tls_manager, ensure_pvc, ignored_client_config, to_core_backup_config,
+    to_core_compression, to_core_kafka_config_with_tls, ResolvedStorage,
 };
-use crate::crd::KafkaBackup;
+use crate::crd::{KafkaBackup, KafkaRestore, OffsetStorageBackendKind};
 use crate::error::{Error, Result};
 use crate::metrics;
-use crate::reconcilers::retention::{self, RetentionReport};
+use crate::reconcilers::retention::{self, RestoreReference, RetentionReport};
+use crate::reconcilers::{circuit_breaker, events, gc, status};
+
+/// Phase reported while an auto-created PVC waits to be bound
+pub const PHASE_PVC_NOT_BOUND: &str = "PvcNotBound";
+
+/// Delay between checks while waiting for an auto-created PVC to bind
+const PVC_BIND_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How often a running backup's bytes written are checked against
+/// `maxTotalBytes`
+const SIZE_LIMIT_POLL_INTERVAL: Duration = Duration::from_secs(5);
 
 /// Process-local guard recording the most recent wall-clock time at which
 /// this operator fired a scheduled backup for each `{namespace}/{name}`.
@@ -52,6 +70,63 @@ fn guard_key(namespace: &str, name: &str) -> String {
     format!("{}/{}", namespace, name)
 }
 
+/// Default upper bound on the schedule requeue interval
+const DEFAULT_MAX_REQUEUE_SECS: u64 = 300;
+
+/// Environment variable overriding the default schedule requeue bound
+const MAX_REQUEUE_ENV: &str = "BACKUP_MAX_REQUEUE_SECS";
+
+/// Lower bound on the schedule requeue interval so a tick that is due
+/// "now" (or whose gap rounds to zero) does not spin the controller.
+const MIN_REQUEUE: Duration = Duration::from_secs(1);
+
+/// Slack added to a requeue that lands on the next tick so the reconcile
+/// wakes just after the fire time rather than a hair before it, which would
+/// defer the run by another [`MIN_REQUEUE`].
+const SCHEDULE_FIRE_MARGIN: Duration = Duration::from_millis(100);
+
+/// Default shortest allowed gap between scheduled runs
+const DEFAULT_MIN_SCHEDULE_INTERVAL_SECS: u64 = 60;
+
+/// Number of upcoming ticks sampled when measuring a schedule's interval
+const SCHEDULE_INTERVAL_SAMPLES: usize = 10;
+
+/// Shortest gap between the next [`SCHEDULE_INTERVAL_SAMPLES`] ticks of
+/// `schedule` after `after`. `None` if fewer than two ticks remain.
+fn min_schedule_interval(schedule: &Schedule, after: DateTime<Utc>) -> Option<Duration> {
+    let ticks: Vec<DateTime<Utc>> = schedule
+        .after(&after)
+        .take(SCHEDULE_INTERVAL_SAMPLES)
+        .collect();
+    ticks
+        .windows(2)
+        .filter_map(|pair| (pair[1] - pair[0]).to_std().ok())
+        .min()
+}
+
+/// Resolve the maximum schedule requeue interval: spec, then env, then default.
+fn max_requeue(backup: &KafkaBackup) -> Duration {
+    let secs = backup.spec.max_requeue_secs.unwrap_or_else(|| {
+        std::env::var(MAX_REQUEUE_ENV)
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_MAX_REQUEUE_SECS)
+    });
+    Duration::from_secs(secs)
+}
+
+/// Requeue until the next tick when it falls within `max`, so a backup fires
+/// on time instead of up to `max` late; otherwise wait `max` and look again.
+/// Floored at [`MIN_REQUEUE`] so sub-minute schedules do not spin.
+fn schedule_requeue_duration(until_next: Duration, max: Duration) -> Duration {
+    if until_next < max {
+        (until_next + SCHEDULE_FIRE_MARGIN).max(MIN_REQUEUE)
+    } else {
+        max
+    }
+}
+
 /// Validate the KafkaBackup spec
 pub fn validate(backup: &KafkaBackup) -> Result<()> {
     // Validate topics
@@ -70,32 +145,63 @@ pub fn validate(backup: &KafkaBackup) -> Result<()> {
     validate_storage(&backup.spec.storage)?;
 
     // Validate schedule if provided
-    if let Some(schedule) = &backup.spec.schedule {
-        Schedule::from_str(schedule).map_err(|e| {
-            Error::validation(format!("Invalid cron schedule '{}': {}", schedule, e))
+    if let Some(schedule_str) = &backup.spec.schedule {
+        let schedule = Schedule::from_str(schedule_str).map_err(|e| {
+            Error::validation(format!("Invalid cron schedule '{}': {}", schedule_str, e))
         })?;
+        validate_schedule_interval(backup, schedule_str, &schedule)?;
     }
 
-    // Validate compression
-    match backup.spec.compression.as_str() {
-        "none" | "lz4" | "zstd" => {}
-        other => {
+    if backup.spec.timeout_secs == Some(0) {
+        return Err(Error::validation("timeoutSecs must be greater than 0"));
+    }
+
+    if backup.spec.max_total_bytes == Some(0) {
+        return Err(Error::validation("maxTotalBytes must be greater than 0"));
+    }
+
+    if backup.spec.offset_storage_backend == OffsetStorageBackendKind::Memory
+        && checkpoint_enabled(backup)
+    {
+        return Err(Error::validation(
+            "offsetStorageBackend memory cannot resume a checkpointed backup; set checkpoint.enabled to false or use sqlite",
+        ));
+    }
+
+    if let Some(max_message_bytes) = backup.spec.max_message_bytes {
+        if max_message_bytes == 0 {
+            return Err(Error::validation("maxMessageBytes must be greater than 0"));
+        }
+        if max_message_bytes > backup.spec.segment_max_bytes {
             return Err(Error::validation(format!(
-                "Invalid compression '{}': must be one of: none, lz4, zstd",
-                other
+                "maxMessageBytes ({}) cannot exceed segmentMaxBytes ({})",
+                max_message_bytes, backup.spec.segment_max_bytes
             )));
         }
     }
 
-    // Validate compression level for zstd
-    if backup.spec.compression == "zstd"
-        && (backup.spec.compression_level < 1 || backup.spec.compression_level > 22)
+    if backup.spec.max_requeue_secs == Some(0) {
+        return Err(Error::validation("maxRequeueSecs must be greater than 0"));
+    }
+
+    // Validate compression, globally and per topic
+    if let Some(message) =
+        compression_error(&backup.spec.compression, backup.spec.compression_level)
     {
-        return Err(Error::validation(format!(
-            "Invalid zstd compression level {}: must be between 1 and 22",
-            backup.spec.compression_level
-        )));
+        return Err(Error::validation(message));
+    }
+    for (topic, topic_override) in &backup.spec.topic_compression {
+        if let Some(message) = compression_error(
+            &topic_override.compression,
+            topic_override.compression_level,
+        ) {
+            return Err(Error::validation(format!(
+                "topicCompression for '{}': {}",
+                topic, message
+            )));
+        }
     }
+    validate_uniform_compression(backup)?;
 
     if backup.spec.segment_max_bytes == 0 {
         return Err(Error::validation("segmentMaxBytes must be greater than 0"));
@@ -119,18 +225,15 @@ pub fn validate(backup: &KafkaBackup) -> Result<()> {
                 "rateLimiting.maxConcurrentPartitions must be greater than 0",
             ));
         }
+        if rate_limiting.ramp_up_secs.is_some() {
+            return Err(Error::validation(
+                "rateLimiting.rampUpSecs only applies to restores",
+            ));
+        }
     }
 
-    // Validate TLS configuration: SSL/SASL_SSL requires at least one TLS secret
-    let protocol = backup.spec.kafka_cluster.security_protocol.to_uppercase();
-    if (protocol == "SSL" || protocol == "SASL_SSL")
-        && backup.spec.kafka_cluster.tls_secret.is_none()
-        && backup.spec.kafka_cluster.ca_secret.is_none()
-    {
-        return Err(Error::validation(
-            "securityProtocol SSL/SASL_SSL requires either tlsSecret or caSecret to be configured",
-        ));
-    }
+    // Validate security: SASL needs credentials, SSL needs certificates
+    super::validate_kafka_security(&backup.spec.kafka_cluster)?;
 
     if let Some(connection) = &backup.spec.kafka_cluster.connection {
         if connection.connections_per_broker == 0 {
@@ -142,9 +245,110 @@ pub fn validate(backup: &KafkaBackup) -> Result<()> {
 
     retention::validate_retention(backup.spec.retention.as_ref())?;
 
+    for (key, value) in &backup.spec.labels {
+        if let Some(message) = label_error(key, value) {
+            return Err(Error::validation(format!("labels: {}", message)));
+        }
+    }
+
     Ok(())
 }
 
+/// Maximum length of a label name or value
+const MAX_LABEL_NAME_LEN: usize = 63;
+
+/// Maximum length of a label key prefix
+const MAX_LABEL_PREFIX_LEN: usize = 253;
+
+/// Why `key: value` is not a valid Kubernetes label, or `None` if it is. A
+/// key is an optional DNS subdomain prefix and `/`, then a name; a value is
+/// empty or a name. Names are at most 63 alphanumerics, `-`, `_` and `.`,
+/// starting and ending with an alphanumeric.
+fn label_error(key: &str, value: &str) -> Option<String> {
+    let is_name = |name: &str| {
+        let bytes = name.as_bytes();
+        !bytes.is_empty()
+            && bytes.len() <= MAX_LABEL_NAME_LEN
+            && bytes[0].is_ascii_alphanumeric()
+            && bytes[bytes.len() - 1].is_ascii_alphanumeric()
+            && bytes
+                .iter()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+    };
+    let is_subdomain = |prefix: &str| {
+        prefix.len() <= MAX_LABEL_PREFIX_LEN
+            && prefix.split('.').all(|part| {
+                let bytes = part.as_bytes();
+                !bytes.is_empty()
+                    && bytes.len() <= MAX_LABEL_NAME_LEN
+                    && bytes[0].is_ascii_alphanumeric()
+                    && bytes[bytes.len() - 1].is_ascii_alphanumeric()
+                    && bytes
+                        .iter()
+                        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'-')
+            })
+    };
+
+    let (prefix, name) = match key.split_once('/') {
+        Some((prefix, name)) => (Some(prefix), name),
+        None => (None, key),
+    };
+    if prefix.is_some_and(|prefix| !is_subdomain(prefix)) {
+        return Some(format!(
+            "key '{}' must have a lowercase DNS subdomain prefix of at most {} characters",
+            key, MAX_LABEL_PREFIX_LEN
+        ));
+    }
+    if !is_name(name) {
+        return Some(format!(
+            "key '{}' must be at most {} alphanumerics, '-', '_' or '.', starting and ending with an alphanumeric",
+            key, MAX_LABEL_NAME_LEN
+        ));
+    }
+    if !value.is_empty() && !is_name(value) {
+        return Some(format!(
+            "value '{}' of '{}' must be empty or at most {} alphanumerics, '-', '_' or '.', starting and ending with an alphanumeric",
+            value, key, MAX_LABEL_NAME_LEN
+        ));
+    }
+    None
+}
+
+/// Reject a schedule whose runs would come closer together than
+/// `minScheduleIntervalSecs`, or only warn when `allowFrequentSchedule` is set
+fn validate_schedule_interval(
+    backup: &KafkaBackup,
+    schedule_str: &str,
+    schedule: &Schedule,
+) -> Result<()> {
+    let floor = backup
+        .spec
+        .min_schedule_interval_secs
+        .unwrap_or(DEFAULT_MIN_SCHEDULE_INTERVAL_SECS);
+    let Some(interval) = min_schedule_interval(schedule, Utc::now()) else {
+        return Ok(());
+    };
+    if interval.as_secs() >= floor {
+        return Ok(());
+    }
+
+    if backup.spec.allow_frequent_schedule {
+        warn!(
+            schedule = %schedule_str,
+            interval_secs = interval.as_secs(),
+            min_interval_secs = floor,
+            "Schedule fires more often than minScheduleIntervalSecs; runs may overlap and queue"
+        );
+        return Ok(());
+    }
+    Err(Error::validation(format!(
+        "Cron schedule '{}' fires every {}s, more often than minScheduleIntervalSecs ({}s); set allowFrequentSchedule to accept it",
+        schedule_str,
+        interval.as_secs(),
+        floor
+    )))
+}
+
 /// Validate storage configuration
 fn validate_storage(storage: &crate::crd::StorageSpec) -> Result<()> {
     match storage.storage_type.as_str() {
@@ -156,38 +360,38 @@ fn validate_storage(storage: &crate::crd::StorageSpec) -> Result<()> {
             }
         }
         "s3" => {
-            if storage.s3.is_none() {
-                return Err(Error::validation(
-                    "S3 storage selected but s3 configuration is missing",
-                ));
-            }
+            let s3 = storage.s3.as_ref().ok_or_else(|| {
+                Error::validation("S3 storage selected but s3 configuration is missing")
+            })?;
+            super::validate_s3_transport(s3)?;
         }
         "azure" => {
             let azure = storage.azure.as_ref().ok_or_else(|| {
                 Error::validation("Azure storage selected but azure configuration is missing")
             })?;
             // The adapter supports workload identity, service principal, SAS token,
-            // account key, and default credential fallback.
-            if azure.credentials_secret.is_some()
-                && (azure.sas_token_secret.is_some()
-                    || azure.service_principal_secret.is_some()
-                    || azure.use_workload_identity)
-            {
-                return Err(Error::validation(
-                    "Azure storage authentication methods are mutually exclusive",
-                ));
-            }
-            if azure.sas_token_secret.is_some()
-                && (azure.service_principal_secret.is_some() || azure.use_workload_identity)
-            {
-                return Err(Error::validation(
-                    "Azure storage authentication methods are mutually exclusive",
-                ));
+            // account key, and default credential fallback, picking the first one
+            // set; more than one would silently ignore the others.
+            let auth_methods: Vec<&str> = [
+                (azure.use_workload_identity, "useWorkloadIdentity"),
+                (
+                    azure.service_principal_secret.is_some(),
+                    "servicePrincipalSecret",
+                ),
+                (azure.sas_token_secret.is_some(), "sasTokenSecret"),
+                (azure.credentials_secret.is_some(), "credentialsSecret"),
+            ]
+            .into_iter()
+            .filter_map(|(configured, field)| configured.then_some(field))
+            .collect();
+            if auth_methods.len() > 1 {
+                return Err(Error::validation(format!(
+                    "Azure storage authentication methods are mutually exclusive, but {} are all set",
+                    auth_methods.join(", ")
+                )));
             }
-            if azure.service_principal_secret.is_some() && azure.use_workload_identity {
-                return Err(Error::validation(
-                    "Azure storage authentication methods are mutually exclusive",
-                ));
+            if let Some(endpoint) = &azure.endpoint {
+                super::validate_endpoint_url("azure.endpoint", endpoint)?;
             }
         }
         "gcs" => {
@@ -204,7 +408,7 @@ fn validate_storage(storage: &crate::crd::StorageSpec) -> Result<()> {
             )));
         }
     }
-    Ok(())
+    super::validate_storage_credentials(storage)
 }
 
 /// Check if a backup should run based on the schedule
@@ -212,16 +416,25 @@ pub async fn check_schedule(
     backup: &KafkaBackup,
     client: &Client,
     namespace: &str,
+    shutdown: &CancellationToken,
 ) -> Result<Action> {
     let name = backup.name_any();
 
+    if let Some(wait) = ensure_backup_pvc(backup, client, namespace).await? {
+        return Ok(Action::requeue(wait));
+    }
+
     // If no schedule, this is a one-shot backup - check if already completed
     let Some(schedule_str) = &backup.spec.schedule else {
+        // A timed-out one-shot backup is retried once the retry delay elapses
+        if let Some(wait) = one_shot_timeout_retry_wait(backup, Utc::now()) {
+            return Ok(Action::requeue(wait));
+        }
         if !should_execute_one_shot_backup(backup) {
             return Ok(Action::await_change());
         }
         // One-shot backup that hasn't run - execute now
-        return execute_backup(backup, client, namespace).await;
+        return execute_backup(backup, client, namespace, None, shutdown).await;
     };
 
     // Parse schedule
@@ -263,7 +476,7 @@ pub async fn check_schedule(
         // above even if it reads a stale cache.
         scheduler_guard().lock().unwrap().insert(key, now);
         info!(name = %name, "Scheduled backup time reached, executing backup");
-        return execute_backup(backup, client, namespace).await;
+        return execute_backup(backup, client, namespace, None, shutdown).await;
     }
 
     // Calculate next run time
@@ -274,7 +487,7 @@ pub async fn check_schedule(
 
     // Requeue for next scheduled backup
     let duration_until_next = (next_run - now).to_std().unwrap_or(Duration::from_secs(60));
-    let requeue_duration = duration_until_next.min(Duration::from_secs(300)); // Max 5 minutes
+    let requeue_duration = schedule_requeue_duration(duration_until_next, max_requeue(backup));
 
     Ok(Action::requeue(requeue_duration))
 }
@@ -285,10 +498,80 @@ fn should_execute_one_shot_backup(backup: &KafkaBackup) -> bool {
             .status
             .as_ref()
             .and_then(|status| status.phase.as_deref()),
-        Some("Running" | "Completed" | "Failed")
+        Some("Running" | "Completed" | "Failed" | super::PHASE_ABORTED_SIZE_LIMIT)
     )
 }
 
+/// Remaining wait before a one-shot backup in the TimedOut phase is retried
+fn one_shot_timeout_retry_wait(backup: &KafkaBackup, now: DateTime<Utc>) -> Option<Duration> {
+    let status = backup.status.as_ref()?;
+    if status.phase.as_deref() != Some(super::PHASE_TIMED_OUT) {
+        return None;
+    }
+    super::timeout_retry_wait(&status.conditions, now)
+}
+
+/// Await `operation`, polling `bytes_written` every
+/// [`SIZE_LIMIT_POLL_INTERVAL`] and mapping a total above `max_total_bytes` to
+/// [`Error::SizeLimitExceeded`]. The future is dropped at that point.
+async fn run_within_size_limit<F: Future>(
+    max_total_bytes: Option<u64>,
+    bytes_written: impl Fn() -> u64,
+    description: &str,
+    operation: F,
+) -> Result<F::Output> {
+    let Some(limit) = max_total_bytes else {
+        return Ok(operation.await);
+    };
+
+    tokio::pin!(operation);
+    let mut poll = tokio::time::interval(SIZE_LIMIT_POLL_INTERVAL);
+    loop {
+        tokio::select! {
+            output = &mut operation => return Ok(output),
+            _ = poll.tick() => {
+                let written = bytes_written();
+                if written > limit {
+                    return Err(Error::SizeLimitExceeded(format!(
+                        "{} wrote {} bytes, over maxTotalBytes={}",
+                        description, written, limit
+                    )));
+                }
+            }
+        }
+    }
+}
+
+/// Remove the SQLite offset database the core engine creates in its working
+/// directory for `backup_id`, including WAL side files.
+fn remove_offset_db(working_dir: &Path, backup_id: &str) {
+    for suffix in ["", "-wal", "-shm"] {
+        let path = working_dir.join(format!("{}-offsets.db{}", backup_id, suffix));
+        if let Err(e) = std::fs::remove_file(&path) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                warn!(path = %path.display(), error = %e, "Failed to remove offset database");
+            }
+        }
+    }
+}
+
+/// Remove the offset database of a completed run that nothing will resume
+/// from. Checkpointed and continuous backups keep theirs on local storage;
+/// for S3 the database was synced to the bucket, so the work directory copy
+/// always goes.
+fn remove_finished_offset_db(
+    backup: &KafkaBackup,
+    storage: &ResolvedStorage,
+    working_dir: &Path,
+    backup_id: &str,
+) {
+    let resumable = checkpoint_enabled(backup) || backup.spec.continuous;
+    if !resumable || matches!(storage, ResolvedStorage::S3(_)) {
+        debug!(backup_id = %backup_id, "Removing offset database of completed backup");
+        remove_offset_db(working_dir, backup_id);
+    }
+}
+
 /// Determine if a scheduled backup tick is due.
 ///
 /// Uses `status.lastScheduleTime` as the monotonic anchor — this is written
@@ -318,12 +601,290 @@ fn should_run_backup(backup: &KafkaBackup, schedule: &Schedule, now: DateTime<Ut
         .unwrap_or(false)
 }
 
-/// Execute a backup operation
-async fn execute_backup(backup: &KafkaBackup, client: &Client, namespace: &str) -> Result<Action> {
+/// Refresh the seconds-since-last-backup gauge from `status.lastBackupTime`.
+/// Backups that never completed have no sample.
+pub fn record_backup_age(backup: &KafkaBackup, namespace: &str, now: DateTime<Utc>) {
+    if let Some(last_backup) = backup.status.as_ref().and_then(|s| s.last_backup_time) {
+        metrics::SECONDS_SINCE_LAST_BACKUP
+            .with_label_values(&[namespace, &backup.name_any()])
+            .set(backup_age_secs(last_backup, now));
+    }
+}
+
+fn backup_age_secs(last_backup: DateTime<Utc>, now: DateTime<Utc>) -> f64 {
+    (now - last_backup).num_milliseconds().max(0) as f64 / 1000.0
+}
+
+/// Time left until a `suspendUntil` window ends, or `None` if backups are
+/// not currently suspended by it.
+pub fn suspension_remaining(backup: &KafkaBackup, now: DateTime<Utc>) -> Option<Duration> {
+    let until = backup.spec.suspend_until?;
+    (until > now).then(|| {
+        (until - now)
+            .to_std()
+            .unwrap_or(MIN_REQUEUE)
+            .max(MIN_REQUEUE)
+    })
+}
+
+/// Annotation requesting an immediate backup outside the cron schedule.
+/// Each distinct value fires exactly once.
+pub const TRIGGER_ANNOTATION: &str = "kafka.oso.sh/trigger";
+
+/// Return the trigger token if the annotation carries a value that has not
+/// yet been recorded in `status.lastTriggerToken`.
+pub fn pending_trigger(backup: &KafkaBackup) -> Option<String> {
+    let token = backup
+        .annotations()
+        .get(TRIGGER_ANNOTATION)
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())?;
+
+    let last_token = backup
+        .status
+        .as_ref()
+        .and_then(|status| status.last_trigger_token.as_deref());
+
+    (last_token != Some(token)).then(|| token.to_string())
+}
+
+/// Run a manually triggered backup. The token is recorded before execution
+/// so a failed backup is not retried on every reconcile for the same token.
+pub async fn execute_triggered_backup(
+    backup: &KafkaBackup,
+    client: &Client,
+    namespace: &str,
+    token: &str,
+    shutdown: &CancellationToken,
+) -> Result<Action> {
+    let name = backup.name_any();
+    let api: Api<KafkaBackup> = Api::namespaced(client.clone(), namespace);
+
+    info!(name = %name, token = %token, "Manual backup trigger received");
+
+    // Leave the token unrecorded until the PVC binds so the trigger still fires
+    if let Some(wait) = ensure_backup_pvc(backup, client, namespace).await? {
+        return Ok(Action::requeue(wait));
+    }
+
+    let trigger_status = json!({
+        "status": {
+            "lastTriggerToken": token,
+        }
+    });
+    api.patch_status(
+        &name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(trigger_status),
+    )
+    .await?;
+
+    execute_backup(backup, client, namespace, None, shutdown).await
+}
+
+/// Provision the backup PVC when `storage.pvc.create.enabled` is set. While
+/// the claim waits to bind the resource is parked in the `PvcNotBound` phase
+/// and the returned delay says when to check again.
+async fn ensure_backup_pvc(
+    backup: &KafkaBackup,
+    client: &Client,
+    namespace: &str,
+) -> Result<Option<Duration>> {
+    let storage = &backup.spec.storage;
+    let Some(pvc) = storage
+        .pvc
+        .as_ref()
+        .filter(|_| storage.storage_type == "pvc")
+    else {
+        return Ok(None);
+    };
+    let Some(create) = pvc.create.as_ref().filter(|create| create.enabled) else {
+        return Ok(None);
+    };
+
+    let name = backup.name_any();
+    let api: Api<KafkaBackup> = Api::namespaced(client.clone(), namespace);
+    let phase = backup.status.as_ref().and_then(|s| s.phase.as_deref());
+
+    let bound = ensure_pvc(client, namespace, &pvc.claim_name, create).await?;
+
+    if bound {
+        if phase == Some(PHASE_PVC_NOT_BOUND) {
+            info!(name = %name, claim = %pvc.claim_name, "Backup PVC bound");
+            let ready_status = json!({
+                "status": {
+                    "phase": "Ready",
+                    "message": format!("PVC {} is bound", pvc.claim_name),
+                }
+            });
+            api.patch_status(
+                &name,
+                &PatchParams::apply("kafka-backup-operator"),
+                &Patch::Merge(ready_status),
+            )
+            .await?;
+        }
+        return Ok(None);
+    }
+
+    info!(name = %name, claim = %pvc.claim_name, "Waiting for backup PVC to bind");
+    if phase != Some(PHASE_PVC_NOT_BOUND) {
+        let waiting_status = json!({
+            "status": {
+                "phase": PHASE_PVC_NOT_BOUND,
+                "message": format!(
+                    "Waiting for PVC {} to be bound and mounted in the operator pod at {}",
+                    pvc.claim_name,
+                    crate::adapters::pvc_mount_path(&pvc.claim_name).display()
+                ),
+            }
+        });
+        api.patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(waiting_status),
+        )
+        .await?;
+    }
+
+    Ok(Some(PVC_BIND_POLL_INTERVAL))
+}
+
+/// Whether the engine checkpoints its progress; on unless explicitly disabled
+fn checkpoint_enabled(backup: &KafkaBackup) -> bool {
+    backup
+        .spec
+        .checkpoint
+        .as_ref()
+        .map(|c| c.enabled)
+        .unwrap_or(true)
+}
+
+/// What to do with a backup found in the Running or Interrupted phase by a
+/// reconcile that is not the one running it
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RunningBackupDecision {
+    /// The owning engine is still heartbeating
+    Wait,
+    /// The engine is gone; continue from the offsets DB of this backup ID
+    Resume(String),
+    /// The engine is gone and there is nothing to resume from
+    MarkFailed,
+}
+
+fn running_backup_decision(backup: &KafkaBackup, now: DateTime<Utc>) -> RunningBackupDecision {
+    let status = backup.status.as_ref();
+    let interrupted = status.and_then(|s| s.phase.as_deref()) == Some(super::PHASE_INTERRUPTED);
+    let last_seen = status.and_then(|s| s.heartbeat_time.or(s.last_schedule_time));
+    if !interrupted && !super::heartbeat_is_stale(last_seen, now) {
+        return RunningBackupDecision::Wait;
+    }
+
+    match status.and_then(|s| s.backup_id.clone()) {
+        Some(backup_id) if checkpoint_enabled(backup) => RunningBackupDecision::Resume(backup_id),
+        _ => RunningBackupDecision::MarkFailed,
+    }
+}
+
+/// Handle a backup left in the Running or Interrupted phase. A live engine is
+/// left alone; one orphaned by an operator restart or stopped by shutdown is
+/// resumed from its offsets DB when checkpointing is enabled, and marked
+/// Failed otherwise.
+pub async fn reconcile_running(
+    backup: &KafkaBackup,
+    client: &Client,
+    namespace: &str,
+    shutdown: &CancellationToken,
+) -> Result<Action> {
+    let name = backup.name_any();
+
+    match running_backup_decision(backup, Utc::now()) {
+        RunningBackupDecision::Wait => Ok(Action::requeue(super::HEARTBEAT_STALE_AFTER)),
+        RunningBackupDecision::Resume(backup_id) => {
+            info!(name = %name, backup_id = %backup_id, "Resuming interrupted backup from checkpoint");
+            execute_backup(backup, client, namespace, Some(backup_id), shutdown).await
+        }
+        RunningBackupDecision::MarkFailed => {
+            let message =
+                "Backup was interrupted before completing and cannot be resumed without checkpointing";
+            warn!(name = %name, "{}", message);
+            let api: Api<KafkaBackup> = Api::namespaced(client.clone(), namespace);
+            let recent_errors = super::record_recent_error(backup, "Failed", message);
+            let failed_status = json!({
+                "status": {
+                    "phase": "Failed",
+                    "message": message,
+                    "resumable": false,
+                    "recentErrors": recent_errors,
+                    "conditions": [{
+                        "type": "Ready",
+                        "status": "False",
+                        "lastTransitionTime": Utc::now(),
+                        "reason": "BackupInterrupted",
+                        "message": message
+                    }]
+                }
+            });
+            api.patch_status(
+                &name,
+                &PatchParams::apply("kafka-backup-operator"),
+                &Patch::Merge(failed_status),
+            )
+            .await?;
+
+            if backup.spec.schedule.is_some() {
+                Ok(Action::requeue(Duration::from_secs(60)))
+            } else {
+                Ok(Action::await_change())
+            }
+        }
+    }
+}
+
+/// Execute a backup operation. `resume_backup_id` continues an interrupted
+/// backup under its original ID so the engine reopens that offsets DB.
+async fn execute_backup(
+    backup: &KafkaBackup,
+    client: &Client,
+    namespace: &str,
+    resume_backup_id: Option<String>,
+    shutdown: &CancellationToken,
+) -> Result<Action> {
     let name = backup.name_any();
     let api: Api<KafkaBackup> = Api::namespaced(client.clone(), namespace);
 
-    info!(name = %name, "Starting backup execution");
+    let resumed = resume_backup_id.is_some();
+    let backup_id = resume_backup_id
+        .unwrap_or_else(|| format!("{}-{}", name, Utc::now().format("%Y%m%d-%H%M%S")));
+
+    info!(name = %name, backup_id = %backup_id, resumed, "Starting backup execution");
+
+    // Repeated failures open the circuit breaker; hold off until it half-opens
+    let breaker_config = backup
+        .spec
+        .circuit_breaker
+        .as_ref()
+        .map(build_circuit_breaker_config);
+    if let Some(config) = &breaker_config {
+        if let Some(wait) = circuit_breaker::admit("KafkaBackup", namespace, &name, config) {
+            warn!(name = %name, retry_in = ?wait, "Circuit breaker open, deferring backup");
+            return Ok(Action::requeue(wait));
+        }
+    }
+
+    // Probe storage before marking Running, so an outage is a retried
+    // reconcile error rather than a failure deep inside the engine
+    let storage = build_storage_config(&backup.spec.storage, client, namespace).await?;
+    super::probe_storage(&storage, "KafkaBackup", namespace, &name).await?;
+
+    // Likewise connect to the source cluster first: a broker outage is an
+    // Error::KafkaConnection, retried on its short interval, not a failed run
+    let cluster = {
+        let kafka = build_kafka_config(&backup.spec.kafka_cluster, client, namespace).await?;
+        let tls_manager = create_tls_manager(&kafka, &name)?;
+        let source = to_core_kafka_config_with_tls(&kafka, &[], tls_manager.as_ref());
+        super::fetch_cluster_info(source).await?
+    };
 
     // Tentative anchor: write `lastScheduleTime` BEFORE the engine runs so the
     // reflector cache always has something to scheduler-anchor against on the
@@ -333,8 +894,10 @@ async fn execute_backup(backup: &KafkaBackup, client: &Client, namespace: &str)
     let running_status = json!({
         "status": {
             "phase": "Running",
-            "message": "Backup in progress",
+            "message": if resumed { "Backup resumed from checkpoint" } else { "Backup in progress" },
             "lastScheduleTime": Utc::now(),
+            "backupId": backup_id,
+            "resumable": checkpoint_enabled(backup),
             "observedGeneration": backup.metadata.generation,
         }
     });
@@ -345,13 +908,38 @@ async fn execute_backup(backup: &KafkaBackup, client: &Client, namespace: &str)
     )
     .await?;
 
-    // TODO: Execute actual backup using kafka-backup-core
-    // For now, simulate a successful backup
-    let backup_result = execute_backup_internal(backup, client, namespace).await;
+    // Execute backup
+    let start_time = std::time::Instant::now();
+    let backup_result = execute_backup_internal(
+        backup,
+        client,
+        namespace,
+        backup_id.clone(),
+        cluster.cluster_id,
+        shutdown,
+    )
+    .await;
+    let duration = start_time.elapsed();
+
+    // A run stopped by operator shutdown says nothing about the backup's health
+    let interrupted = matches!(backup_result, Err(Error::Interrupted(_)));
+    if let Some(config) = breaker_config.as_ref().filter(|_| !interrupted) {
+        let state = circuit_breaker::record(
+            "KafkaBackup",
+            namespace,
+            &name,
+            config,
+            backup_result.is_ok(),
+        );
+        debug!(name = %name, state = ?state, "Circuit breaker updated");
+    }
 
     match backup_result {
         Ok(result) => {
-            info!(name = %name, records = result.records_processed, bytes = result.bytes_processed, "Backup completed successfully");
+            info!(name = %name, records = result.records_processed, bytes = result.bytes_processed, duration = ?duration, "Backup completed successfully");
+
+            let records_per_sec = throughput_per_sec(result.records_processed, duration);
+            let bytes_per_sec = throughput_per_sec(result.bytes_processed, duration);
 
             // Update metrics
             metrics::BACKUPS_TOTAL
@@ -363,6 +951,27 @@ async fn execute_backup(backup: &KafkaBackup, client: &Client, namespace: &str)
             metrics::BACKUP_RECORDS
                 .with_label_values(&[namespace, &name])
                 .set(result.records_processed as f64);
+            metrics::BACKUP_DURATION
+                .with_label_values(&[namespace, &name])
+                .observe(duration.as_secs_f64());
+            metrics::SECONDS_SINCE_LAST_BACKUP
+                .with_label_values(&[namespace, &name])
+                .set(0.0);
+            if let Some(ratio) = result.compression_ratio {
+                metrics::BACKUP_COMPRESSION_RATIO
+                    .with_label_values(&[namespace, &name])
+                    .set(ratio);
+            }
+            if let Some(skipped) = result.skipped_records.filter(|skipped| *skipped > 0) {
+                metrics::SKIPPED_RECORDS
+                    .with_label_values(&[namespace, &name, SKIPPED_OVERSIZED])
+                    .inc_by(skipped as f64);
+            }
+            if let Some(retention) = &result.retention {
+                metrics::RETAINED_BACKUPS
+                    .with_label_values(&[namespace, &name])
+                    .set(retention.remaining_backups() as f64);
+            }
 
             // Calculate next scheduled backup
             let next_backup = backup.spec.schedule.as_ref().and_then(|s| {
@@ -375,11 +984,22 @@ async fn execute_backup(backup: &KafkaBackup, client: &Client, namespace: &str)
                 "status": {
                     "phase": "Completed",
                     "message": "Backup completed successfully",
+                    "summary": status::backup_summary(
+                        result.topics_backed_up,
+                        result.records_processed,
+                        result.bytes_processed,
+                        duration,
+                    ),
                     "lastBackupTime": Utc::now(),
                     "nextScheduledBackup": next_backup,
                     "recordsProcessed": result.records_processed,
                     "bytesProcessed": result.bytes_processed,
                     "segmentsCompleted": result.segments_completed,
+                    "skippedRecords": result.skipped_records,
+                    "throughputRecordsPerSec": records_per_sec,
+                    "throughputBytesPerSec": bytes_per_sec,
+                    "topicsBackedUp": result.topics_backed_up,
+                    "partitionsBackedUp": result.partitions_backed_up,
                     "backupId": result.backup_id,
                     "observedGeneration": backup.metadata.generation,
                     "conditions": [{
@@ -421,6 +1041,18 @@ async fn execute_backup(backup: &KafkaBackup, client: &Client, namespace: &str)
                 if let Some(retention_error) = &result.retention_error {
                     status.insert("retentionError".to_string(), json!(retention_error));
                 }
+
+                if let Some(retention) = result
+                    .retention
+                    .as_ref()
+                    .filter(|retention| !retention.blocked_by.is_empty())
+                {
+                    if let Some(conditions) =
+                        status.get_mut("conditions").and_then(|c| c.as_array_mut())
+                    {
+                        conditions.push(deletion_blocked_condition(&retention.blocked_by));
+                    }
+                }
             }
 
             api.patch_status(
@@ -440,20 +1072,65 @@ async fn execute_backup(backup: &KafkaBackup, client: &Client, namespace: &str)
         Err(e) => {
             error!(name = %name, error = %e, "Backup failed");
 
+            let timed_out = matches!(e, Error::Timeout(_));
+            let size_limited = matches!(e, Error::SizeLimitExceeded(_));
+            // Rejected credentials leave the run resumable like an interrupted
+            // one; the retry reads the rotated secret
+            let rotated = matches!(e, Error::CredentialsRotated(_));
+            let (outcome, phase, reason) = if interrupted {
+                ("interrupted", super::PHASE_INTERRUPTED, "BackupInterrupted")
+            } else if rotated {
+                (
+                    "credentials_rotated",
+                    super::PHASE_INTERRUPTED,
+                    "CredentialsRotated",
+                )
+            } else if timed_out {
+                ("timeout", super::PHASE_TIMED_OUT, "BackupTimedOut")
+            } else if size_limited {
+                (
+                    "size_limit",
+                    super::PHASE_ABORTED_SIZE_LIMIT,
+                    "BackupSizeLimitExceeded",
+                )
+            } else {
+                ("failure", "Failed", "BackupFailed")
+            };
+
             metrics::BACKUPS_TOTAL
-                .with_label_values(&["failure", namespace, &name])
+                .with_label_values(&[outcome, namespace, &name])
                 .inc();
 
+            // An interrupted run resumes from its checkpoint and a run aborted
+            // at maxTotalBytes keeps what it wrote; anything else is not
+            // coming back for its segments
+            if matches!(outcome, "failure" | "timeout") {
+                collect_failed_backup(&storage, namespace, &name, &backup_id).await;
+            } else if size_limited {
+                if let Err(marker_error) =
+                    gc::mark_aborted(&storage, &backup_id, &e.to_string()).await
+                {
+                    warn!(name = %name, backup_id = %backup_id, error = %marker_error, "Failed to mark backup as aborted");
+                }
+            }
+
+            if timed_out || size_limited || rotated {
+                events::publish_warning(client, backup, reason, "Backup", &e.to_string()).await;
+            }
+
+            let message = format!("Backup failed: {}", e);
+            let recent_errors = super::record_recent_error(backup, phase, &message);
             let failed_status = json!({
                 "status": {
-                    "phase": "Failed",
-                    "message": format!("Backup failed: {}", e),
+                    "phase": phase,
+                    "message": message,
+                    "recentErrors": recent_errors,
                     "observedGeneration": backup.metadata.generation,
                     "conditions": [{
                         "type": "Ready",
                         "status": "False",
                         "lastTransitionTime": Utc::now(),
-                        "reason": "BackupFailed",
+                        "reason": reason,
                         "message": e.to_string()
                     }]
                 }
@@ -465,32 +1142,629 @@ async fn execute_backup(backup: &KafkaBackup, client: &Client, namespace: &str)
             )
             .await?;
 
+            // Handed to error_policy, which retries quickly with backoff
+            if rotated {
+                return Err(e);
+            }
+
             // Retry after delay
             Ok(Action::requeue(Duration::from_secs(300)))
         }
     }
 }
 
-/// Internal backup execution result
-struct BackupResult {
-    backup_id: String,
-    records_processed: u64,
-    bytes_processed: u64,
-    segments_completed: u64,
-    retention: Option<RetentionReport>,
-    retention_error: Option<String>,
+/// Remove what a failed run left in storage. Best effort: anything left
+/// behind is picked up by the next startup sweep.
+async fn collect_failed_backup(
+    storage: &ResolvedStorage,
+    namespace: &str,
+    name: &str,
+    backup_id: &str,
+) {
+    match gc::collect_backup(storage, backup_id).await {
+        Ok(report) => {
+            metrics::GC_BYTES_RECLAIMED
+                .with_label_values(&[namespace, name])
+                .inc_by(report.reclaimed_bytes as f64);
+        }
+        Err(e) => {
+            warn!(name = %name, backup_id = %backup_id, error = %e, "Failed to remove artifacts of failed backup");
+        }
+    }
 }
 
-/// Execute the actual backup using kafka-backup-core library
-async fn execute_backup_internal(
-    backup: &KafkaBackup,
-    client: &Client,
+/// Backup ID whose segments the startup sweep must leave alone: the run a
+/// Running or Interrupted backup will resume, or the last one aborted at
+/// maxTotalBytes (earlier aborted runs are kept by their marker)
+fn kept_backup_id(backup: &KafkaBackup) -> Option<&str> {
+    let status = backup.status.as_ref()?;
+    match status.phase.as_deref() {
+        Some("Running")
+        | Some(super::PHASE_INTERRUPTED)
+        | Some(super::PHASE_ABORTED_SIZE_LIMIT) => status.backup_id.as_deref(),
+        _ => None,
+    }
+}
+
+/// Remove the incomplete artifacts failed or aborted runs of every
+/// KafkaBackup left in storage, e.g. from an operator that crashed before
+/// its failure branch ran. Called once at startup, before any backup runs.
+pub async fn sweep_incomplete_backups(client: &Client, apis: &[Api<KafkaBackup>]) {
+    for api in apis {
+        let backups = match api.list(&ListParams::default()).await {
+            Ok(list) => list.items,
+            Err(e) => {
+                warn!(error = %e, "Failed to list KafkaBackups for incomplete backup sweep");
+                continue;
+            }
+        };
+        for backup in &backups {
+            let name = backup.name_any();
+            let namespace = backup.namespace().unwrap_or_default();
+            let report = match build_storage_config(&backup.spec.storage, client, &namespace).await
+            {
+                Ok(storage) => gc::sweep(&storage, &name, kept_backup_id(backup)).await,
+                Err(e) => Err(e),
+            };
+            match report {
+                Ok(report) => {
+                    metrics::GC_BYTES_RECLAIMED
+                        .with_label_values(&[&namespace, &name])
+                        .inc_by(report.reclaimed_bytes as f64);
+                }
+                Err(e) => {
+                    warn!(name = %name, namespace = %namespace, error = %e, "Skipping incomplete backup sweep");
+                }
+            }
+        }
+    }
+}
+
+/// Internal backup execution result
+struct BackupResult {
+    backup_id: String,
+    records_processed: u64,
+    bytes_processed: u64,
+    segments_completed: u64,
+    skipped_records: Option<u64>,
+    topics_backed_up: Option<u32>,
+    partitions_backed_up: Option<u32>,
+    compression_ratio: Option<f64>,
+    retention: Option<RetentionReport>,
+    retention_error: Option<String>,
+}
+
+/// Compression algorithms a backup may use. kafka-backup-core writes
+/// segments with lz4 or zstd only; gzip and snappy are rejected rather than
+/// silently written as zstd.
+pub const SUPPORTED_COMPRESSIONS: [&str; 3] = ["none", "lz4", "zstd"];
+
+/// Why a compression algorithm and level are invalid, if they are
+fn compression_error(compression: &str, level: i32) -> Option<String> {
+    match compression {
+        "zstd" if !(1..=22).contains(&level) => Some(format!(
+            "Invalid zstd compression level {}: must be between 1 and 22",
+            level
+        )),
+        other if SUPPORTED_COMPRESSIONS.contains(&other) => None,
+        other => Some(format!(
+            "Invalid compression '{}': must be one of: {}",
+            other,
+            SUPPORTED_COMPRESSIONS.join(", ")
+        )),
+    }
+}
+
+/// The engine applies one compression setting per run, so overrides must
+/// agree with each other and, when they differ from the global setting,
+/// name every backed-up topic
+fn validate_uniform_compression(backup: &KafkaBackup) -> Result<()> {
+    let spec = &backup.spec;
+    let mut overrides = spec
+        .topic_compression
+        .iter()
+        .map(|(topic, topic_override)| {
+            (
+                topic,
+                (
+                    topic_override.compression.as_str(),
+                    topic_override.compression_level,
+                ),
+            )
+        });
+    let Some((_, first)) = overrides.next() else {
+        return Ok(());
+    };
+    if let Some((topic, _)) = overrides.find(|(_, other)| *other != first) {
+        return Err(Error::validation(format!(
+            "topicCompression for '{}' differs from the other overrides; the backup engine \
+             applies one compression setting to every topic in a run",
+            topic
+        )));
+    }
+
+    if first == (spec.compression.as_str(), spec.compression_level) {
+        return Ok(());
+    }
+    match spec
+        .topics
+        .iter()
+        .find(|topic| !spec.topic_compression.contains_key(*topic))
+    {
+        Some(topic) => Err(Error::validation(format!(
+            "topicCompression differs from compression but does not cover topic '{}'; the \
+             backup engine applies one compression setting to every topic in a run",
+            topic
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// KafkaRestore phases after which a restore no longer reads its backup
+const TERMINAL_RESTORE_PHASES: [&str; 3] = ["Completed", "Failed", "RolledBack"];
+
+/// Restores, in any watched namespace, that still read from this backup
+pub async fn restores_using_backup(
+    backup: &KafkaBackup,
+    client: &Client,
+) -> Result<Vec<RestoreReference>> {
+    let mut restores = Vec::new();
+    for api in super::scoped_apis::<KafkaRestore>(client, &super::watch_namespaces()) {
+        restores.extend(api.list(&ListParams::default()).await?.items);
+    }
+    Ok(restore_references(backup, &restores))
+}
+
+/// Restores not yet in a terminal phase whose backupRef names `backup`.
+/// Restores reading storage directly are not tracked.
+fn restore_references(backup: &KafkaBackup, restores: &[KafkaRestore]) -> Vec<RestoreReference> {
+    let backup_name = backup.name_any();
+    let backup_namespace = backup.namespace().unwrap_or_else(|| "default".to_string());
+
+    restores
+        .iter()
+        .filter(|restore| {
+            let backup_ref = &restore.spec.backup_ref;
+            let ref_namespace = backup_ref
+                .namespace
+                .clone()
+                .or_else(|| restore.namespace())
+                .unwrap_or_else(|| "default".to_string());
+            backup_ref.storage.is_none()
+                && backup_ref.name == backup_name
+                && ref_namespace == backup_namespace
+        })
+        .filter(|restore| {
+            let phase = restore.status.as_ref().and_then(|s| s.phase.as_deref());
+            !phase.is_some_and(|phase| TERMINAL_RESTORE_PHASES.contains(&phase))
+        })
+        .map(|restore| {
+            let requested = restore
+                .spec
+                .backup_ref
+                .backup_id
+                .clone()
+                .filter(|_| !restore.spec.backup_ref.use_latest)
+                .filter(|id| {
+                    id != retention::LATEST_BACKUP_ID && id != retention::OLDEST_BACKUP_ID
+                });
+            RestoreReference {
+                restore: format!(
+                    "{}/{}",
+                    restore.namespace().unwrap_or_else(|| "default".to_string()),
+                    restore.name_any()
+                ),
+                backup_id: requested.or_else(|| {
+                    restore
+                        .status
+                        .as_ref()
+                        .and_then(|status| status.backup_id.clone())
+                }),
+            }
+        })
+        .collect()
+}
+
+/// DeletionBlocked condition naming the restores that hold backup data
+fn deletion_blocked_condition(restores: &[String]) -> serde_json::Value {
+    json!({
+        "type": "DeletionBlocked",
+        "status": "True",
+        "lastTransitionTime": Utc::now(),
+        "reason": "InUseByRestore",
+        "message": format!("Backup data is in use by restores: {}", restores.join(", "))
+    })
+}
+
+/// Refuse to let a KafkaBackup go while restores still read from it. The
+/// DeletionBlocked condition is added to status and the finalizer stays until
+/// the restores reach a terminal phase.
+pub async fn check_deletion_allowed(
+    backup: &KafkaBackup,
+    client: &Client,
     namespace: &str,
-) -> Result<BackupResult> {
+) -> Result<()> {
+    let references = restores_using_backup(backup, client).await?;
+    if references.is_empty() {
+        return Ok(());
+    }
+
+    let name = backup.name_any();
+    let mut restores: Vec<String> = references
+        .into_iter()
+        .map(|reference| reference.restore)
+        .collect();
+    restores.sort();
+    restores.dedup();
+
+    let mut conditions: Vec<serde_json::Value> = backup
+        .status
+        .as_ref()
+        .map(|status| {
+            status
+                .conditions
+                .iter()
+                .filter(|condition| condition.type_ != "DeletionBlocked")
+                .filter_map(|condition| serde_json::to_value(condition).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    conditions.push(deletion_blocked_condition(&restores));
+
+    let api: Api<KafkaBackup> = Api::namespaced(client.clone(), namespace);
+    let status = json!({
+        "status": {
+            "conditions": conditions
+        }
+    });
+    if let Err(e) = api
+        .patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(status),
+        )
+        .await
+    {
+        warn!(name = %name, error = %e, "Failed to record DeletionBlocked condition");
+    }
+
+    Err(Error::DeletionBlocked(format!(
+        "KafkaBackup '{}' is in use by restores: {}",
+        name,
+        restores.join(", ")
+    )))
+}
+
+/// Read the raw manifest of a backup from storage
+pub(crate) async fn read_backup_manifest(
+    storage: &ResolvedStorage,
+    backup_id: &str,
+) -> Result<Vec<u8>> {
+    let backend = retention::create_backend(&storage.for_backup(backup_id))?;
+    let data = backend
+        .get(&format!("{}/manifest.json", backup_id))
+        .await
+        .map_err(|e| Error::storage(format!("Failed to read backup manifest: {}", e)))?;
+    Ok(data.to_vec())
+}
+
+/// File, inside a backup's directory, holding the labels of the KafkaBackup
+/// that wrote it
+const BACKUP_LABELS_FILE: &str = "labels.json";
+
+/// Store `labels` with a finished backup
+async fn write_backup_labels(
+    storage: &ResolvedStorage,
+    backup_id: &str,
+    labels: &HashMap<String, String>,
+) -> Result<()> {
+    let backend = retention::create_backend(&storage.for_backup(backup_id))?;
+    let sorted: BTreeMap<&String, &String> = labels.iter().collect();
+    let key = format!("{}/{}", backup_id, BACKUP_LABELS_FILE);
+    backend
+        .put(&key, serde_json::to_vec_pretty(&sorted)?.into())
+        .await
+        .map_err(|e| Error::storage(format!("Failed to write backup labels '{}': {}", key, e)))
+}
+
+/// Labels stored with a backup; empty when its KafkaBackup had none
+pub(crate) async fn read_backup_labels(
+    storage: &ResolvedStorage,
+    backup_id: &str,
+) -> Result<BTreeMap<String, String>> {
+    let backend = retention::create_backend(&storage.for_backup(backup_id))?;
+    let key = format!("{}/{}", backup_id, BACKUP_LABELS_FILE);
+    let keys = backend
+        .list(&format!("{}/", backup_id))
+        .await
+        .map_err(|e| Error::storage(format!("Failed to list backup '{}': {}", backup_id, e)))?;
+    if !keys.contains(&key) {
+        return Ok(BTreeMap::new());
+    }
+    let data = backend
+        .get(&key)
+        .await
+        .map_err(|e| Error::storage(format!("Failed to read backup labels '{}': {}", key, e)))?;
+    Ok(serde_json::from_slice(&data)?)
+}
+
+/// `reason` label of records dropped for exceeding maxMessageBytes
+const SKIPPED_OVERSIZED: &str = "max_message_bytes";
+
+/// Bytes a record counts against maxMessageBytes: its key plus its value
+fn record_size(record: &BackupRecord) -> u64 {
+    let key = record.key.as_ref().map_or(0, Vec::len);
+    let value = record.value.as_ref().map_or(0, Vec::len);
+    (key + value) as u64
+}
+
+/// Rewrite each segment of a finished backup that holds records larger than
+/// `max_message_bytes` without them, and store the manifest with the new
+/// segment sizes. kafka-backup-core has no per-record hook, so the limit is
+/// applied after the run. Returns the number of records dropped.
+async fn drop_oversized_records(
+    storage: &ResolvedStorage,
+    backup_id: &str,
+    manifest: &mut BackupManifest,
+    max_message_bytes: u64,
+    compression: CompressionType,
+) -> Result<u64> {
+    let backend = retention::create_backend(&storage.for_backup(backup_id))?;
+    let mut dropped = 0;
+    for segment in manifest
+        .topics
+        .iter_mut()
+        .flat_map(|topic| topic.partitions.iter_mut())
+        .flat_map(|partition| partition.segments.iter_mut())
+    {
+        let records = super::restore::read_segment(backend.as_ref(), &segment.key).await?;
+        let (kept, oversized): (Vec<_>, Vec<_>) = records
+            .into_iter()
+            .partition(|record| record_size(record) <= max_message_bytes);
+        if oversized.is_empty() {
+            continue;
+        }
+
+        let mut writer = SegmentWriter::new(compression);
+        for record in &kept {
+            writer.write(record).map_err(|e| {
+                Error::Core(format!(
+                    "Failed to rewrite segment '{}': {}",
+                    segment.key, e
+                ))
+            })?;
+        }
+        let data = writer.finish().map_err(|e| {
+            Error::Core(format!(
+                "Failed to rewrite segment '{}': {}",
+                segment.key, e
+            ))
+        })?;
+        segment.compressed_size = data.len() as u64;
+        segment.record_count = kept.len() as u64;
+        backend.put(&segment.key, data).await.map_err(|e| {
+            Error::storage(format!("Failed to write segment '{}': {}", segment.key, e))
+        })?;
+        dropped += oversized.len() as u64;
+    }
+
+    if dropped > 0 {
+        let key = format!("{}/manifest.json", backup_id);
+        backend
+            .put(&key, serde_json::to_vec_pretty(manifest)?.into())
+            .await
+            .map_err(|e| Error::storage(format!("Failed to write backup manifest: {}", e)))?;
+    }
+    Ok(dropped)
+}
+
+/// File, inside a backup's directory, holding the CRC32 of every segment its
+/// manifest lists; the manifest itself only records sizes
+const SEGMENT_CHECKSUMS_FILE: &str = "checksums.json";
+
+/// CRC32 of a segment as stored, in hex
+pub(crate) fn segment_checksum(data: &[u8]) -> String {
+    format!("{:08x}", crc32fast::hash(data))
+}
+
+/// Store the checksum of every segment `manifest` lists, keyed by segment key
+async fn write_segment_checksums(
+    storage: &ResolvedStorage,
+    backup_id: &str,
+    manifest: &BackupManifest,
+) -> Result<()> {
+    let backend = retention::create_backend(&storage.for_backup(backup_id))?;
+    let mut checksums = BTreeMap::new();
+    for segment in manifest
+        .topics
+        .iter()
+        .flat_map(|topic| topic.partitions.iter())
+        .flat_map(|partition| partition.segments.iter())
+    {
+        let data = backend.get(&segment.key).await.map_err(|e| {
+            Error::storage(format!("Failed to read segment '{}': {}", segment.key, e))
+        })?;
+        checksums.insert(segment.key.clone(), segment_checksum(&data));
+    }
+    let key = format!("{}/{}", backup_id, SEGMENT_CHECKSUMS_FILE);
+    backend
+        .put(&key, serde_json::to_vec_pretty(&checksums)?.into())
+        .await
+        .map_err(|e| {
+            Error::storage(format!(
+                "Failed to write segment checksums '{}': {}",
+                key, e
+            ))
+        })
+}
+
+/// Segment checksums stored with a backup, or `None` when it was written
+/// without `segmentChecksums`
+pub(crate) async fn read_segment_checksums(
+    storage: &ResolvedStorage,
+    backup_id: &str,
+) -> Result<Option<BTreeMap<String, String>>> {
+    let backend = retention::create_backend(&storage.for_backup(backup_id))?;
+    let key = format!("{}/{}", backup_id, SEGMENT_CHECKSUMS_FILE);
+    let keys = backend
+        .list(&format!("{}/", backup_id))
+        .await
+        .map_err(|e| Error::storage(format!("Failed to list backup '{}': {}", backup_id, e)))?;
+    if !keys.contains(&key) {
+        return Ok(None);
+    }
+    let data = backend.get(&key).await.map_err(|e| {
+        Error::storage(format!("Failed to read segment checksums '{}': {}", key, e))
+    })?;
+    Ok(Some(serde_json::from_slice(&data)?))
+}
+
+/// File, inside a backup's directory, holding the offsets the source
+/// cluster's consumer groups had committed on the backed-up topics when the
+/// run finished
+const GROUP_OFFSETS_FILE: &str = "consumer-offsets.json";
+
+/// Read the committed consumer group offsets on the topics `manifest` lists
+/// from the source cluster and store them with the backup. Returns the
+/// number of groups stored.
+async fn write_group_offsets(
+    storage: &ResolvedStorage,
+    backup_id: &str,
+    kafka_config: kafka_backup_core::config::KafkaConfig,
+    manifest: &BackupManifest,
+) -> Result<usize> {
+    let topics: Vec<String> = manifest
+        .topics
+        .iter()
+        .map(|topic| topic.name.clone())
+        .collect();
+    let group_offsets = super::fetch_group_offsets(kafka_config, &topics).await?;
+    let backend = retention::create_backend(&storage.for_backup(backup_id))?;
+    let key = format!("{}/{}", backup_id, GROUP_OFFSETS_FILE);
+    backend
+        .put(&key, serde_json::to_vec_pretty(&group_offsets)?.into())
+        .await
+        .map_err(|e| {
+            Error::storage(format!(
+                "Failed to write consumer group offsets '{}': {}",
+                key, e
+            ))
+        })?;
+    Ok(group_offsets.len())
+}
+
+/// Consumer group offsets stored with a backup, or `None` when it was
+/// written without `consumerGroupSnapshot`
+pub(crate) async fn read_group_offsets(
+    storage: &ResolvedStorage,
+    backup_id: &str,
+) -> Result<Option<super::GroupOffsets>> {
+    let backend = retention::create_backend(&storage.for_backup(backup_id))?;
+    let key = format!("{}/{}", backup_id, GROUP_OFFSETS_FILE);
+    let keys = backend
+        .list(&format!("{}/", backup_id))
+        .await
+        .map_err(|e| Error::storage(format!("Failed to list backup '{}': {}", backup_id, e)))?;
+    if !keys.contains(&key) {
+        return Ok(None);
+    }
+    let data = backend.get(&key).await.map_err(|e| {
+        Error::storage(format!(
+            "Failed to read consumer group offsets '{}': {}",
+            key, e
+        ))
+    })?;
+    Ok(Some(serde_json::from_slice(&data)?))
+}
+
+/// Read the manifest of a finished backup from storage
+async fn load_backup_manifest(
+    storage: &ResolvedStorage,
+    backup_id: &str,
+) -> Result<BackupManifest> {
+    let data = read_backup_manifest(storage, backup_id).await?;
+    serde_json::from_slice(&data)
+        .map_err(|e| Error::Core(format!("Failed to parse backup manifest: {}", e)))
+}
+
+/// Count the topics and partitions recorded in a backup manifest
+fn manifest_totals(manifest: &BackupManifest) -> (u32, u32) {
+    let topics = u32::try_from(manifest.topics.len()).unwrap_or(u32::MAX);
+    let partitions = manifest
+        .topics
+        .iter()
+        .map(|topic| topic.partitions.len())
+        .sum::<usize>();
+    (topics, u32::try_from(partitions).unwrap_or(u32::MAX))
+}
+
+/// Sum of the uncompressed segment sizes recorded in a backup manifest
+fn manifest_uncompressed_bytes(manifest: &BackupManifest) -> u64 {
+    manifest
+        .topics
+        .iter()
+        .flat_map(|topic| topic.partitions.iter())
+        .flat_map(|partition| partition.segments.iter())
+        .map(|segment| segment.uncompressed_size)
+        .sum()
+}
+
+/// Average rate of `count` over `elapsed`, or `None` for a zero-length run
+fn throughput_per_sec(count: u64, elapsed: Duration) -> Option<f64> {
+    let secs = elapsed.as_secs_f64();
+    (secs > 0.0).then(|| count as f64 / secs)
+}
+
+/// Ratio of raw record bytes to bytes written to storage, if anything was written
+fn compression_ratio(uncompressed_bytes: u64, bytes_written: u64) -> Option<f64> {
+    (bytes_written > 0 && uncompressed_bytes > 0)
+        .then(|| uncompressed_bytes as f64 / bytes_written as f64)
+}
+
+/// Topics recorded in a backup manifest, sorted
+fn manifest_topics(manifest: &BackupManifest) -> Vec<String> {
+    let mut topics: Vec<String> = manifest
+        .topics
+        .iter()
+        .map(|topic| topic.name.clone())
+        .collect();
+    topics.sort_unstable();
+    topics.dedup();
+    topics
+}
+
+/// Show the concrete topic list of the current run in status
+async fn record_resolved_topics(
+    backup: &KafkaBackup,
+    client: &Client,
+    namespace: &str,
+    topics: &[String],
+) {
     let name = backup.name_any();
+    info!(name = %name, topics = ?topics, "Resolved backup topics");
+    let api: Api<KafkaBackup> = Api::namespaced(client.clone(), namespace);
+    if let Err(e) = api
+        .patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(json!({ "status": { "resolvedTopics": topics } })),
+        )
+        .await
+    {
+        warn!(name = %name, error = %e, "Failed to record resolved topics");
+    }
+}
 
-    // Generate unique backup ID
-    let backup_id = format!("{}-{}", name, Utc::now().format("%Y%m%d-%H%M%S"));
+/// Execute the actual backup using kafka-backup-core library
+async fn execute_backup_internal(
+    backup: &KafkaBackup,
+    client: &Client,
+    namespace: &str,
+    backup_id: String,
+    source_cluster_id: Option<String>,
+    shutdown: &CancellationToken,
+) -> Result<BackupResult> {
+    let name = backup.name_any();
 
     info!(name = %name, backup_id = %backup_id, "Building backup configuration");
 
@@ -498,20 +1772,25 @@ async fn execute_backup_internal(
     let resolved_config = build_backup_config(backup, client, namespace).await?;
 
     // 2. Ensure storage directory exists before creating the backup engine
+    require_pvc_mount(&backup.spec.storage)?;
     ensure_storage_directories(&resolved_config.storage)?;
+    retention::record_date_partition(&resolved_config.storage, &backup_id).await?;
 
     // 2b. Create TLS file manager if TLS is configured
-    let tls_manager = if let Some(tls) = &resolved_config.kafka.tls {
-        let tls_dir = default_tls_dir(&name);
-        Some(TlsFileManager::new(tls, &tls_dir)?)
-    } else {
-        None
-    };
+    let tls_manager = create_tls_manager(&resolved_config.kafka, &name)?;
 
     // 3. Convert to kafka-backup-core Config
-    let core_config = to_core_backup_config(&resolved_config, &backup_id, tls_manager.as_ref())
+    let mut core_config = to_core_backup_config(&resolved_config, &backup_id, tls_manager.as_ref())
         .map_err(|e| Error::Core(format!("Failed to build core config: {}", e)))?;
 
+    // 3b. Record the source cluster ID in the manifest so restores can tell
+    // when they target a different cluster (an explicit spec value wins)
+    if resolved_config.backup_options.source_cluster_id.is_none() {
+        if let Some(backup_options) = core_config.backup.as_mut() {
+            backup_options.source_cluster_id = source_cluster_id;
+        }
+    }
+
     info!(
         name = %name,
         backup_id = %backup_id,
@@ -524,7 +1803,9 @@ async fn execute_backup_internal(
     // ./backup_id-offsets.db, so we need to ensure current directory is writable
     let working_dir = get_storage_working_directory(&resolved_config.storage);
     let original_dir = std::env::current_dir().ok();
-    if let Err(e) = std::env::set_current_dir(&working_dir) {
+    if let Err(e) =
+        std::fs::create_dir_all(&working_dir).and_then(|_| std::env::set_current_dir(&working_dir))
+    {
         return Err(Error::Storage(format!(
             "Failed to change working directory to '{}': {}",
             working_dir.display(),
@@ -541,15 +1822,52 @@ async fn execute_backup_internal(
     // 6. Get metrics handle for tracking progress
     let metrics_handle = engine.metrics();
 
-    // 7. Run the backup (must run in the same working directory as engine was created)
-    let run_result = engine.run().await;
+    // 7. Run the backup (must run in the same working directory as engine was created),
+    // heartbeating so a restarted operator can tell this run is still alive and
+    // polling the bytes written against maxTotalBytes
+    let heartbeat_cancel = CancellationToken::new();
+    let heartbeat_task = super::spawn_heartbeat(
+        Api::<KafkaBackup>::namespaced(client.clone(), namespace),
+        name.clone(),
+        heartbeat_cancel.clone(),
+    );
+    let description = format!("Backup {}", backup_id);
+    let run_result = super::run_until_shutdown(
+        shutdown,
+        &description,
+        super::run_with_timeout(
+            backup.spec.timeout_secs.map(Duration::from_secs),
+            &description,
+            run_within_size_limit(
+                backup.spec.max_total_bytes,
+                || metrics_handle.report().bytes_written,
+                &description,
+                engine.run(),
+            ),
+        ),
+    )
+    .await;
+    heartbeat_cancel.cancel();
+    let _ = heartbeat_task.await;
 
     // Restore original working directory after backup completes
     if let Some(ref orig) = original_dir {
         let _ = std::env::set_current_dir(orig);
     }
 
-    run_result.map_err(|e| Error::Core(format!("Backup execution failed: {}", e)))?;
+    // An interrupted run keeps its offset database so the next operator
+    // resumes it. A timed-out run leaves a half-written one behind; remove it
+    // so the retry starts clean. A run aborted at maxTotalBytes keeps
+    // everything it wrote.
+    let run_result = run_result?.inspect_err(|_| remove_offset_db(&working_dir, &backup_id))??;
+
+    run_result.map_err(|e| {
+        super::classify_credentials_rotation(
+            Error::Core(format!("Backup execution failed: {}", e)),
+            metrics_handle.report().records_processed > 0,
+        )
+    })?;
+    remove_finished_offset_db(backup, &resolved_config.storage, &working_dir, &backup_id);
 
     // 8. Extract final metrics
     let metrics_report = metrics_handle.report();
@@ -562,26 +1880,141 @@ async fn execute_backup_internal(
         "Backup completed successfully"
     );
 
+    if !backup.spec.labels.is_empty() {
+        if let Err(e) =
+            write_backup_labels(&resolved_config.storage, &backup_id, &backup.spec.labels).await
+        {
+            warn!(name = %name, backup_id = %backup_id, error = %e, "Could not store backup labels");
+        }
+    }
+
+    // Topic and partition totals come from the manifest the engine wrote,
+    // which reflects the topics actually matched against cluster metadata.
+    // The same list is shown in status as the topics the run selected.
+    let mut manifest = match load_backup_manifest(&resolved_config.storage, &backup_id).await {
+        Ok(manifest) => {
+            record_resolved_topics(backup, client, namespace, &manifest_topics(&manifest)).await;
+            Some(manifest)
+        }
+        Err(e) => {
+            warn!(
+                name = %name,
+                backup_id = %backup_id,
+                error = %e,
+                "Could not read backup manifest for topic totals"
+            );
+            None
+        }
+    };
+    let mut skipped_records = None;
+    if let Some(max_message_bytes) = resolved_config.backup_options.max_message_bytes {
+        let compression = to_core_compression(&resolved_config.run_compression().algorithm);
+        let dropped = match manifest.as_mut() {
+            Some(manifest) => {
+                drop_oversized_records(
+                    &resolved_config.storage,
+                    &backup_id,
+                    manifest,
+                    max_message_bytes,
+                    compression,
+                )
+                .await
+            }
+            None => Err(Error::storage("the backup manifest could not be read")),
+        };
+        match dropped {
+            Ok(dropped) => {
+                if dropped > 0 {
+                    warn!(name = %name, backup_id = %backup_id, dropped, max_message_bytes, "Dropped records exceeding maxMessageBytes");
+                }
+                skipped_records = Some(dropped);
+            }
+            Err(e) => {
+                warn!(name = %name, backup_id = %backup_id, error = %e, "Could not apply maxMessageBytes; oversized records were kept");
+            }
+        }
+    }
+
+    if let Some(manifest) = manifest.as_ref().filter(|_| backup.spec.segment_checksums) {
+        if let Err(e) =
+            write_segment_checksums(&resolved_config.storage, &backup_id, manifest).await
+        {
+            warn!(name = %name, backup_id = %backup_id, error = %e, "Could not store segment checksums");
+        }
+    }
+
+    if let Some(manifest) = manifest
+        .as_ref()
+        .filter(|_| backup.spec.consumer_group_snapshot)
+    {
+        let source =
+            to_core_kafka_config_with_tls(&resolved_config.kafka, &[], tls_manager.as_ref());
+        match write_group_offsets(&resolved_config.storage, &backup_id, source, manifest).await {
+            Ok(groups) => {
+                info!(name = %name, backup_id = %backup_id, groups, "Stored consumer group offsets");
+            }
+            Err(e) => {
+                warn!(name = %name, backup_id = %backup_id, error = %e, "Could not store consumer group offsets");
+            }
+        }
+    }
+
+    let (topics_backed_up, partitions_backed_up) = manifest
+        .as_ref()
+        .map(manifest_totals)
+        .map_or((None, None), |(topics, partitions)| {
+            (Some(topics), Some(partitions))
+        });
+    let compression_ratio = manifest.as_ref().and_then(|manifest| {
+        compression_ratio(
+            manifest_uncompressed_bytes(manifest),
+            metrics_report.bytes_written,
+        )
+    });
+
     let mut retention_report = None;
     let mut retention_error = None;
 
-    match retention::apply_retention(
-        &name,
-        backup.spec.retention.as_ref(),
-        &resolved_config.storage,
-        Some(&backup_id),
-    )
-    .await
-    {
-        Ok(report) => retention_report = report,
+    // Backup sets that restores still read from are never pruned
+    let retention_enabled = backup
+        .spec
+        .retention
+        .as_ref()
+        .is_some_and(|retention| retention.enabled);
+    let in_use = if retention_enabled {
+        restores_using_backup(backup, client).await
+    } else {
+        Ok(Vec::new())
+    };
+
+    match in_use {
+        Ok(in_use) => match retention::apply_retention(
+            &name,
+            backup.spec.retention.as_ref(),
+            &resolved_config.storage,
+            Some(&backup_id),
+            &in_use,
+        )
+        .await
+        {
+            Ok(report) => retention_report = report,
+            Err(e) => {
+                warn!(
+                    name = %name,
+                    backup_id = %backup_id,
+                    error = %e,
+                    "Backup completed but retention pruning failed"
+                );
+                retention_error = Some(e.to_string());
+            }
+        },
         Err(e) => {
             warn!(
                 name = %name,
-                backup_id = %backup_id,
                 error = %e,
-                "Backup completed but retention pruning failed"
+                "Skipping retention: could not check restores using this backup"
             );
-            retention_error = Some(e.to_string());
+            retention_error = Some(format!("Could not check restores using this backup: {}", e));
         }
     }
 
@@ -590,6 +2023,10 @@ async fn execute_backup_internal(
         records_processed: metrics_report.records_processed,
         bytes_processed: metrics_report.bytes_written,
         segments_completed: metrics_report.segments_written,
+        skipped_records,
+        topics_backed_up,
+        partitions_backed_up,
+        compression_ratio,
         retention: retention_report,
         retention_error,
     })
@@ -618,6 +2055,7 @@ pub async fn update_status_ready(
             "nextScheduledBackup": next_backup,
             "observedGeneration": backup.metadata.generation,
             "checkpointEnabled": backup.spec.checkpoint.as_ref().map(|c| c.enabled).unwrap_or(true),
+            "ignoredClientConfig": ignored_client_config(&backup.spec.kafka_cluster.client_config),
             "conditions": [{
                 "type": "Ready",
                 "status": "True",
@@ -648,10 +2086,12 @@ pub async fn update_status_failed(
     let name = backup.name_any();
     let api: Api<KafkaBackup> = Api::namespaced(client.clone(), namespace);
 
+    let recent_errors = super::record_recent_error(backup, "Failed", error_message);
     let status = json!({
         "status": {
             "phase": "Failed",
             "message": error_message,
+            "recentErrors": recent_errors,
             "observedGeneration": backup.metadata.generation,
             "conditions": [{
                 "type": "Ready",
@@ -673,6 +2113,29 @@ pub async fn update_status_failed(
     Ok(())
 }
 
+/// PVC storage is written under the claim's mount path, so the claim must be
+/// mounted there; otherwise the backup would land on the container's own
+/// filesystem and be lost with the pod
+fn require_pvc_mount(storage: &crate::crd::StorageSpec) -> Result<()> {
+    let Some(pvc) = storage
+        .pvc
+        .as_ref()
+        .filter(|_| storage.storage_type == "pvc")
+    else {
+        return Ok(());
+    };
+    let mount_path = crate::adapters::pvc_mount_path(&pvc.claim_name);
+    if crate::adapters::is_mount_point(&mount_path) {
+        return Ok(());
+    }
+    Err(Error::Storage(format!(
+        "PVC '{}' is not mounted into the operator pod at '{}'; add it with the \
+         chart's extraVolumes and extraVolumeMounts",
+        pvc.claim_name,
+        mount_path.display()
+    )))
+}
+
 /// Ensure storage directories exist before backup execution
 /// This prevents "unable to open database file" errors from the core library
 fn ensure_storage_directories(storage: &ResolvedStorage) -> Result<()> {
@@ -719,9 +2182,10 @@ fn ensure_storage_directories(storage: &ResolvedStorage) -> Result<()> {
 fn get_storage_working_directory(storage: &ResolvedStorage) -> std::path::PathBuf {
     match storage {
         ResolvedStorage::Local(local) => std::path::PathBuf::from(&local.path),
-        // For cloud storage, use /tmp as the working directory
+        // For cloud storage, use the operator work directory, which survives
+        // a pod restart when WORK_DIR is on a persistent volume
         ResolvedStorage::S3(_) | ResolvedStorage::Azure(_) | ResolvedStorage::Gcs(_) => {
-            std::path::PathBuf::from("/tmp")
+            crate::adapters::operator_work_dir()
         }
     }
 }
@@ -734,7 +2198,10 @@ mod should_run_backup_tests {
     use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
     use serde_json::json;
 
-    fn make_backup(creation: DateTime<Utc>, status: Option<KafkaBackupStatus>) -> KafkaBackup {
+    pub(super) fn make_backup(
+        creation: DateTime<Utc>,
+        status: Option<KafkaBackupStatus>,
+    ) -> KafkaBackup {
         let spec = serde_json::from_value(json!({
             "kafkaCluster": {"bootstrapServers": ["localhost:9092"]},
             "topics": ["t"],
@@ -750,12 +2217,12 @@ mod should_run_backup_tests {
         b
     }
 
-    fn every_ten_seconds() -> Schedule {
+    pub(super) fn every_ten_seconds() -> Schedule {
         // cron crate 0.12 expects 7 fields: sec min hour dom month dow year
         Schedule::from_str("*/10 * * * * * *").unwrap()
     }
 
-    fn at(h: u32, m: u32, s: u32) -> DateTime<Utc> {
+    pub(super) fn at(h: u32, m: u32, s: u32) -> DateTime<Utc> {
         Utc.with_ymd_and_hms(2026, 4, 21, h, m, s).unwrap()
     }
 
@@ -865,4 +2332,564 @@ mod should_run_backup_tests {
         let pending_backup = make_backup(at(9, 0, 0), Some(pending_status));
         assert!(should_execute_one_shot_backup(&pending_backup));
     }
+
+    #[test]
+    fn requeue_uses_actual_gap_within_bound() {
+        let max = Duration::from_secs(3600);
+        assert_eq!(
+            schedule_requeue_duration(Duration::from_secs(1800), max),
+            Duration::from_secs(1800) + SCHEDULE_FIRE_MARGIN
+        );
+        assert_eq!(
+            schedule_requeue_duration(Duration::from_secs(7 * 24 * 3600), max),
+            max
+        );
+    }
+
+    #[test]
+    fn requeue_is_floored_for_due_ticks() {
+        let max = Duration::from_secs(300);
+        assert_eq!(schedule_requeue_duration(Duration::ZERO, max), MIN_REQUEUE);
+        assert_eq!(
+            schedule_requeue_duration(Duration::from_secs(5), max),
+            Duration::from_secs(5) + SCHEDULE_FIRE_MARGIN
+        );
+    }
+
+    #[test]
+    fn spec_max_requeue_overrides_default() {
+        let mut backup = make_backup(at(9, 0, 0), None);
+        backup.spec.max_requeue_secs = Some(3600);
+        assert_eq!(max_requeue(&backup), Duration::from_secs(3600));
+    }
+}
+
+#[cfg(test)]
+mod requeue_deadline_tests {
+    use super::should_run_backup_tests::at;
+    use super::*;
+
+    #[test]
+    fn requeue_lands_on_tick_inside_window() {
+        // Last requeue landed at 11:55:01 for a 12:00:00 tick: wake at the
+        // tick rather than after a full 300s window at 12:00:01
+        let max = Duration::from_secs(300);
+        let until_next = (at(12, 0, 0) - at(11, 55, 1)).to_std().unwrap();
+        assert_eq!(
+            schedule_requeue_duration(until_next, max),
+            Duration::from_secs(299) + SCHEDULE_FIRE_MARGIN
+        );
+        assert_eq!(
+            schedule_requeue_duration(Duration::from_millis(299_950), max),
+            Duration::from_millis(300_050)
+        );
+    }
+}
+
+#[cfg(test)]
+mod schedule_floor_tests {
+    use super::should_run_backup_tests::{at, every_ten_seconds};
+    use super::*;
+
+    #[test]
+    fn min_schedule_interval_finds_shortest_gap() {
+        assert_eq!(
+            min_schedule_interval(&every_ten_seconds(), at(10, 0, 0)),
+            Some(Duration::from_secs(10))
+        );
+        // Minutes 0 and 1 of every hour: the hourly gap is not the shortest
+        let schedule = Schedule::from_str("0 0,1 * * * * *").unwrap();
+        assert_eq!(
+            min_schedule_interval(&schedule, at(10, 30, 0)),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn min_schedule_interval_needs_two_ticks() {
+        let schedule = Schedule::from_str("0 0 0 1 1 * 2020").unwrap();
+        assert_eq!(min_schedule_interval(&schedule, at(10, 0, 0)), None);
+    }
+}
+
+#[cfg(test)]
+mod trigger_tests {
+    use super::should_run_backup_tests::{at, make_backup};
+    use super::*;
+    use crate::crd::KafkaBackupStatus;
+
+    #[test]
+    fn trigger_fires_once_per_distinct_token() {
+        let mut backup = make_backup(at(9, 0, 0), None);
+        assert_eq!(pending_trigger(&backup), None);
+
+        backup
+            .metadata
+            .annotations
+            .get_or_insert_with(Default::default)
+            .insert(TRIGGER_ANNOTATION.to_string(), "run-1".to_string());
+        assert_eq!(pending_trigger(&backup), Some("run-1".to_string()));
+
+        backup.status = Some(KafkaBackupStatus {
+            last_trigger_token: Some("run-1".into()),
+            ..Default::default()
+        });
+        assert_eq!(pending_trigger(&backup), None);
+
+        backup
+            .metadata
+            .annotations
+            .get_or_insert_with(Default::default)
+            .insert(TRIGGER_ANNOTATION.to_string(), "run-2".to_string());
+        assert_eq!(pending_trigger(&backup), Some("run-2".to_string()));
+    }
+}
+
+#[cfg(test)]
+mod timeout_retry_tests {
+    use super::should_run_backup_tests::{at, make_backup};
+    use super::*;
+    use crate::crd::KafkaBackupStatus;
+
+    #[test]
+    fn timed_out_one_shot_waits_for_retry_delay() {
+        let timed_out_at = at(10, 0, 0);
+        let status = KafkaBackupStatus {
+            phase: Some(crate::reconcilers::PHASE_TIMED_OUT.into()),
+            conditions: vec![crate::crd::Condition {
+                type_: "Ready".into(),
+                status: "False".into(),
+                last_transition_time: timed_out_at,
+                reason: Some("BackupTimedOut".into()),
+                message: None,
+            }],
+            ..Default::default()
+        };
+        let backup = make_backup(at(9, 0, 0), Some(status));
+
+        assert_eq!(
+            one_shot_timeout_retry_wait(&backup, at(10, 1, 0)),
+            Some(Duration::from_secs(240))
+        );
+        assert_eq!(one_shot_timeout_retry_wait(&backup, at(10, 5, 0)), None);
+        assert!(should_execute_one_shot_backup(&backup));
+    }
+}
+
+#[cfg(test)]
+mod resume_tests {
+    use super::should_run_backup_tests::{at, make_backup};
+    use super::*;
+    use crate::crd::KafkaBackupStatus;
+    use serde_json::json;
+
+    fn running_status(heartbeat: DateTime<Utc>, backup_id: Option<&str>) -> KafkaBackupStatus {
+        KafkaBackupStatus {
+            phase: Some("Running".into()),
+            heartbeat_time: Some(heartbeat),
+            backup_id: backup_id.map(Into::into),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn running_backup_with_fresh_heartbeat_is_left_alone() {
+        let status = running_status(at(10, 0, 0), Some("test-20260421-100000"));
+        let backup = make_backup(at(9, 0, 0), Some(status));
+
+        assert_eq!(
+            running_backup_decision(&backup, at(10, 0, 30)),
+            RunningBackupDecision::Wait
+        );
+    }
+
+    #[test]
+    fn orphaned_running_backup_resumes_its_backup_id() {
+        let status = running_status(at(10, 0, 0), Some("test-20260421-100000"));
+        let backup = make_backup(at(9, 0, 0), Some(status));
+
+        assert_eq!(
+            running_backup_decision(&backup, at(10, 5, 0)),
+            RunningBackupDecision::Resume("test-20260421-100000".into())
+        );
+    }
+
+    #[test]
+    fn interrupted_backup_resumes_without_waiting_for_heartbeat() {
+        let mut status = running_status(at(10, 0, 0), Some("test-20260421-100000"));
+        status.phase = Some(crate::reconcilers::PHASE_INTERRUPTED.into());
+        let backup = make_backup(at(9, 0, 0), Some(status));
+
+        assert_eq!(
+            running_backup_decision(&backup, at(10, 0, 30)),
+            RunningBackupDecision::Resume("test-20260421-100000".into())
+        );
+    }
+
+    #[test]
+    fn orphaned_running_backup_without_checkpointing_fails() {
+        let status = running_status(at(10, 0, 0), Some("test-20260421-100000"));
+        let mut backup = make_backup(at(9, 0, 0), Some(status));
+        backup.spec.checkpoint = serde_json::from_value(json!({"enabled": false})).unwrap();
+
+        assert_eq!(
+            running_backup_decision(&backup, at(10, 5, 0)),
+            RunningBackupDecision::MarkFailed
+        );
+    }
+
+    #[test]
+    fn sweep_keeps_the_backup_id_a_running_or_aborted_backup_left() {
+        let mut status = running_status(at(10, 0, 0), Some("test-20260421-100000"));
+        let backup = make_backup(at(9, 0, 0), Some(status.clone()));
+        assert_eq!(kept_backup_id(&backup), Some("test-20260421-100000"));
+
+        status.phase = Some(crate::reconcilers::PHASE_ABORTED_SIZE_LIMIT.into());
+        let backup = make_backup(at(9, 0, 0), Some(status.clone()));
+        assert_eq!(kept_backup_id(&backup), Some("test-20260421-100000"));
+
+        status.phase = Some("Failed".into());
+        let backup = make_backup(at(9, 0, 0), Some(status));
+        assert_eq!(kept_backup_id(&backup), None);
+    }
+}
+
+#[cfg(test)]
+mod suspend_tests {
+    use super::should_run_backup_tests::{at, make_backup};
+    use super::*;
+
+    #[test]
+    fn suspend_until_in_future_requeues_at_resume_time() {
+        let mut backup = make_backup(at(9, 0, 0), None);
+        backup.spec.suspend_until = Some(at(12, 0, 0));
+
+        assert_eq!(
+            suspension_remaining(&backup, at(10, 0, 0)),
+            Some(Duration::from_secs(2 * 3600))
+        );
+    }
+
+    #[test]
+    fn suspend_until_in_past_does_not_suspend() {
+        let mut backup = make_backup(at(9, 0, 0), None);
+        backup.spec.suspend_until = Some(at(10, 0, 0));
+
+        assert_eq!(suspension_remaining(&backup, at(10, 0, 0)), None);
+        assert_eq!(
+            suspension_remaining(&make_backup(at(9, 0, 0), None), at(10, 0, 0)),
+            None
+        );
+    }
+}
+
+#[cfg(test)]
+mod label_tests {
+    use super::*;
+
+    #[test]
+    fn kubernetes_label_syntax_is_accepted() {
+        assert_eq!(label_error("team", "payments"), None);
+        assert_eq!(label_error("kafka.oso.sh/owner", "data-platform_1.2"), None);
+        assert_eq!(label_error("tier", ""), None);
+        assert_eq!(label_error(&"k".repeat(63), &"v".repeat(63)), None);
+    }
+
+    #[test]
+    fn malformed_labels_are_rejected() {
+        for (key, value) in [
+            ("", "payments"),
+            ("-team", "payments"),
+            ("team name", "payments"),
+            ("Example.com/team", "payments"),
+            ("example..com/team", "payments"),
+            ("example.com/", "payments"),
+            ("team", "-payments"),
+            ("team", "a/b"),
+        ] {
+            assert!(label_error(key, value).is_some(), "{}: {}", key, value);
+        }
+        assert!(label_error(&"k".repeat(64), "v").is_some());
+        assert!(label_error("team", &"v".repeat(64)).is_some());
+    }
+}
+
+#[cfg(test)]
+mod size_limit_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn backup_over_max_total_bytes_is_aborted() {
+        let result = run_within_size_limit(
+            Some(1_000),
+            || 4_096,
+            "Backup nightly-20260101-000000",
+            std::future::pending::<()>(),
+        )
+        .await;
+        let err = result.unwrap_err();
+        assert!(matches!(err, Error::SizeLimitExceeded(_)));
+        assert!(err
+            .to_string()
+            .contains("wrote 4096 bytes, over maxTotalBytes=1000"));
+    }
+
+    #[tokio::test]
+    async fn backup_within_max_total_bytes_completes() {
+        let output = run_within_size_limit(Some(1_000), || 10, "Backup", async { 42 }).await;
+        assert_eq!(output.unwrap(), 42);
+
+        let output = run_within_size_limit(None, || u64::MAX, "Backup", async { 7 }).await;
+        assert_eq!(output.unwrap(), 7);
+    }
+}
+
+#[cfg(test)]
+mod offset_db_cleanup_tests {
+    use super::*;
+    use crate::adapters::LocalStorageConfig;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    fn backup(checkpoint_enabled: bool) -> KafkaBackup {
+        let spec = serde_json::from_value(json!({
+            "kafkaCluster": {"bootstrapServers": ["localhost:9092"]},
+            "topics": ["t"],
+            "storage": {
+                "storageType": "pvc",
+                "pvc": {"claimName": "c"}
+            },
+            "checkpoint": {"enabled": checkpoint_enabled},
+        }))
+        .unwrap();
+        KafkaBackup::new("test", spec)
+    }
+
+    fn offset_db_files(dir: &Path, backup_id: &str) -> Vec<std::path::PathBuf> {
+        ["", "-wal", "-shm"]
+            .iter()
+            .map(|suffix| dir.join(format!("{}-offsets.db{}", backup_id, suffix)))
+            .collect()
+    }
+
+    #[test]
+    fn completed_one_shot_backup_removes_offset_db() {
+        let dir = tempdir().unwrap();
+        let storage = ResolvedStorage::Local(LocalStorageConfig {
+            path: dir.path().display().to_string(),
+        });
+        let files = offset_db_files(dir.path(), "demo-20260101-000000");
+        for file in &files {
+            std::fs::write(file, b"db").unwrap();
+        }
+
+        remove_finished_offset_db(&backup(false), &storage, dir.path(), "demo-20260101-000000");
+
+        assert!(files.iter().all(|file| !file.exists()));
+    }
+
+    #[test]
+    fn checkpointed_backup_keeps_offset_db_on_local_storage() {
+        let dir = tempdir().unwrap();
+        let storage = ResolvedStorage::Local(LocalStorageConfig {
+            path: dir.path().display().to_string(),
+        });
+        let files = offset_db_files(dir.path(), "demo-20260101-000000");
+        std::fs::write(&files[0], b"db").unwrap();
+
+        remove_finished_offset_db(&backup(true), &storage, dir.path(), "demo-20260101-000000");
+
+        assert!(files[0].exists());
+    }
+}
+
+#[cfg(test)]
+mod backup_stats_tests {
+    use super::*;
+    use kafka_backup_core::manifest::TopicBackup;
+
+    #[test]
+    fn backup_age_is_seconds_since_last_backup() {
+        let last_backup = Utc::now();
+        let now = last_backup + chrono::Duration::milliseconds(90_500);
+        assert_eq!(backup_age_secs(last_backup, now), 90.5);
+        assert_eq!(backup_age_secs(now, last_backup), 0.0);
+    }
+
+    #[test]
+    fn record_size_counts_key_and_value() {
+        let record = BackupRecord {
+            key: Some(b"order-1".to_vec()),
+            value: Some(vec![0; 1024]),
+            headers: Vec::new(),
+            timestamp: 1_700_000_000_000,
+            offset: 7,
+        };
+        assert_eq!(record_size(&record), 1031);
+
+        let tombstone = BackupRecord {
+            value: None,
+            ..record
+        };
+        assert_eq!(record_size(&tombstone), 7);
+    }
+
+    #[test]
+    fn counts_topics_in_manifest() {
+        let mut manifest = BackupManifest::new("demo-20260101-000000".to_string());
+        for name in ["orders", "payments"] {
+            manifest.topics.push(TopicBackup {
+                name: name.to_string(),
+                original_partition_count: Some(3),
+                partitions: Vec::new(),
+            });
+        }
+
+        assert_eq!(manifest_totals(&manifest), (2, 0));
+    }
+
+    #[test]
+    fn resolved_topics_are_the_manifest_topics_sorted() {
+        let mut manifest = BackupManifest::new("demo-20260101-000000".to_string());
+        for name in ["payments", "orders-us", "orders-eu"] {
+            manifest.topics.push(TopicBackup {
+                name: name.to_string(),
+                original_partition_count: Some(3),
+                partitions: Vec::new(),
+            });
+        }
+
+        assert_eq!(
+            manifest_topics(&manifest),
+            vec!["orders-eu", "orders-us", "payments"]
+        );
+    }
+
+    #[test]
+    fn empty_manifest_has_no_totals() {
+        let manifest = BackupManifest::new("demo-20260101-000000".to_string());
+        assert_eq!(manifest_totals(&manifest), (0, 0));
+    }
+
+    #[test]
+    fn compression_ratio_divides_raw_by_written_bytes() {
+        assert_eq!(compression_ratio(4_000, 1_000), Some(4.0));
+        assert_eq!(compression_ratio(1_000, 1_000), Some(1.0));
+    }
+
+    #[test]
+    fn throughput_is_count_over_elapsed_seconds() {
+        assert_eq!(
+            throughput_per_sec(1_000, Duration::from_secs(4)),
+            Some(250.0)
+        );
+        assert_eq!(throughput_per_sec(1_000, Duration::ZERO), None);
+    }
+
+    #[test]
+    fn compression_ratio_is_unknown_without_data() {
+        assert_eq!(compression_ratio(4_000, 0), None);
+        assert_eq!(compression_ratio(0, 1_000), None);
+    }
+}
+
+#[cfg(test)]
+mod restore_reference_tests {
+    use super::*;
+    use crate::crd::KafkaRestoreStatus;
+
+    fn backup() -> KafkaBackup {
+        let spec = serde_json::from_value(json!({
+            "kafkaCluster": {"bootstrapServers": ["localhost:9092"]},
+            "topics": ["t"],
+            "storage": {
+                "storageType": "pvc",
+                "pvc": {"claimName": "c"}
+            },
+        }))
+        .unwrap();
+        let mut backup = KafkaBackup::new("nightly", spec);
+        backup.metadata.namespace = Some("kafka".to_string());
+        backup
+    }
+
+    fn restore(name: &str, backup_ref: serde_json::Value, phase: Option<&str>) -> KafkaRestore {
+        let spec = serde_json::from_value(json!({
+            "backupRef": backup_ref,
+            "kafkaCluster": {"bootstrapServers": ["localhost:9092"]},
+        }))
+        .unwrap();
+        let mut restore = KafkaRestore::new(name, spec);
+        restore.metadata.namespace = Some("kafka".to_string());
+        restore.status = phase.map(|phase| KafkaRestoreStatus {
+            phase: Some(phase.to_string()),
+            ..Default::default()
+        });
+        restore
+    }
+
+    #[test]
+    fn only_active_restores_of_this_backup_are_references() {
+        let restores = vec![
+            restore(
+                "pinned",
+                json!({"name": "nightly", "backupId": "nightly-20260101-000000"}),
+                Some("Running"),
+            ),
+            restore("pending", json!({"name": "nightly"}), None),
+            restore("done", json!({"name": "nightly"}), Some("Completed")),
+            restore("other", json!({"name": "weekly"}), Some("Running")),
+            restore(
+                "elsewhere",
+                json!({"name": "nightly", "namespace": "staging"}),
+                Some("Running"),
+            ),
+        ];
+
+        let references = restore_references(&backup(), &restores);
+
+        assert_eq!(
+            references,
+            vec![
+                RestoreReference {
+                    restore: "kafka/pinned".to_string(),
+                    backup_id: Some("nightly-20260101-000000".to_string()),
+                },
+                RestoreReference {
+                    restore: "kafka/pending".to_string(),
+                    backup_id: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn keyword_backup_ids_use_the_resolved_id_from_status() {
+        let mut latest = restore(
+            "latest",
+            json!({"name": "nightly", "backupId": "latest"}),
+            Some("Running"),
+        );
+        latest.status.as_mut().unwrap().backup_id = Some("nightly-20260102-000000".to_string());
+
+        let references = restore_references(&backup(), &[latest]);
+
+        assert_eq!(
+            references[0].backup_id.as_deref(),
+            Some("nightly-20260102-000000")
+        );
+    }
+
+    #[test]
+    fn use_latest_ignores_the_requested_backup_id() {
+        let standby = restore(
+            "standby",
+            json!({"name": "nightly", "backupId": "nightly-20260101-000000", "useLatest": true}),
+            Some("Running"),
+        );
+
+        let references = restore_references(&backup(), &[standby]);
+
+        assert_eq!(references[0].backup_id, None);
+    }
 }
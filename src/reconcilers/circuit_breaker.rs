@@ -0,0 +1,263 @@
+//! Run-level circuit breaker for backup and restore operations
+//!
+//! kafka-backup-core does not take a circuit breaker in its options, so the
+//! operator applies `circuitBreaker` at the granularity it controls: whole
+//! engine runs. After `failureThreshold` consecutive failed runs the breaker
+//! opens and further runs are refused until `resetTimeoutSecs` has passed.
+//! It then admits runs half-open and closes again after `successThreshold`
+//! consecutive successes; a failure while half-open re-opens it.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::adapters::ResolvedCircuitBreakerConfig;
+use crate::metrics;
+
+/// Breaker state, exported as 0 (closed), 1 (open) or 2 (half-open)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+impl BreakerState {
+    /// Value reported by the circuit breaker state gauge
+    pub fn gauge_value(self) -> f64 {
+        match self {
+            BreakerState::Closed => 0.0,
+            BreakerState::Open => 1.0,
+            BreakerState::HalfOpen => 2.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Breaker {
+    state: BreakerState,
+    failures: u32,
+    successes: u32,
+    opened_at: Option<Instant>,
+}
+
+impl Default for Breaker {
+    fn default() -> Self {
+        Self {
+            state: BreakerState::Closed,
+            failures: 0,
+            successes: 0,
+            opened_at: None,
+        }
+    }
+}
+
+impl Breaker {
+    /// Admit a run, or return how long until the open breaker may be retried
+    fn admit(
+        &mut self,
+        config: &ResolvedCircuitBreakerConfig,
+        now: Instant,
+    ) -> std::result::Result<(), Duration> {
+        if self.state != BreakerState::Open {
+            return Ok(());
+        }
+
+        let reset_timeout = Duration::from_secs(config.reset_timeout_secs);
+        let open_for = self
+            .opened_at
+            .map_or(reset_timeout, |opened_at| now.duration_since(opened_at));
+        if open_for < reset_timeout {
+            return Err(reset_timeout - open_for);
+        }
+
+        self.state = BreakerState::HalfOpen;
+        self.successes = 0;
+        Ok(())
+    }
+
+    fn record_success(&mut self, config: &ResolvedCircuitBreakerConfig) {
+        self.failures = 0;
+        if self.state == BreakerState::HalfOpen {
+            self.successes += 1;
+            if self.successes >= config.success_threshold {
+                self.state = BreakerState::Closed;
+                self.successes = 0;
+                self.opened_at = None;
+            }
+        }
+    }
+
+    fn record_failure(&mut self, config: &ResolvedCircuitBreakerConfig, now: Instant) {
+        self.successes = 0;
+        self.failures = self.failures.saturating_add(1);
+        if self.state == BreakerState::HalfOpen || self.failures >= config.failure_threshold {
+            self.state = BreakerState::Open;
+            self.opened_at = Some(now);
+        }
+    }
+}
+
+/// Breakers keyed by `{kind}/{namespace}/{name}`
+fn breakers() -> &'static Mutex<HashMap<String, Breaker>> {
+    static BREAKERS: OnceLock<Mutex<HashMap<String, Breaker>>> = OnceLock::new();
+    BREAKERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn breaker_key(kind: &str, namespace: &str, name: &str) -> String {
+    format!("{}/{}/{}", kind, namespace, name)
+}
+
+fn set_gauge(kind: &str, namespace: &str, name: &str, state: BreakerState) {
+    metrics::CIRCUIT_BREAKER_STATE
+        .with_label_values(&[kind, namespace, name])
+        .set(state.gauge_value());
+}
+
+/// Ask the breaker whether a run may start. Returns the remaining wait when
+/// the breaker is open. A disabled breaker always admits.
+pub fn admit(
+    kind: &str,
+    namespace: &str,
+    name: &str,
+    config: &ResolvedCircuitBreakerConfig,
+) -> Option<Duration> {
+    if !config.enabled {
+        return None;
+    }
+
+    let mut breakers = breakers().lock().unwrap();
+    let breaker = breakers
+        .entry(breaker_key(kind, namespace, name))
+        .or_default();
+    let admitted = breaker.admit(config, Instant::now());
+    set_gauge(kind, namespace, name, breaker.state);
+    admitted.err()
+}
+
+/// Record the outcome of a run the breaker admitted
+pub fn record(
+    kind: &str,
+    namespace: &str,
+    name: &str,
+    config: &ResolvedCircuitBreakerConfig,
+    succeeded: bool,
+) -> BreakerState {
+    if !config.enabled {
+        return BreakerState::Closed;
+    }
+
+    let mut breakers = breakers().lock().unwrap();
+    let breaker = breakers
+        .entry(breaker_key(kind, namespace, name))
+        .or_default();
+    if succeeded {
+        breaker.record_success(config);
+    } else {
+        breaker.record_failure(config, Instant::now());
+    }
+    set_gauge(kind, namespace, name, breaker.state);
+    breaker.state
+}
+
+/// Drop the breaker and gauge series of a deleted resource
+pub fn forget(kind: &str, namespace: &str, name: &str) {
+    breakers()
+        .lock()
+        .unwrap()
+        .remove(&breaker_key(kind, namespace, name));
+    let _ = metrics::CIRCUIT_BREAKER_STATE.remove_label_values(&[kind, namespace, name]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ResolvedCircuitBreakerConfig {
+        ResolvedCircuitBreakerConfig {
+            enabled: true,
+            failure_threshold: 2,
+            reset_timeout_secs: 60,
+            success_threshold: 2,
+            operation_timeout_ms: 30000,
+        }
+    }
+
+    #[test]
+    fn opens_after_failure_threshold() {
+        let config = config();
+        let now = Instant::now();
+        let mut breaker = Breaker::default();
+
+        breaker.record_failure(&config, now);
+        assert_eq!(breaker.state, BreakerState::Closed);
+        breaker.record_failure(&config, now);
+        assert_eq!(breaker.state, BreakerState::Open);
+
+        let wait = breaker
+            .admit(&config, now + Duration::from_secs(10))
+            .unwrap_err();
+        assert_eq!(wait, Duration::from_secs(50));
+    }
+
+    #[test]
+    fn half_open_closes_after_success_threshold() {
+        let config = config();
+        let now = Instant::now();
+        let mut breaker = Breaker::default();
+        breaker.record_failure(&config, now);
+        breaker.record_failure(&config, now);
+
+        assert!(breaker
+            .admit(&config, now + Duration::from_secs(60))
+            .is_ok());
+        assert_eq!(breaker.state, BreakerState::HalfOpen);
+
+        breaker.record_success(&config);
+        assert_eq!(breaker.state, BreakerState::HalfOpen);
+        breaker.record_success(&config);
+        assert_eq!(breaker.state, BreakerState::Closed);
+    }
+
+    #[test]
+    fn failure_while_half_open_reopens() {
+        let config = config();
+        let now = Instant::now();
+        let mut breaker = Breaker::default();
+        breaker.record_failure(&config, now);
+        breaker.record_failure(&config, now);
+        breaker
+            .admit(&config, now + Duration::from_secs(60))
+            .unwrap();
+
+        breaker.record_failure(&config, now + Duration::from_secs(61));
+        assert_eq!(breaker.state, BreakerState::Open);
+    }
+
+    #[test]
+    fn forgotten_breaker_starts_closed() {
+        let config = config();
+        for _ in 0..2 {
+            record("BreakerTest", "kafka", "nightly", &config, false);
+        }
+        assert!(admit("BreakerTest", "kafka", "nightly", &config).is_some());
+
+        forget("BreakerTest", "kafka", "nightly");
+        assert!(metrics::CIRCUIT_BREAKER_STATE
+            .remove_label_values(&["BreakerTest", "kafka", "nightly"])
+            .is_err());
+        assert!(admit("BreakerTest", "kafka", "nightly", &config).is_none());
+    }
+
+    #[test]
+    fn success_resets_failure_count() {
+        let config = config();
+        let now = Instant::now();
+        let mut breaker = Breaker::default();
+
+        breaker.record_failure(&config, now);
+        breaker.record_success(&config);
+        breaker.record_failure(&config, now);
+        assert_eq!(breaker.state, BreakerState::Closed);
+    }
+}
@@ -0,0 +1,36 @@
+//! Kubernetes event publishing
+//!
+//! Best-effort helpers for surfacing notable transitions (timeouts, aborts)
+//! as events on the owning resource. Failures to publish are logged and never
+//! fail the reconcile.
+
+use kube::runtime::events::{Event, EventType, Recorder, Reporter};
+use kube::{Client, Resource};
+use tracing::warn;
+
+/// Reporting controller name used on published events
+const REPORTER: &str = "kafka-backup-operator";
+
+/// Publish a `Warning` event on `obj`
+pub async fn publish_warning<K>(client: &Client, obj: &K, reason: &str, action: &str, note: &str)
+where
+    K: Resource<DynamicType = ()>,
+{
+    let reporter = Reporter {
+        controller: REPORTER.to_string(),
+        instance: std::env::var("POD_NAME").ok(),
+    };
+    let recorder = Recorder::new(client.clone(), reporter, obj.object_ref(&()));
+
+    let event = Event {
+        type_: EventType::Warning,
+        reason: reason.to_string(),
+        note: Some(note.to_string()),
+        action: action.to_string(),
+        secondary: None,
+    };
+
+    if let Err(e) = recorder.publish(event).await {
+        warn!(reason = %reason, error = %e, "Failed to publish Kubernetes event");
+    }
+}
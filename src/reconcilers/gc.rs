@@ -0,0 +1,343 @@
+//! Garbage collection of incomplete backups.
+//!
+//! A backup that fails partway leaves segments in storage that no manifest
+//! accounts for: either the run never wrote its manifest, or it wrote one and
+//! then kept uploading segments the manifest does not list. Neither retention
+//! (which only sees backup sets with a manifest) nor restore (which only reads
+//! listed segments) ever touches them, so they are removed here.
+//!
+//! Storage with a date layout keeps each backup under a `YYYY/MM/DD/`
+//! partition; a backup is always collected within its own partition.
+//!
+//! A run aborted at `maxTotalBytes` leaves an [`ABORTED_MARKER`] next to its
+//! segments. Those are kept on purpose, so garbage collection skips them.
+
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+
+use chrono::Utc;
+use kafka_backup_core::manifest::BackupManifest;
+use kafka_backup_core::storage::StorageBackend;
+use tracing::{info, warn};
+
+use crate::adapters::{split_date_partition, ResolvedStorage};
+use crate::error::{Error, Result};
+
+use super::retention;
+
+/// File written into a backup aborted at `maxTotalBytes`, whose data is kept
+pub const ABORTED_MARKER: &str = "aborted.json";
+
+/// Result from a garbage collection run.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GcReport {
+    /// Backups whose incomplete artifacts were removed.
+    pub collected_backups: Vec<String>,
+    /// Storage keys removed.
+    pub deleted_keys: u64,
+    /// Bytes reclaimed by removing them.
+    pub reclaimed_bytes: u64,
+}
+
+/// Remove what a failed run of `backup_id` left behind. A backup with a
+/// complete manifest only loses segments the manifest does not list.
+pub async fn collect_backup(storage: &ResolvedStorage, backup_id: &str) -> Result<GcReport> {
+    let storage = storage.for_backup(backup_id);
+    let backend = retention::create_backend(&storage)?;
+    let local_root = retention::local_storage_root(&storage);
+    let keys = retention::list_keys(backend.as_ref(), backup_id).await?;
+
+    let mut report = GcReport::default();
+    collect_with_backend(
+        backend.as_ref(),
+        local_root.as_deref(),
+        &keys,
+        "",
+        backup_id,
+        &mut report,
+    )
+    .await?;
+    Ok(report)
+}
+
+/// Remove incomplete artifacts of every backup owned by `backup_name`,
+/// except `keep`, a backup that may still resume from its checkpoint, and
+/// backups marked as aborted at `maxTotalBytes`.
+pub async fn sweep(
+    storage: &ResolvedStorage,
+    backup_name: &str,
+    keep: Option<&str>,
+) -> Result<GcReport> {
+    let backend = retention::create_backend(storage)?;
+    let local_root = retention::local_storage_root(storage);
+    let keys = retention::list_backup_keys(backend.as_ref(), storage, backup_name).await?;
+
+    let mut report = GcReport::default();
+    for (partition, backup_id) in owned_backup_ids(&keys, backup_name)
+        .into_iter()
+        .filter(|(_, backup_id)| Some(backup_id.as_str()) != keep)
+    {
+        collect_with_backend(
+            backend.as_ref(),
+            local_root.as_deref(),
+            &keys,
+            &partition,
+            &backup_id,
+            &mut report,
+        )
+        .await?;
+    }
+    Ok(report)
+}
+
+/// Mark `backup_id` as aborted at `maxTotalBytes`, so the data it wrote is
+/// kept by later sweeps
+pub async fn mark_aborted(storage: &ResolvedStorage, backup_id: &str, reason: &str) -> Result<()> {
+    let backend = retention::create_backend(&storage.for_backup(backup_id))?;
+    let key = format!("{}/{}", backup_id, ABORTED_MARKER);
+    let body = serde_json::to_vec_pretty(&serde_json::json!({
+        "backupId": backup_id,
+        "abortedAt": Utc::now(),
+        "reason": reason,
+    }))?;
+    backend
+        .put(&key, body.into())
+        .await
+        .map_err(|e| Error::storage(format!("Failed to write '{}': {}", key, e)))
+}
+
+async fn collect_with_backend(
+    backend: &dyn StorageBackend,
+    local_root: Option<&Path>,
+    keys: &[String],
+    partition: &str,
+    backup_id: &str,
+    report: &mut GcReport,
+) -> Result<()> {
+    if keys.contains(&format!("{}{}/{}", partition, backup_id, ABORTED_MARKER)) {
+        return Ok(());
+    }
+
+    let manifest_key = format!("{}{}/manifest.json", partition, backup_id);
+    let manifest = if keys.contains(&manifest_key) {
+        let data = backend
+            .get(&manifest_key)
+            .await
+            .map_err(|e| Error::storage(format!("Failed to read '{}': {}", manifest_key, e)))?;
+        match serde_json::from_slice::<BackupManifest>(&data) {
+            Ok(manifest) => Some(manifest),
+            Err(e) => {
+                // Retention skips these too; leave them for an operator to inspect
+                warn!(key = %manifest_key, error = %e, "Skipping backup with invalid manifest");
+                return Ok(());
+            }
+        }
+    } else {
+        None
+    };
+
+    let garbage = incomplete_artifacts(partition, backup_id, keys, manifest.as_ref());
+    if garbage.is_empty() {
+        return Ok(());
+    }
+
+    let mut bytes = 0;
+    for key in &garbage {
+        bytes += backend.size(key).await.unwrap_or(0);
+        backend
+            .delete(key)
+            .await
+            .map_err(|e| Error::storage(format!("Failed to delete '{}': {}", key, e)))?;
+    }
+    if manifest.is_none() && partition.is_empty() {
+        retention::cleanup_local_backup_dir(local_root, backup_id)?;
+    }
+
+    info!(
+        backup_id = %backup_id,
+        complete_manifest = manifest.is_some(),
+        keys = garbage.len(),
+        bytes,
+        "Removed artifacts of incomplete backup"
+    );
+    report.collected_backups.push(backup_id.to_string());
+    report.deleted_keys += garbage.len() as u64;
+    report.reclaimed_bytes += bytes;
+    Ok(())
+}
+
+/// Date partitions and IDs of the backups owned by `backup_name` with
+/// anything in storage, whether or not they have a manifest
+fn owned_backup_ids(keys: &[String], backup_name: &str) -> BTreeSet<(String, String)> {
+    keys.iter()
+        .filter_map(|key| {
+            let (partition, key) = split_date_partition(key);
+            let backup_id = match key.split_once('/') {
+                Some((backup_id, _)) => backup_id,
+                None => key.strip_suffix("-offsets.db")?,
+            };
+            Some((partition, backup_id))
+        })
+        .filter(|(_, backup_id)| retention::is_owned_backup_id(backup_name, backup_id))
+        .map(|(partition, backup_id)| (partition.to_string(), backup_id.to_string()))
+        .collect()
+}
+
+/// Keys of `backup_id` in `partition` that no complete manifest accounts
+/// for. Without a manifest that is everything the run wrote; with one, only
+/// the segments under `topics/` that the manifest does not list.
+fn incomplete_artifacts(
+    partition: &str,
+    backup_id: &str,
+    keys: &[String],
+    manifest: Option<&BackupManifest>,
+) -> Vec<String> {
+    let Some(manifest) = manifest else {
+        let prefix = format!("{}{}/", partition, backup_id);
+        let offset_db = format!("{}{}-offsets.db", partition, backup_id);
+        return keys
+            .iter()
+            .filter(|key| key.starts_with(&prefix) || **key == offset_db)
+            .cloned()
+            .collect();
+    };
+
+    // Segment keys are relative to the partition the backup was written to
+    let listed: HashSet<String> = manifest
+        .topics
+        .iter()
+        .flat_map(|topic| topic.partitions.iter())
+        .flat_map(|topic_partition| topic_partition.segments.iter())
+        .map(|segment| format!("{}{}", partition, segment.key))
+        .collect();
+    let segments_prefix = format!("{}{}/topics/", partition, backup_id);
+    keys.iter()
+        .filter(|key| key.starts_with(&segments_prefix) && !listed.contains(key.as_str()))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::LocalStorageConfig;
+    use kafka_backup_core::manifest::TopicBackup;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn keys(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    fn local_storage(path: &Path) -> ResolvedStorage {
+        ResolvedStorage::Local(LocalStorageConfig {
+            path: path.to_string_lossy().to_string(),
+        })
+    }
+
+    #[test]
+    fn backup_without_manifest_is_entirely_garbage() {
+        let keys = keys(&[
+            "demo-20260101-000000/topics/orders/partition=0/segment-000001.bin.zst",
+            "demo-20260101-000000-offsets.db",
+            "demo-20260102-000000/manifest.json",
+        ]);
+
+        assert_eq!(
+            incomplete_artifacts("", "demo-20260101-000000", &keys, None),
+            keys[..2].to_vec()
+        );
+    }
+
+    #[test]
+    fn backup_with_manifest_only_loses_unlisted_segments() {
+        let keys = keys(&[
+            "demo-20260101-000000/manifest.json",
+            "demo-20260101-000000/offset-reports/kafka/restore-a.json",
+            "demo-20260101-000000/topics/orders/partition=0/segment-000001.bin.zst",
+        ]);
+        let mut manifest = BackupManifest::new("demo-20260101-000000".to_string());
+        manifest.topics.push(TopicBackup {
+            name: "orders".to_string(),
+            original_partition_count: Some(1),
+            partitions: Vec::new(),
+        });
+
+        assert_eq!(
+            incomplete_artifacts("", "demo-20260101-000000", &keys, Some(&manifest)),
+            keys[2..].to_vec()
+        );
+    }
+
+    #[test]
+    fn owned_ids_include_backups_without_manifest() {
+        let keys = keys(&[
+            "demo-20260101-000000/topics/orders/partition=0/segment-000001.bin.zst",
+            "demo-20260102-000000-offsets.db",
+            "demo-archive-20260101-000000/manifest.json",
+            "2026/01/03/demo-20260103-000000/topics/orders/partition=0/segment-000001.bin.zst",
+        ]);
+
+        assert_eq!(
+            owned_backup_ids(&keys, "demo"),
+            BTreeSet::from([
+                (String::new(), "demo-20260101-000000".to_string()),
+                (String::new(), "demo-20260102-000000".to_string()),
+                (
+                    "2026/01/03/".to_string(),
+                    "demo-20260103-000000".to_string()
+                ),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn sweep_removes_incomplete_backups_but_keeps_resumable_one() {
+        let dir = tempdir().unwrap();
+        for backup_id in ["demo-20260101-000000", "demo-20260102-000000"] {
+            let partition_dir = dir.path().join(backup_id).join("topics/orders/partition=0");
+            fs::create_dir_all(&partition_dir).unwrap();
+            fs::write(
+                partition_dir.join("segment-000001.bin.zst"),
+                b"segment-data",
+            )
+            .unwrap();
+        }
+
+        let report = sweep(
+            &local_storage(dir.path()),
+            "demo",
+            Some("demo-20260102-000000"),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.collected_backups, vec!["demo-20260101-000000"]);
+        assert_eq!(report.reclaimed_bytes, b"segment-data".len() as u64);
+        assert!(!dir.path().join("demo-20260101-000000").exists());
+        assert!(dir.path().join("demo-20260102-000000").exists());
+    }
+
+    #[tokio::test]
+    async fn sweep_keeps_backups_aborted_at_size_limit() {
+        let dir = tempdir().unwrap();
+        let partition_dir = dir
+            .path()
+            .join("demo-20260101-000000/topics/orders/partition=0");
+        fs::create_dir_all(&partition_dir).unwrap();
+        fs::write(
+            partition_dir.join("segment-000001.bin.zst"),
+            b"segment-data",
+        )
+        .unwrap();
+        let storage = local_storage(dir.path());
+        mark_aborted(&storage, "demo-20260101-000000", "over maxTotalBytes")
+            .await
+            .unwrap();
+
+        let report = sweep(&storage, "demo", None).await.unwrap();
+
+        assert!(report.collected_backups.is_empty());
+        assert!(partition_dir.join("segment-000001.bin.zst").exists());
+    }
+}
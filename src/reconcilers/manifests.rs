@@ -0,0 +1,160 @@
+//! Offline manifest checks.
+//!
+//! `kafka-backup-operator --validate` runs each reconciler's structural
+//! `validate` against manifests read from a file or stdin, so CI can reject
+//! a bad spec before it is applied without needing access to a cluster.
+
+use kube::Resource;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::crd::{
+    KafkaBackup, KafkaBackupValidation, KafkaOffsetReset, KafkaOffsetRollback, KafkaRestore,
+};
+use crate::error::Result;
+
+/// Outcome of checking one manifest
+#[derive(Debug)]
+pub struct ManifestCheck {
+    /// `Kind/name`, or the document's position when it has no usable kind
+    pub resource: String,
+    /// Deserialization or validation error, if the manifest is invalid
+    pub error: Option<String>,
+}
+
+/// Check every operator resource in a (multi-document) YAML stream.
+/// Documents of other API groups, and empty ones, are skipped.
+pub fn validate_manifests(yaml: &str) -> Vec<ManifestCheck> {
+    let mut checks = Vec::new();
+    for (index, document) in serde_yaml::Deserializer::from_str(yaml).enumerate() {
+        let position = format!("document {}", index + 1);
+        let value = match serde_yaml::Value::deserialize(document) {
+            Ok(serde_yaml::Value::Null) => continue,
+            Ok(value) => value,
+            Err(e) => {
+                checks.push(ManifestCheck {
+                    resource: position,
+                    error: Some(format!("invalid YAML: {}", e)),
+                });
+                // A syntax error leaves the rest of the stream unreadable
+                break;
+            }
+        };
+        if let Some(check) = validate_manifest(value, position) {
+            checks.push(check);
+        }
+    }
+    checks
+}
+
+/// Check a single document, or `None` when it is not an operator resource
+fn validate_manifest(value: serde_yaml::Value, position: String) -> Option<ManifestCheck> {
+    let field = |name: &str| value.get(name).and_then(serde_yaml::Value::as_str);
+    let group = field("apiVersion")?.split_once('/')?.0;
+    if group != KafkaBackup::group(&()) {
+        return None;
+    }
+    let kind = field("kind").unwrap_or_default().to_string();
+    let name = value
+        .get("metadata")
+        .and_then(|metadata| metadata.get("name"))
+        .and_then(serde_yaml::Value::as_str)
+        .unwrap_or("<unnamed>");
+    let resource = if kind.is_empty() {
+        position
+    } else {
+        format!("{}/{}", kind, name)
+    };
+
+    let result = if kind == KafkaBackup::kind(&()) {
+        check(value, super::backup::validate)
+    } else if kind == KafkaRestore::kind(&()) {
+        check(value, super::restore::validate)
+    } else if kind == KafkaOffsetReset::kind(&()) {
+        check(value, super::offset_reset::validate)
+    } else if kind == KafkaOffsetRollback::kind(&()) {
+        check(value, super::offset_rollback::validate)
+    } else if kind == KafkaBackupValidation::kind(&()) {
+        check(value, super::validation::validate)
+    } else if kind.is_empty() {
+        Err("missing kind".to_string())
+    } else {
+        Err(format!("unknown kind '{}'", kind))
+    };
+
+    Some(ManifestCheck {
+        resource,
+        error: result.err(),
+    })
+}
+
+/// Deserialize `value` as `K` and run its validation
+fn check<K: DeserializeOwned>(
+    value: serde_yaml::Value,
+    validate: fn(&K) -> Result<()>,
+) -> std::result::Result<(), String> {
+    let resource: K = serde_yaml::from_value(value).map_err(|e| format!("invalid spec: {}", e))?;
+    validate(&resource).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BACKUP: &str = r#"
+apiVersion: kafka.oso.sh/v1alpha1
+kind: KafkaBackup
+metadata:
+  name: nightly
+spec:
+  kafkaCluster:
+    bootstrapServers: ["kafka:9092"]
+  topics: ["orders"]
+  storage:
+    storageType: pvc
+    pvc:
+      claimName: backups
+"#;
+
+    #[test]
+    fn valid_backup_passes() {
+        let checks = validate_manifests(BACKUP);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].resource, "KafkaBackup/nightly");
+        assert!(checks[0].error.is_none(), "{:?}", checks[0].error);
+    }
+
+    #[test]
+    fn every_document_is_checked_and_other_groups_skipped() {
+        let broken = BACKUP
+            .replace("nightly", "broken")
+            .replace("\"orders\"", "");
+        let config_map = "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: unrelated\n";
+        let yaml = format!("{}---\n{}---\n{}---\n", BACKUP, broken, config_map);
+        let checks = validate_manifests(&yaml);
+        assert_eq!(checks.len(), 2);
+        assert!(checks[0].error.is_none());
+        assert_eq!(checks[1].resource, "KafkaBackup/broken");
+        assert!(checks[1].error.is_some());
+    }
+
+    #[test]
+    fn malformed_specs_and_unknown_kinds_fail() {
+        let manifest = |kind: &str| {
+            format!(
+                "apiVersion: kafka.oso.sh/v1alpha1\nkind: {}\nmetadata:\n  name: a\nspec: {{}}\n",
+                kind
+            )
+        };
+
+        let checks = validate_manifests(&manifest("KafkaRestore"));
+        let error = checks[0].error.as_deref().unwrap();
+        assert!(error.starts_with("invalid spec"), "{}", error);
+
+        let checks = validate_manifests(&manifest("KafkaArchive"));
+        assert_eq!(
+            checks[0].error.as_deref(),
+            Some("unknown kind 'KafkaArchive'")
+        );
+    }
+}
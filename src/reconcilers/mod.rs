@@ -7,8 +7,1094 @@
 //! - Updating resource status
 
 pub mod backup;
+pub mod circuit_breaker;
+pub mod events;
+pub mod gc;
+pub mod manifests;
+pub mod offset_report;
 pub mod offset_reset;
 pub mod offset_rollback;
+pub mod partitioner;
 pub mod restore;
 pub mod retention;
+pub mod status;
 pub mod validation;
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::future::Future;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::NamespaceResourceScope;
+use kafka_backup_core::config::KafkaConfig as CoreKafkaConfig;
+use kafka_backup_core::kafka::admin::describe_topic_configs;
+use kafka_backup_core::kafka::consumer_groups::{fetch_offsets, list_groups};
+use kafka_backup_core::kafka::KafkaClient;
+use kube::api::{Api, Patch, PatchParams};
+use kube::{Client, Resource};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use crate::adapters::{
+    client_config_bool, is_managed_client_config, ResolvedStorage, CREDENTIAL_ENV_PREFIX,
+    SUPPORTED_CLIENT_CONFIG,
+};
+use crate::crd::{
+    Condition, ErrorEntry, KafkaBackup, KafkaBackupValidation, KafkaClusterSpec, KafkaOffsetReset,
+    KafkaOffsetRollback, KafkaRestore, S3StorageSpec, StorageSpec,
+};
+use crate::error::{Error, Result};
+use crate::metrics;
+
+/// Phase recorded when an operation exceeds its `timeoutSecs`
+pub const PHASE_TIMED_OUT: &str = "TimedOut";
+
+/// Phase recorded when operator shutdown stops an operation mid-run
+pub const PHASE_INTERRUPTED: &str = "Interrupted";
+
+/// Phase recorded when an operation makes no progress for its stall timeout
+pub const PHASE_STALLED: &str = "Stalled";
+
+/// Phase recorded when a backup is aborted for exceeding `maxTotalBytes`
+pub const PHASE_ABORTED_SIZE_LIMIT: &str = "AbortedSizeLimit";
+
+/// Phase recorded when a new generation is picked up, until its spec has
+/// been validated
+pub const PHASE_VALIDATING: &str = "Validating";
+
+/// Delay before an operation that timed out is attempted again
+pub const TIMEOUT_RETRY_DELAY: Duration = Duration::from_secs(300);
+
+/// Interval between `status.heartbeatTime` patches while an operation runs
+pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A Running resource whose heartbeat is older than this has lost the
+/// operation that owned it (e.g. the operator pod restarted)
+pub const HEARTBEAT_STALE_AFTER: Duration = Duration::from_secs(90);
+
+/// Condition reason for operations a previous operator instance left Running
+pub const REASON_ORPHANED_BY_RESTART: &str = "OrphanedByRestart";
+
+/// Environment variable restricting the operator to a comma-separated list of
+/// namespaces. Unset or empty watches every namespace.
+pub const WATCH_NAMESPACES_ENV: &str = "WATCH_NAMESPACES";
+
+/// Namespaces the operator is restricted to; empty means all namespaces
+pub fn watch_namespaces() -> Vec<String> {
+    parse_namespaces(&std::env::var(WATCH_NAMESPACES_ENV).unwrap_or_default())
+}
+
+fn parse_namespaces(value: &str) -> Vec<String> {
+    let mut namespaces: Vec<String> = Vec::new();
+    for namespace in value.split(',').map(str::trim) {
+        if !namespace.is_empty() && !namespaces.iter().any(|known| known == namespace) {
+            namespaces.push(namespace.to_string());
+        }
+    }
+    namespaces
+}
+
+/// One API per namespace in `namespaces`, or a single cluster-wide API when
+/// the list is empty. Namespace-scoped RBAC only allows the former.
+pub fn scoped_apis<K>(client: &Client, namespaces: &[String]) -> Vec<Api<K>>
+where
+    K: Resource<Scope = NamespaceResourceScope>,
+    <K as Resource>::DynamicType: Default,
+{
+    if namespaces.is_empty() {
+        return vec![Api::all(client.clone())];
+    }
+    namespaces
+        .iter()
+        .map(|namespace| Api::namespaced(client.clone(), namespace))
+        .collect()
+}
+
+/// Move a resource to [`PHASE_VALIDATING`] for `generation`, so users see the
+/// change was picked up before any slow validation runs
+pub async fn set_validating<K>(api: &Api<K>, name: &str, generation: Option<i64>) -> Result<()>
+where
+    K: Clone + DeserializeOwned + Debug,
+{
+    let status = json!({
+        "status": {
+            "phase": PHASE_VALIDATING,
+            "message": "Validating spec",
+            "observedGeneration": generation,
+        }
+    });
+    api.patch_status(
+        name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(status),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Patch `status.heartbeatTime` every [`HEARTBEAT_INTERVAL`] until `cancel`
+/// fires, so other reconciles can tell a live operation from an orphaned one.
+pub fn spawn_heartbeat<K>(api: Api<K>, name: String, cancel: CancellationToken) -> JoinHandle<()>
+where
+    K: Clone + DeserializeOwned + Debug + Send + Sync + 'static,
+{
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(HEARTBEAT_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = ticker.tick() => {}
+            }
+
+            let heartbeat = json!({
+                "status": {
+                    "heartbeatTime": Utc::now(),
+                }
+            });
+            if let Err(e) = api
+                .patch_status(
+                    &name,
+                    &PatchParams::apply("kafka-backup-operator"),
+                    &Patch::Merge(heartbeat),
+                )
+                .await
+            {
+                warn!(name = %name, error = %e, "Failed to patch heartbeat");
+            }
+        }
+    })
+}
+
+/// Move every resource left Running by a previous operator instance to
+/// `phase`. During a rolling update the previous instance may still be
+/// running the operation, so a resource is only recovered once its
+/// heartbeat (from `heartbeat_of`) has gone stale. Kinds without a
+/// heartbeat are always recovered. Kinds that resume from a checkpoint pass
+/// [`PHASE_INTERRUPTED`]; the rest pass `Failed`. Conditions other than
+/// `Ready` (from `conditions_of`) are kept.
+pub async fn recover_orphaned<K>(
+    client: &Client,
+    apis: &[Api<K>],
+    kind: &str,
+    phase_of: impl Fn(&K) -> Option<String>,
+    heartbeat_of: impl Fn(&K) -> Option<DateTime<Utc>>,
+    conditions_of: impl Fn(&K) -> Vec<Condition>,
+    phase: &str,
+    message: &str,
+) where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+    <K as Resource>::DynamicType: Default,
+{
+    for api in apis {
+        let resources = match api.list(&Default::default()).await {
+            Ok(list) => list.items,
+            Err(e) => {
+                warn!(kind, error = %e, "Failed to list resources for orphan recovery");
+                continue;
+            }
+        };
+        let now = Utc::now();
+        for resource in resources.iter().filter(|resource| {
+            phase_of(resource).as_deref() == Some("Running")
+                && heartbeat_is_stale(heartbeat_of(resource), now)
+        }) {
+            let name = resource.meta().name.clone().unwrap_or_default();
+            let namespace = resource.meta().namespace.clone().unwrap_or_default();
+            warn!(kind, name = %name, namespace = %namespace, phase, "Recovering operation orphaned by operator restart");
+            let namespaced: Api<K> = Api::namespaced(client.clone(), &namespace);
+            if let Err(e) = namespaced
+                .patch_status(
+                    &name,
+                    &PatchParams::apply("kafka-backup-operator"),
+                    &Patch::Merge(orphaned_status(
+                        phase,
+                        message,
+                        &conditions_of(resource),
+                        Utc::now(),
+                    )),
+                )
+                .await
+            {
+                warn!(kind, name = %name, error = %e, "Failed to recover orphaned resource");
+            }
+        }
+    }
+}
+
+/// Status patch moving an orphaned resource to `phase`. A merge patch
+/// replaces the whole conditions list, so the existing conditions other than
+/// `Ready` are carried over.
+fn orphaned_status(
+    phase: &str,
+    message: &str,
+    existing: &[Condition],
+    now: DateTime<Utc>,
+) -> serde_json::Value {
+    let mut conditions: Vec<serde_json::Value> = existing
+        .iter()
+        .filter(|condition| condition.type_ != "Ready")
+        .filter_map(|condition| serde_json::to_value(condition).ok())
+        .collect();
+    conditions.push(json!({
+        "type": "Ready",
+        "status": "False",
+        "lastTransitionTime": now,
+        "reason": REASON_ORPHANED_BY_RESTART,
+        "message": message
+    }));
+    json!({
+        "status": {
+            "phase": phase,
+            "message": message,
+            "conditions": conditions
+        }
+    })
+}
+
+/// Whether the last sign of life from a Running operation is too old for it
+/// to still be alive. A missing heartbeat counts as stale.
+pub fn heartbeat_is_stale(last_seen: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+    let Some(last_seen) = last_seen else {
+        return true;
+    };
+    let stale_after =
+        chrono::Duration::from_std(HEARTBEAT_STALE_AFTER).unwrap_or(chrono::Duration::zero());
+    now - last_seen > stale_after
+}
+
+/// Await `operation` under an optional timeout, mapping expiry to
+/// [`Error::Timeout`]. The future is dropped (cancelled) when the limit hits.
+pub async fn run_with_timeout<F: Future>(
+    timeout: Option<Duration>,
+    description: &str,
+    operation: F,
+) -> Result<F::Output> {
+    match timeout {
+        Some(limit) => tokio::time::timeout(limit, operation).await.map_err(|_| {
+            Error::Timeout(format!(
+                "{} exceeded timeoutSecs={}",
+                description,
+                limit.as_secs()
+            ))
+        }),
+        None => Ok(operation.await),
+    }
+}
+
+/// Await `operation` unless operator shutdown is signalled first, mapping
+/// shutdown to [`Error::Interrupted`]. The future is dropped on shutdown, so
+/// the engine's last checkpoint is what the next attempt resumes from.
+pub async fn run_until_shutdown<F: Future>(
+    shutdown: &CancellationToken,
+    description: &str,
+    operation: F,
+) -> Result<F::Output> {
+    tokio::select! {
+        output = operation => Ok(output),
+        _ = shutdown.cancelled() => Err(Error::Interrupted(format!(
+            "{} stopped by operator shutdown",
+            description
+        ))),
+    }
+}
+
+/// Remaining wait before a timed-out operation may be retried, anchored on the
+/// `Ready` condition transition written with the `TimedOut` phase. Returns
+/// `None` once the retry delay has elapsed.
+pub fn timeout_retry_wait(conditions: &[Condition], now: DateTime<Utc>) -> Option<Duration> {
+    let timed_out_at = conditions
+        .iter()
+        .find(|condition| condition.type_ == "Ready")?
+        .last_transition_time;
+
+    let retry_at = timed_out_at + chrono::Duration::from_std(TIMEOUT_RETRY_DELAY).ok()?;
+    (retry_at - now)
+        .to_std()
+        .ok()
+        .filter(|wait| !wait.is_zero())
+}
+
+/// Number of failures kept in `status.recentErrors`
+pub const MAX_RECENT_ERRORS: usize = 5;
+
+/// Append a failure to a resource's error history, dropping the oldest
+/// entries beyond [`MAX_RECENT_ERRORS`]. Merge patches replace lists
+/// wholesale, so the whole history is returned for the patch.
+/// A failure repeating the newest entry only refreshes its timestamp, so a
+/// retried error does not push the rest of the history out.
+pub fn push_recent_error(
+    history: &[ErrorEntry],
+    phase: &str,
+    message: &str,
+    now: DateTime<Utc>,
+) -> Vec<ErrorEntry> {
+    let mut history = history.to_vec();
+    if let Some(last) = history
+        .last_mut()
+        .filter(|last| last.phase == phase && last.message == message)
+    {
+        last.timestamp = now;
+        return history;
+    }
+
+    history.push(ErrorEntry {
+        timestamp: now,
+        phase: phase.to_string(),
+        message: message.to_string(),
+    });
+    let skip = history.len().saturating_sub(MAX_RECENT_ERRORS);
+    history.split_off(skip)
+}
+
+/// Resources whose status keeps a `recentErrors` history
+pub trait RecentErrors {
+    /// The current history, oldest first; empty without a status
+    fn recent_errors(&self) -> &[ErrorEntry];
+}
+
+impl RecentErrors for KafkaBackup {
+    fn recent_errors(&self) -> &[ErrorEntry] {
+        self.status
+            .as_ref()
+            .map(|status| status.recent_errors.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl RecentErrors for KafkaRestore {
+    fn recent_errors(&self) -> &[ErrorEntry] {
+        self.status
+            .as_ref()
+            .map(|status| status.recent_errors.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl RecentErrors for KafkaOffsetReset {
+    fn recent_errors(&self) -> &[ErrorEntry] {
+        self.status
+            .as_ref()
+            .map(|status| status.recent_errors.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl RecentErrors for KafkaOffsetRollback {
+    fn recent_errors(&self) -> &[ErrorEntry] {
+        self.status
+            .as_ref()
+            .map(|status| status.recent_errors.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl RecentErrors for KafkaBackupValidation {
+    fn recent_errors(&self) -> &[ErrorEntry] {
+        self.status
+            .as_ref()
+            .map(|status| status.recent_errors.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// `resource`'s error history with a failure recorded now, ready for a
+/// `recentErrors` status patch
+pub fn record_recent_error(
+    resource: &impl RecentErrors,
+    phase: &str,
+    message: &str,
+) -> Vec<ErrorEntry> {
+    push_recent_error(resource.recent_errors(), phase, message, Utc::now())
+}
+
+/// SASL mechanisms supported by the kafka-backup-core client
+pub const SUPPORTED_SASL_MECHANISMS: &[&str] = &["PLAIN", "SCRAM-SHA-256", "SCRAM-SHA-512"];
+
+/// Cross-field validation of the Kafka security settings: SSL protocols need
+/// certificates, SASL protocols need credentials, the SASL mechanism must be
+/// one the core client supports, and clientConfig must not override any of
+/// them.
+pub(crate) fn validate_kafka_security(cluster: &KafkaClusterSpec) -> Result<()> {
+    let protocol = cluster.security_protocol.to_uppercase();
+
+    if let Some(cert_dir) = &cluster.tls_cert_dir {
+        if cluster.tls_secret.is_some() || cluster.ca_secret.is_some() {
+            return Err(Error::validation(
+                "tlsCertDir is mutually exclusive with tlsSecret and caSecret",
+            ));
+        }
+        if !cert_dir.starts_with('/') {
+            return Err(Error::validation(format!(
+                "tlsCertDir '{}' must be an absolute path",
+                cert_dir
+            )));
+        }
+    }
+
+    if protocol.contains("SSL")
+        && cluster.tls_secret.is_none()
+        && cluster.ca_secret.is_none()
+        && cluster.tls_cert_dir.is_none()
+    {
+        return Err(Error::validation(
+            "securityProtocol SSL/SASL_SSL requires either tlsSecret or caSecret, or tlsCertDir, to be configured",
+        ));
+    }
+
+    if protocol.contains("SASL") && cluster.sasl_secret.is_none() {
+        return Err(Error::validation(format!(
+            "securityProtocol {} requires saslSecret to be configured",
+            protocol
+        )));
+    }
+
+    if let Some(sasl) = &cluster.sasl_secret {
+        validate_credential_source(
+            "saslSecret",
+            sasl.name.as_deref(),
+            &[
+                ("usernameEnv", sasl.username_env.as_deref()),
+                ("passwordEnv", sasl.password_env.as_deref()),
+            ],
+        )?;
+    }
+
+    validate_sasl_mechanism(cluster)?;
+    validate_client_config(cluster)
+}
+
+/// Reject clientConfig keys that would override the bootstrap servers or
+/// security settings the operator manages, and supported keys whose value is
+/// not a boolean
+fn validate_client_config(cluster: &KafkaClusterSpec) -> Result<()> {
+    let mut invalid: Vec<&str> = cluster
+        .client_config
+        .iter()
+        .filter(|(key, value)| {
+            SUPPORTED_CLIENT_CONFIG.contains(&key.as_str()) && client_config_bool(value).is_none()
+        })
+        .map(|(key, _)| key.as_str())
+        .collect();
+    if !invalid.is_empty() {
+        invalid.sort_unstable();
+        return Err(Error::validation(format!(
+            "clientConfig {} must be \"true\" or \"false\"",
+            invalid.join(", ")
+        )));
+    }
+
+    let mut managed: Vec<&str> = cluster
+        .client_config
+        .keys()
+        .map(String::as_str)
+        .filter(|key| is_managed_client_config(key))
+        .collect();
+    if managed.is_empty() {
+        return Ok(());
+    }
+
+    managed.sort_unstable();
+    Err(Error::validation(format!(
+        "clientConfig must not set {}: use the bootstrapServers, securityProtocol, \
+         tlsSecret and saslSecret fields instead",
+        managed.join(", ")
+    )))
+}
+
+/// Reject SASL mechanisms the core client cannot speak (e.g. OAUTHBEARER,
+/// GSSAPI) instead of letting them fall through to an opaque connect failure.
+fn validate_sasl_mechanism(cluster: &KafkaClusterSpec) -> Result<()> {
+    let Some(sasl) = &cluster.sasl_secret else {
+        return Ok(());
+    };
+
+    let mechanism = crate::adapters::normalize_sasl_mechanism(&sasl.mechanism);
+    if SUPPORTED_SASL_MECHANISMS.contains(&mechanism.as_str()) {
+        return Ok(());
+    }
+
+    Err(Error::validation(format!(
+        "Unsupported SASL mechanism '{}': must be one of: {}",
+        sasl.mechanism,
+        SUPPORTED_SASL_MECHANISMS.join(", ")
+    )))
+}
+
+/// Check that a credential ref names exactly one source: either a secret
+/// (`name`) or an environment variable for every value it carries. Only
+/// variables named `KAFKA_BACKUP_CRED_*` may be referenced.
+pub(crate) fn validate_credential_source(
+    field: &str,
+    secret_name: Option<&str>,
+    env_vars: &[(&str, Option<&str>)],
+) -> Result<()> {
+    for (env_field, var) in env_vars {
+        if let Some(var) = var.filter(|var| !var.starts_with(CREDENTIAL_ENV_PREFIX)) {
+            return Err(Error::validation(format!(
+                "{}.{} '{}' must name an environment variable starting with {}",
+                field, env_field, var, CREDENTIAL_ENV_PREFIX
+            )));
+        }
+    }
+
+    let set: Vec<&str> = env_vars
+        .iter()
+        .filter(|(_, var)| var.is_some())
+        .map(|(env_field, _)| *env_field)
+        .collect();
+
+    if secret_name.is_some() {
+        if set.is_empty() {
+            return Ok(());
+        }
+        return Err(Error::validation(format!(
+            "{} sets both name and {}: credentials come from either a secret or environment variables",
+            field,
+            set.join(", ")
+        )));
+    }
+
+    let missing: Vec<&str> = env_vars
+        .iter()
+        .filter(|(_, var)| var.is_none())
+        .map(|(env_field, _)| *env_field)
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    if set.is_empty() {
+        return Err(Error::validation(format!(
+            "{} must set either name or {}",
+            field,
+            missing.join(", ")
+        )));
+    }
+    Err(Error::validation(format!(
+        "{} sources credentials from environment variables but is missing {}",
+        field,
+        missing.join(", ")
+    )))
+}
+
+/// Validate the credential source of every storage backend configured in `storage`
+pub(crate) fn validate_storage_credentials(storage: &StorageSpec) -> Result<()> {
+    if let Some(s3) = &storage.s3 {
+        let creds = &s3.credentials_secret;
+        validate_credential_source(
+            "s3.credentialsSecret",
+            creds.name.as_deref(),
+            &[
+                ("accessKeyIdEnv", creds.access_key_id_env.as_deref()),
+                ("secretAccessKeyEnv", creds.secret_access_key_env.as_deref()),
+            ],
+        )?;
+    }
+    if let Some(azure) = &storage.azure {
+        if let Some(creds) = &azure.credentials_secret {
+            validate_credential_source(
+                "azure.credentialsSecret",
+                creds.name.as_deref(),
+                &[("accountKeyEnv", creds.account_key_env.as_deref())],
+            )?;
+        }
+        if let Some(sas) = &azure.sas_token_secret {
+            validate_credential_source(
+                "azure.sasTokenSecret",
+                sas.name.as_deref(),
+                &[("sasTokenEnv", sas.sas_token_env.as_deref())],
+            )?;
+        }
+        if let Some(sp) = &azure.service_principal_secret {
+            validate_credential_source(
+                "azure.servicePrincipalSecret",
+                sp.name.as_deref(),
+                &[
+                    ("clientIdEnv", sp.client_id_env.as_deref()),
+                    ("tenantIdEnv", sp.tenant_id_env.as_deref()),
+                    ("clientSecretEnv", sp.client_secret_env.as_deref()),
+                ],
+            )?;
+        }
+    }
+    if let Some(gcs) = &storage.gcs {
+        let creds = &gcs.credentials_secret;
+        validate_credential_source(
+            "gcs.credentialsSecret",
+            creds.name.as_deref(),
+            &[(
+                "serviceAccountJsonEnv",
+                creds.service_account_json_env.as_deref(),
+            )],
+        )?;
+    }
+    Ok(())
+}
+
+/// Check that a storage endpoint override is an absolute http(s) URL with a
+/// host, so a typo fails validation instead of surfacing as a DNS error
+/// halfway through a backup.
+pub(crate) fn validate_endpoint_url(field: &str, endpoint: &str) -> Result<reqwest::Url> {
+    let url = reqwest::Url::parse(endpoint).map_err(|e| {
+        Error::validation(format!(
+            "{} '{}' is not a valid URL: {}",
+            field, endpoint, e
+        ))
+    })?;
+
+    if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+        return Err(Error::validation(format!(
+            "{} '{}' must be an http:// or https:// URL with a host",
+            field, endpoint
+        )));
+    }
+
+    Ok(url)
+}
+
+/// A custom S3 endpoint must be a well-formed URL, and `allowHttp` is only
+/// accepted when that endpoint explicitly uses plain `http://`.
+pub(crate) fn validate_s3_transport(s3: &S3StorageSpec) -> Result<()> {
+    let endpoint = s3
+        .endpoint
+        .as_deref()
+        .map(|endpoint| validate_endpoint_url("s3.endpoint", endpoint))
+        .transpose()?;
+
+    if s3.allow_http.unwrap_or(false) && endpoint.as_ref().map(|url| url.scheme()) != Some("http") {
+        return Err(Error::validation(
+            "s3.allowHttp requires s3.endpoint to be an explicit http:// URL",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Fragments of Kafka errors that mean the broker rejected the SASL
+/// credentials
+const KAFKA_AUTH_REJECTION_MARKERS: [&str; 3] = [
+    "authentication failed",
+    "saslauthenticationfailed",
+    "illegal sasl state",
+];
+
+/// Reclassify an error that ended a running backup or restore as
+/// [`Error::CredentialsRotated`] when Kafka or storage rejected the
+/// credentials after the same run had `authenticated` (made progress with
+/// them). Secrets are read again on every attempt, so the retry picks up
+/// rotated credentials; controllers retry these quickly. Credentials that
+/// never worked keep their original error and its slower retry.
+pub(crate) fn classify_credentials_rotation(error: Error, authenticated: bool) -> Error {
+    if !authenticated {
+        return error;
+    }
+    let message = match &error {
+        Error::Core(message)
+        | Error::KafkaConnection(message)
+        | Error::Storage(message)
+        | Error::StorageAuth(message) => message,
+        _ => return error,
+    };
+    let lower = message.to_ascii_lowercase();
+    if crate::adapters::is_auth_rejection(message)
+        || KAFKA_AUTH_REJECTION_MARKERS
+            .iter()
+            .any(|marker| lower.contains(marker))
+    {
+        Error::CredentialsRotated(message.clone())
+    } else {
+        error
+    }
+}
+
+/// Probe `storage` ahead of a backup or restore, recording the result in the
+/// storage reachability gauge. An unreachable backend is an
+/// [`Error::Storage`], retried on the controllers' storage requeue interval;
+/// one that rejects the credentials is an [`Error::StorageAuth`], retried far
+/// less often since the credentials have to change first.
+pub(crate) async fn probe_storage(
+    storage: &ResolvedStorage,
+    kind: &str,
+    namespace: &str,
+    name: &str,
+) -> Result<()> {
+    let result = storage.probe().await;
+    metrics::STORAGE_REACHABLE
+        .with_label_values(&[kind, storage.backend_name(), namespace, name])
+        .set(if result.is_ok() { 1.0 } else { 0.0 });
+    if let Err(e) = &result {
+        warn!(name = %name, backend = storage.backend_name(), error = %e, "Storage probe failed");
+    }
+    result
+}
+
+/// Drop the storage reachability series of a deleted resource, whichever
+/// backend it was probed on
+pub(crate) fn forget_storage_probe(kind: &str, namespace: &str, name: &str) {
+    for backend in ["pvc", "s3", "azure", "gcs"] {
+        let _ = metrics::STORAGE_REACHABLE.remove_label_values(&[kind, backend, namespace, name]);
+    }
+}
+
+/// Cluster-wide facts read from Kafka metadata
+#[derive(Debug, Clone)]
+pub(crate) struct ClusterInfo {
+    /// Cluster ID; clusters that predate cluster IDs report none
+    pub cluster_id: Option<String>,
+    /// Number of live brokers
+    pub broker_count: usize,
+    /// Partition count of each existing topic
+    pub topic_partitions: BTreeMap<String, usize>,
+}
+
+/// Connect to a cluster and read its ID, broker count and topic partition
+/// counts from metadata
+pub(crate) async fn fetch_cluster_info(kafka_config: CoreKafkaConfig) -> Result<ClusterInfo> {
+    let kafka_client = KafkaClient::new(kafka_config);
+    kafka_client
+        .connect()
+        .await
+        .map_err(|e| Error::KafkaConnection(e.to_string()))?;
+    let metadata = kafka_client
+        .fetch_metadata(None)
+        .await
+        .map_err(|e| Error::KafkaConnection(format!("failed to fetch metadata: {}", e)))?;
+    Ok(ClusterInfo {
+        cluster_id: metadata.cluster_id,
+        broker_count: metadata.brokers.len(),
+        topic_partitions: metadata
+            .topics
+            .iter()
+            .map(|topic| (topic.name.clone(), topic.partitions.len()))
+            .collect(),
+    })
+}
+
+/// Connect to a cluster and describe the configs of `topics`, keeping only
+/// `keys`. Topics the broker reports an error for are left out.
+pub(crate) async fn fetch_topic_configs(
+    kafka_config: CoreKafkaConfig,
+    topics: &[String],
+    keys: &[&str],
+) -> Result<BTreeMap<String, BTreeMap<String, String>>> {
+    let kafka_client = KafkaClient::new(kafka_config);
+    kafka_client
+        .connect()
+        .await
+        .map_err(|e| Error::KafkaConnection(e.to_string()))?;
+    let described = describe_topic_configs(&kafka_client, topics)
+        .await
+        .map_err(|e| Error::KafkaConnection(format!("failed to describe topic configs: {}", e)))?;
+    Ok(described
+        .into_iter()
+        .filter(|topic| topic.error_code == 0)
+        .map(|topic| {
+            let configs = topic
+                .configs
+                .into_iter()
+                .filter(|(key, _)| keys.contains(&key.as_str()))
+                .collect();
+            (topic.topic, configs)
+        })
+        .collect())
+}
+
+/// Committed offsets of consumer groups: group, then topic, then partition
+pub(crate) type GroupOffsets = BTreeMap<String, BTreeMap<String, BTreeMap<i32, i64>>>;
+
+/// Connect to a cluster and read the offsets each consumer group has
+/// committed on `topics`. Groups with nothing committed on them are left out.
+pub(crate) async fn fetch_group_offsets(
+    kafka_config: CoreKafkaConfig,
+    topics: &[String],
+) -> Result<GroupOffsets> {
+    let kafka_client = KafkaClient::new(kafka_config);
+    kafka_client
+        .connect()
+        .await
+        .map_err(|e| Error::KafkaConnection(e.to_string()))?;
+    let groups = list_groups(&kafka_client)
+        .await
+        .map_err(|e| Error::KafkaConnection(format!("failed to list consumer groups: {}", e)))?;
+
+    let mut group_offsets = GroupOffsets::new();
+    for group in groups {
+        let committed = fetch_offsets(&kafka_client, &group.group_id, Some(topics))
+            .await
+            .map_err(|e| {
+                Error::KafkaConnection(format!(
+                    "failed to fetch offsets of consumer group {}: {}",
+                    group.group_id, e
+                ))
+            })?;
+        let mut offsets: BTreeMap<String, BTreeMap<i32, i64>> = BTreeMap::new();
+        for offset in committed.into_iter().filter(|offset| offset.offset >= 0) {
+            offsets
+                .entry(offset.topic)
+                .or_default()
+                .insert(offset.partition, offset.offset);
+        }
+        if !offsets.is_empty() {
+            group_offsets.insert(group.group_id, offsets);
+        }
+    }
+    Ok(group_offsets)
+}
+
+/// Metadata error code for a topic the principal may not describe
+const TOPIC_AUTHORIZATION_FAILED: i16 = 29;
+
+/// Operations the connected principal is authorized for, as Kafka reports
+/// them in metadata: bitfields where bit `n` allows ACL operation `n`
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AuthorizedOperations {
+    /// Operations on the cluster, if the broker reported them
+    pub cluster: Option<i32>,
+    /// Access to each requested topic the broker answered for
+    pub topics: BTreeMap<String, TopicAccess>,
+}
+
+/// What metadata revealed about one topic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TopicAccess {
+    /// The topic exists; operations on it, if the broker reported them
+    Exists(Option<i32>),
+    /// The topic does not exist; operations on it (which topic ACLs such
+    /// as prefixed ones grant), if the broker reported them
+    Missing(Option<i32>),
+    /// The principal may not describe the topic
+    DescribeDenied,
+}
+
+/// Connect to a cluster and read the operations the configured principal may
+/// perform on it and on `topics`
+pub(crate) async fn fetch_authorized_operations(
+    kafka_config: CoreKafkaConfig,
+    topics: &[String],
+) -> Result<AuthorizedOperations> {
+    let kafka_client = KafkaClient::new(kafka_config);
+    kafka_client
+        .connect()
+        .await
+        .map_err(|e| Error::KafkaConnection(e.to_string()))?;
+    let metadata = kafka_client
+        .fetch_metadata_with_authorized_operations(Some(topics))
+        .await
+        .map_err(|e| Error::KafkaConnection(format!("failed to fetch metadata: {}", e)))?;
+    Ok(AuthorizedOperations {
+        cluster: reported_operations(metadata.cluster_authorized_operations),
+        topics: metadata
+            .topics
+            .iter()
+            .map(|topic| {
+                let access = match topic.error_code {
+                    0 => TopicAccess::Exists(reported_operations(topic.authorized_operations)),
+                    TOPIC_AUTHORIZATION_FAILED => TopicAccess::DescribeDenied,
+                    _ => TopicAccess::Missing(reported_operations(topic.authorized_operations)),
+                };
+                (topic.name.clone(), access)
+            })
+            .collect(),
+    })
+}
+
+/// Brokers that don't report authorized operations send `i32::MIN`
+fn reported_operations(operations: i32) -> Option<i32> {
+    (operations != i32::MIN).then_some(operations)
+}
+
+/// Whether two cluster IDs are known to belong to different clusters. An
+/// unknown ID on either side is not treated as a mismatch.
+pub(crate) fn cluster_ids_differ(source: Option<&str>, target: Option<&str>) -> bool {
+    matches!((source, target), (Some(source), Some(target)) if source != target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watch_namespaces_are_trimmed_and_deduplicated() {
+        assert!(parse_namespaces("").is_empty());
+        assert!(parse_namespaces(" , ").is_empty());
+        assert_eq!(
+            parse_namespaces("kafka, team-a,,kafka "),
+            vec!["kafka".to_string(), "team-a".to_string()]
+        );
+    }
+
+    #[test]
+    fn heartbeat_is_stale_when_missing_or_old() {
+        let now = Utc::now();
+        assert!(heartbeat_is_stale(None, now));
+        assert!(heartbeat_is_stale(
+            Some(now - chrono::Duration::seconds(91)),
+            now
+        ));
+    }
+
+    #[test]
+    fn orphaned_status_records_restart_reason() {
+        let now = Utc::now();
+        let status = orphaned_status(PHASE_INTERRUPTED, "orphaned", &[], now);
+        assert_eq!(status["status"]["phase"], PHASE_INTERRUPTED);
+        assert_eq!(status["status"]["message"], "orphaned");
+        assert_eq!(
+            status["status"]["conditions"][0]["reason"],
+            REASON_ORPHANED_BY_RESTART
+        );
+        assert_eq!(status["status"]["conditions"][0]["status"], "False");
+    }
+
+    #[test]
+    fn orphaned_status_keeps_other_conditions() {
+        let now = Utc::now();
+        let condition = |type_: &str, status: &str| Condition {
+            type_: type_.to_string(),
+            status: status.to_string(),
+            last_transition_time: now,
+            reason: None,
+            message: None,
+        };
+        let existing = [
+            condition("Ready", "True"),
+            condition("Paused", "True"),
+            condition("ReplicationFactorReduced", "True"),
+        ];
+
+        let status = orphaned_status(PHASE_INTERRUPTED, "orphaned", &existing, now);
+        let conditions = status["status"]["conditions"].as_array().unwrap();
+        let types: Vec<_> = conditions.iter().map(|c| c["type"].clone()).collect();
+        assert_eq!(types, ["Paused", "ReplicationFactorReduced", "Ready"]);
+        assert_eq!(conditions[2]["reason"], REASON_ORPHANED_BY_RESTART);
+    }
+
+    #[test]
+    fn recent_heartbeat_is_live() {
+        let now = Utc::now();
+        assert!(!heartbeat_is_stale(Some(now), now));
+        assert!(!heartbeat_is_stale(
+            Some(now - chrono::Duration::seconds(60)),
+            now
+        ));
+    }
+
+    #[tokio::test]
+    async fn shutdown_interrupts_running_operation() {
+        let shutdown = CancellationToken::new();
+        shutdown.cancel();
+
+        let result =
+            run_until_shutdown(&shutdown, "Backup demo", std::future::pending::<()>()).await;
+        assert!(matches!(result, Err(Error::Interrupted(_))));
+    }
+
+    #[tokio::test]
+    async fn operation_finishing_before_shutdown_returns_its_output() {
+        let shutdown = CancellationToken::new();
+        let result = run_until_shutdown(&shutdown, "Backup demo", async { 7 }).await;
+        assert_eq!(result.unwrap(), 7);
+    }
+
+    #[test]
+    fn recent_errors_keep_only_the_newest_entries() {
+        let now = Utc::now();
+        let mut history = Vec::new();
+        for attempt in 0..7 {
+            history = push_recent_error(&history, "Failed", &format!("attempt {}", attempt), now);
+        }
+
+        assert_eq!(history.len(), MAX_RECENT_ERRORS);
+        assert_eq!(history[0].message, "attempt 2");
+        assert_eq!(history[MAX_RECENT_ERRORS - 1].message, "attempt 6");
+    }
+
+    #[test]
+    fn repeated_errors_refresh_the_newest_entry() {
+        let earlier = Utc::now() - chrono::Duration::minutes(5);
+        let now = Utc::now();
+        let history = push_recent_error(&[], "Failed", "first", earlier);
+        let history = push_recent_error(&history, "Failed", "broker down", earlier);
+        let history = push_recent_error(&history, "Failed", "broker down", now);
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].message, "broker down");
+        assert_eq!(history[1].timestamp, now);
+
+        let history = push_recent_error(&history, "TimedOut", "broker down", now);
+        assert_eq!(history.len(), 3);
+    }
+
+    #[test]
+    fn credentials_rejected_mid_run_are_classified_as_rotated() {
+        for error in [
+            Error::Core("Backup execution failed: SASL authentication failed for user".into()),
+            Error::KafkaConnection("SaslAuthenticationFailed (58)".into()),
+            Error::Storage("put segment: status 403 AccessDenied".into()),
+        ] {
+            assert!(matches!(
+                classify_credentials_rotation(error, true),
+                Error::CredentialsRotated(_)
+            ));
+        }
+
+        // Credentials rejected before the run made any progress never worked
+        assert!(matches!(
+            classify_credentials_rotation(Error::StorageAuth("status 403".into()), false),
+            Error::StorageAuth(_)
+        ));
+
+        for error in [
+            Error::Core("Backup execution failed: broker 10.0.0.4:9403 unreachable".into()),
+            Error::Validation("authentication failed".into()),
+        ] {
+            assert!(!matches!(
+                classify_credentials_rotation(error, true),
+                Error::CredentialsRotated(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn cluster_ids_differ_only_when_both_known() {
+        assert!(cluster_ids_differ(Some("abc"), Some("xyz")));
+        assert!(!cluster_ids_differ(Some("abc"), Some("abc")));
+        assert!(!cluster_ids_differ(None, Some("xyz")));
+        assert!(!cluster_ids_differ(Some("abc"), None));
+    }
+
+    #[test]
+    fn credential_source_requires_exactly_one_of_secret_or_env() {
+        let env = |var: Option<&'static str>| [("accountKeyEnv", var)];
+
+        assert!(validate_credential_source("creds", Some("secret"), &env(None)).is_ok());
+        assert!(validate_credential_source(
+            "creds",
+            None,
+            &env(Some("KAFKA_BACKUP_CRED_AZURE_KEY"))
+        )
+        .is_ok());
+
+        let both = validate_credential_source(
+            "creds",
+            Some("secret"),
+            &env(Some("KAFKA_BACKUP_CRED_AZURE_KEY")),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(both.contains("sets both name and accountKeyEnv"));
+
+        let neither = validate_credential_source("creds", None, &env(None))
+            .unwrap_err()
+            .to_string();
+        assert!(neither.contains("must set either name or accountKeyEnv"));
+
+        let outside = validate_credential_source("creds", None, &env(Some("AZURE_KEY")))
+            .unwrap_err()
+            .to_string();
+        assert!(outside.contains("must name an environment variable starting with"));
+    }
+}
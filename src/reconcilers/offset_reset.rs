@@ -2,20 +2,23 @@
 //!
 //! Handles the business logic for consumer group offset reset operations.
 
-use std::path::Path;
-use std::time::Duration;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
 
 use chrono::Utc;
+use futures::stream::{self, StreamExt};
 use kafka_backup_core::config::KafkaConfig as CoreKafkaConfig;
 use kafka_backup_core::config::{SaslMechanism, SecurityConfig, SecurityProtocol, TopicSelection};
 use kafka_backup_core::kafka::consumer_groups::{
-    commit_offsets, fetch_offsets, offsets_for_times, CommittedOffset,
+    commit_offsets, fetch_offsets, list_groups, offsets_for_times, CommittedOffset,
 };
 use kafka_backup_core::kafka::KafkaClient;
 use kafka_backup_core::manifest::OffsetMapping;
-use kafka_backup_core::{snapshot_current_offsets, BulkOffsetResetConfig};
+use kafka_backup_core::{snapshot_current_offsets, BulkOffsetResetConfig, OffsetSnapshot};
 use kube::{
-    api::{Patch, PatchParams},
+    api::{ListParams, Patch, PatchParams},
     runtime::controller::Action,
     Api, Client, ResourceExt,
 };
@@ -23,14 +26,28 @@ use serde_json::json;
 use tracing::{error, info, warn};
 
 use crate::adapters::{
-    build_kafka_config, default_tls_dir, to_core_connection_config, TlsFileManager,
+    build_kafka_config, create_tls_manager, get_snapshot_storage_path, to_core_connection_config,
+    TlsFileManager,
+};
+use crate::crd::{
+    KafkaOffsetReset, KafkaRestore, OffsetMappingRef, OffsetResetStrategy, PartitionDelta,
+    SnapshotRef, TopicPartitions,
 };
-use crate::crd::{KafkaOffsetReset, KafkaRestore, OffsetMappingRef, OffsetResetStrategy};
 use crate::error::{Error, Result};
 use crate::metrics;
+use crate::reconcilers::restore::target_consumer_group;
 
 const STALE_RUNNING_AFTER_SECS: i64 = 30 * 60;
 
+/// Subdirectory of the snapshot storage path holding pre-reset snapshots
+const RESET_SNAPSHOT_SUBDIR: &str = "offset-resets";
+
+/// Shortest per-attempt timeout `retry.requestTimeoutMs` accepts
+const MIN_REQUEST_TIMEOUT_MS: u64 = 1000;
+
+/// Cap on the retry delay doubling, so large attempt counts can't overflow
+const MAX_RETRY_EXPONENT: u32 = 16;
+
 /// Validate the KafkaOffsetReset spec
 pub fn validate(reset: &KafkaOffsetReset) -> Result<()> {
     // Validate kafka cluster
@@ -41,12 +58,33 @@ pub fn validate(reset: &KafkaOffsetReset) -> Result<()> {
     }
 
     // Validate consumer groups
-    if reset.spec.consumer_groups.is_empty() {
+    if reset.spec.all_groups {
+        if !reset.spec.consumer_groups.is_empty() {
+            return Err(Error::validation(
+                "consumerGroups must be empty when allGroups is set",
+            ));
+        }
+    } else if reset.spec.consumer_groups.is_empty() {
         return Err(Error::validation(
             "At least one consumer group must be specified",
         ));
     }
 
+    // Listing a group twice would reset it twice, the second time against
+    // offsets the first pass already moved
+    if let Some(group) = first_duplicate(&reset.spec.consumer_groups) {
+        return Err(Error::validation(format!(
+            "consumerGroups lists '{}' more than once",
+            group
+        )));
+    }
+    if let Some(topic) = first_duplicate(&reset.spec.topics) {
+        return Err(Error::validation(format!(
+            "topics lists '{}' more than once",
+            topic
+        )));
+    }
+
     // Validate strategy-specific requirements
     match &reset.spec.reset_strategy {
         OffsetResetStrategy::ToTimestamp if reset.spec.reset_timestamp.is_none() => {
@@ -67,22 +105,42 @@ pub fn validate(reset: &KafkaOffsetReset) -> Result<()> {
         _ => {}
     }
 
+    // Partition targeting narrows the topic filter, so it must stay inside it
+    for selection in &reset.spec.partitions {
+        if !reset.spec.topics.contains(&selection.topic) {
+            return Err(Error::validation(format!(
+                "partitions entry for topic '{}' must also be listed in topics",
+                selection.topic
+            )));
+        }
+        if selection.partitions.iter().any(|partition| *partition < 0) {
+            return Err(Error::validation(format!(
+                "partitions entry for topic '{}' contains a negative partition",
+                selection.topic
+            )));
+        }
+    }
+
     // Validate parallelism
     if reset.spec.parallelism == 0 {
         return Err(Error::validation("parallelism must be greater than 0"));
     }
 
-    // Validate TLS configuration: SSL/SASL_SSL requires at least one TLS secret
-    let protocol = reset.spec.kafka_cluster.security_protocol.to_uppercase();
-    if (protocol == "SSL" || protocol == "SASL_SSL")
-        && reset.spec.kafka_cluster.tls_secret.is_none()
-        && reset.spec.kafka_cluster.ca_secret.is_none()
-    {
-        return Err(Error::validation(
-            "securityProtocol SSL/SASL_SSL requires either tlsSecret or caSecret to be configured",
-        ));
+    if let Some(retry) = &reset.spec.retry {
+        if retry.max_attempts == 0 {
+            return Err(Error::validation("retry.maxAttempts must be at least 1"));
+        }
+        if retry.request_timeout_ms < MIN_REQUEST_TIMEOUT_MS {
+            return Err(Error::validation(format!(
+                "retry.requestTimeoutMs must be at least {}",
+                MIN_REQUEST_TIMEOUT_MS
+            )));
+        }
     }
 
+    // Validate security: SASL needs credentials, SSL needs certificates
+    super::validate_kafka_security(&reset.spec.kafka_cluster)?;
+
     if let Some(connection) = &reset.spec.kafka_cluster.connection {
         if connection.connections_per_broker == 0 {
             return Err(Error::validation(
@@ -94,6 +152,39 @@ pub fn validate(reset: &KafkaOffsetReset) -> Result<()> {
     Ok(())
 }
 
+/// First entry that appears more than once in `values`
+fn first_duplicate(values: &[String]) -> Option<&str> {
+    let mut seen = HashSet::new();
+    values
+        .iter()
+        .find(|value| !seen.insert(value.as_str()))
+        .map(String::as_str)
+}
+
+/// Whether the resource has reached a terminal phase for its current
+/// generation and needs no further reconcile work. Resources being deleted
+/// are never settled so the finalizer still runs.
+pub fn is_settled(reset: &KafkaOffsetReset) -> bool {
+    reset.metadata.deletion_timestamp.is_none()
+        && reset.status.as_ref().is_some_and(|status| {
+            status.observed_generation == reset.metadata.generation
+                && matches!(
+                    status.phase.as_deref(),
+                    Some("Completed" | "Failed" | "PartiallyCompleted")
+                )
+        })
+}
+
+/// How often a settled reset is revisited to sweep expired snapshots
+pub const SNAPSHOT_SWEEP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Action for a reconcile of a settled reset, or `None` when the reset
+/// still needs a full reconcile. Snapshots expire whether or not their reset
+/// is ever deleted, so finished resets are revisited to sweep them.
+pub fn settled_action(reset: &KafkaOffsetReset) -> Option<Action> {
+    is_settled(reset).then(|| Action::requeue(SNAPSHOT_SWEEP_INTERVAL))
+}
+
 /// Monitor offset reset progress
 pub async fn monitor_progress(
     reset: &KafkaOffsetReset,
@@ -132,6 +223,7 @@ pub async fn execute(reset: &KafkaOffsetReset, client: &Client, namespace: &str)
     info!(
         name = %name,
         groups = reset.spec.consumer_groups.len(),
+        all_groups = reset.spec.all_groups,
         strategy = ?reset.spec.reset_strategy,
         "Starting offset reset execution"
     );
@@ -142,13 +234,18 @@ pub async fn execute(reset: &KafkaOffsetReset, client: &Client, namespace: &str)
         return execute_dry_run(reset, client, namespace).await;
     }
 
+    // Connect before marking Running so an unreachable cluster surfaces as a
+    // retryable reconcile error rather than a terminal Failed status.
+    let (kafka_client, _tls_manager) = connect_kafka(reset, client, namespace).await?;
+    let consumer_groups = resolve_consumer_groups(reset, &kafka_client).await?;
+
     // Update status to Running
     let running_status = json!({
         "status": {
             "phase": "Running",
             "message": "Offset reset in progress",
             "startTime": Utc::now(),
-            "groupsTotal": reset.spec.consumer_groups.len(),
+            "groupsTotal": consumer_groups.len(),
             "groupsReset": 0,
             "groupsFailed": 0,
             "observedGeneration": reset.metadata.generation,
@@ -161,15 +258,10 @@ pub async fn execute(reset: &KafkaOffsetReset, client: &Client, namespace: &str)
     )
     .await?;
 
-    // Create snapshot if enabled
-    if reset.spec.snapshot_before_reset {
-        info!(name = %name, "Creating pre-reset offset snapshot");
-        // TODO: Create offset snapshot
-    }
-
     // Execute offset reset
     let start_time = std::time::Instant::now();
-    let reset_result = execute_reset_internal(reset, client, namespace).await;
+    let reset_result =
+        execute_reset_internal(reset, &kafka_client, &consumer_groups, client, namespace).await;
     let duration = start_time.elapsed();
 
     match reset_result {
@@ -215,13 +307,24 @@ pub async fn execute(reset: &KafkaOffsetReset, client: &Client, namespace: &str)
                 "status": {
                     "phase": phase,
                     "message": message.clone(),
-                    "groupsTotal": reset.spec.consumer_groups.len(),
+                    "summary": super::status::offset_reset_summary(
+                        u64::from(result.groups_reset),
+                        consumer_groups.len() as u64,
+                        u64::from(result.groups_failed),
+                        duration,
+                    ),
+                    "groupsTotal": consumer_groups.len(),
                     "groupsReset": result.groups_reset,
                     "groupsFailed": result.groups_failed,
                     "completionTime": Utc::now(),
                     "duration": format!("{:.2}s", duration.as_secs_f64()),
                     "snapshotId": result.snapshot_id,
                     "snapshotPath": result.snapshot_path,
+                    "snapshotRef": rollback_snapshot_ref(
+                        reset,
+                        result.snapshot_id.as_deref(),
+                        result.snapshot_path.as_deref(),
+                    ),
                     "groupResults": result.group_results,
                     "observedGeneration": reset.metadata.generation,
                     "conditions": [{
@@ -249,10 +352,13 @@ pub async fn execute(reset: &KafkaOffsetReset, client: &Client, namespace: &str)
                 .with_label_values(&["failure", namespace])
                 .inc();
 
+            let message = format!("Offset reset failed: {}", e);
+            let recent_errors = super::record_recent_error(reset, "Failed", &message);
             let failed_status = json!({
                 "status": {
                     "phase": "Failed",
-                    "message": format!("Offset reset failed: {}", e),
+                    "message": message,
+                    "recentErrors": recent_errors,
                     "observedGeneration": reset.metadata.generation,
                     "conditions": [{
                         "type": "Ready",
@@ -275,7 +381,8 @@ pub async fn execute(reset: &KafkaOffsetReset, client: &Client, namespace: &str)
     }
 }
 
-/// Execute dry run validation
+/// Execute a dry run: compute the target offsets for every group and report
+/// the per-partition deltas without committing anything
 async fn execute_dry_run(
     reset: &KafkaOffsetReset,
     client: &Client,
@@ -284,21 +391,106 @@ async fn execute_dry_run(
     let name = reset.name_any();
     let api: Api<KafkaOffsetReset> = Api::namespaced(client.clone(), namespace);
 
-    // TODO: Validate consumer groups exist
-    // TODO: Validate target offsets are valid
+    let (kafka_client, _tls_manager) = connect_kafka(reset, client, namespace).await?;
+    let offset_mapping = if reset.spec.reset_strategy == OffsetResetStrategy::FromMapping {
+        Some(load_offset_mapping(reset, client, namespace).await?)
+    } else {
+        None
+    };
+
+    let consumer_groups = resolve_consumer_groups(reset, &kafka_client).await?;
+
+    // A group that doesn't exist yet is not fatal (the reset may be
+    // pre-creating its offsets), but it is usually a typo, so call it out.
+    // Discovered groups exist by definition.
+    let missing = if reset.spec.all_groups {
+        Vec::new()
+    } else {
+        match list_groups(&kafka_client).await {
+            Ok(groups) => {
+                let existing: Vec<String> =
+                    groups.into_iter().map(|group| group.group_id).collect();
+                missing_groups(&reset.spec.consumer_groups, &existing)
+            }
+            Err(e) => {
+                warn!(name = %name, error = %e, "Could not list consumer groups to check they exist");
+                Vec::new()
+            }
+        }
+    };
+    if !missing.is_empty() {
+        warn!(name = %name, groups = ?missing, "Consumer groups do not exist on the cluster");
+    }
+
+    let mut groups_changed = 0usize;
+    let mut groups_failed = 0usize;
+    let mut partitions_changed = 0usize;
+    let mut group_results = Vec::new();
 
+    for group_id in &consumer_groups {
+        match plan_group_reset(
+            &kafka_client,
+            group_id,
+            reset,
+            offset_mapping.as_ref(),
+            topics_filter(reset),
+        )
+        .await
+        {
+            Ok(plan) => {
+                let deltas = partition_deltas(&plan.current_offsets, &plan.target_offsets);
+                let changed = deltas
+                    .iter()
+                    .filter(|delta| delta.old_offset != Some(delta.new_offset))
+                    .count();
+                if changed > 0 {
+                    groups_changed += 1;
+                    partitions_changed += changed;
+                }
+                info!(name = %name, group = %group_id, partitions = changed, "Dry run computed target offsets");
+                group_results.push(json!({
+                    "groupId": group_id,
+                    "success": true,
+                    "partitionsReset": changed,
+                    "partitionDeltas": deltas,
+                }));
+            }
+            Err(e) => {
+                groups_failed += 1;
+                warn!(name = %name, group = %group_id, error = %e, "Dry run could not compute target offsets");
+                group_results.push(json!({
+                    "groupId": group_id,
+                    "success": false,
+                    "error": e.to_string(),
+                }));
+            }
+        }
+    }
+
+    let mut message = format!(
+        "Dry run: {} groups would change {} partitions, {} failed",
+        groups_changed, partitions_changed, groups_failed
+    );
+    if !missing.is_empty() {
+        message.push_str(&format!(
+            "; groups not found on cluster: {}",
+            missing.join(", ")
+        ));
+    }
     let status = json!({
         "status": {
             "phase": "Completed",
-            "message": "Dry run validation passed",
-            "groupsTotal": reset.spec.consumer_groups.len(),
+            "message": message.clone(),
+            "groupsTotal": consumer_groups.len(),
+            "groupsFailed": groups_failed,
+            "groupResults": group_results,
             "observedGeneration": reset.metadata.generation,
             "conditions": [{
                 "type": "Ready",
-                "status": "True",
+                "status": if groups_failed == 0 { "True" } else { "False" },
                 "lastTransitionTime": Utc::now(),
-                "reason": "DryRunPassed",
-                "message": "Offset reset validation completed successfully"
+                "reason": if groups_failed == 0 { "DryRunPassed" } else { "DryRunFailed" },
+                "message": message
             }]
         }
     });
@@ -312,6 +504,145 @@ async fn execute_dry_run(
     Ok(Action::await_change())
 }
 
+/// Directory holding the snapshots taken by the reset with the given UID.
+/// Keying by UID ties each snapshot to the resource that created it.
+fn reset_snapshot_dir(snapshot_root: &Path, owner_uid: &str) -> PathBuf {
+    snapshot_root.join(RESET_SNAPSHOT_SUBDIR).join(owner_uid)
+}
+
+/// Write a pre-reset snapshot under the reset's UID directory
+async fn persist_snapshot(reset: &KafkaOffsetReset, snapshot: &OffsetSnapshot) -> Result<PathBuf> {
+    let owner_uid = reset
+        .uid()
+        .ok_or_else(|| Error::Config("KafkaOffsetReset has no UID".to_string()))?;
+    let dir = reset_snapshot_dir(&get_snapshot_storage_path(None), &owner_uid);
+    tokio::fs::create_dir_all(&dir).await?;
+
+    let path = dir.join(format!("{}.json", snapshot.snapshot_id));
+    let contents = serde_json::to_string_pretty(snapshot)?;
+    tokio::fs::write(&path, contents).await?;
+    Ok(path)
+}
+
+/// Snapshot reference for a KafkaOffsetRollback undoing this reset. Only a
+/// snapshot persisted to disk can be rolled back to, so none without a path.
+fn rollback_snapshot_ref(
+    reset: &KafkaOffsetReset,
+    snapshot_id: Option<&str>,
+    snapshot_path: Option<&str>,
+) -> Option<SnapshotRef> {
+    Some(SnapshotRef {
+        name: snapshot_id?.to_string(),
+        pvc_name: None,
+        path: Some(snapshot_path?.to_string()),
+        restore_ref: None,
+        offset_reset_ref: Some(reset.name_any()),
+        cluster: reset.spec.kafka_cluster.bootstrap_servers.clone(),
+    })
+}
+
+/// Delete expired reset snapshots. Snapshots of a live reset expire after
+/// that reset's `snapshotRetentionHours`; snapshots whose owning reset no
+/// longer exists expire after `reset`'s. When `reset` is being finalized
+/// (`finalizing`), its own snapshots count as orphaned. Returns the number
+/// of files removed.
+pub async fn cleanup_snapshots(
+    reset: &KafkaOffsetReset,
+    client: &Client,
+    finalizing: bool,
+) -> Result<usize> {
+    let root = get_snapshot_storage_path(None).join(RESET_SNAPSHOT_SUBDIR);
+    if !tokio::fs::try_exists(&root).await.unwrap_or(false) {
+        return Ok(0);
+    }
+
+    let own_uid = reset.uid().filter(|_| finalizing);
+    let mut live_retention: HashMap<String, u32> = HashMap::new();
+    for api in super::scoped_apis::<KafkaOffsetReset>(client, &super::watch_namespaces()) {
+        live_retention.extend(
+            api.list(&ListParams::default())
+                .await?
+                .into_iter()
+                .filter_map(|other| Some((other.uid()?, other.spec.snapshot_retention_hours)))
+                .filter(|(uid, _)| Some(uid) != own_uid.as_ref()),
+        );
+    }
+
+    let now = SystemTime::now();
+    let mut removed = 0;
+
+    let mut owners = tokio::fs::read_dir(&root).await?;
+    while let Some(owner) = owners.next_entry().await? {
+        let owner_uid = owner.file_name().to_string_lossy().into_owned();
+        if !owner.file_type().await?.is_dir() {
+            continue;
+        }
+        let retention_hours = live_retention
+            .get(&owner_uid)
+            .copied()
+            .unwrap_or(reset.spec.snapshot_retention_hours);
+        let retention = Duration::from_secs(u64::from(retention_hours) * 3600);
+
+        let mut remaining = 0;
+        let mut snapshots = tokio::fs::read_dir(owner.path()).await?;
+        while let Some(snapshot) = snapshots.next_entry().await? {
+            let modified = snapshot.metadata().await?.modified()?;
+            if snapshot_expired(modified, now, retention) {
+                tokio::fs::remove_file(snapshot.path()).await?;
+                removed += 1;
+            } else {
+                remaining += 1;
+            }
+        }
+        if remaining == 0 {
+            tokio::fs::remove_dir(owner.path()).await?;
+        }
+    }
+
+    Ok(removed)
+}
+
+/// Whether a snapshot last written at `modified` has outlived `retention`
+fn snapshot_expired(modified: SystemTime, now: SystemTime, retention: Duration) -> bool {
+    now.duration_since(modified)
+        .is_ok_and(|age| age >= retention)
+}
+
+/// Requested groups that are absent from the cluster's group listing
+fn missing_groups(requested: &[String], existing: &[String]) -> Vec<String> {
+    requested
+        .iter()
+        .filter(|group| !existing.contains(group))
+        .cloned()
+        .collect()
+}
+
+/// The consumer groups a reset applies to: those named in the spec, or every
+/// group on the cluster when `allGroups` is set
+async fn resolve_consumer_groups(
+    reset: &KafkaOffsetReset,
+    kafka_client: &KafkaClient,
+) -> Result<Vec<String>> {
+    if !reset.spec.all_groups {
+        return Ok(reset.spec.consumer_groups.clone());
+    }
+
+    let mut groups: Vec<String> = list_groups(kafka_client)
+        .await
+        .map_err(|e| Error::KafkaConnection(format!("failed to list consumer groups: {}", e)))?
+        .into_iter()
+        .map(|group| group.group_id)
+        .collect();
+    groups.sort();
+
+    info!(
+        name = %reset.name_any(),
+        groups = groups.len(),
+        "Discovered consumer groups for all-groups reset"
+    );
+    Ok(groups)
+}
+
 /// Internal reset execution result
 struct ResetResult {
     groups_reset: u32,
@@ -322,37 +653,25 @@ struct ResetResult {
     group_results: Vec<serde_json::Value>,
 }
 
-/// Execute the actual offset reset using kafka-backup-core library
-async fn execute_reset_internal(
+/// Build a Kafka client for the reset's cluster and connect it. The TLS file
+/// manager is returned alongside so credential files outlive the client.
+async fn connect_kafka(
     reset: &KafkaOffsetReset,
     client: &Client,
     namespace: &str,
-) -> Result<ResetResult> {
+) -> Result<(KafkaClient, Option<TlsFileManager>)> {
     let name = reset.name_any();
-    let bootstrap_servers = reset.spec.kafka_cluster.bootstrap_servers.clone();
-
-    info!(
-        name = %name,
-        groups = reset.spec.consumer_groups.len(),
-        parallelism = reset.spec.parallelism,
-        "Building offset reset configuration"
-    );
 
     // Build resolved Kafka configuration from operator config
     let resolved_kafka = build_kafka_config(&reset.spec.kafka_cluster, client, namespace).await?;
 
     // Create TLS file manager if TLS is configured
-    let _tls_manager = if let Some(tls) = &resolved_kafka.tls {
-        let tls_dir = default_tls_dir(&name);
-        Some(TlsFileManager::new(tls, &tls_dir)?)
-    } else {
-        None
-    };
+    let tls_manager = create_tls_manager(&resolved_kafka, &name)?;
 
     // Build kafka-backup-core KafkaConfig
-    let security_config = build_core_security_config(&resolved_kafka, _tls_manager.as_ref());
+    let security_config = build_core_security_config(&resolved_kafka, tls_manager.as_ref());
     let core_kafka_config = CoreKafkaConfig {
-        bootstrap_servers: bootstrap_servers.clone(),
+        bootstrap_servers: reset.spec.kafka_cluster.bootstrap_servers.clone(),
         security: security_config,
         topics: TopicSelection {
             include: reset.spec.topics.clone(),
@@ -366,20 +685,37 @@ async fn execute_reset_internal(
     kafka_client
         .connect()
         .await
-        .map_err(|e| Error::Core(format!("Failed to connect to Kafka: {}", e)))?;
+        .map_err(|e| Error::KafkaConnection(e.to_string()))?;
 
     info!(name = %name, "Connected to Kafka cluster");
 
+    Ok((kafka_client, tls_manager))
+}
+
+/// Execute the actual offset reset using kafka-backup-core library
+async fn execute_reset_internal(
+    reset: &KafkaOffsetReset,
+    kafka_client: &KafkaClient,
+    consumer_groups: &[String],
+    client: &Client,
+    namespace: &str,
+) -> Result<ResetResult> {
+    let name = reset.name_any();
+    let bootstrap_servers = reset.spec.kafka_cluster.bootstrap_servers.clone();
+
+    info!(
+        name = %name,
+        groups = consumer_groups.len(),
+        parallelism = reset.spec.parallelism,
+        "Executing offset reset"
+    );
+
     // Create snapshot if requested
     let (snapshot_id, snapshot_path) = if reset.spec.snapshot_before_reset {
         info!(name = %name, "Creating pre-reset offset snapshot");
 
-        match snapshot_current_offsets(
-            &kafka_client,
-            &reset.spec.consumer_groups,
-            bootstrap_servers.clone(),
-        )
-        .await
+        match snapshot_current_offsets(kafka_client, consumer_groups, bootstrap_servers.clone())
+            .await
         {
             Ok(snapshot) => {
                 let snapshot_id = snapshot.snapshot_id.clone();
@@ -389,7 +725,14 @@ async fn execute_reset_internal(
                     groups = snapshot.group_offsets.len(),
                     "Created offset snapshot"
                 );
-                (Some(snapshot_id), None)
+                let snapshot_path = match persist_snapshot(reset, &snapshot).await {
+                    Ok(path) => Some(path.display().to_string()),
+                    Err(e) => {
+                        warn!(name = %name, error = %e, "Failed to persist offset snapshot");
+                        None
+                    }
+                };
+                (Some(snapshot_id), snapshot_path)
             }
             Err(e) => {
                 warn!(name = %name, error = %e, "Failed to create snapshot, continuing without");
@@ -400,14 +743,7 @@ async fn execute_reset_internal(
         (None, None)
     };
 
-    // Build bulk reset configuration
-    let _bulk_config = BulkOffsetResetConfig {
-        max_concurrent_requests: reset.spec.parallelism,
-        max_retry_attempts: 3,
-        retry_base_delay_ms: 100,
-        request_timeout_ms: 30000,
-        continue_on_error: reset.spec.continue_on_error,
-    };
+    let bulk_config = bulk_reset_config(reset);
 
     info!(
         name = %name,
@@ -426,12 +762,44 @@ async fn execute_reset_internal(
         None
     };
 
-    // Process each consumer group
-    for group_id in &reset.spec.consumer_groups {
-        info!(name = %name, group = %group_id, "Processing consumer group");
+    // Reset up to `parallelism` groups at once, handling outcomes in group
+    // order. Without continueOnError the first failure stops the reset: no
+    // further group starts, but groups already in flight may still commit,
+    // so their outcomes are drained and recorded before failing.
+    let mapping = offset_mapping.as_ref();
+    let config = &bulk_config;
+    let name_ref = &name;
+    let stopping = AtomicBool::new(false);
+    let stopping_ref = &stopping;
+    let mut stop_error = None;
+    let mut outcomes = stream::iter(consumer_groups)
+        .map(|group_id| async move {
+            if stopping_ref.load(Ordering::SeqCst) {
+                return (group_id, None);
+            }
+            info!(name = %name_ref, group = %group_id, "Processing consumer group");
+            let outcome =
+                reset_consumer_group_with_retry(kafka_client, group_id, reset, mapping, config)
+                    .await;
+            (group_id, Some(outcome))
+        })
+        .buffered(config.max_concurrent_requests.max(1));
 
-        match reset_consumer_group(&kafka_client, group_id, reset, offset_mapping.as_ref()).await {
-            Ok(GroupResetOutcome::Applied(partitions_reset)) => {
+    while let Some((group_id, outcome)) = outcomes.next().await {
+        // Not started because the reset was stopping
+        let Some(outcome) = outcome else {
+            continue;
+        };
+        match outcome {
+            Ok(GroupResetOutcome::Applied {
+                partitions,
+                deltas,
+                verified,
+            }) => {
+                let partitions_reset: usize = partitions
+                    .iter()
+                    .map(|selection| selection.partitions.len())
+                    .sum();
                 if partitions_reset > 0 {
                     groups_reset += 1;
                 } else {
@@ -440,7 +808,10 @@ async fn execute_reset_internal(
                 group_results.push(json!({
                     "groupId": group_id,
                     "success": true,
-                    "partitionsReset": partitions_reset
+                    "partitionsReset": partitions_reset,
+                    "partitions": partitions,
+                    "partitionDeltas": deltas,
+                    "verified": verified
                 }));
                 info!(name = %name, group = %group_id, partitions = partitions_reset, "Group reset successful");
             }
@@ -463,8 +834,9 @@ async fn execute_reset_internal(
                 }));
                 error!(name = %name, group = %group_id, error = %e, "Group reset failed");
 
-                if !reset.spec.continue_on_error {
-                    return Err(Error::Core(format!(
+                if !reset.spec.continue_on_error && stop_error.is_none() {
+                    stopping.store(true, Ordering::SeqCst);
+                    stop_error = Some(Error::Core(format!(
                         "Failed to reset group {}: {}",
                         group_id, e
                     )));
@@ -473,6 +845,36 @@ async fn execute_reset_internal(
         }
     }
 
+    if let Some(e) = stop_error {
+        // Keep what the groups that ran did, and the snapshot to roll it back
+        let api: Api<KafkaOffsetReset> = Api::namespaced(client.clone(), namespace);
+        let partial_status = json!({
+            "status": {
+                "groupsReset": groups_reset,
+                "groupsFailed": groups_failed,
+                "snapshotId": snapshot_id,
+                "snapshotPath": snapshot_path,
+                "snapshotRef": rollback_snapshot_ref(
+                    reset,
+                    snapshot_id.as_deref(),
+                    snapshot_path.as_deref(),
+                ),
+                "groupResults": group_results,
+            }
+        });
+        if let Err(patch_err) = api
+            .patch_status(
+                &name,
+                &PatchParams::apply("kafka-backup-operator"),
+                &Patch::Merge(partial_status),
+            )
+            .await
+        {
+            warn!(name = %name, error = %patch_err, "Failed to record partial reset results");
+        }
+        return Err(e);
+    }
+
     info!(
         name = %name,
         groups_reset = groups_reset,
@@ -506,7 +908,23 @@ async fn load_offset_mapping(
             path, e
         ))
     })?;
-    serde_json::from_str(&contents).map_err(Error::from)
+    let mut mapping: OffsetMapping = serde_json::from_str(&contents)?;
+
+    // The mapping records groups under their source names; a restore that
+    // renames groups wants them reset under the target names
+    if let (Some(restore_name), None, None) = (
+        &mapping_ref.restore_name,
+        &mapping_ref.pvc_name,
+        &mapping_ref.path,
+    ) {
+        let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+        let restore = api.get(restore_name).await?;
+        mapping.consumer_groups = std::mem::take(&mut mapping.consumer_groups)
+            .into_iter()
+            .map(|(group, offsets)| (target_consumer_group(&restore.spec, &group), offsets))
+            .collect();
+    }
+    Ok(mapping)
 }
 
 async fn resolve_offset_mapping_path(
@@ -560,7 +978,7 @@ fn build_core_security_config(
 
     let (sasl_mechanism, sasl_username, sasl_password) = match &resolved.sasl {
         Some(sasl) => {
-            let mechanism = match sasl.mechanism.to_uppercase().as_str() {
+            let mechanism = match sasl.mechanism.as_str() {
                 "PLAIN" => Some(SaslMechanism::Plain),
                 "SCRAM-SHA-256" => Some(SaslMechanism::ScramSha256),
                 "SCRAM-SHA-512" => Some(SaslMechanism::ScramSha512),
@@ -599,6 +1017,78 @@ fn build_core_security_config(
     }
 }
 
+/// Topics the reset is restricted to, or `None` for every topic of the group
+fn topics_filter(reset: &KafkaOffsetReset) -> Option<&[String]> {
+    if reset.spec.topics.is_empty() {
+        None
+    } else {
+        Some(&reset.spec.topics)
+    }
+}
+
+/// Bulk reset settings from the spec, with `retry` defaults filled in
+fn bulk_reset_config(reset: &KafkaOffsetReset) -> BulkOffsetResetConfig {
+    let retry = reset.spec.retry.clone().unwrap_or_default();
+    BulkOffsetResetConfig {
+        max_concurrent_requests: reset.spec.parallelism,
+        max_retry_attempts: retry.max_attempts,
+        retry_base_delay_ms: retry.base_delay_ms,
+        request_timeout_ms: retry.request_timeout_ms,
+        continue_on_error: reset.spec.continue_on_error,
+    }
+}
+
+/// Delay before retry number `retry` (1-based): the base delay doubled for
+/// each earlier retry
+fn retry_delay(base_delay_ms: u64, retry: u32) -> Duration {
+    let exponent = retry.saturating_sub(1).min(MAX_RETRY_EXPONENT);
+    Duration::from_millis(base_delay_ms.saturating_mul(1u64 << exponent))
+}
+
+/// Reset a consumer group, bounding each attempt by the request timeout and
+/// retrying failures with exponential backoff
+async fn reset_consumer_group_with_retry(
+    kafka_client: &KafkaClient,
+    group_id: &str,
+    reset: &KafkaOffsetReset,
+    mapping: Option<&OffsetMapping>,
+    config: &BulkOffsetResetConfig,
+) -> std::result::Result<GroupResetOutcome, String> {
+    let attempts = config.max_retry_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        let error = match tokio::time::timeout(
+            Duration::from_millis(config.request_timeout_ms),
+            reset_consumer_group(kafka_client, group_id, reset, mapping),
+        )
+        .await
+        {
+            Ok(Ok(outcome)) => return Ok(outcome),
+            Ok(Err(e)) => e.to_string(),
+            Err(_) => format!("timed out after {}ms", config.request_timeout_ms),
+        };
+        if attempt >= attempts {
+            return Err(if attempts > 1 {
+                format!("{} (after {} attempts)", error, attempts)
+            } else {
+                error
+            });
+        }
+
+        let delay = retry_delay(config.retry_base_delay_ms, attempt);
+        warn!(
+            group = %group_id,
+            attempt,
+            attempts,
+            retry_in = ?delay,
+            error = %error,
+            "Group reset attempt failed, retrying"
+        );
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
 /// Reset offsets for a single consumer group
 async fn reset_consumer_group(
     kafka_client: &KafkaClient,
@@ -606,14 +1096,8 @@ async fn reset_consumer_group(
     reset: &KafkaOffsetReset,
     mapping: Option<&OffsetMapping>,
 ) -> std::result::Result<GroupResetOutcome, kafka_backup_core::Error> {
-    // First, fetch current offsets to know which partitions to reset
-    // Pass None for topics filter to get all offsets for this group
-    let topics_filter: Option<&[String]> = if reset.spec.topics.is_empty() {
-        None
-    } else {
-        Some(&reset.spec.topics)
-    };
-    reset_consumer_group_with_mapping(kafka_client, group_id, reset, mapping, topics_filter).await
+    reset_consumer_group_with_mapping(kafka_client, group_id, reset, mapping, topics_filter(reset))
+        .await
 }
 
 async fn reset_consumer_group_with_mapping(
@@ -623,6 +1107,68 @@ async fn reset_consumer_group_with_mapping(
     mapping: Option<&OffsetMapping>,
     topics_filter: Option<&[String]>,
 ) -> std::result::Result<GroupResetOutcome, kafka_backup_core::Error> {
+    let plan = plan_group_reset(kafka_client, group_id, reset, mapping, topics_filter).await?;
+    if plan.target_offsets.is_empty() {
+        return Ok(GroupResetOutcome::NoOp(0));
+    }
+
+    if offsets_already_at_target(&plan.current_offsets, &plan.target_offsets) {
+        return Ok(GroupResetOutcome::NoOp(plan.target_offsets.len() as u32));
+    }
+
+    // Convert to tuple format expected by commit_offsets: (topic, partition, offset, metadata)
+    let offsets_tuples: Vec<(String, i32, i64, Option<String>)> = plan
+        .target_offsets
+        .iter()
+        .map(|o| (o.topic.clone(), o.partition, o.offset, o.metadata.clone()))
+        .collect();
+
+    // Commit the new offsets
+    commit_offsets(kafka_client, group_id, &offsets_tuples).await?;
+
+    // A commit can be accepted and then moved by a member of a rebalancing
+    // group, so read the offsets back when asked to
+    if reset.spec.verify_after_reset {
+        let topics: Vec<String> = plan
+            .target_offsets
+            .iter()
+            .map(|offset| offset.topic.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let committed = fetch_offsets(kafka_client, group_id, Some(topics.as_slice())).await?;
+        let unapplied = unapplied_offsets(&committed, &plan.target_offsets);
+        if !unapplied.is_empty() {
+            return Err(kafka_backup_core::Error::Config(format!(
+                "Committed offsets of consumer group '{}' do not match the reset targets: {}",
+                group_id,
+                unapplied.join(", ")
+            )));
+        }
+    }
+
+    Ok(GroupResetOutcome::Applied {
+        partitions: touched_partitions(&plan.target_offsets),
+        deltas: partition_deltas(&plan.current_offsets, &plan.target_offsets),
+        verified: reset.spec.verify_after_reset,
+    })
+}
+
+/// Current and target offsets computed for a group before anything is
+/// committed. Both are empty when the group has no committed offsets.
+struct GroupResetPlan {
+    current_offsets: Vec<CommittedOffset>,
+    target_offsets: Vec<CommittedOffset>,
+}
+
+/// Fetch a group's current offsets and calculate where the reset moves them
+async fn plan_group_reset(
+    kafka_client: &KafkaClient,
+    group_id: &str,
+    reset: &KafkaOffsetReset,
+    mapping: Option<&OffsetMapping>,
+    topics_filter: Option<&[String]>,
+) -> std::result::Result<GroupResetPlan, kafka_backup_core::Error> {
     let fetch_topics = if reset.spec.reset_strategy == OffsetResetStrategy::FromMapping {
         None
     } else {
@@ -640,10 +1186,19 @@ async fn reset_consumer_group_with_mapping(
         }
         Err(e) => return Err(e),
     };
+    let current_offsets: Vec<CommittedOffset> = current_offsets
+        .into_iter()
+        .filter(|offset| {
+            partition_selected(&reset.spec.partitions, &offset.topic, offset.partition)
+        })
+        .collect();
 
     if current_offsets.is_empty() && reset.spec.reset_strategy != OffsetResetStrategy::FromMapping {
         info!(group = %group_id, "No committed offsets found for group");
-        return Ok(GroupResetOutcome::NoOp(0));
+        return Ok(GroupResetPlan {
+            current_offsets,
+            target_offsets: Vec::new(),
+        });
     }
 
     // Calculate target offsets based on strategy
@@ -654,6 +1209,11 @@ async fn reset_consumer_group_with_mapping(
             )
         })?;
         target_offsets_from_mapping(mapping, group_id, topics_filter, &current_offsets)?
+            .into_iter()
+            .filter(|offset| {
+                partition_selected(&reset.spec.partitions, &offset.topic, offset.partition)
+            })
+            .collect()
     } else {
         calculate_target_offsets(
             kafka_client,
@@ -672,21 +1232,64 @@ async fn reset_consumer_group_with_mapping(
         )));
     }
 
-    if offsets_already_at_target(&current_offsets, &target_offsets) {
-        return Ok(GroupResetOutcome::NoOp(target_offsets.len() as u32));
-    }
+    Ok(GroupResetPlan {
+        current_offsets,
+        target_offsets,
+    })
+}
 
-    // Convert to tuple format expected by commit_offsets: (topic, partition, offset, metadata)
-    let offsets_tuples: Vec<(String, i32, i64, Option<String>)> = target_offsets
-        .iter()
-        .map(|o| (o.topic.clone(), o.partition, o.offset, o.metadata.clone()))
-        .collect();
+/// Whether a partition falls inside the reset's partition selection. Topics
+/// without an entry, and entries listing no partitions, cover every partition.
+fn partition_selected(selection: &[TopicPartitions], topic: &str, partition: i32) -> bool {
+    match selection.iter().find(|entry| entry.topic == topic) {
+        Some(entry) => entry.partitions.is_empty() || entry.partitions.contains(&partition),
+        None => true,
+    }
+}
 
-    // Commit the new offsets
-    let partitions_reset = offsets_tuples.len() as u32;
-    commit_offsets(kafka_client, group_id, &offsets_tuples).await?;
+/// Group committed offsets into the partitions they cover, per topic
+fn touched_partitions(offsets: &[CommittedOffset]) -> Vec<TopicPartitions> {
+    let mut by_topic: BTreeMap<&str, Vec<i32>> = BTreeMap::new();
+    for offset in offsets {
+        by_topic
+            .entry(offset.topic.as_str())
+            .or_default()
+            .push(offset.partition);
+    }
+    by_topic
+        .into_iter()
+        .map(|(topic, mut partitions)| {
+            partitions.sort_unstable();
+            TopicPartitions {
+                topic: topic.to_string(),
+                partitions,
+            }
+        })
+        .collect()
+}
 
-    Ok(GroupResetOutcome::Applied(partitions_reset))
+/// Pair each target offset with the offset currently committed for the same
+/// partition
+fn partition_deltas(
+    current_offsets: &[CommittedOffset],
+    target_offsets: &[CommittedOffset],
+) -> Vec<PartitionDelta> {
+    target_offsets
+        .iter()
+        .map(|target| PartitionDelta {
+            topic: target.topic.clone(),
+            partition: target.partition,
+            old_offset: current_offsets
+                .iter()
+                .find(|current| {
+                    current.error_code == 0
+                        && current.topic == target.topic
+                        && current.partition == target.partition
+                })
+                .map(|current| current.offset),
+            new_offset: target.offset,
+        })
+        .collect()
 }
 
 /// Calculate target offsets based on reset strategy
@@ -720,8 +1323,8 @@ async fn calculate_target_offsets(
                 // offsets_for_times takes &[(String, i32, i64)] - (topic, partition, timestamp)
                 let timestamp = reset_timestamp.unwrap_or(0);
                 let requests = vec![(offset.topic.clone(), offset.partition, timestamp)];
-                let timestamp_offsets = offsets_for_times(kafka_client, &requests).await?;
-                timestamp_offsets
+                offsets_for_times(kafka_client, &requests)
+                    .await?
                     .first()
                     .filter(|to| to.error_code == 0)
                     .map(|to| to.offset)
@@ -750,9 +1353,14 @@ async fn calculate_target_offsets(
     Ok(target_offsets)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 enum GroupResetOutcome {
-    Applied(u32),
+    Applied {
+        partitions: Vec<TopicPartitions>,
+        deltas: Vec<PartitionDelta>,
+        /// Committed offsets were read back and matched the targets
+        verified: bool,
+    },
     NoOp(u32),
 }
 
@@ -956,6 +1564,30 @@ fn offsets_already_at_target(
     })
 }
 
+/// Target offsets a group's committed offsets do not hold, as
+/// `topic/partition (expected X, committed Y)`
+fn unapplied_offsets(committed: &[CommittedOffset], targets: &[CommittedOffset]) -> Vec<String> {
+    targets
+        .iter()
+        .filter_map(|target| {
+            let actual = committed
+                .iter()
+                .find(|offset| offset.topic == target.topic && offset.partition == target.partition)
+                .filter(|offset| offset.error_code == 0)
+                .map(|offset| offset.offset);
+            (actual != Some(target.offset)).then(|| {
+                format!(
+                    "{}/{} (expected {}, committed {})",
+                    target.topic,
+                    target.partition,
+                    target.offset,
+                    actual.map_or_else(|| "none".to_string(), |offset| offset.to_string())
+                )
+            })
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum RunningMonitorDecision {
     Requeue { after: Duration, elapsed_secs: i64 },
@@ -989,10 +1621,12 @@ pub async fn update_status_failed(
     let name = reset.name_any();
     let api: Api<KafkaOffsetReset> = Api::namespaced(client.clone(), namespace);
 
+    let recent_errors = super::record_recent_error(reset, "Failed", error_message);
     let status = json!({
         "status": {
             "phase": "Failed",
             "message": error_message,
+            "recentErrors": recent_errors,
             "observedGeneration": reset.metadata.generation,
             "conditions": [{
                 "type": "Ready",
@@ -1016,6 +1650,8 @@ pub async fn update_status_failed(
 
 #[cfg(test)]
 mod issue49_tests {
+    use std::collections::HashMap;
+
     use super::*;
     use crate::crd::{KafkaClusterSpec, KafkaOffsetResetSpec, KafkaOffsetResetStatus};
 
@@ -1052,19 +1688,26 @@ mod issue49_tests {
                     security_protocol: "PLAINTEXT".to_string(),
                     tls_secret: None,
                     ca_secret: None,
+                    tls_cert_dir: None,
                     sasl_secret: None,
                     connection: None,
+                    client_config: HashMap::new(),
                 },
                 consumer_groups: vec!["test.consumergroup.v1".to_string()],
+                all_groups: false,
                 reset_strategy: OffsetResetStrategy::FromMapping,
                 reset_timestamp: None,
                 reset_offset: None,
                 topics: vec![],
+                partitions: vec![],
                 parallelism: 50,
                 dry_run: false,
                 continue_on_error: false,
                 offset_mapping_ref: None,
                 snapshot_before_reset: false,
+                snapshot_retention_hours: 24,
+                retry: None,
+                verify_after_reset: false,
             },
         );
         reset.status = Some(KafkaOffsetResetStatus {
@@ -1075,6 +1718,51 @@ mod issue49_tests {
         reset
     }
 
+    #[test]
+    fn terminal_phase_for_current_generation_is_settled() {
+        let mut reset = reset_with_start_time(None);
+        reset.metadata.generation = Some(2);
+        reset.status = Some(KafkaOffsetResetStatus {
+            phase: Some("Completed".to_string()),
+            observed_generation: Some(2),
+            ..Default::default()
+        });
+        assert!(is_settled(&reset));
+
+        reset.metadata.generation = Some(3);
+        assert!(!is_settled(&reset), "spec changes must be reconciled");
+
+        reset.metadata.generation = Some(2);
+        reset.metadata.deletion_timestamp = Some(
+            k8s_openapi::apimachinery::pkg::apis::meta::v1::Time(Utc::now()),
+        );
+        assert!(!is_settled(&reset), "deletion must reach the finalizer");
+    }
+
+    #[test]
+    fn running_phase_is_not_settled() {
+        assert!(!is_settled(&reset_with_start_time(Some(Utc::now()))));
+    }
+
+    #[test]
+    fn settled_reset_requeues_for_the_snapshot_sweep() {
+        let mut reset = reset_with_start_time(None);
+        reset.metadata.generation = Some(1);
+        reset.status = Some(KafkaOffsetResetStatus {
+            phase: Some("PartiallyCompleted".to_string()),
+            observed_generation: Some(1),
+            ..Default::default()
+        });
+        assert_eq!(
+            settled_action(&reset),
+            Some(Action::requeue(SNAPSHOT_SWEEP_INTERVAL))
+        );
+        assert_eq!(
+            settled_action(&reset_with_start_time(Some(Utc::now()))),
+            None
+        );
+    }
+
     #[tokio::test]
     async fn from_mapping_strategy_must_not_fall_back_to_current_offsets() {
         let current_offsets = vec![CommittedOffset {
@@ -1184,6 +1872,184 @@ mod issue49_tests {
         assert!(offsets_already_at_target(&current, &target));
     }
 
+    #[test]
+    fn unapplied_offsets_list_moved_and_missing_partitions() {
+        let offset = |partition, offset| CommittedOffset {
+            topic: "orders".to_string(),
+            partition,
+            offset,
+            metadata: None,
+            error_code: 0,
+        };
+        let targets = vec![offset(0, 7), offset(1, 9), offset(2, 11)];
+        let committed = vec![offset(0, 7), offset(1, 12)];
+
+        assert_eq!(
+            unapplied_offsets(&committed, &targets),
+            vec![
+                "orders/1 (expected 9, committed 12)".to_string(),
+                "orders/2 (expected 11, committed none)".to_string(),
+            ]
+        );
+        assert!(unapplied_offsets(&targets, &targets).is_empty());
+    }
+
+    #[test]
+    fn snapshots_expire_after_retention() {
+        let now = SystemTime::now();
+        let retention = Duration::from_secs(24 * 3600);
+
+        assert!(snapshot_expired(
+            now - Duration::from_secs(25 * 3600),
+            now,
+            retention
+        ));
+        assert!(!snapshot_expired(
+            now - Duration::from_secs(3600),
+            now,
+            retention
+        ));
+    }
+
+    #[test]
+    fn reset_snapshots_are_keyed_by_owner_uid() {
+        assert_eq!(
+            reset_snapshot_dir(Path::new("/data/snapshots"), "uid-1"),
+            PathBuf::from("/data/snapshots/offset-resets/uid-1")
+        );
+    }
+
+    #[test]
+    fn snapshot_ref_is_ready_to_copy_into_a_rollback() {
+        let reset = reset_with_start_time(None);
+        let snapshot_ref = rollback_snapshot_ref(
+            &reset,
+            Some("snap-1"),
+            Some("/data/snapshots/offset-resets/uid-1/snap-1.json"),
+        )
+        .unwrap();
+
+        assert_eq!(snapshot_ref.name, "snap-1");
+        assert_eq!(
+            snapshot_ref.path.as_deref(),
+            Some("/data/snapshots/offset-resets/uid-1/snap-1.json")
+        );
+        assert_eq!(snapshot_ref.offset_reset_ref.as_deref(), Some("issue49"));
+        assert_eq!(snapshot_ref.cluster, vec!["localhost:9092".to_string()]);
+    }
+
+    #[test]
+    fn snapshot_ref_requires_a_persisted_snapshot() {
+        let reset = reset_with_start_time(None);
+        assert!(rollback_snapshot_ref(&reset, Some("snap-1"), None).is_none());
+        assert!(rollback_snapshot_ref(&reset, None, None).is_none());
+    }
+
+    #[test]
+    fn retry_delay_doubles_per_retry() {
+        assert_eq!(retry_delay(100, 1), Duration::from_millis(100));
+        assert_eq!(retry_delay(100, 2), Duration::from_millis(200));
+        assert_eq!(retry_delay(100, 4), Duration::from_millis(800));
+        assert_eq!(retry_delay(u64::MAX, 40), Duration::from_millis(u64::MAX));
+    }
+
+    #[test]
+    fn missing_groups_reports_requested_groups_absent_from_cluster() {
+        let requested = vec!["orders-app".to_string(), "ordres-app".to_string()];
+        let existing = vec!["orders-app".to_string(), "billing".to_string()];
+
+        assert_eq!(
+            missing_groups(&requested, &existing),
+            vec!["ordres-app".to_string()]
+        );
+        assert!(missing_groups(&requested[..1], &existing).is_empty());
+    }
+
+    #[test]
+    fn partition_selection_limits_to_listed_partitions() {
+        let selection = vec![TopicPartitions {
+            topic: "orders".to_string(),
+            partitions: vec![3],
+        }];
+
+        assert!(partition_selected(&selection, "orders", 3));
+        assert!(!partition_selected(&selection, "orders", 0));
+        assert!(partition_selected(&[], "payments", 0));
+    }
+
+    #[test]
+    fn partition_selection_without_partitions_covers_the_whole_topic() {
+        let selection = vec![TopicPartitions {
+            topic: "orders".to_string(),
+            partitions: vec![],
+        }];
+
+        assert!(partition_selected(&selection, "orders", 0));
+        assert!(partition_selected(&selection, "orders", 7));
+        assert!(partition_selected(&selection, "payments", 3));
+    }
+
+    #[test]
+    fn touched_partitions_are_grouped_by_topic() {
+        let committed = |topic: &str, partition| CommittedOffset {
+            topic: topic.to_string(),
+            partition,
+            offset: 0,
+            metadata: None,
+            error_code: 0,
+        };
+        let offsets = vec![
+            committed("orders", 3),
+            committed("billing", 0),
+            committed("orders", 1),
+        ];
+
+        assert_eq!(
+            touched_partitions(&offsets),
+            vec![
+                TopicPartitions {
+                    topic: "billing".to_string(),
+                    partitions: vec![0],
+                },
+                TopicPartitions {
+                    topic: "orders".to_string(),
+                    partitions: vec![1, 3],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn partition_deltas_pair_current_and_target_offsets() {
+        let committed = |partition, offset| CommittedOffset {
+            topic: "orders".to_string(),
+            partition,
+            offset,
+            metadata: None,
+            error_code: 0,
+        };
+        let current = vec![committed(0, 100)];
+        let target = vec![committed(0, 40), committed(1, 0)];
+
+        assert_eq!(
+            partition_deltas(&current, &target),
+            vec![
+                PartitionDelta {
+                    topic: "orders".to_string(),
+                    partition: 0,
+                    old_offset: Some(100),
+                    new_offset: 40,
+                },
+                PartitionDelta {
+                    topic: "orders".to_string(),
+                    partition: 1,
+                    old_offset: None,
+                    new_offset: 0,
+                },
+            ]
+        );
+    }
+
     #[test]
     fn running_monitor_requeues_fresh_running_status() {
         let now = Utc::now();
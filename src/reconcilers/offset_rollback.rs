@@ -19,11 +19,13 @@ use serde_json::json;
 use tracing::{error, info, warn};
 
 use crate::adapters::{
-    build_kafka_config, default_tls_dir, to_core_connection_config, TlsFileManager,
+    build_kafka_config, create_tls_manager, to_core_connection_config, TlsFileManager,
 };
 use crate::crd::KafkaOffsetRollback;
 use crate::error::{Error, Result};
 
+const STALE_RUNNING_AFTER_SECS: i64 = 30 * 60;
+
 /// Validate the KafkaOffsetRollback spec
 pub fn validate(rollback: &KafkaOffsetRollback) -> Result<()> {
     // Validate kafka cluster
@@ -40,16 +42,8 @@ pub fn validate(rollback: &KafkaOffsetRollback) -> Result<()> {
         ));
     }
 
-    // Validate TLS configuration: SSL/SASL_SSL requires at least one TLS secret
-    let protocol = rollback.spec.kafka_cluster.security_protocol.to_uppercase();
-    if (protocol == "SSL" || protocol == "SASL_SSL")
-        && rollback.spec.kafka_cluster.tls_secret.is_none()
-        && rollback.spec.kafka_cluster.ca_secret.is_none()
-    {
-        return Err(Error::validation(
-            "securityProtocol SSL/SASL_SSL requires either tlsSecret or caSecret to be configured",
-        ));
-    }
+    // Validate security: SASL needs credentials, SSL needs certificates
+    super::validate_kafka_security(&rollback.spec.kafka_cluster)?;
 
     if let Some(connection) = &rollback.spec.kafka_cluster.connection {
         if connection.connections_per_broker == 0 {
@@ -62,17 +56,47 @@ pub fn validate(rollback: &KafkaOffsetRollback) -> Result<()> {
     Ok(())
 }
 
+/// Whether the resource has reached a terminal phase for its current
+/// generation and needs no further reconcile work. Resources being deleted
+/// are never settled so the finalizer still runs.
+pub fn is_settled(rollback: &KafkaOffsetRollback) -> bool {
+    rollback.metadata.deletion_timestamp.is_none()
+        && rollback.status.as_ref().is_some_and(|status| {
+            status.observed_generation == rollback.metadata.generation
+                && matches!(status.phase.as_deref(), Some("Completed" | "Failed"))
+        })
+}
+
 /// Monitor rollback progress
+///
+/// Rollbacks run inside the reconcile that started them, so a resource still
+/// Running well past [`STALE_RUNNING_AFTER_SECS`] (or with no start time) has
+/// lost its operation, typically to an operator restart, and is marked Failed.
 pub async fn monitor_progress(
     rollback: &KafkaOffsetRollback,
-    _client: &Client,
-    _namespace: &str,
+    client: &Client,
+    namespace: &str,
 ) -> Result<Action> {
     let name = rollback.name_any();
 
-    info!(name = %name, "Monitoring rollback progress");
+    let elapsed_secs = rollback
+        .status
+        .as_ref()
+        .and_then(|status| status.start_time)
+        .map(|start_time| Utc::now().signed_duration_since(start_time).num_seconds());
 
-    Ok(Action::requeue(Duration::from_secs(2)))
+    match elapsed_secs {
+        Some(elapsed_secs) if elapsed_secs < STALE_RUNNING_AFTER_SECS => {
+            info!(name = %name, elapsed_secs, "Offset rollback still running");
+            Ok(Action::requeue(Duration::from_secs(10)))
+        }
+        _ => {
+            let message = "Offset rollback was left Running without an active tracked operation";
+            warn!(name = %name, "{}", message);
+            update_status_failed(rollback, client, namespace, message).await?;
+            Ok(Action::await_change())
+        }
+    }
 }
 
 /// Execute a rollback operation
@@ -90,17 +114,41 @@ pub async fn execute(
         "Starting offset rollback execution"
     );
 
+    // The same cluster may be reachable under other addresses, so a mismatch
+    // is only worth a warning
+    let snapshot_cluster = &rollback.spec.snapshot_ref.cluster;
+    if !snapshot_cluster.is_empty()
+        && !snapshot_cluster.iter().any(|server| {
+            rollback
+                .spec
+                .kafka_cluster
+                .bootstrap_servers
+                .contains(server)
+        })
+    {
+        warn!(
+            name = %name,
+            snapshot_cluster = ?snapshot_cluster,
+            "Snapshot was taken from a cluster with different bootstrap servers"
+        );
+    }
+
     // Check if this is a dry run
     if rollback.spec.dry_run {
         info!(name = %name, "Dry run mode - validating rollback parameters");
         return execute_dry_run(rollback, client, namespace).await;
     }
 
+    // Connect before marking Running so an unreachable cluster surfaces as a
+    // retryable reconcile error rather than a terminal Failed status.
+    let (kafka_client, _tls_manager) = connect_kafka(rollback, client, namespace).await?;
+
     // Update status to Running
     let running_status = json!({
         "status": {
             "phase": "Running",
             "message": "Offset rollback in progress",
+            "startTime": Utc::now(),
             "observedGeneration": rollback.metadata.generation,
         }
     });
@@ -113,7 +161,7 @@ pub async fn execute(
 
     // Execute rollback
     let start_time = std::time::Instant::now();
-    let rollback_result = execute_rollback_internal(rollback, client, namespace).await;
+    let rollback_result = execute_rollback_internal(rollback, &kafka_client).await;
     let duration = start_time.elapsed();
 
     match rollback_result {
@@ -129,6 +177,11 @@ pub async fn execute(
                 "status": {
                     "phase": "Completed",
                     "message": format!("Rolled back {} groups", result.groups_rolled_back),
+                    "summary": super::status::offset_rollback_summary(
+                        u64::from(result.groups_rolled_back),
+                        result.verified,
+                        duration,
+                    ),
                     "groupsRolledBack": result.groups_rolled_back,
                     "duration": format!("{:.2}s", duration.as_secs_f64()),
                     "verified": result.verified,
@@ -154,10 +207,13 @@ pub async fn execute(
         Err(e) => {
             error!(name = %name, error = %e, "Offset rollback failed");
 
+            let message = format!("Offset rollback failed: {}", e);
+            let recent_errors = super::record_recent_error(rollback, "Failed", &message);
             let failed_status = json!({
                 "status": {
                     "phase": "Failed",
-                    "message": format!("Offset rollback failed: {}", e),
+                    "message": message,
+                    "recentErrors": recent_errors,
                     "observedGeneration": rollback.metadata.generation,
                     "conditions": [{
                         "type": "Ready",
@@ -222,37 +278,26 @@ struct RollbackResult {
     verified: bool,
 }
 
-/// Execute the actual rollback using kafka-backup-core library
-async fn execute_rollback_internal(
+/// Build a Kafka client for the rollback's cluster and connect it. The TLS
+/// file manager is returned alongside so credential files outlive the client.
+async fn connect_kafka(
     rollback: &KafkaOffsetRollback,
     client: &Client,
     namespace: &str,
-) -> Result<RollbackResult> {
+) -> Result<(KafkaClient, Option<TlsFileManager>)> {
     let name = rollback.name_any();
-    let bootstrap_servers = rollback.spec.kafka_cluster.bootstrap_servers.clone();
-
-    info!(
-        name = %name,
-        snapshot = %rollback.spec.snapshot_ref.name,
-        "Building rollback configuration"
-    );
 
     // Build resolved Kafka configuration
     let resolved_kafka =
         build_kafka_config(&rollback.spec.kafka_cluster, client, namespace).await?;
 
     // Create TLS file manager if TLS is configured
-    let _tls_manager = if let Some(tls) = &resolved_kafka.tls {
-        let tls_dir = default_tls_dir(&name);
-        Some(TlsFileManager::new(tls, &tls_dir)?)
-    } else {
-        None
-    };
+    let tls_manager = create_tls_manager(&resolved_kafka, &name)?;
 
     // Build kafka-backup-core KafkaConfig
-    let security_config = build_core_security_config(&resolved_kafka, _tls_manager.as_ref());
+    let security_config = build_core_security_config(&resolved_kafka, tls_manager.as_ref());
     let core_kafka_config = CoreKafkaConfig {
-        bootstrap_servers: bootstrap_servers.clone(),
+        bootstrap_servers: rollback.spec.kafka_cluster.bootstrap_servers.clone(),
         security: security_config,
         topics: TopicSelection {
             include: vec![],
@@ -266,10 +311,26 @@ async fn execute_rollback_internal(
     kafka_client
         .connect()
         .await
-        .map_err(|e| Error::Core(format!("Failed to connect to Kafka: {}", e)))?;
+        .map_err(|e| Error::KafkaConnection(e.to_string()))?;
 
     info!(name = %name, "Connected to Kafka cluster");
 
+    Ok((kafka_client, tls_manager))
+}
+
+/// Execute the actual rollback using kafka-backup-core library
+async fn execute_rollback_internal(
+    rollback: &KafkaOffsetRollback,
+    kafka_client: &KafkaClient,
+) -> Result<RollbackResult> {
+    let name = rollback.name_any();
+
+    info!(
+        name = %name,
+        snapshot = %rollback.spec.snapshot_ref.name,
+        "Building rollback configuration"
+    );
+
     // 1. Load snapshot from storage
     let snapshot_path = rollback.spec.snapshot_ref.path.as_ref().ok_or_else(|| {
         Error::SnapshotNotFound(format!(
@@ -302,7 +363,7 @@ async fn execute_rollback_internal(
     );
 
     // 2. Apply rollback using kafka-backup-core
-    let rollback_result = rollback_offset_reset(&kafka_client, &snapshot)
+    let rollback_result = rollback_offset_reset(kafka_client, &snapshot)
         .await
         .map_err(|e| Error::Rollback(format!("Rollback failed: {}", e)))?;
 
@@ -319,7 +380,7 @@ async fn execute_rollback_internal(
     let verified = if rollback.spec.verify_after_rollback {
         info!(name = %name, "Verifying rollback");
 
-        let verification = verify_rollback(&kafka_client, &snapshot)
+        let verification = verify_rollback(kafka_client, &snapshot)
             .await
             .map_err(|e| Error::Rollback(format!("Verification failed: {}", e)))?;
 
@@ -413,10 +474,12 @@ pub async fn update_status_failed(
     let name = rollback.name_any();
     let api: Api<KafkaOffsetRollback> = Api::namespaced(client.clone(), namespace);
 
+    let recent_errors = super::record_recent_error(rollback, "Failed", error_message);
     let status = json!({
         "status": {
             "phase": "Failed",
             "message": error_message,
+            "recentErrors": recent_errors,
             "observedGeneration": rollback.metadata.generation,
             "conditions": [{
                 "type": "Ready",
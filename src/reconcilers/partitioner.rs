@@ -0,0 +1,76 @@
+//! Kafka's default key partitioner.
+//!
+//! A producer places a keyed record on the positive murmur2 hash of its key
+//! modulo the topic's partition count. The restore engine routes topics
+//! restored with `partitionStrategy: key_hash` the same way; dry runs use
+//! this copy to show where previewed records would land.
+
+/// 32-bit murmur2 hash of `data`, as computed by Kafka's `Utils.murmur2`
+pub fn murmur2(data: &[u8]) -> i32 {
+    const SEED: u32 = 0x9747_b28c;
+    const M: u32 = 0x5bd1_e995;
+    const R: u32 = 24;
+
+    let mut h = SEED ^ data.len() as u32;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(M);
+        k ^= k >> R;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M);
+        h ^= k;
+    }
+
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        for (index, byte) in tail.iter().enumerate().rev() {
+            h ^= u32::from(*byte) << (8 * index);
+        }
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+    h as i32
+}
+
+/// Partition a Kafka producer assigns a record with `key` among `partitions`
+pub fn key_partition(key: &[u8], partitions: i32) -> i32 {
+    (murmur2(key) & 0x7fff_ffff) % partitions.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn murmur2_matches_the_java_client() {
+        // Vectors from Kafka's UtilsTest
+        let cases: [(&[u8], i32); 6] = [
+            (b"21", -973_932_308),
+            (b"foobar", -790_332_482),
+            (b"a-little-bit-long-string", -985_981_536),
+            (b"a-little-bit-longer-string", -1_486_304_829),
+            (
+                b"lkjh234lh9fiuh90y23oiuhsafujhadof229phr9h19h89h8",
+                -58_897_971,
+            ),
+            (b"abc", 479_470_107),
+        ];
+        for (key, expected) in cases {
+            assert_eq!(murmur2(key), expected, "key {:?}", key);
+        }
+    }
+
+    #[test]
+    fn key_partition_is_positive_and_in_range() {
+        assert_eq!(key_partition(b"abc", 6), 3);
+        assert_eq!(key_partition(b"foobar", 12), 6);
+        for key in [&b""[..], b"a", b"order-1", b"order-2"] {
+            let partition = key_partition(key, 12);
+            assert!((0..12).contains(&partition), "key {:?}", key);
+        }
+    }
+}
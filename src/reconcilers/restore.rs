@@ -7,26 +7,72 @@
 //! - Offset recovery
 //! - Rollback handling
 
-use std::time::Duration;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use kafka_backup_core::kafka::consumer_groups::commit_offsets;
+use kafka_backup_core::kafka::KafkaClient;
+use kafka_backup_core::manifest::{BackupManifest, BackupRecord};
 use kafka_backup_core::restore::engine::RestoreEngine;
 use kafka_backup_core::restore::ThreePhaseRestore;
+use kafka_backup_core::segment::SegmentReader;
+use kafka_backup_core::snapshot_current_offsets;
+use kafka_backup_core::storage::StorageBackend;
 use kube::{
     api::{Patch, PatchParams},
     runtime::controller::Action,
     Api, Client, ResourceExt,
 };
+use serde::{Deserialize, Serialize};
 use serde_json::json;
+use tokio::sync::broadcast::error::RecvError;
+use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
 
 use crate::adapters::{
-    build_restore_config, default_tls_dir, to_core_restore_config, ResolvedBackupSource,
-    ResolvedStorage, TlsFileManager,
+    build_circuit_breaker_config, build_kafka_config, build_restore_config, build_rollback_config,
+    create_tls_manager, get_snapshot_storage_path, ignored_client_config, operator_work_dir,
+    restore_checkpoint_path, to_core_kafka_config_with_tls, to_core_restore_config,
+    ResolvedBackupSource, ResolvedPitrConfig, ResolvedRestoreConfig, ResolvedStorage,
+    ResolvedTopicRepartitioningConfig,
+};
+use crate::crd::{
+    KafkaBackup, KafkaRestore, KafkaRestoreSpec, KafkaRestoreStatus, LockedRestoreSpec,
+    PartitionStrategy, PreviewRecord, PreviewSpec, RateLimitingSpec, RestoreOffsetStrategy,
+    RollbackSpec, RollbackStatus,
 };
-use crate::crd::{KafkaBackup, KafkaRestore};
 use crate::error::{Error, Result};
 use crate::metrics;
+use crate::reconcilers::{
+    circuit_breaker, events, offset_report, retention, AuthorizedOperations, TopicAccess,
+};
+
+/// Minimum interval between progress status patches while a restore runs
+const PROGRESS_PATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Directory below the operator work directory holding the progress files
+/// persisted while restores run
+const PROGRESS_SUBDIR: &str = "restores";
+
+/// Upper bound for `produceBatchSize`; larger batches risk exceeding the
+/// broker's `max.request.size`
+pub const MAX_PRODUCE_BATCH_SIZE: usize = 100_000;
+
+/// Upper bound for `preview.maxRecords`, keeping the sample small enough
+/// for status
+pub const MAX_PREVIEW_RECORDS: u32 = 100;
+
+/// Characters of a key or value shown in a preview
+const PREVIEW_TEXT_CHARS: usize = 64;
+
+/// Backup manifest schema versions this operator can restore
+pub const SUPPORTED_MANIFEST_VERSIONS: RangeInclusive<u32> = 1..=1;
+
+/// Shortest `rateLimiting.rampUpSecs`
+const MIN_RAMP_UP_SECS: u64 = 60;
 
 /// Validate the KafkaRestore spec
 pub fn validate(restore: &KafkaRestore) -> Result<()> {
@@ -61,10 +107,30 @@ pub fn validate(restore: &KafkaRestore) -> Result<()> {
                 "rateLimiting.maxConcurrentPartitions must be greater than 0",
             ));
         }
+        validate_ramp_up(&restore.spec, rate_limiting)?;
+    }
+
+    if restore.spec.timeout_secs == Some(0) {
+        return Err(Error::validation("timeoutSecs must be greater than 0"));
+    }
+
+    if restore.spec.stall_timeout_secs == Some(0) {
+        return Err(Error::validation("stallTimeoutSecs must be greater than 0"));
+    }
+
+    // The three-phase orchestrator reports no progress for the watchdog to follow
+    if restore.spec.stall_timeout_secs.is_some() && runs_three_phase(&restore.spec) {
+        return Err(Error::validation(
+            "stallTimeoutSecs cannot be combined with autoConsumerGroups or an offsetReset run \
+             by the restore engine, whose three-phase restores report no progress",
+        ));
     }
 
-    if restore.spec.produce_batch_size == 0 {
-        return Err(Error::validation("produceBatchSize must be greater than 0"));
+    if !(1..=MAX_PRODUCE_BATCH_SIZE).contains(&restore.spec.produce_batch_size) {
+        return Err(Error::validation(format!(
+            "produceBatchSize must be between 1 and {}",
+            MAX_PRODUCE_BATCH_SIZE
+        )));
     }
 
     if ![-1, 0, 1].contains(&restore.spec.produce_acks) {
@@ -75,7 +141,36 @@ pub fn validate(restore: &KafkaRestore) -> Result<()> {
         return Err(Error::validation("produceTimeoutMs must be greater than 0"));
     }
 
+    validate_topic_mapping(restore)?;
     validate_restore_target_safety(restore)?;
+    validate_consumer_group_mapping(restore)?;
+    validate_offsets_only(restore)?;
+    validate_rerun_on_new_backup(restore)?;
+
+    if let Some(preview) = &restore.spec.preview {
+        if !restore.spec.dry_run {
+            return Err(Error::validation("preview only runs with dryRun"));
+        }
+        if preview.topic.is_empty() {
+            return Err(Error::validation("preview.topic must be set"));
+        }
+        if !(1..=MAX_PREVIEW_RECORDS).contains(&preview.max_records) {
+            return Err(Error::validation(format!(
+                "preview.maxRecords must be between 1 and {}",
+                MAX_PREVIEW_RECORDS
+            )));
+        }
+    }
+
+    if matches!(
+        restore.spec.offset_strategy,
+        Some(RestoreOffsetStrategy::Earliest | RestoreOffsetStrategy::Latest)
+    ) && affected_consumer_groups(restore).is_empty()
+    {
+        return Err(Error::validation(
+            "offsetStrategy earliest and latest need the groups to reset in offsetReset.consumerGroups",
+        ));
+    }
 
     for (topic, repartitioning) in &restore.spec.repartitioning {
         if repartitioning.target_partitions <= 0 {
@@ -96,17 +191,43 @@ pub fn validate(restore: &KafkaRestore) -> Result<()> {
         }
     }
 
-    // Validate TLS configuration: SSL/SASL_SSL requires at least one TLS secret
-    let protocol = restore.spec.kafka_cluster.security_protocol.to_uppercase();
-    if (protocol == "SSL" || protocol == "SASL_SSL")
-        && restore.spec.kafka_cluster.tls_secret.is_none()
-        && restore.spec.kafka_cluster.ca_secret.is_none()
+    if restore
+        .spec
+        .default_replication_factor
+        .is_some_and(|replication_factor| {
+            replication_factor < 1 && replication_factor != BROKER_DEFAULT_REPLICATION_FACTOR
+        })
+    {
+        return Err(Error::validation(
+            "defaultReplicationFactor must be at least 1, or -1 for the broker default",
+        ));
+    }
+
+    if restore.spec.partition_strategy == PartitionStrategy::KeyHash
+        && !restore.spec.partition_mapping.is_empty()
     {
         return Err(Error::validation(
-            "securityProtocol SSL/SASL_SSL requires either tlsSecret or caSecret to be configured",
+            "partitionMapping cannot be combined with partitionStrategy key_hash, which picks \
+             target partitions by record key",
         ));
     }
 
+    // Validate security: SASL needs credentials, SSL needs certificates
+    super::validate_kafka_security(&restore.spec.kafka_cluster)?;
+
+    for storage in restore
+        .spec
+        .backup_ref
+        .storage
+        .iter()
+        .chain(&restore.spec.backup_ref.storage_fallbacks)
+    {
+        super::validate_storage_credentials(storage)?;
+        if let Some(s3) = &storage.s3 {
+            super::validate_s3_transport(s3)?;
+        }
+    }
+
     if let Some(connection) = &restore.spec.kafka_cluster.connection {
         if connection.connections_per_broker == 0 {
             return Err(Error::validation(
@@ -118,8 +239,221 @@ pub fn validate(restore: &KafkaRestore) -> Result<()> {
     Ok(())
 }
 
+/// Reject topic mappings that would merge records from different source
+/// topics into a single target topic.
+fn validate_topic_mapping(restore: &KafkaRestore) -> Result<()> {
+    let spec = &restore.spec;
+    let in_scope = |topic: &str| spec.topics.is_empty() || spec.topics.iter().any(|t| t == topic);
+
+    // Injectivity: no two restored source topics may share a target.
+    let mut sources_by_target: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (source, target) in &spec.topic_mapping {
+        if in_scope(source) {
+            sources_by_target
+                .entry(target.as_str())
+                .or_default()
+                .push(source.as_str());
+        }
+    }
+
+    let mut collisions: Vec<String> = sources_by_target
+        .iter()
+        .filter(|(_, sources)| sources.len() > 1)
+        .map(|(target, sources)| {
+            let mut sources = sources.clone();
+            sources.sort_unstable();
+            format!("{} <- [{}]", target, sources.join(", "))
+        })
+        .collect();
+    if !collisions.is_empty() {
+        collisions.sort_unstable();
+        return Err(Error::validation(format!(
+            "topicMapping maps multiple source topics to the same target: {}",
+            collisions.join("; ")
+        )));
+    }
+
+    // A mapped target must not also be an unmapped restored source topic,
+    // which would restore both into the same topic.
+    let mut merges: Vec<String> = sources_by_target
+        .iter()
+        .flat_map(|(target, sources)| sources.iter().map(move |source| (*source, *target)))
+        .filter(|(source, target)| {
+            source != target
+                && spec.topics.iter().any(|t| t == target)
+                && !spec.topic_mapping.contains_key(*target)
+        })
+        .map(|(source, target)| format!("{} -> {}", source, target))
+        .collect();
+    if !merges.is_empty() {
+        merges.sort_unstable();
+        return Err(Error::validation(format!(
+            "topicMapping target(s) collide with unmapped restored topic(s): {}",
+            merges.join(", ")
+        )));
+    }
+
+    Ok(())
+}
+
+/// Name a source consumer group gets on the target cluster:
+/// `consumerGroupMapping` first, then `consumerGroupPrefix`
+pub fn target_consumer_group(spec: &KafkaRestoreSpec, group: &str) -> String {
+    if let Some(target) = spec.consumer_group_mapping.get(group) {
+        return target.clone();
+    }
+    match &spec.consumer_group_prefix {
+        Some(prefix) => format!("{}{}", prefix, group),
+        None => group.to_string(),
+    }
+}
+
+/// Reject consumer group renames that would merge two source groups into one
+/// target group, or that the engine's own offset reset would ignore.
+fn validate_consumer_group_mapping(restore: &KafkaRestore) -> Result<()> {
+    let spec = &restore.spec;
+    if spec.consumer_group_mapping.is_empty() && spec.consumer_group_prefix.is_none() {
+        return Ok(());
+    }
+
+    if let Some(source) = spec
+        .consumer_group_mapping
+        .iter()
+        .find(|(_, target)| target.trim().is_empty())
+        .map(|(source, _)| source)
+    {
+        return Err(Error::validation(format!(
+            "consumerGroupMapping for '{}' must name a target group",
+            source
+        )));
+    }
+
+    // Injectivity over every group the restore is known to touch
+    let mut groups: Vec<&str> = spec
+        .consumer_group_mapping
+        .keys()
+        .map(String::as_str)
+        .chain(
+            spec.offset_reset
+                .iter()
+                .flat_map(|offset_reset| offset_reset.consumer_groups.iter().map(String::as_str)),
+        )
+        .collect();
+    groups.sort_unstable();
+    groups.dedup();
+
+    let mut sources_by_target: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for group in groups {
+        sources_by_target
+            .entry(target_consumer_group(spec, group))
+            .or_default()
+            .push(group);
+    }
+    let collisions: Vec<String> = sources_by_target
+        .iter()
+        .filter(|(_, sources)| sources.len() > 1)
+        .map(|(target, sources)| format!("{} <- [{}]", target, sources.join(", ")))
+        .collect();
+    if !collisions.is_empty() {
+        return Err(Error::validation(format!(
+            "consumerGroupMapping maps multiple source groups to the same target: {}",
+            collisions.join("; ")
+        )));
+    }
+
+    // The engine commits header-based offsets under the source group names
+    if engine_resets_offsets(spec) {
+        return Err(Error::validation(
+            "consumerGroupMapping and consumerGroupPrefix can't rename groups reset by the \
+             restore engine; use offsetStrategy earliest/latest or a KafkaOffsetReset with \
+             this restore's offset mapping",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Whether the restore engine resets consumer offsets itself, which mirrors
+/// how `to_core_restore_options` sets `reset_consumer_offsets`
+fn engine_resets_offsets(spec: &KafkaRestoreSpec) -> bool {
+    spec.offset_reset.as_ref().is_some_and(|offset_reset| {
+        offset_reset.enabled
+            && !matches!(
+                offset_reset.strategy.to_lowercase().as_str(),
+                "manual" | "dry-run" | "dry_run"
+            )
+    }) && matches!(
+        spec.offset_strategy,
+        None | Some(RestoreOffsetStrategy::HeaderBased)
+    )
+}
+
+/// Whether the restore runs through the three-phase orchestrator rather
+/// than the plain restore engine
+fn runs_three_phase(spec: &KafkaRestoreSpec) -> bool {
+    spec.auto_consumer_groups || engine_resets_offsets(spec)
+}
+
+/// A ramp-up needs a records limit to ramp towards, and runs the engine once
+/// per topic, so it cannot collect the single offset mapping that consumer
+/// offset resets and offset reports are built from.
+fn validate_ramp_up(spec: &KafkaRestoreSpec, rate_limiting: &RateLimitingSpec) -> Result<()> {
+    let Some(ramp_up_secs) = rate_limiting.ramp_up_secs else {
+        return Ok(());
+    };
+
+    if ramp_up_secs < MIN_RAMP_UP_SECS {
+        return Err(Error::validation(format!(
+            "rateLimiting.rampUpSecs must be at least {}",
+            MIN_RAMP_UP_SECS
+        )));
+    }
+    if rate_limiting.records_per_sec == 0 {
+        return Err(Error::validation(
+            "rateLimiting.rampUpSecs requires rateLimiting.recordsPerSec to be set",
+        ));
+    }
+
+    let offset_reset = spec
+        .offset_reset
+        .as_ref()
+        .is_some_and(|offset_reset| offset_reset.enabled);
+    if offset_reset || spec.auto_consumer_groups || spec.offset_report.is_some() {
+        return Err(Error::validation(
+            "rateLimiting.rampUpSecs cannot be combined with offsetReset, autoConsumerGroups or offsetReport",
+        ));
+    }
+    Ok(())
+}
+
+/// Records limit `elapsed` into a ramp-up: 10% of `limit` at the start,
+/// rising linearly to the full limit once `ramp_up` has passed
+fn ramp_up_rate(limit: u64, ramp_up: Duration, elapsed: Duration) -> u64 {
+    if elapsed >= ramp_up {
+        return limit;
+    }
+    let permille = 100 + 900 * elapsed.as_millis() / ramp_up.as_millis();
+    ((u128::from(limit) * permille / 1000) as u64).max(1)
+}
+
+/// How many of the `pending` topics the next pass of a ramped restore takes,
+/// and its records limit. While the ramp lasts a pass restores one topic at
+/// the limit reached so far; after it the rest go in one pass at `limit`.
+fn next_ramp_pass(
+    pending: &[String],
+    limit: u64,
+    ramp_up: Duration,
+    elapsed: Duration,
+) -> (usize, u64) {
+    if elapsed >= ramp_up {
+        (pending.len(), limit)
+    } else {
+        (pending.len().min(1), ramp_up_rate(limit, ramp_up, elapsed))
+    }
+}
+
 fn validate_restore_target_safety(restore: &KafkaRestore) -> Result<()> {
-    if restore.spec.dry_run || restore.spec.purge_topics {
+    if restore.spec.dry_run || restore.spec.purge_topics || restore.spec.restore_offsets_only {
         return Ok(());
     }
 
@@ -162,23 +496,231 @@ fn validate_restore_target_safety(restore: &KafkaRestore) -> Result<()> {
     )))
 }
 
+/// Re-running a completed restore for every newer backup would append every
+/// record to the target topics again, so it must replace them instead
+fn validate_rerun_on_new_backup(restore: &KafkaRestore) -> Result<()> {
+    let spec = &restore.spec;
+    if !spec.backup_ref.rerun_on_new_backup {
+        return Ok(());
+    }
+    if !spec.backup_ref.use_latest {
+        return Err(Error::validation(
+            "backupRef.rerunOnNewBackup requires backupRef.useLatest",
+        ));
+    }
+    if !spec.purge_topics {
+        return Err(Error::validation(
+            "backupRef.rerunOnNewBackup requires purgeTopics=true so each run replaces the \
+             target topics instead of appending every record again",
+        ));
+    }
+    Ok(())
+}
+
+/// An offsets-only restore produces no records, so options that shape,
+/// prepare for or translate offsets through produced records don't apply.
+fn validate_offsets_only(restore: &KafkaRestore) -> Result<()> {
+    let spec = &restore.spec;
+    if !spec.restore_offsets_only {
+        return Ok(());
+    }
+
+    let conflicting: Vec<&str> = [
+        ("purgeTopics", spec.purge_topics),
+        ("createTopics", spec.create_topics),
+        ("pitr", spec.pitr.is_some()),
+        (
+            "offsetReset",
+            spec.offset_reset
+                .as_ref()
+                .is_some_and(|offset_reset| offset_reset.enabled),
+        ),
+        ("offsetStrategy", spec.offset_strategy.is_some()),
+        ("autoConsumerGroups", spec.auto_consumer_groups),
+        ("offsetReport", spec.offset_report.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(field, set)| set.then_some(field))
+    .collect();
+
+    if conflicting.is_empty() {
+        return Ok(());
+    }
+    Err(Error::validation(format!(
+        "restoreOffsetsOnly commits the backed-up consumer group offsets without restoring records \
+         and cannot be combined with: {}",
+        conflicting.join(", ")
+    )))
+}
+
+/// Remaining wait before a restore in the TimedOut phase is retried
+pub fn timeout_retry_wait(restore: &KafkaRestore) -> Option<Duration> {
+    let status = restore.status.as_ref()?;
+    super::timeout_retry_wait(&status.conditions, Utc::now())
+}
+
 /// Monitor restore progress
+///
+/// The engine runs inside the reconcile that started it, so this is reached
+/// when that reconcile is no longer driving status. Progress is read back from
+/// the file the progress task persists; a finished run whose Completed patch
+/// never landed is completed here.
 pub async fn monitor_progress(
     restore: &KafkaRestore,
-    _client: &Client,
-    _namespace: &str,
+    client: &Client,
+    namespace: &str,
+    shutdown: &CancellationToken,
 ) -> Result<Action> {
     let name = restore.name_any();
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+
+    let Some(progress) = read_progress(&progress_file_path(namespace, &name)).await else {
+        if restore_is_orphaned(restore, Utc::now()) {
+            info!(name = %name, "Restore engine is gone, resuming from checkpoint");
+            return execute(restore, client, namespace, shutdown).await;
+        }
+        info!(name = %name, "Monitoring restore progress, no progress recorded yet");
+        return Ok(Action::requeue(Duration::from_secs(10)));
+    };
+
+    if progress.finished {
+        info!(
+            name = %name,
+            records = progress.records_restored,
+            "Restore engine finished, completing status"
+        );
+        // The engine ran detached, so only the start time bounds how long it took
+        let duration = restore
+            .status
+            .as_ref()
+            .and_then(|status| status.start_time)
+            .and_then(|start| (progress.updated_at - start).to_std().ok());
+        let completed_status = json!({
+            "status": {
+                "phase": "Completed",
+                "message": "Restore completed successfully",
+                "summary": super::status::restore_summary(progress.records_restored, None, duration),
+                "completionTime": progress.updated_at,
+                "recordsRestored": progress.records_restored,
+                "progressPercent": 100,
+                "conditions": [{
+                    "type": "Ready",
+                    "status": "True",
+                    "lastTransitionTime": Utc::now(),
+                    "reason": "RestoreSucceeded",
+                    "message": "Restore completed successfully"
+                }]
+            }
+        });
+        api.patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(completed_status),
+        )
+        .await?;
+        remove_progress_file(namespace, &name).await;
+        return Ok(Action::await_change());
+    }
+
+    if restore_is_orphaned(restore, Utc::now()) {
+        info!(name = %name, "Restore engine is gone, resuming from checkpoint");
+        return execute(restore, client, namespace, shutdown).await;
+    }
+
+    info!(
+        name = %name,
+        records = progress.records_restored,
+        percentage = progress.progress_percent,
+        "Monitoring restore progress"
+    );
+
+    let status = restore.status.as_ref();
+    let changed = status.and_then(|s| s.records_restored) != Some(progress.records_restored)
+        || status.and_then(|s| s.progress_percent) != Some(progress.progress_percent);
+    if changed {
+        let progress_status = json!({
+            "status": {
+                "progressPercent": progress.progress_percent,
+                "recordsRestored": progress.records_restored,
+            }
+        });
+        api.patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(progress_status),
+        )
+        .await?;
+    }
+
+    Ok(Action::requeue(Duration::from_secs(10)))
+}
+
+/// Whether a Running restore has lost its engine: its heartbeat (or, before
+/// the first heartbeat, its start time) is older than the stale threshold.
+fn restore_is_orphaned(restore: &KafkaRestore, now: DateTime<Utc>) -> bool {
+    let last_seen = restore
+        .status
+        .as_ref()
+        .and_then(|status| status.heartbeat_time.or(status.start_time));
+    super::heartbeat_is_stale(last_seen, now)
+}
+
+/// Restore progress persisted by the progress task so a later reconcile can
+/// pick it up without access to the engine
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedProgress {
+    records_restored: u64,
+    progress_percent: f64,
+    finished: bool,
+    updated_at: DateTime<Utc>,
+}
+
+/// File the progress of restore `namespace/name` is persisted to. Each
+/// namespace gets its own directory, since names may contain the hyphens a
+/// flat `{namespace}-{name}` key would need to tell them apart. Kept in the
+/// work directory next to the restore checkpoints, so a persistent work
+/// directory keeps both across a pod restart.
+fn progress_file_path(namespace: &str, name: &str) -> PathBuf {
+    operator_work_dir()
+        .join(PROGRESS_SUBDIR)
+        .join(namespace)
+        .join(format!("{}.json", name))
+}
+
+/// Persist progress, writing to a temporary file first so readers never see
+/// a partial document
+async fn write_progress(path: &Path, progress: &PersistedProgress) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let tmp_path = path.with_extension("json.tmp");
+    tokio::fs::write(&tmp_path, serde_json::to_vec(progress)?).await?;
+    tokio::fs::rename(&tmp_path, path).await
+}
 
-    // TODO: Check actual restore progress from the running operation
-    // For now, just requeue to check again
-    info!(name = %name, "Monitoring restore progress");
+/// Read persisted progress; a missing or unreadable file means no progress
+async fn read_progress(path: &Path) -> Option<PersistedProgress> {
+    let data = tokio::fs::read(path).await.ok()?;
+    serde_json::from_slice(&data).ok()
+}
 
-    Ok(Action::requeue(Duration::from_secs(5)))
+async fn remove_progress_file(namespace: &str, name: &str) {
+    let path = progress_file_path(namespace, name);
+    if let Err(e) = tokio::fs::remove_file(&path).await {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            warn!(path = %path.display(), error = %e, "Failed to remove restore progress file");
+        }
+    }
 }
 
 /// Execute a restore operation
-pub async fn execute(restore: &KafkaRestore, client: &Client, namespace: &str) -> Result<Action> {
+pub async fn execute(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    shutdown: &CancellationToken,
+) -> Result<Action> {
     let name = restore.name_any();
     let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
 
@@ -190,12 +732,35 @@ pub async fn execute(restore: &KafkaRestore, client: &Client, namespace: &str) -
         return execute_dry_run(restore, client, namespace).await;
     }
 
+    // Repeated failures open the circuit breaker; hold off until it half-opens
+    let breaker_config = restore
+        .spec
+        .circuit_breaker
+        .as_ref()
+        .map(build_circuit_breaker_config);
+    if let Some(config) = &breaker_config {
+        if let Some(wait) = circuit_breaker::admit("KafkaRestore", namespace, &name, config) {
+            warn!(name = %name, retry_in = ?wait, "Circuit breaker open, deferring restore");
+            return Ok(Action::requeue(wait));
+        }
+    }
+
+    // Probe the backup's storage before marking Running, so an outage is a
+    // retried reconcile error rather than a failure deep inside the engine
+    let resolved_config = build_restore_config(restore, client, namespace).await?;
+    probe_backup_locations(&resolved_config, client, namespace, &name).await?;
+
+    // Progress left over from an earlier run must not leak into this one
+    remove_progress_file(namespace, &name).await;
+
     // Update status to Running
     let running_status = json!({
         "status": {
             "phase": "Running",
             "message": "Restore in progress",
             "startTime": Utc::now(),
+            "lockedSpec": LockedRestoreSpec::from_spec(&restore.spec),
+            "ignoredClientConfig": ignored_client_config(&restore.spec.kafka_cluster.client_config),
             "observedGeneration": restore.metadata.generation,
         }
     });
@@ -206,17 +771,62 @@ pub async fn execute(restore: &KafkaRestore, client: &Client, namespace: &str) -
     )
     .await?;
 
-    // Create rollback snapshot if enabled
-    if let Some(rollback) = &restore.spec.rollback {
+    // Create rollback snapshot if enabled. A failed snapshot only disables
+    // rollback; the restore itself still proceeds.
+    // A resumed restore keeps the snapshot taken before its first attempt;
+    // re-snapshotting now would capture partially restored offsets.
+    let already_snapshotted = restore
+        .status
+        .as_ref()
+        .is_some_and(|status| status.rollback.is_some());
+    if let Some(rollback) = restore
+        .spec
+        .rollback
+        .as_ref()
+        .filter(|_| !already_snapshotted)
+    {
         if rollback.snapshot_before_restore {
             info!(name = %name, "Creating pre-restore offset snapshot for rollback");
-            // TODO: Create offset snapshot using kafka-backup-core
+            match create_pre_restore_snapshot(restore, rollback, client, namespace).await {
+                Ok(Some(rollback_status)) => {
+                    let snapshot_status = json!({
+                        "status": {
+                            "rollback": rollback_status,
+                        }
+                    });
+                    api.patch_status(
+                        &name,
+                        &PatchParams::apply("kafka-backup-operator"),
+                        &Patch::Merge(snapshot_status),
+                    )
+                    .await?;
+                }
+                Ok(None) => {
+                    info!(name = %name, "No consumer groups affected, skipping pre-restore snapshot");
+                }
+                Err(e) => {
+                    warn!(name = %name, error = %e, "Failed to create pre-restore snapshot, continuing without");
+                }
+            }
         }
     }
 
     // Execute restore. Offset reset, when requested, is handled inside
     // execute_restore_internal so the final status reflects the full workflow.
-    let restore_result = execute_restore_internal(restore, client, namespace).await;
+    let start_time = std::time::Instant::now();
+    let restore_result = execute_restore_internal(restore, client, namespace, shutdown).await;
+    let duration = start_time.elapsed();
+    // A run stopped by operator shutdown says nothing about the restore's health
+    let interrupted = matches!(restore_result, Err(Error::Interrupted(_)));
+    if let Some(config) = breaker_config.as_ref().filter(|_| !interrupted) {
+        circuit_breaker::record(
+            "KafkaRestore",
+            namespace,
+            &name,
+            config,
+            restore_result.is_ok(),
+        );
+    }
 
     match restore_result {
         Ok(result) => {
@@ -231,24 +841,38 @@ pub async fn execute(restore: &KafkaRestore, client: &Client, namespace: &str) -
                 .with_label_values(&["success", namespace, &name])
                 .inc();
 
+            let mut conditions = vec![json!({
+                "type": "Ready",
+                "status": "True",
+                "lastTransitionTime": Utc::now(),
+                "reason": "RestoreSucceeded",
+                "message": "Restore completed successfully"
+            })];
+            conditions.extend(result.replication_factor_reduced);
             let completed_status = json!({
                 "status": {
                     "phase": "Completed",
                     "message": "Restore completed successfully",
+                    "summary": match result.offsets_only_groups {
+                        Some(groups) => super::status::offsets_only_restore_summary(
+                            u64::from(groups),
+                            duration,
+                        ),
+                        None => super::status::restore_summary(
+                            result.records_restored,
+                            Some(result.bytes_restored),
+                            Some(duration),
+                        ),
+                    },
                     "completionTime": Utc::now(),
                     "recordsRestored": result.records_restored,
                     "bytesRestored": result.bytes_restored,
                     "segmentsProcessed": result.segments_processed,
                     "progressPercent": 100,
                     "offsetMappingPath": result.offset_mapping_path,
+                    "offsetReportPaths": result.offset_report_paths,
                     "observedGeneration": restore.metadata.generation,
-                    "conditions": [{
-                        "type": "Ready",
-                        "status": "True",
-                        "lastTransitionTime": Utc::now(),
-                        "reason": "RestoreSucceeded",
-                        "message": "Restore completed successfully"
-                    }]
+                    "conditions": conditions
                 }
             });
             api.patch_status(
@@ -257,6 +881,7 @@ pub async fn execute(restore: &KafkaRestore, client: &Client, namespace: &str) -
                 &Patch::Merge(completed_status),
             )
             .await?;
+            remove_progress_file(namespace, &name).await;
 
             // Offset reset is handled during restore execution when configured.
             if let Some(offset_reset) = &restore.spec.offset_reset {
@@ -270,10 +895,41 @@ pub async fn execute(restore: &KafkaRestore, client: &Client, namespace: &str) -
         Err(e) => {
             error!(name = %name, error = %e, "Restore failed");
 
+            remove_progress_file(namespace, &name).await;
+
+            let timed_out = matches!(e, Error::Timeout(_));
+            let stalled = matches!(e, Error::Stalled(_));
+            // Rejected credentials leave the restore to be retried like an
+            // interrupted one; the retry reads the rotated secret
+            let rotated = matches!(e, Error::CredentialsRotated(_));
+            let (outcome, phase, reason) = if interrupted {
+                (
+                    "interrupted",
+                    super::PHASE_INTERRUPTED,
+                    "RestoreInterrupted",
+                )
+            } else if rotated {
+                (
+                    "credentials_rotated",
+                    super::PHASE_INTERRUPTED,
+                    "CredentialsRotated",
+                )
+            } else if timed_out {
+                ("timeout", super::PHASE_TIMED_OUT, "RestoreTimedOut")
+            } else if stalled {
+                ("stalled", super::PHASE_STALLED, "RestoreStalled")
+            } else {
+                ("failure", "Failed", "RestoreFailed")
+            };
+
             metrics::RESTORES_TOTAL
-                .with_label_values(&["failure", namespace, &name])
+                .with_label_values(&[outcome, namespace, &name])
                 .inc();
 
+            if timed_out || stalled || rotated {
+                events::publish_warning(client, restore, reason, "Restore", &e.to_string()).await;
+            }
+
             // Check if auto-rollback is enabled
             if let Some(rollback) = &restore.spec.rollback {
                 if rollback.auto_rollback_on_failure {
@@ -282,16 +938,19 @@ pub async fn execute(restore: &KafkaRestore, client: &Client, namespace: &str) -
                 }
             }
 
+            let message = format!("Restore failed: {}", e);
+            let recent_errors = super::record_recent_error(restore, phase, &message);
             let failed_status = json!({
                 "status": {
-                    "phase": "Failed",
-                    "message": format!("Restore failed: {}", e),
+                    "phase": phase,
+                    "message": message,
+                    "recentErrors": recent_errors,
                     "observedGeneration": restore.metadata.generation,
                     "conditions": [{
                         "type": "Ready",
                         "status": "False",
                         "lastTransitionTime": Utc::now(),
-                        "reason": "RestoreFailed",
+                        "reason": reason,
                         "message": e.to_string()
                     }]
                 }
@@ -303,72 +962,577 @@ pub async fn execute(restore: &KafkaRestore, client: &Client, namespace: &str) -
             )
             .await?;
 
+            // Handed to error_policy, which retries quickly with backoff
+            if rotated {
+                return Err(e);
+            }
+
             Ok(Action::requeue(Duration::from_secs(300)))
         }
     }
 }
 
-/// Execute dry run validation
-async fn execute_dry_run(
+/// Consumer groups whose offsets the restore will touch
+fn affected_consumer_groups(restore: &KafkaRestore) -> Vec<String> {
+    restore
+        .spec
+        .offset_reset
+        .as_ref()
+        .map(|offset_reset| offset_reset.consumer_groups.clone())
+        .unwrap_or_default()
+}
+
+/// Snapshot the committed offsets of the affected consumer groups and persist
+/// the snapshot as JSON so a later KafkaOffsetRollback can restore them.
+///
+/// Returns `None` when no consumer groups are affected by the restore.
+async fn create_pre_restore_snapshot(
     restore: &KafkaRestore,
+    rollback: &RollbackSpec,
     client: &Client,
     namespace: &str,
-) -> Result<Action> {
+) -> Result<Option<RollbackStatus>> {
     let name = restore.name_any();
-    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+    let groups = affected_consumer_groups(restore);
+    if groups.is_empty() {
+        return Ok(None);
+    }
 
-    // TODO: Validate backup exists and is accessible
-    // TODO: Validate target cluster is reachable
-    // TODO: Validate topics can be created/written to
+    let resolved_kafka = build_kafka_config(&restore.spec.kafka_cluster, client, namespace).await?;
+    let tls_manager = create_tls_manager(&resolved_kafka, &name)?;
+    let kafka_client = KafkaClient::new(to_core_kafka_config_with_tls(
+        &resolved_kafka,
+        &[],
+        tls_manager.as_ref(),
+    ));
+    kafka_client
+        .connect()
+        .await
+        .map_err(|e| Error::KafkaConnection(e.to_string()))?;
 
-    let status = json!({
-        "status": {
-            "phase": "Completed",
-            "message": "Dry run validation passed",
-            "observedGeneration": restore.metadata.generation,
-            "conditions": [{
-                "type": "Ready",
-                "status": "True",
-                "lastTransitionTime": Utc::now(),
-                "reason": "DryRunPassed",
-                "message": "Restore validation completed successfully"
-            }]
-        }
-    });
-    api.patch_status(
-        &name,
-        &PatchParams::apply("kafka-backup-operator"),
-        &Patch::Merge(status),
+    let snapshot = snapshot_current_offsets(
+        &kafka_client,
+        &groups,
+        restore.spec.kafka_cluster.bootstrap_servers.clone(),
     )
-    .await?;
+    .await
+    .map_err(|e| Error::Core(format!("Failed to snapshot consumer offsets: {}", e)))?;
 
-    Ok(Action::await_change())
-}
+    let snapshot_dir =
+        get_snapshot_storage_path(build_rollback_config(rollback).snapshot_path.as_deref());
+    let snapshot_path = snapshot_file_path(&snapshot_dir, &snapshot.snapshot_id);
+    tokio::fs::create_dir_all(&snapshot_dir).await?;
+    let snapshot_json = serde_json::to_string_pretty(&snapshot)
+        .map_err(|e| Error::Core(format!("Failed to serialize offset snapshot: {}", e)))?;
+    tokio::fs::write(&snapshot_path, snapshot_json).await?;
 
-/// Internal restore execution result
-struct RestoreResult {
-    records_restored: u64,
-    bytes_restored: u64,
-    segments_processed: u64,
-    offset_mapping_path: Option<String>,
+    info!(
+        name = %name,
+        snapshot_id = %snapshot.snapshot_id,
+        groups = snapshot.group_offsets.len(),
+        path = %snapshot_path.display(),
+        "Created pre-restore offset snapshot"
+    );
+
+    let snapshot_time = Utc::now();
+    Ok(Some(RollbackStatus {
+        snapshot_id: snapshot.snapshot_id.clone(),
+        snapshot_time,
+        snapshot_path: snapshot_path.display().to_string(),
+        rollback_available: true,
+        expires_at: Some(snapshot_expiry(
+            snapshot_time,
+            rollback.snapshot_retention_hours,
+        )),
+    }))
 }
 
-/// Execute the actual restore using kafka-backup-core library
-async fn execute_restore_internal(
+/// Commit the consumer group offsets stored with the backup to the target
+/// cluster, under their target group and topic names. Returns the number of
+/// groups committed.
+async fn restore_consumer_group_offsets(
     restore: &KafkaRestore,
-    client: &Client,
-    namespace: &str,
-) -> Result<RestoreResult> {
+    resolved_config: &ResolvedRestoreConfig,
+    storage: &ResolvedStorage,
+    backup_id: &str,
+) -> Result<u32> {
     let name = restore.name_any();
+    let Some(group_offsets) = super::backup::read_group_offsets(storage, backup_id).await? else {
+        return Err(Error::validation(format!(
+            "Backup '{}' holds no consumer group offsets; restoreOffsetsOnly needs a backup \
+             taken with consumerGroupSnapshot enabled",
+            backup_id
+        )));
+    };
+    let offsets = target_group_offsets(
+        &restore.spec,
+        &resolved_config.topics,
+        &resolved_config.topic_mapping,
+        group_offsets,
+    );
+    if offsets.is_empty() {
+        return Err(Error::validation(format!(
+            "Backup '{}' captured no consumer group offsets on the restored topics",
+            backup_id
+        )));
+    }
 
-    info!(name = %name, "Building restore configuration");
+    let tls_manager = create_tls_manager(&resolved_config.kafka, &name)?;
+    let kafka_client = KafkaClient::new(to_core_kafka_config_with_tls(
+        &resolved_config.kafka,
+        &[],
+        tls_manager.as_ref(),
+    ));
+    kafka_client
+        .connect()
+        .await
+        .map_err(|e| Error::KafkaConnection(e.to_string()))?;
 
-    // 1. Build resolved configuration from CRD spec
-    let resolved_config = build_restore_config(restore, client, namespace).await?;
+    for (group, group_offsets) in &offsets {
+        commit_offsets(&kafka_client, group, group_offsets)
+            .await
+            .map_err(|e| {
+                Error::Core(format!(
+                    "Failed to commit offsets for consumer group {}: {}",
+                    group, e
+                ))
+            })?;
+    }
+    let groups = u32::try_from(offsets.len()).unwrap_or(u32::MAX);
+
+    info!(
+        name = %name,
+        backup_id = %backup_id,
+        groups,
+        "Committed backed-up consumer group offsets"
+    );
+    Ok(groups)
+}
+
+/// Offsets to commit for each target consumer group: the stored offsets on
+/// the restored `topics` (empty = all), with groups renamed by
+/// `consumerGroupMapping`/`consumerGroupPrefix` and topics by `topic_mapping`
+fn target_group_offsets(
+    spec: &KafkaRestoreSpec,
+    topics: &[String],
+    topic_mapping: &HashMap<String, String>,
+    group_offsets: super::GroupOffsets,
+) -> BTreeMap<String, Vec<(String, i32, i64, Option<String>)>> {
+    group_offsets
+        .into_iter()
+        .filter_map(|(group, group_topics)| {
+            let offsets: Vec<_> = group_topics
+                .into_iter()
+                .filter(|(topic, _)| topics.is_empty() || topics.contains(topic))
+                .flat_map(|(topic, partitions)| {
+                    let target = topic_mapping.get(&topic).cloned().unwrap_or(topic);
+                    partitions
+                        .into_iter()
+                        .map(move |(partition, offset)| (target.clone(), partition, offset, None))
+                })
+                .collect();
+            (!offsets.is_empty()).then(|| (target_consumer_group(spec, &group), offsets))
+        })
+        .collect()
+}
+
+/// File a snapshot is written to inside the snapshot directory
+fn snapshot_file_path(snapshot_dir: &Path, snapshot_id: &str) -> PathBuf {
+    snapshot_dir.join(format!("{}.json", snapshot_id))
+}
+
+/// Time after which a snapshot taken at `snapshot_time` is no longer retained
+fn snapshot_expiry(snapshot_time: DateTime<Utc>, retention_hours: u32) -> DateTime<Utc> {
+    snapshot_time + chrono::Duration::hours(i64::from(retention_hours))
+}
+
+/// Execute dry run validation
+async fn execute_dry_run(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+) -> Result<Action> {
+    let name = restore.name_any();
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+
+    // TODO: Validate target cluster is reachable
+
+    // The backup must exist, be readable by this operator, be recent enough,
+    // contain the requested topics and hold data inside any PITR window
+    let backup_check = async {
+        let mut resolved_config = build_restore_config(restore, client, namespace).await?;
+        let (backup_id, storage, location) =
+            resolve_backup_source(&resolved_config, client, namespace).await?;
+        record_source_location(restore, client, namespace, &location).await;
+        let manifest =
+            load_compatible_manifest(restore, client, namespace, &storage, &backup_id).await?;
+        record_backup_labels(restore, client, namespace, &storage, &backup_id).await;
+        check_backup_age(
+            &backup_id,
+            manifest.created_at,
+            restore.spec.max_backup_age_hours,
+            Utc::now(),
+        )?;
+        check_backup_offset_headers(&resolved_config, client).await?;
+        let topics_to_restore =
+            check_backup_topics(&resolved_config.topics, &manifest, &backup_id)?;
+        let pitr_note = check_pitr_window(
+            resolved_config.pitr.as_ref(),
+            manifest_time_range(&manifest, &resolved_config.topics),
+        )?;
+        let tls_manager = create_tls_manager(&resolved_config.kafka, &name)?;
+        let kafka_config =
+            to_core_kafka_config_with_tls(&resolved_config.kafka, &[], tls_manager.as_ref());
+        if restore.spec.acl_preflight {
+            check_topic_acls(&resolved_config, &manifest, kafka_config.clone()).await?;
+        }
+        // Drift is informational: an unreachable target only skips it
+        let target_partitions = match super::fetch_cluster_info(kafka_config.clone()).await {
+            Ok(info) => Some(info.topic_partitions),
+            Err(e) => {
+                warn!(name = %name, error = %e, "Could not read target cluster metadata; skipping topic drift check");
+                None
+            }
+        };
+        let drift = target_partitions
+            .as_ref()
+            .map(|target| {
+                topic_drift(
+                    &restored_topic_partitions(&resolved_config, &manifest),
+                    target,
+                )
+            })
+            .unwrap_or_default();
+        let target_configs = match &target_partitions {
+            Some(target) => {
+                let existing: Vec<String> = restored_target_topics(&resolved_config, &manifest)
+                    .into_iter()
+                    .filter(|topic| target.contains_key(topic))
+                    .collect();
+                fetch_target_topic_configs(&name, kafka_config, &existing).await
+            }
+            None => BTreeMap::new(),
+        };
+        if resolved_config.partition_strategy == PartitionStrategy::KeyHash {
+            let routed = key_hash_repartitioning(
+                &resolved_config,
+                &manifest,
+                &target_partitions.unwrap_or_default(),
+            );
+            resolved_config.repartitioning.extend(routed);
+        }
+        let preview = match &restore.spec.preview {
+            Some(preview) => {
+                let routed_partitions = key_routed_partitions(&resolved_config, &preview.topic);
+                preview_records(&storage, &manifest, preview, routed_partitions).await?
+            }
+            None => Vec::new(),
+        };
+        Ok::<_, Error>((topics_to_restore, pitr_note, drift, target_configs, preview))
+    }
+    .await;
+
+    if let Ok((_, Some(note), _, _, _)) = &backup_check {
+        warn!(name = %name, "{}", note);
+        events::publish_warning(client, restore, "PitrWindowPartial", "Restore", note).await;
+    }
+
+    let status = match backup_check {
+        Ok((topics_to_restore, pitr_note, drift, target_configs, preview)) => {
+            let mut message = format!(
+                "Dry run validation passed: {} topics to restore",
+                topics_to_restore
+            );
+            if let Some(note) = pitr_note {
+                message = format!("{}; {}", message, note);
+            }
+            if !drift.is_empty() {
+                warn!(name = %name, drift = ?drift, "Target topics differ from the backup");
+                message = format!(
+                    "{}; {} topic(s) differ from the backup",
+                    message,
+                    drift.len()
+                );
+            }
+            json!({
+                "status": {
+                    "phase": "Completed",
+                    "message": message,
+                    "topicsToRestore": topics_to_restore,
+                    "topicDrift": drift,
+                    "targetTopicConfigs": target_configs,
+                    "preview": preview,
+                    "partitionStrategy": restore.spec.partition_strategy,
+                    "observedGeneration": restore.metadata.generation,
+                    "conditions": [{
+                        "type": "Ready",
+                        "status": "True",
+                        "lastTransitionTime": Utc::now(),
+                        "reason": "DryRunPassed",
+                        "message": "Restore validation completed successfully"
+                    }]
+                }
+            })
+        }
+        Err(e) => {
+            let message = format!("Dry run validation failed: {}", e);
+            let recent_errors = super::record_recent_error(restore, "Failed", &message);
+            json!({
+                "status": {
+                    "phase": "Failed",
+                    "message": message,
+                    "recentErrors": recent_errors,
+                    "observedGeneration": restore.metadata.generation,
+                    "conditions": [{
+                        "type": "Ready",
+                        "status": "False",
+                        "lastTransitionTime": Utc::now(),
+                        "reason": "DryRunFailed",
+                        "message": e.to_string()
+                    }]
+                }
+            })
+        }
+    };
+    api.patch_status(
+        &name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(status),
+    )
+    .await?;
+
+    Ok(Action::await_change())
+}
+
+/// The first `preview.maxRecords` records of the preview topic, in partition
+/// order, read from the backup's segments without producing anything. With
+/// `routed_partitions`, each keyed record also shows the target partition its
+/// key hashes to.
+async fn preview_records(
+    storage: &ResolvedStorage,
+    manifest: &BackupManifest,
+    preview: &PreviewSpec,
+    routed_partitions: Option<i32>,
+) -> Result<Vec<PreviewRecord>> {
+    let topic = manifest
+        .topics
+        .iter()
+        .find(|topic| topic.name == preview.topic)
+        .ok_or_else(|| {
+            Error::validation(format!(
+                "preview topic '{}' is not in backup '{}'",
+                preview.topic, manifest.backup_id
+            ))
+        })?;
+    let mut partitions: Vec<_> = topic.partitions.iter().collect();
+    partitions.sort_by_key(|partition| partition.partition_id);
+
+    let backend = retention::create_backend(storage)?;
+    let limit = preview.max_records as usize;
+    let mut records = Vec::new();
+    for partition in partitions {
+        for segment in &partition.segments {
+            if records.len() >= limit {
+                return Ok(records);
+            }
+            let segment_records = read_segment(backend.as_ref(), &segment.key).await?;
+            let remaining = limit - records.len();
+            records.extend(segment_records.iter().take(remaining).map(|record| {
+                preview_record(
+                    partition.partition_id,
+                    record.offset,
+                    record.timestamp,
+                    record.key.as_deref(),
+                    record.value.as_deref(),
+                    preview.include_values,
+                    routed_partitions,
+                )
+            }));
+        }
+    }
+    Ok(records)
+}
+
+/// Fetch a segment and decode its records, decompressing with the codec named
+/// in the segment header
+pub(super) async fn read_segment(
+    backend: &dyn StorageBackend,
+    key: &str,
+) -> Result<Vec<BackupRecord>> {
+    let data = backend.get(key).await.map_err(|e| {
+        Error::storage(format!(
+            "Segment '{}' could not be read from storage: {}",
+            key, e
+        ))
+    })?;
+    SegmentReader::open(data)
+        .and_then(|mut reader| reader.read_all())
+        .map_err(|e| Error::Core(format!("Failed to read segment '{}': {}", key, e)))
+}
+
+/// Sanitized view of one backed-up record: the key, the value size, and the
+/// start of the value only with `include_values`
+fn preview_record(
+    partition: i32,
+    offset: i64,
+    timestamp: i64,
+    key: Option<&[u8]>,
+    value: Option<&[u8]>,
+    include_values: bool,
+    routed_partitions: Option<i32>,
+) -> PreviewRecord {
+    PreviewRecord {
+        partition,
+        offset,
+        timestamp,
+        key: key.map(preview_text),
+        target_partition: key
+            .zip(routed_partitions)
+            .map(|(key, partitions)| super::partitioner::key_partition(key, partitions)),
+        value_bytes: value.map_or(0, <[u8]>::len),
+        value: value.filter(|_| include_values).map(preview_text),
+    }
+}
+
+/// `bytes` as text, invalid UTF-8 replaced and control characters escaped,
+/// cut to [`PREVIEW_TEXT_CHARS`] characters
+fn preview_text(bytes: &[u8]) -> String {
+    let text = String::from_utf8_lossy(bytes);
+    let mut preview: String = text
+        .chars()
+        .take(PREVIEW_TEXT_CHARS)
+        .map(|c| {
+            if c.is_control() {
+                c.escape_debug().to_string()
+            } else {
+                c.to_string()
+            }
+        })
+        .collect();
+    if text.chars().count() > PREVIEW_TEXT_CHARS {
+        preview.push_str("...");
+    }
+    preview
+}
+
+/// Internal restore execution result
+struct RestoreResult {
+    records_restored: u64,
+    bytes_restored: u64,
+    segments_processed: u64,
+    offset_mapping_path: Option<String>,
+    /// Storage keys of the exported offset reports
+    offset_report_paths: Vec<String>,
+    /// ReplicationFactorReduced condition, when topics were created with a
+    /// lower replication factor than requested
+    replication_factor_reduced: Option<serde_json::Value>,
+    /// Consumer groups committed by an offsets-only restore
+    offsets_only_groups: Option<u32>,
+}
+
+/// Execute the actual restore using kafka-backup-core library
+async fn execute_restore_internal(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    shutdown: &CancellationToken,
+) -> Result<RestoreResult> {
+    let name = restore.name_any();
+
+    info!(name = %name, "Building restore configuration");
+
+    // 1. Build resolved configuration from CRD spec
+    let mut resolved_config = build_restore_config(restore, client, namespace).await?;
+    if let Some(backup_id) = run_backup_id(restore.status.as_ref()) {
+        resolved_config.backup_source.pin_backup_id(backup_id);
+    }
 
     // 2. Resolve the backup source to get storage config and backup ID
-    let (backup_id, storage) =
-        resolve_backup_source(&resolved_config.backup_source, client, namespace).await?;
+    let (backup_id, storage, location) =
+        resolve_backup_source(&resolved_config, client, namespace).await?;
+    record_source_location(restore, client, namespace, &location).await;
+
+    // 2a. Reject manifests this engine can't read before the engine trips
+    // over them with a less helpful parse error, and backups older than
+    // maxBackupAgeHours
+    let manifest =
+        load_compatible_manifest(restore, client, namespace, &storage, &backup_id).await?;
+    record_backup_labels(restore, client, namespace, &storage, &backup_id).await;
+    check_backup_age(
+        &backup_id,
+        manifest.created_at,
+        restore.spec.max_backup_age_hours,
+        Utc::now(),
+    )?;
+
+    // An offsets-only restore commits the captured consumer group
+    // offsets and skips the produce phase entirely
+    if restore.spec.restore_offsets_only {
+        let groups =
+            restore_consumer_group_offsets(restore, &resolved_config, &storage, &backup_id).await?;
+        return Ok(RestoreResult {
+            records_restored: 0,
+            bytes_restored: 0,
+            segments_processed: 0,
+            offset_mapping_path: None,
+            offset_report_paths: Vec::new(),
+            replication_factor_reduced: None,
+            offsets_only_groups: Some(groups),
+        });
+    }
+
+    check_backup_offset_headers(&resolved_config, client).await?;
+    let topics_to_restore = check_backup_topics(&resolved_config.topics, &manifest, &backup_id)?;
+    let completed_topics = completed_topics_to_skip(
+        restore.spec.force_full_restore,
+        restore.status.as_ref(),
+        &backup_id,
+    );
+    record_restore_scope(
+        restore,
+        client,
+        namespace,
+        &backup_id,
+        topics_to_restore,
+        &completed_topics,
+    )
+    .await;
+
+    // 2a. A retry only replays the topics its earlier attempts didn't finish
+    let scope_topics = resolved_config.topics.clone();
+    let mut data_restored = false;
+    if !completed_topics.is_empty() {
+        match remaining_topics(&resolved_config.topics, &manifest, &completed_topics) {
+            Some(remaining) => {
+                info!(name = %name, skipped = ?completed_topics, remaining = ?remaining, "Skipping topics restored by an earlier attempt");
+                resolved_config.topics = remaining;
+            }
+            None => {
+                info!(name = %name, "Every topic was restored by an earlier attempt; running only the post-restore steps");
+                data_restored = true;
+            }
+        }
+    }
+    if let Some(note) = check_pitr_window(
+        resolved_config.pitr.as_ref(),
+        manifest_time_range(&manifest, &resolved_config.topics),
+    )? {
+        warn!(name = %name, "{}", note);
+        events::publish_warning(client, restore, "PitrWindowPartial", "Restore", &note).await;
+    }
+
+    // 2b. Catch truncated or missing segments before anything is produced
+    if restore.spec.verify_checksums {
+        let checksums = super::backup::read_segment_checksums(&storage, &backup_id).await?;
+        if checksums.is_none() {
+            warn!(name = %name, backup_id = %backup_id, "Backup has no segment checksums; verifying segment sizes only");
+        }
+        let (verified, total, outcome) =
+            verify_segments(&storage, &manifest, &resolved_config, checksums.as_ref()).await;
+        record_segment_verification(restore, client, namespace, verified, total).await;
+        outcome?;
+        info!(name = %name, verified, total, "Backup segments verified");
+    }
 
     info!(
         name = %name,
@@ -377,19 +1541,130 @@ async fn execute_restore_internal(
         "Starting restore engine"
     );
 
-    // 2b. Create TLS file manager if TLS is configured
-    let tls_manager = if let Some(tls) = &resolved_config.kafka.tls {
-        let tls_dir = default_tls_dir(&name);
-        Some(TlsFileManager::new(tls, &tls_dir)?)
-    } else {
-        None
-    };
+    // 2c. Create TLS file manager if TLS is configured
+    let tls_manager = create_tls_manager(&resolved_config.kafka, &name)?;
+
+    // 2d. Key-hash routing spreads each topic's records by key over the
+    // target topic's partitions, so their counts are needed up front
+    if resolved_config.partition_strategy == PartitionStrategy::KeyHash {
+        let kafka_config =
+            to_core_kafka_config_with_tls(&resolved_config.kafka, &[], tls_manager.as_ref());
+        let info = super::fetch_cluster_info(kafka_config).await?;
+        let routed = key_hash_repartitioning(&resolved_config, &manifest, &info.topic_partitions);
+        info!(name = %name, topics = ?routed.keys().collect::<Vec<_>>(), "Routing records by key hash");
+        resolved_config.repartitioning.extend(routed);
+    }
 
     // 3. Convert to kafka-backup-core Config
-    let core_config =
+    let mut core_config =
         to_core_restore_config(&resolved_config, &backup_id, &storage, tls_manager.as_ref())
             .map_err(|e| Error::Core(format!("Failed to build core config: {}", e)))?;
 
+    if data_restored {
+        return finish_restored_data(
+            restore,
+            client,
+            &mut resolved_config,
+            &manifest,
+            scope_topics,
+            core_config,
+        )
+        .await;
+    }
+
+    // 3a. Inspect the target cluster: compare its ID with the cluster the
+    // backup came from, and make sure topics the engine creates can get the
+    // requested replication factor (or the most the target can hold)
+    let mut replication_factor_reduced = None;
+    if let Some(target) = core_config.target.clone() {
+        if restore.spec.acl_preflight {
+            check_topic_acls(&resolved_config, &manifest, target.clone()).await?;
+        }
+
+        let target_info = match super::fetch_cluster_info(target).await {
+            Ok(info) => Some(info),
+            Err(e) => {
+                warn!(name = %name, error = %e, "Could not read target cluster metadata");
+                None
+            }
+        };
+        record_cluster_ids(
+            restore,
+            client,
+            namespace,
+            manifest.source_cluster_id.clone(),
+            target_info
+                .as_ref()
+                .and_then(|info| info.cluster_id.clone()),
+        )
+        .await;
+
+        if resolved_config.create_topics {
+            if let (Some(replication_factor), Some(info)) =
+                (resolved_config.default_replication_factor, &target_info)
+            {
+                let applied = applied_replication_factor(
+                    replication_factor,
+                    info.broker_count,
+                    restore.spec.allow_replication_factor_downscale,
+                )?;
+                let created: Vec<String> = restored_target_topics(&resolved_config, &manifest)
+                    .into_iter()
+                    .filter(|topic| !info.topic_partitions.contains_key(topic))
+                    .collect();
+                if applied != replication_factor && !created.is_empty() {
+                    warn!(name = %name, topics = ?created, requested = replication_factor, applied, "Creating topics with a reduced replication factor");
+                    if let Some(restore_options) = core_config.restore.as_mut() {
+                        restore_options.default_replication_factor = Some(applied);
+                    }
+                    let condition =
+                        replication_factor_reduced_condition(&created, replication_factor, applied);
+                    record_replication_factor_reduced(restore, client, namespace, &condition).await;
+                    replication_factor_reduced = Some(condition);
+                }
+            }
+        }
+
+        // Every backed-up partition must land on a partition the target topic
+        // has; with allowPartitionDownscale the rest are folded into it
+        if let Some(info) = &target_info {
+            let topics = restored_topic_partitions(&resolved_config, &manifest);
+            let folds = plan_partition_folds(
+                &topics,
+                &info.topic_partitions,
+                &resolved_config.partition_mapping,
+                restore.spec.allow_partition_downscale,
+            )?;
+            if !folds.is_empty() {
+                let partition_mapping =
+                    fold_partition_mapping(&topics, &folds, &resolved_config.partition_mapping)?;
+                info!(name = %name, folds = ?folds, "Folding partitions missing from target topics");
+                if let Some(restore_options) = core_config.restore.as_mut() {
+                    restore_options.partition_mapping = partition_mapping;
+                }
+                record_partition_remapping(restore, client, namespace, &folds).await;
+            }
+        }
+    }
+
+    let target_kafka = core_config.target.clone();
+
+    // 3b. Let the engine checkpoint its position so an interrupted restore
+    // resumes from the last checkpoint on the next attempt
+    let checkpoint_path = restore_checkpoint_path(&storage, &backup_id, namespace, &name);
+    if let Some(parent) = checkpoint_path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    if tokio::fs::try_exists(&checkpoint_path)
+        .await
+        .unwrap_or(false)
+    {
+        info!(name = %name, path = %checkpoint_path.display(), "Resuming restore from checkpoint");
+    }
+    if let Some(restore_options) = core_config.restore.as_mut() {
+        restore_options.checkpoint_state = Some(checkpoint_path.clone());
+    }
+
     let offset_mapping_path = core_config
         .restore
         .as_ref()
@@ -407,42 +1682,282 @@ async fn execute_restore_internal(
         .as_ref()
         .is_some_and(|restore| restore.reset_consumer_offsets || restore.auto_consumer_groups);
 
-    let report = if run_three_phase {
-        let orchestrator = ThreePhaseRestore::new(core_config)
-            .map_err(|e| Error::Core(format!("Failed to create restore orchestrator: {}", e)))?;
-        let three_phase_report = orchestrator
-            .run_all_phases()
-            .await
-            .map_err(|e| Error::Core(format!("Three-phase restore execution failed: {}", e)))?;
-        three_phase_report.restore_report
-    } else {
-        // 4. Create the restore engine (sync constructor)
-        let engine = RestoreEngine::new(core_config)
-            .map_err(|e| Error::Core(format!("Failed to create restore engine: {}", e)))?;
+    let restore_timeout = restore.spec.timeout_secs.map(Duration::from_secs);
+    let stall_timeout = restore.spec.stall_timeout_secs.map(Duration::from_secs);
+    let description = format!("Restore {}", name);
 
-        // 5. Get progress receiver for monitoring
-        let mut progress_rx = engine.progress_receiver();
+    // Heartbeat for the duration of the run so monitor_progress can tell a
+    // live restore from one orphaned by an operator restart
+    let heartbeat_cancel = CancellationToken::new();
+    let heartbeat_task = super::spawn_heartbeat(
+        Api::<KafkaRestore>::namespaced(client.clone(), namespace),
+        name.clone(),
+        heartbeat_cancel.clone(),
+    );
 
-        // Spawn progress monitoring task
-        let name_clone = name.clone();
-        tokio::spawn(async move {
-            while let Ok(progress) = progress_rx.recv().await {
-                info!(
-                    name = %name_clone,
-                    records = progress.records_restored,
-                    percentage = progress.percentage,
-                    throughput = progress.throughput_records_per_sec,
-                    "Restore progress"
+    let run_result = async {
+        let report = if run_three_phase {
+            let orchestrator = ThreePhaseRestore::new(core_config).map_err(|e| {
+                Error::Core(format!("Failed to create restore orchestrator: {}", e))
+            })?;
+            let three_phase_report = super::run_until_shutdown(
+                shutdown,
+                &description,
+                super::run_with_timeout(
+                    restore_timeout,
+                    &description,
+                    orchestrator.run_all_phases(),
+                ),
+            )
+            .await??
+            .map_err(|e| Error::Core(format!("Three-phase restore execution failed: {}", e)))?;
+            three_phase_report.restore_report
+        } else {
+            // 4. Run the engine once, or with rampUpSecs once per topic until
+            // the ramp is over: each pass restores topics no earlier pass
+            // touched, at the records limit the ramp has reached
+            let ramp = core_config
+                .restore
+                .as_ref()
+                .and_then(|restore| restore.rate_limit_records_per_sec)
+                .zip(
+                    resolved_config
+                        .rate_limiting
+                        .as_ref()
+                        .and_then(|rate_limiting| rate_limiting.ramp_up_secs)
+                        .map(Duration::from_secs),
                 );
+            let mut pending = match ramp {
+                Some(_) => remaining_topics(&resolved_config.topics, &manifest, &[])
+                    .unwrap_or_default(),
+                None => Vec::new(),
+            };
+            let started = tokio::time::Instant::now();
+            let mut completed_so_far = completed_topics.clone();
+            let mut records_before = 0u64;
+            let mut report = None;
+            loop {
+                let mut pass_config = core_config.clone();
+                let mut pass_topics = Vec::new();
+                if let Some((limit, ramp_up)) = ramp {
+                    let (topics, records_per_sec) =
+                        next_ramp_pass(&pending, limit, ramp_up, started.elapsed());
+                    pass_topics = pending.drain(..topics).collect::<Vec<_>>();
+                    if let Some(target) = pass_config.target.as_mut() {
+                        target.topics.include = pass_topics.clone();
+                    }
+                    if let Some(restore_options) = pass_config.restore.as_mut() {
+                        restore_options.rate_limit_records_per_sec = Some(records_per_sec);
+                    }
+                    info!(name = %name, topics = ?pass_topics, records_per_sec, "Starting ramp-up pass");
+                    record_ramp_pass(restore, client, namespace, records_per_sec, &completed_so_far)
+                        .await;
+                }
+
+                // Create the restore engine (sync constructor)
+                let engine = RestoreEngine::new(pass_config)
+                    .map_err(|e| Error::Core(format!("Failed to create restore engine: {}", e)))?;
+
+                // 5. Get progress receiver for monitoring
+                let mut progress_rx = engine.progress_receiver();
+
+                // Spawn progress monitoring task. The token is cancelled once the
+                // engine returns so the task never outlives this reconcile. The
+                // task also watches for stalls: when the restored record count
+                // doesn't advance for stallTimeoutSecs it fires `stalled`.
+                let progress_cancel = CancellationToken::new();
+                let task_cancel = progress_cancel.clone();
+                let stalled = CancellationToken::new();
+                let task_stalled = stalled.clone();
+                let progress_api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+                let name_clone = name.clone();
+                let progress_path = progress_file_path(namespace, &name);
+                let mut task_completed = completed_so_far.clone();
+                let progress_task = tokio::spawn(async move {
+                    let mut last_patch: Option<Instant> = None;
+                    let mut last_advance = tokio::time::Instant::now();
+                    let mut records_seen = records_before;
+                    loop {
+                        let progress = tokio::select! {
+                            _ = task_cancel.cancelled() => break,
+                            _ = stall_deadline(last_advance, stall_timeout) => {
+                                warn!(name = %name_clone, records = records_seen, "Restore progress stalled");
+                                task_stalled.cancel();
+                                break;
+                            }
+                            received = progress_rx.recv() => match received {
+                                Ok(progress) => progress,
+                                Err(RecvError::Lagged(_)) => continue,
+                                Err(RecvError::Closed) => break,
+                            },
+                        };
+
+                        let records_restored = records_before + progress.records_restored;
+                        if records_restored > records_seen {
+                            records_seen = records_restored;
+                            last_advance = tokio::time::Instant::now();
+                        }
+
+                        info!(
+                            name = %name_clone,
+                            records = records_restored,
+                            percentage = progress.percentage,
+                            throughput = progress.throughput_records_per_sec,
+                            "Restore progress"
+                        );
+
+                        // A finished topic is recorded straight away so a retry
+                        // can skip it, regardless of the patch interval
+                        let mut topic_finished = false;
+                        for topic in &progress.completed_topics {
+                            if !task_completed.contains(topic) {
+                                task_completed.push(topic.clone());
+                                topic_finished = true;
+                            }
+                        }
+
+                        if !topic_finished
+                            && last_patch.is_some_and(|at| at.elapsed() < PROGRESS_PATCH_INTERVAL)
+                        {
+                            continue;
+                        }
+                        last_patch = Some(Instant::now());
+
+                        let eta_ms = estimate_eta_ms(
+                            records_restored,
+                            progress.percentage,
+                            progress.throughput_records_per_sec,
+                        );
+                        let progress_status = json!({
+                            "status": {
+                                "progressPercent": progress.percentage,
+                                "recordsRestored": records_restored,
+                                "throughputRecordsPerSec": progress.throughput_records_per_sec,
+                                "etaMs": eta_ms,
+                                "completedTopics": task_completed,
+                            }
+                        });
+                        if let Err(e) = progress_api
+                            .patch_status(
+                                &name_clone,
+                                &PatchParams::apply("kafka-backup-operator"),
+                                &Patch::Merge(progress_status),
+                            )
+                            .await
+                        {
+                            warn!(name = %name_clone, error = %e, "Failed to patch restore progress");
+                        }
+
+                        let persisted = PersistedProgress {
+                            records_restored,
+                            progress_percent: progress.percentage,
+                            finished: false,
+                            updated_at: Utc::now(),
+                        };
+                        if let Err(e) = write_progress(&progress_path, &persisted).await {
+                            warn!(name = %name_clone, error = %e, "Failed to persist restore progress");
+                        }
+                    }
+                    (records_seen, task_completed)
+                });
+
+                // 6. Run the restore, within what is left of the timeout
+                let remaining_timeout =
+                    restore_timeout.map(|timeout| timeout.saturating_sub(started.elapsed()));
+                let run_result = super::run_until_shutdown(
+                    shutdown,
+                    &description,
+                    super::run_with_timeout(
+                        remaining_timeout,
+                        &description,
+                        run_until_stalled(&stalled, stall_timeout, &description, engine.run()),
+                    ),
+                )
+                .await;
+                progress_cancel.cancel();
+                let (records_seen, task_completed) = progress_task
+                    .await
+                    .unwrap_or_else(|_| (records_before, completed_so_far.clone()));
+
+                let pass_report = run_result???.map_err(|e| {
+                    super::classify_credentials_rotation(
+                        Error::Core(format!("Restore execution failed: {}", e)),
+                        records_seen > 0,
+                    )
+                })?;
+                records_before += pass_report.records_restored;
+                completed_so_far = task_completed;
+                report = Some(match report {
+                    None => pass_report,
+                    Some(mut total) => {
+                        total.records_restored += pass_report.records_restored;
+                        total.bytes_restored += pass_report.bytes_restored;
+                        total.segments_processed += pass_report.segments_processed;
+                        total
+                    }
+                });
+                if pending.is_empty() {
+                    break;
+                }
+
+                // The pass restored its topics in full: a retry may skip them,
+                // and the next pass must not resume from this pass's checkpoint
+                for topic in pass_topics {
+                    if !completed_so_far.contains(&topic) {
+                        completed_so_far.push(topic);
+                    }
+                }
+                if let Err(e) = tokio::fs::remove_file(&checkpoint_path).await {
+                    if e.kind() != std::io::ErrorKind::NotFound {
+                        warn!(name = %name, error = %e, "Failed to remove restore checkpoint");
+                    }
+                }
             }
-        });
+            report.ok_or_else(|| Error::Core("Restore engine ran no passes".to_string()))?
+        };
+        Ok::<_, Error>(report)
+    }
+    .await;
+    heartbeat_cancel.cancel();
+    let _ = heartbeat_task.await;
+    let report = run_result?;
 
-        // 6. Run the restore
-        engine
-            .run()
-            .await
-            .map_err(|e| Error::Core(format!("Restore execution failed: {}", e)))?
-    };
+    // The run finished, so its checkpoint must not be resumed from again
+    if let Err(e) = tokio::fs::remove_file(&checkpoint_path).await {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            warn!(name = %name, error = %e, "Failed to remove restore checkpoint");
+        }
+    }
+
+    // 6a. Export the offset mapping for tools outside Kubernetes. The data is
+    // already restored, so a failed export is logged rather than failing the
+    // restore.
+    let mut offset_report_paths = Vec::new();
+    if let (false, Some(path)) = (
+        resolved_config.offset_report_formats.is_empty(),
+        offset_mapping_path.as_deref(),
+    ) {
+        match offset_report::export_offset_report(
+            &storage,
+            std::path::Path::new(path),
+            &backup_id,
+            namespace,
+            &name,
+            &resolved_config.offset_report_formats,
+            &resolved_config.topic_mapping,
+            &resolved_config.partition_mapping,
+        )
+        .await
+        {
+            Ok(keys) => offset_report_paths = keys,
+            Err(e) => warn!(name = %name, error = %e, "Failed to export offset report"),
+        }
+    }
+
+    // 7. Earliest and latest offset strategies are applied once the data is
+    // in, covering topics skipped as already restored too
+    resolved_config.topics = scope_topics;
+    apply_offset_strategy(restore, &resolved_config, &manifest, target_kafka).await?;
 
     info!(
         name = %name,
@@ -452,89 +1967,2373 @@ async fn execute_restore_internal(
         "Restore completed successfully"
     );
 
+    // Record completion so monitor_progress can finish the status even if
+    // the Completed patch below never lands
+    let finished = PersistedProgress {
+        records_restored: report.records_restored,
+        progress_percent: 100.0,
+        finished: true,
+        updated_at: Utc::now(),
+    };
+    if let Err(e) = write_progress(&progress_file_path(namespace, &name), &finished).await {
+        warn!(name = %name, error = %e, "Failed to persist restore completion");
+    }
+
     Ok(RestoreResult {
         records_restored: report.records_restored,
         bytes_restored: report.bytes_restored,
         segments_processed: report.segments_processed,
         offset_mapping_path,
+        offset_report_paths,
+        replication_factor_reduced,
+        offsets_only_groups: None,
     })
 }
 
-/// Resolve backup source to get backup ID and storage configuration
-async fn resolve_backup_source(
-    source: &ResolvedBackupSource,
-    client: &Client,
-    _namespace: &str,
-) -> Result<(String, ResolvedStorage)> {
-    match source {
-        ResolvedBackupSource::Storage { storage, backup_id } => {
-            // Direct storage reference - use provided backup_id or generate one
-            let resolved_id = backup_id
-                .clone()
-                .unwrap_or_else(|| format!("restore-{}", Utc::now().format("%Y%m%d-%H%M%S")));
-            Ok((resolved_id, storage.clone()))
-        }
-        ResolvedBackupSource::BackupResource {
-            name,
-            namespace: backup_ns,
-            backup_id,
-        } => {
-            // Reference to a KafkaBackup resource - fetch it to get storage config
-            let api: Api<KafkaBackup> = Api::namespaced(client.clone(), backup_ns);
-            let backup = api.get(name).await.map_err(|e| {
-                Error::BackupNotFound(format!("Failed to fetch KafkaBackup '{}': {}", name, e))
-            })?;
-
-            // Use provided backup_id or latest from status
-            let resolved_backup_id = backup_id.clone().unwrap_or_else(|| {
-                backup
-                    .status
-                    .as_ref()
-                    .and_then(|s| s.backup_id.clone())
-                    .unwrap_or_else(|| format!("{}-latest", name))
-            });
+/// Commit the earliest or latest offsets of the consumer groups the restore
+/// affects on the target cluster. Other strategies are applied by the engine.
+async fn apply_offset_strategy(
+    restore: &KafkaRestore,
+    config: &ResolvedRestoreConfig,
+    manifest: &BackupManifest,
+    target: Option<kafka_backup_core::config::KafkaConfig>,
+) -> Result<()> {
+    let (Some(strategy), Some(target)) = (config.offset_strategy, target) else {
+        return Ok(());
+    };
+    if !matches!(
+        strategy,
+        RestoreOffsetStrategy::Earliest | RestoreOffsetStrategy::Latest
+    ) {
+        return Ok(());
+    }
 
-            // Build storage config from the backup's storage spec
-            let storage =
-                crate::adapters::build_storage_config(&backup.spec.storage, client, backup_ns)
-                    .await?;
+    let groups: Vec<String> = affected_consumer_groups(restore)
+        .iter()
+        .map(|group| target_consumer_group(&restore.spec, group))
+        .collect();
+    let partitions =
+        commit_restored_group_offsets(config, manifest, target, &groups, strategy).await?;
+    info!(name = %restore.name_any(), groups = ?groups, partitions, strategy = ?strategy, "Committed consumer group offsets");
+    Ok(())
+}
 
-            Ok((resolved_backup_id, storage))
-        }
+/// Finish a retry whose earlier attempts already restored every topic: the
+/// data is not produced again, only the post-restore steps run. The engine's
+/// own consumer offset reset is part of its run and is not repeated.
+async fn finish_restored_data(
+    restore: &KafkaRestore,
+    client: &Client,
+    config: &mut ResolvedRestoreConfig,
+    manifest: &BackupManifest,
+    scope_topics: Vec<String>,
+    core_config: kafka_backup_core::config::Config,
+) -> Result<RestoreResult> {
+    let name = restore.name_any();
+    let engine_resets_offsets = core_config
+        .restore
+        .as_ref()
+        .is_some_and(|restore| restore.reset_consumer_offsets || restore.auto_consumer_groups);
+    if engine_resets_offsets {
+        events::publish_warning(
+            client,
+            restore,
+            "OffsetResetNotRepeated",
+            "Restore",
+            "Every topic was restored by an earlier attempt, so the consumer offset reset \
+             was not repeated; use a KafkaOffsetReset to reset the groups",
+        )
+        .await;
     }
+
+    config.topics = scope_topics;
+    apply_offset_strategy(restore, config, manifest, core_config.target).await?;
+
+    let status = restore.status.as_ref();
+    let records_restored = status
+        .and_then(|status| status.records_restored)
+        .unwrap_or_default();
+    info!(name = %name, records = records_restored, "Restore completed by an earlier attempt");
+    Ok(RestoreResult {
+        records_restored,
+        bytes_restored: status
+            .and_then(|status| status.bytes_restored)
+            .unwrap_or_default(),
+        segments_processed: status
+            .and_then(|status| status.segments_processed)
+            .unwrap_or_default(),
+        offset_mapping_path: None,
+        offset_report_paths: Vec::new(),
+        replication_factor_reduced: None,
+        offsets_only_groups: None,
+    })
 }
 
-/// Update status to Failed
-pub async fn update_status_failed(
+/// Show the records limit of the next ramp-up pass in status, along with
+/// the topics earlier passes restored so a retry can skip them
+async fn record_ramp_pass(
     restore: &KafkaRestore,
     client: &Client,
     namespace: &str,
-    error_message: &str,
-) -> Result<()> {
+    records_per_sec: u64,
+    completed_topics: &[String],
+) {
     let name = restore.name_any();
     let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
-
-    let status = json!({
+    let ramp_status = json!({
         "status": {
-            "phase": "Failed",
-            "message": error_message,
-            "observedGeneration": restore.metadata.generation,
-            "conditions": [{
-                "type": "Ready",
-                "status": "False",
-                "lastTransitionTime": Utc::now(),
-                "reason": "ValidationFailed",
-                "message": error_message
-            }]
+            "effectiveRecordsPerSec": records_per_sec,
+            "completedTopics": completed_topics,
         }
     });
+    if let Err(e) = api
+        .patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(ramp_status),
+        )
+        .await
+    {
+        warn!(name = %name, error = %e, "Failed to record ramp-up rate limit in status");
+    }
+}
 
-    api.patch_status(
-        &name,
-        &PatchParams::apply("kafka-backup-operator"),
-        &Patch::Merge(status),
-    )
+/// Resolves once `stall_timeout` has passed since `last_advance`; never
+/// without a stall timeout
+async fn stall_deadline(last_advance: tokio::time::Instant, stall_timeout: Option<Duration>) {
+    match stall_timeout {
+        Some(timeout) => tokio::time::sleep_until(last_advance + timeout).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Await `operation` unless the progress watchdog fires `stalled` first,
+/// mapping that to [`Error::Stalled`]. The engine is dropped on a stall, so
+/// the next attempt resumes from its checkpoint.
+async fn run_until_stalled<F: std::future::Future>(
+    stalled: &CancellationToken,
+    stall_timeout: Option<Duration>,
+    description: &str,
+    operation: F,
+) -> Result<F::Output> {
+    tokio::select! {
+        output = operation => Ok(output),
+        _ = stalled.cancelled() => Err(Error::Stalled(format!(
+            "{} made no progress for stallTimeoutSecs={}",
+            description,
+            stall_timeout.map(|timeout| timeout.as_secs()).unwrap_or_default()
+        ))),
+    }
+}
+
+/// Estimate the remaining restore time from the completed percentage and
+/// current throughput. Returns `None` until there is enough signal to
+/// extrapolate.
+fn estimate_eta_ms(records_restored: u64, percentage: f64, throughput: f64) -> Option<u64> {
+    if percentage >= 100.0 {
+        return Some(0);
+    }
+    if percentage <= 0.0 || throughput <= 0.0 || records_restored == 0 {
+        return None;
+    }
+
+    let records_total = records_restored as f64 * 100.0 / percentage;
+    let records_remaining = (records_total - records_restored as f64).max(0.0);
+    Some((records_remaining / throughput * 1000.0).round() as u64)
+}
+
+/// Replication factor Kafka's CreateTopics reads as "use the broker's
+/// `default.replication.factor`"
+const BROKER_DEFAULT_REPLICATION_FACTOR: i16 = -1;
+
+/// Replication factor for topics the engine creates. They must fit on the
+/// target cluster's brokers; with `allow_downscale` a larger factor is
+/// reduced to the broker count instead of failing the restore. The broker
+/// default isn't visible in metadata, so it is left to the broker unchecked.
+fn applied_replication_factor(
+    replication_factor: i16,
+    broker_count: usize,
+    allow_downscale: bool,
+) -> Result<i16> {
+    if replication_factor == BROKER_DEFAULT_REPLICATION_FACTOR
+        || !usize::try_from(replication_factor).is_ok_and(|rf| rf > broker_count)
+    {
+        return Ok(replication_factor);
+    }
+    if allow_downscale {
+        return Ok(i16::try_from(broker_count).unwrap_or(i16::MAX).max(1));
+    }
+    Err(Error::validation(format!(
+        "defaultReplicationFactor {} exceeds the {} brokers available in the target cluster. \
+         Set allowReplicationFactorDownscale to create topics with {} replicas instead.",
+        replication_factor, broker_count, broker_count
+    )))
+}
+
+/// ReplicationFactorReduced condition listing each created topic with its
+/// requested and applied replication factor
+fn replication_factor_reduced_condition(
+    topics: &[String],
+    requested: i16,
+    applied: i16,
+) -> serde_json::Value {
+    let reductions: Vec<String> = topics
+        .iter()
+        .map(|topic| {
+            format!(
+                "{} (requested RF {} -> applied RF {})",
+                topic, requested, applied
+            )
+        })
+        .collect();
+    json!({
+        "type": "ReplicationFactorReduced",
+        "status": "True",
+        "lastTransitionTime": Utc::now(),
+        "reason": "TargetHasFewerBrokers",
+        "message": format!("Topics created with a reduced replication factor: {}", reductions.join(", "))
+    })
+}
+
+/// Add the ReplicationFactorReduced condition while the restore runs
+async fn record_replication_factor_reduced(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    condition: &serde_json::Value,
+) {
+    let name = restore.name_any();
+    let mut conditions: Vec<serde_json::Value> = restore
+        .status
+        .as_ref()
+        .map(|status| {
+            status
+                .conditions
+                .iter()
+                .filter(|existing| existing.type_ != "ReplicationFactorReduced")
+                .filter_map(|existing| serde_json::to_value(existing).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    conditions.push(condition.clone());
+
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+    if let Err(e) = api
+        .patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(json!({ "status": { "conditions": conditions } })),
+        )
+        .await
+    {
+        warn!(name = %name, error = %e, "Failed to record ReplicationFactorReduced condition");
+    }
+    events::publish_warning(
+        client,
+        restore,
+        "ReplicationFactorReduced",
+        "Restore",
+        condition["message"].as_str().unwrap_or_default(),
+    )
+    .await;
+}
+
+/// `header_based` offsets are translated from the original-offset headers, so
+/// a referenced KafkaBackup must write them. Direct storage references can't
+/// be checked and are trusted.
+async fn check_backup_offset_headers(
+    config: &ResolvedRestoreConfig,
+    client: &Client,
+) -> Result<()> {
+    if config.offset_strategy != Some(RestoreOffsetStrategy::HeaderBased) {
+        return Ok(());
+    }
+    let ResolvedBackupSource::BackupResource {
+        name, namespace, ..
+    } = &config.backup_source
+    else {
+        return Ok(());
+    };
+
+    let backup = match Api::<KafkaBackup>::namespaced(client.clone(), namespace)
+        .get(name)
+        .await
+    {
+        Ok(backup) => backup,
+        // The backup may still be read from a fallback location, which is
+        // trusted like a direct storage reference
+        Err(_) if !config.storage_fallbacks.is_empty() => return Ok(()),
+        Err(e) => {
+            return Err(Error::BackupNotFound(format!(
+                "Failed to fetch KafkaBackup '{}': {}",
+                name, e
+            )))
+        }
+    };
+    check_offset_headers_enabled(name, backup.spec.include_offset_headers)
+}
+
+fn check_offset_headers_enabled(backup_name: &str, include_offset_headers: bool) -> Result<()> {
+    if include_offset_headers {
+        return Ok(());
+    }
+    Err(Error::validation(format!(
+        "offsetStrategy header_based needs KafkaBackup '{}' to set includeOffsetHeaders: true",
+        backup_name
+    )))
+}
+
+/// Commit the earliest or latest offset of every partition of the restored
+/// target topics for each of `groups` (target cluster names). Returns the partitions committed per
+/// group.
+async fn commit_restored_group_offsets(
+    config: &ResolvedRestoreConfig,
+    manifest: &BackupManifest,
+    kafka_config: kafka_backup_core::config::KafkaConfig,
+    groups: &[String],
+    strategy: RestoreOffsetStrategy,
+) -> Result<usize> {
+    let info = super::fetch_cluster_info(kafka_config.clone()).await?;
+    let kafka_client = KafkaClient::new(kafka_config);
+    kafka_client
+        .connect()
+        .await
+        .map_err(|e| Error::KafkaConnection(e.to_string()))?;
+
+    let mut offsets: Vec<(String, i32, i64, Option<String>)> = Vec::new();
+    for topic in restored_target_topics(config, manifest) {
+        let Some(&partitions) = info.topic_partitions.get(&topic) else {
+            continue;
+        };
+        for partition in 0..i32::try_from(partitions).unwrap_or(i32::MAX) {
+            let (earliest, latest) =
+                kafka_client
+                    .get_offsets(&topic, partition)
+                    .await
+                    .map_err(|e| {
+                        Error::KafkaConnection(format!(
+                            "failed to read offsets of {}-{}: {}",
+                            topic, partition, e
+                        ))
+                    })?;
+            let offset = if strategy == RestoreOffsetStrategy::Earliest {
+                earliest
+            } else {
+                latest
+            };
+            offsets.push((topic.clone(), partition, offset, None));
+        }
+    }
+
+    for group in groups {
+        commit_offsets(&kafka_client, group, &offsets)
+            .await
+            .map_err(|e| {
+                Error::Core(format!(
+                    "Failed to commit offsets for consumer group {}: {}",
+                    group, e
+                ))
+            })?;
+    }
+    Ok(offsets.len())
+}
+
+/// Kafka ACL operation codes, as bit positions in authorized-operations
+/// bitfields
+const ACL_WRITE: i32 = 4;
+const ACL_CREATE: i32 = 5;
+const ACL_DESCRIBE: i32 = 8;
+
+/// Fail when the Kafka principal lacks an operation the restore needs on its
+/// target topics, naming each denied topic and operation
+async fn check_topic_acls(
+    config: &ResolvedRestoreConfig,
+    manifest: &BackupManifest,
+    kafka_config: kafka_backup_core::config::KafkaConfig,
+) -> Result<()> {
+    let topics = restored_target_topics(config, manifest);
+    let authorized = super::fetch_authorized_operations(kafka_config, &topics).await?;
+    let denied = denied_topic_operations(&topics, &authorized, config.create_topics);
+    if denied.is_empty() {
+        return Ok(());
+    }
+    Err(Error::validation(format!(
+        "Kafka principal is not authorized for: {}",
+        denied.join(", ")
+    )))
+}
+
+/// Every target topic the restore writes, after `topicMapping`
+fn restored_target_topics(
+    config: &ResolvedRestoreConfig,
+    manifest: &BackupManifest,
+) -> Vec<String> {
+    let mut topics: Vec<String> = manifest
+        .topics
+        .iter()
+        .filter(|topic| config.topics.is_empty() || config.topics.contains(&topic.name))
+        .map(|topic| {
+            config
+                .topic_mapping
+                .get(&topic.name)
+                .cloned()
+                .unwrap_or_else(|| topic.name.clone())
+        })
+        .collect();
+    topics.sort();
+    topics.dedup();
+    topics
+}
+
+/// Operations the restore needs but the principal lacks, as
+/// "Operation on topic 'name'". Existing topics need Describe and Write;
+/// missing ones need Create, on the cluster or the topic, when the restore
+/// creates topics.
+/// Operations the broker doesn't report are not flagged.
+fn denied_topic_operations(
+    topics: &[String],
+    authorized: &AuthorizedOperations,
+    create_topics: bool,
+) -> Vec<String> {
+    let allows = |operations: i32, operation: i32| operations & (1 << operation) != 0;
+    let mut denied = Vec::new();
+    for topic in topics {
+        let access = authorized
+            .topics
+            .get(topic)
+            .copied()
+            .unwrap_or(TopicAccess::Missing(None));
+        match access {
+            TopicAccess::DescribeDenied => denied.push(format!("Describe on topic '{}'", topic)),
+            TopicAccess::Exists(Some(operations)) => {
+                if !allows(operations, ACL_DESCRIBE) {
+                    denied.push(format!("Describe on topic '{}'", topic));
+                }
+                if !allows(operations, ACL_WRITE) {
+                    denied.push(format!("Write on topic '{}'", topic));
+                }
+            }
+            TopicAccess::Missing(operations) if create_topics => {
+                // Either a cluster ACL or a topic (e.g. prefixed) ACL grants Create
+                let reported: Vec<i32> = [authorized.cluster, operations]
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                if !reported.is_empty()
+                    && !reported
+                        .iter()
+                        .any(|&operations| allows(operations, ACL_CREATE))
+                {
+                    denied.push(format!("Create on topic '{}'", topic));
+                }
+            }
+            TopicAccess::Exists(None) | TopicAccess::Missing(_) => {}
+        }
+    }
+    denied
+}
+
+/// Target topic and backed-up partition count of every topic the restore
+/// writes. Topics the engine repartitions by key are left out.
+fn restored_topic_partitions(
+    config: &ResolvedRestoreConfig,
+    manifest: &BackupManifest,
+) -> Vec<(String, i32)> {
+    manifest
+        .topics
+        .iter()
+        .filter(|topic| config.topics.is_empty() || config.topics.contains(&topic.name))
+        .map(|topic| {
+            let target = config
+                .topic_mapping
+                .get(&topic.name)
+                .cloned()
+                .unwrap_or_else(|| topic.name.clone());
+            let partitions = topic
+                .original_partition_count
+                .unwrap_or_else(|| i32::try_from(topic.partitions.len()).unwrap_or(i32::MAX));
+            (target, partitions)
+        })
+        .filter(|(target, _)| !config.repartitioning.contains_key(target))
+        .collect()
+}
+
+/// Murmur2 repartitioning for every restored topic without its own
+/// `repartitioning` entry, keyed by target topic: records are spread over the
+/// target topic's partitions, or the backed-up count for a topic the restore
+/// creates
+fn key_hash_repartitioning(
+    config: &ResolvedRestoreConfig,
+    manifest: &BackupManifest,
+    target: &BTreeMap<String, usize>,
+) -> HashMap<String, ResolvedTopicRepartitioningConfig> {
+    restored_topic_partitions(config, manifest)
+        .into_iter()
+        .map(|(topic, backed_up)| {
+            let target_partitions = target
+                .get(&topic)
+                .map_or(backed_up, |count| i32::try_from(*count).unwrap_or(i32::MAX));
+            let repartitioning = ResolvedTopicRepartitioningConfig {
+                strategy: "murmur2".to_string(),
+                target_partitions,
+            };
+            (topic, repartitioning)
+        })
+        .collect()
+}
+
+/// Partition count records of backed-up `topic` are spread over by key, or
+/// `None` when they keep their partition
+fn key_routed_partitions(config: &ResolvedRestoreConfig, topic: &str) -> Option<i32> {
+    let target = config
+        .topic_mapping
+        .get(topic)
+        .map_or(topic, String::as_str);
+    config
+        .repartitioning
+        .get(target)
+        .filter(|repartitioning| repartitioning.strategy.eq_ignore_ascii_case("murmur2"))
+        .map(|repartitioning| repartitioning.target_partitions)
+}
+
+/// Topic configs a dry run reports for each existing target topic
+const REPORTED_TOPIC_CONFIGS: [&str; 2] = ["retention.ms", "cleanup.policy"];
+
+/// [`REPORTED_TOPIC_CONFIGS`] of the target `topics`. Like drift, they are
+/// informational: a failed lookup only leaves them out.
+async fn fetch_target_topic_configs(
+    name: &str,
+    kafka_config: kafka_backup_core::config::KafkaConfig,
+    topics: &[String],
+) -> BTreeMap<String, BTreeMap<String, String>> {
+    if topics.is_empty() {
+        return BTreeMap::new();
+    }
+    match super::fetch_topic_configs(kafka_config, topics, &REPORTED_TOPIC_CONFIGS).await {
+        Ok(configs) => configs,
+        Err(e) => {
+            warn!(name = %name, error = %e, "Could not describe target topic configs; leaving them out of the dry run");
+            BTreeMap::new()
+        }
+    }
+}
+
+/// Differences between the backed-up topics (target name, backed-up partition
+/// count) and the target cluster's topics: a missing target topic, or one
+/// whose partition count differs. The manifest does not record topic
+/// configs, so retention and cleanup policy can't be compared; the dry run
+/// reports the target's values on their own.
+fn topic_drift(topics: &[(String, i32)], target: &BTreeMap<String, usize>) -> Vec<String> {
+    topics
+        .iter()
+        .filter_map(|(topic, partitions)| match target.get(topic) {
+            None => Some(format!("{}: missing on target", topic)),
+            Some(&target_partitions)
+                if i32::try_from(target_partitions).ok() != Some(*partitions) =>
+            {
+                Some(format!(
+                    "{}: target partitions={}, backup={}",
+                    topic, target_partitions, partitions
+                ))
+            }
+            Some(_) => None,
+        })
+        .collect()
+}
+
+/// Compare the partitions each restored topic writes, after
+/// `partitionMapping`, with the partitions its target topic has. Topics the
+/// target cluster doesn't have yet are skipped.
+///
+/// Returns the folds per target topic (source partition -> target partition,
+/// the mapped partition modulo the target's partition count), or an error
+/// naming the short topics when `allow_downscale` is off.
+fn plan_partition_folds(
+    topics: &[(String, i32)],
+    target_partitions: &BTreeMap<String, usize>,
+    partition_mapping: &HashMap<i32, i32>,
+    allow_downscale: bool,
+) -> Result<BTreeMap<String, BTreeMap<i32, i32>>> {
+    let mut folds: BTreeMap<String, BTreeMap<i32, i32>> = BTreeMap::new();
+    let mut short_topics = Vec::new();
+
+    for (target, source_partitions) in topics {
+        let Some(available) = target_partitions.get(target) else {
+            continue;
+        };
+        let available = i32::try_from(*available).unwrap_or(i32::MAX);
+        if available == 0 {
+            continue;
+        }
+
+        let mut highest = None;
+        for partition in 0..*source_partitions {
+            let mapped = partition_mapping
+                .get(&partition)
+                .copied()
+                .unwrap_or(partition);
+            if mapped >= available {
+                highest = highest.max(Some(mapped));
+                folds
+                    .entry(target.clone())
+                    .or_default()
+                    .insert(partition, mapped % available);
+            }
+        }
+        if let Some(highest) = highest {
+            short_topics.push(format!(
+                "{} (has {} partitions, restore writes up to partition {})",
+                target, available, highest
+            ));
+        }
+    }
+
+    if !short_topics.is_empty() && !allow_downscale {
+        short_topics.sort_unstable();
+        short_topics.dedup();
+        return Err(Error::validation(format!(
+            "Target topics have fewer partitions than the backup: {}. \
+             Add partitions to the target topics, or set allowPartitionDownscale to fold \
+             the extra partitions into existing ones",
+            short_topics.join(", ")
+        )));
+    }
+
+    Ok(folds)
+}
+
+/// Merge partition folds into the engine's partition mapping. The engine
+/// applies one mapping to every topic, so topics that need a source partition
+/// routed to different targets can't be restored together.
+fn fold_partition_mapping(
+    topics: &[(String, i32)],
+    folds: &BTreeMap<String, BTreeMap<i32, i32>>,
+    partition_mapping: &HashMap<i32, i32>,
+) -> Result<HashMap<i32, i32>> {
+    let mut routed: BTreeMap<i32, (i32, &str)> = BTreeMap::new();
+
+    for (target, source_partitions) in topics {
+        let topic_folds = folds.get(target);
+        for partition in 0..*source_partitions {
+            let destination = topic_folds
+                .and_then(|topic_folds| topic_folds.get(&partition))
+                .or_else(|| partition_mapping.get(&partition))
+                .copied()
+                .unwrap_or(partition);
+
+            match routed.get(&partition) {
+                Some((existing, other)) if *existing != destination => {
+                    return Err(Error::validation(format!(
+                        "allowPartitionDownscale cannot fold partition {} of '{}' into {} while \
+                         '{}' needs it in {}: partitionMapping applies to every topic, so restore \
+                         these topics separately",
+                        partition, target, destination, other, existing
+                    )));
+                }
+                Some(_) => {}
+                None => {
+                    routed.insert(partition, (destination, target));
+                }
+            }
+        }
+    }
+
+    let mut merged = partition_mapping.clone();
+    for (partition, (destination, _)) in routed {
+        if destination != partition || merged.contains_key(&partition) {
+            merged.insert(partition, destination);
+        }
+    }
+    Ok(merged)
+}
+
+/// Record the partitions folded by allowPartitionDownscale in status
+async fn record_partition_remapping(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    folds: &BTreeMap<String, BTreeMap<i32, i32>>,
+) {
+    let name = restore.name_any();
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+    let remapping_status = json!({
+        "status": {
+            "partitionRemapping": folds,
+        }
+    });
+    if let Err(e) = api
+        .patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(remapping_status),
+        )
+        .await
+    {
+        warn!(name = %name, error = %e, "Failed to record partition remapping in status");
+    }
+}
+
+/// Check that every requested topic is present in the backup and return how
+/// many topics the restore covers. An empty request restores every topic.
+fn check_backup_topics(
+    requested: &[String],
+    manifest: &BackupManifest,
+    backup_id: &str,
+) -> Result<usize> {
+    if requested.is_empty() {
+        return Ok(manifest.topics.len());
+    }
+
+    let missing: Vec<&str> = requested
+        .iter()
+        .filter(|topic| {
+            !manifest
+                .topics
+                .iter()
+                .any(|backed_up| &backed_up.name == *topic)
+        })
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(Error::validation(format!(
+            "Topics not found in backup '{}': {}",
+            backup_id,
+            missing.join(", ")
+        )));
+    }
+
+    Ok(requested.len())
+}
+
+/// Backup set this run resolved before it was interrupted. A resumed run
+/// reads the same set rather than resolving `latest` again, which may have
+/// moved on to a newer backup since.
+fn run_backup_id(status: Option<&KafkaRestoreStatus>) -> Option<&str> {
+    let status = status?;
+    let in_flight = matches!(
+        status.phase.as_deref(),
+        Some("Running" | super::PHASE_TIMED_OUT | super::PHASE_STALLED | super::PHASE_INTERRUPTED)
+    );
+    status.backup_id.as_deref().filter(|_| in_flight)
+}
+
+/// Topics a retried restore may skip: those recorded in
+/// status.completedTopics by an unsuccessful attempt at the same backup.
+/// Empty with forceFullRestore, after a successful or rolled-back restore,
+/// or when the restore now resolves to a different backup.
+fn completed_topics_to_skip(
+    force_full_restore: bool,
+    status: Option<&KafkaRestoreStatus>,
+    backup_id: &str,
+) -> Vec<String> {
+    let Some(status) = status else {
+        return Vec::new();
+    };
+    let retrying = matches!(
+        status.phase.as_deref(),
+        Some(
+            "Failed"
+                | "Running"
+                | super::PHASE_TIMED_OUT
+                | super::PHASE_STALLED
+                | super::PHASE_INTERRUPTED
+        )
+    );
+    if force_full_restore || !retrying || status.backup_id.as_deref() != Some(backup_id) {
+        return Vec::new();
+    }
+    status.completed_topics.clone()
+}
+
+/// Topics of the requested scope (every backed-up topic when `requested` is
+/// empty) not yet in `completed`. `None` when nothing is left, since an
+/// empty topic list would make the engine restore everything.
+fn remaining_topics(
+    requested: &[String],
+    manifest: &BackupManifest,
+    completed: &[String],
+) -> Option<Vec<String>> {
+    let remaining: Vec<String> = if requested.is_empty() {
+        manifest
+            .topics
+            .iter()
+            .map(|topic| topic.name.clone())
+            .collect()
+    } else {
+        requested.to_vec()
+    }
+    .into_iter()
+    .filter(|topic| !completed.contains(topic))
+    .collect();
+    (!remaining.is_empty()).then_some(remaining)
+}
+
+/// Earliest and latest record timestamps (epoch milliseconds) in the
+/// segments of the restored `topics` (empty = all), or `None` when they hold
+/// no segments
+fn manifest_time_range(manifest: &BackupManifest, topics: &[String]) -> Option<(i64, i64)> {
+    manifest
+        .topics
+        .iter()
+        .filter(|topic| topics.is_empty() || topics.contains(&topic.name))
+        .flat_map(|topic| topic.partitions.iter())
+        .flat_map(|partition| partition.segments.iter())
+        .fold(None, |range, segment| {
+            Some(match range {
+                None => (segment.start_timestamp, segment.end_timestamp),
+                Some((first, last)) => (
+                    first.min(segment.start_timestamp),
+                    last.max(segment.end_timestamp),
+                ),
+            })
+        })
+}
+
+/// Check that a PITR window overlaps the backup's data range. A window entirely
+/// outside it is rejected; one that only partly overlaps returns a note that
+/// part of the window has no data to restore.
+fn check_pitr_window(
+    pitr: Option<&ResolvedPitrConfig>,
+    backup_range: Option<(i64, i64)>,
+) -> Result<Option<String>> {
+    let (Some(pitr), Some((first, last))) = (pitr, backup_range) else {
+        return Ok(None);
+    };
+    if pitr.start_timestamp_ms.is_none() && pitr.end_timestamp_ms.is_none() {
+        return Ok(None);
+    }
+
+    let start = pitr.start_timestamp_ms.unwrap_or(i64::MIN);
+    let end = pitr.end_timestamp_ms.unwrap_or(i64::MAX);
+    let window = format!(
+        "[{},{}]",
+        pitr.start_timestamp_ms
+            .map_or_else(|| "-".to_string(), format_epoch_ms),
+        pitr.end_timestamp_ms
+            .map_or_else(|| "-".to_string(), format_epoch_ms)
+    );
+    let data = format!("[{},{}]", format_epoch_ms(first), format_epoch_ms(last));
+
+    if end < first || start > last {
+        return Err(Error::validation(format!(
+            "PITR window {} does not overlap backup data {}",
+            window, data
+        )));
+    }
+    if start < first || end > last {
+        return Ok(Some(format!(
+            "PITR window {} only partly overlaps backup data {}; only the overlapping range will be restored",
+            window, data
+        )));
+    }
+    Ok(None)
+}
+
+/// Reject a backup created more than `max_age_hours` before `now`
+fn check_backup_age(
+    backup_id: &str,
+    created_at_ms: i64,
+    max_age_hours: Option<u32>,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let Some(max_age_hours) = max_age_hours else {
+        return Ok(());
+    };
+    let age_hours = (now.timestamp_millis() - created_at_ms).max(0) / 3_600_000;
+    if age_hours > i64::from(max_age_hours) {
+        return Err(Error::validation(format!(
+            "Backup '{}' created at {} is {}h old, exceeding maxBackupAgeHours {}",
+            backup_id,
+            format_epoch_ms(created_at_ms),
+            age_hours,
+            max_age_hours
+        )));
+    }
+    Ok(())
+}
+
+/// Check that every segment of the restored topics exists in storage with the
+/// size the manifest recorded and, given the backup's `checksums`, the CRC32
+/// recorded for it. Sizes catch truncated and partial uploads, checksums
+/// same-size corruption. Returns the verified and total segment counts, which
+/// are kept when the check stops at the first bad segment, along with the
+/// outcome.
+async fn verify_segments(
+    storage: &ResolvedStorage,
+    manifest: &BackupManifest,
+    config: &ResolvedRestoreConfig,
+    checksums: Option<&BTreeMap<String, String>>,
+) -> (u64, u64, Result<()>) {
+    let segments: Vec<_> = manifest
+        .topics
+        .iter()
+        .filter(|topic| config.topics.is_empty() || config.topics.contains(&topic.name))
+        .flat_map(|topic| topic.partitions.iter())
+        .flat_map(|partition| partition.segments.iter())
+        .collect();
+    let total = segments.len() as u64;
+    let backend = match retention::create_backend(storage) {
+        Ok(backend) => backend,
+        Err(e) => return (0, total, Err(e)),
+    };
+
+    let mut verified = 0;
+    for segment in segments {
+        let mut checked = match backend.size(&segment.key).await {
+            Ok(stored) => check_segment_size(&segment.key, stored, segment.compressed_size),
+            Err(e) => Err(Error::storage(format!(
+                "Segment '{}' could not be read from storage: {}",
+                segment.key, e
+            ))),
+        };
+        let expected = checksums.and_then(|checksums| checksums.get(&segment.key));
+        if let (Ok(()), Some(expected)) = (&checked, expected) {
+            checked = match backend.get(&segment.key).await {
+                Ok(data) => check_segment_checksum(&segment.key, &data, expected),
+                Err(e) => Err(Error::storage(format!(
+                    "Segment '{}' could not be read from storage: {}",
+                    segment.key, e
+                ))),
+            };
+        }
+        if let Err(e) = checked {
+            return (verified, total, Err(e));
+        }
+        verified += 1;
+    }
+    (verified, total, Ok(()))
+}
+
+/// A stored segment must be exactly as large as the manifest says
+fn check_segment_size(key: &str, stored: u64, expected: u64) -> Result<()> {
+    if stored != expected {
+        return Err(Error::storage(format!(
+            "Segment '{}' is {} bytes in storage but {} bytes in the manifest",
+            key, stored, expected
+        )));
+    }
+    Ok(())
+}
+
+/// A stored segment must hash to the checksum recorded when it was backed up
+fn check_segment_checksum(key: &str, data: &[u8], expected: &str) -> Result<()> {
+    let stored = super::backup::segment_checksum(data);
+    if stored != expected {
+        return Err(Error::storage(format!(
+            "Segment '{}' has checksum {} in storage but {} in the backup's checksums",
+            key, stored, expected
+        )));
+    }
+    Ok(())
+}
+
+/// Record segment verification results in status
+async fn record_segment_verification(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    verified: u64,
+    total: u64,
+) {
+    let name = restore.name_any();
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+    let verification_status = json!({
+        "status": {
+            "segmentsVerified": verified,
+            "segmentsTotal": total,
+        }
+    });
+    if let Err(e) = api
+        .patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(verification_status),
+        )
+        .await
+    {
+        warn!(name = %name, error = %e, "Failed to record segment verification in status");
+    }
+}
+
+/// Render epoch milliseconds as RFC 3339, falling back to the raw value
+fn format_epoch_ms(ms: i64) -> String {
+    DateTime::<Utc>::from_timestamp_millis(ms)
+        .map(|time| time.to_rfc3339())
+        .unwrap_or_else(|| ms.to_string())
+}
+
+/// Record the resolved backup ID and how many topics the restore covers in
+/// status. Retention keeps the recorded backup set while the restore runs.
+async fn record_restore_scope(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    backup_id: &str,
+    topics_to_restore: usize,
+    completed_topics: &[String],
+) {
+    let name = restore.name_any();
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+    let scope_status = json!({
+        "status": {
+            "backupId": backup_id,
+            "topicsToRestore": topics_to_restore,
+            "completedTopics": completed_topics,
+            "partitionStrategy": restore.spec.partition_strategy,
+        }
+    });
+    if let Err(e) = api
+        .patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(scope_status),
+        )
+        .await
+    {
+        warn!(name = %name, error = %e, "Failed to record restore scope in status");
+    }
+}
+
+/// Record which storage location the backup is read from in status
+async fn record_source_location(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    location: &str,
+) {
+    let name = restore.name_any();
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+    if let Err(e) = api
+        .patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(json!({ "status": { "sourceLocation": location } })),
+        )
+        .await
+    {
+        warn!(name = %name, error = %e, "Failed to record source location in status");
+    }
+}
+
+/// Show the labels stored with the resolved backup in status. Labels are
+/// informational, so a failure to read them is only logged.
+async fn record_backup_labels(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    storage: &ResolvedStorage,
+    backup_id: &str,
+) {
+    let name = restore.name_any();
+    let labels = match super::backup::read_backup_labels(storage, backup_id).await {
+        Ok(labels) if labels.is_empty() => return,
+        Ok(labels) => labels,
+        Err(e) => {
+            warn!(name = %name, backup_id = %backup_id, error = %e, "Could not read backup labels");
+            return;
+        }
+    };
+    info!(name = %name, backup_id = %backup_id, labels = ?labels, "Resolved backup labels");
+
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+    if let Err(e) = api
+        .patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(json!({ "status": { "backupLabels": labels } })),
+        )
+        .await
+    {
+        warn!(name = %name, error = %e, "Failed to record backup labels in status");
+    }
+}
+
+/// Schema version recorded in a raw backup manifest. Manifests written before
+/// the field existed are version 1.
+fn manifest_version(manifest: &serde_json::Value) -> Result<u32> {
+    let version = match manifest.get("version") {
+        None | Some(serde_json::Value::Null) => return Ok(1),
+        Some(serde_json::Value::String(version)) => version.parse().ok(),
+        Some(version) => version.as_u64().and_then(|v| u32::try_from(v).ok()),
+    };
+    version.ok_or_else(|| Error::validation("backup manifest has an unreadable version field"))
+}
+
+/// Fail with a clear message when a manifest's schema version is outside
+/// [`SUPPORTED_MANIFEST_VERSIONS`]
+fn check_manifest_version(version: u32) -> Result<()> {
+    if SUPPORTED_MANIFEST_VERSIONS.contains(&version) {
+        return Ok(());
+    }
+    let (min, max) = (
+        SUPPORTED_MANIFEST_VERSIONS.start(),
+        SUPPORTED_MANIFEST_VERSIONS.end(),
+    );
+    let supported = if min == max {
+        format!("v{}", min)
+    } else {
+        format!("v{}-v{}", min, max)
+    };
+    Err(Error::validation(format!(
+        "backup format v{} not supported by this operator (supports {})",
+        version, supported
+    )))
+}
+
+/// Load a backup's manifest, recording its schema version in status and
+/// rejecting versions this operator can't restore
+async fn load_compatible_manifest(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    storage: &ResolvedStorage,
+    backup_id: &str,
+) -> Result<BackupManifest> {
+    let name = restore.name_any();
+    let data = super::backup::read_backup_manifest(storage, backup_id).await?;
+    let raw: serde_json::Value = serde_json::from_slice(&data)
+        .map_err(|e| Error::Core(format!("Failed to parse backup manifest: {}", e)))?;
+    let version = manifest_version(&raw)?;
+
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+    let version_status = json!({
+        "status": {
+            "manifestVersion": version,
+        }
+    });
+    if let Err(e) = api
+        .patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(version_status),
+        )
+        .await
+    {
+        warn!(name = %name, error = %e, "Failed to record manifest version in status");
+    }
+
+    check_manifest_version(version)?;
+    serde_json::from_value(raw)
+        .map_err(|e| Error::Core(format!("Failed to parse backup manifest: {}", e)))
+}
+
+/// Record the source and target cluster IDs in status. Offset translation
+/// from headers assumes the target partitions line up with the source ones,
+/// so a cross-cluster restore without a partition mapping is flagged.
+async fn record_cluster_ids(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    source_cluster_id: Option<String>,
+    target_cluster_id: Option<String>,
+) {
+    let name = restore.name_any();
+
+    if super::cluster_ids_differ(source_cluster_id.as_deref(), target_cluster_id.as_deref())
+        && restore.spec.partition_mapping.is_empty()
+    {
+        let note = format!(
+            "Backup was taken from cluster '{}' but the restore targets cluster '{}'; \
+             offset translation may be unsafe without partitionMapping",
+            source_cluster_id.as_deref().unwrap_or_default(),
+            target_cluster_id.as_deref().unwrap_or_default()
+        );
+        warn!(name = %name, "{}", note);
+        events::publish_warning(client, restore, "ClusterIdMismatch", "Restore", &note).await;
+    }
+
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+    let cluster_status = json!({
+        "status": {
+            "sourceClusterId": source_cluster_id,
+            "targetClusterId": target_cluster_id,
+        }
+    });
+    if let Err(e) = api
+        .patch_status(
+            &name,
+            &PatchParams::apply("kafka-backup-operator"),
+            &Patch::Merge(cluster_status),
+        )
+        .await
+    {
+        warn!(name = %name, error = %e, "Failed to record cluster IDs in status");
+    }
+}
+
+/// Location name recorded in status when the backup was read from its
+/// primary storage rather than one of `backupRef.storageFallbacks`
+const PRIMARY_SOURCE_LOCATION: &str = "primary";
+
+/// Resolve backup source to get backup ID and storage configuration, along
+/// with the name of the location the backup was found in. The fallback
+/// locations are tried in order when the primary one can't be resolved (e.g.
+/// its KafkaBackup doesn't exist in this cluster), is unreachable, or does not
+/// contain the backup; each fallback's storage is only resolved when it is
+/// tried. If none has the backup, the primary location's error is returned.
+async fn resolve_backup_source(
+    config: &ResolvedRestoreConfig,
+    client: &Client,
+    namespace: &str,
+) -> Result<(String, ResolvedStorage, String)> {
+    let primary_error = match locate_primary_backup_set(&config.backup_source, client).await {
+        Ok((resolved_id, storage)) => {
+            return Ok((resolved_id, storage, PRIMARY_SOURCE_LOCATION.to_string()))
+        }
+        Err(e) if config.storage_fallbacks.is_empty() => return Err(e),
+        Err(e) => e,
+    };
+    warn!(
+        location = PRIMARY_SOURCE_LOCATION,
+        error = %primary_error,
+        "Backup location unavailable"
+    );
+
+    let (backup_name, backup_id) = match &config.backup_source {
+        ResolvedBackupSource::Storage { backup_id, .. } => (None, backup_id),
+        ResolvedBackupSource::BackupResource {
+            name, backup_id, ..
+        } => (Some(name.as_str()), backup_id),
+    };
+    for (index, fallback) in config.storage_fallbacks.iter().enumerate() {
+        let location = format!("storageFallbacks[{}]", index);
+        let storage = crate::adapters::build_storage_config(fallback, client, namespace).await;
+        let located = match storage {
+            Ok(storage) => locate_backup_set(&storage, backup_name, backup_id.as_deref()).await,
+            Err(e) => Err(e),
+        };
+        match located {
+            Ok((resolved_id, storage)) => {
+                info!(
+                    location = %location,
+                    backup_id = %resolved_id,
+                    "Reading backup from fallback location"
+                );
+                return Ok((resolved_id, storage, location));
+            }
+            Err(e) => warn!(location = %location, error = %e, "Backup location unavailable"),
+        }
+    }
+    Err(primary_error)
+}
+
+/// Resolve the backup in the primary location: `backupRef.storage`, or the
+/// storage of the referenced KafkaBackup
+async fn locate_primary_backup_set(
+    source: &ResolvedBackupSource,
+    client: &Client,
+) -> Result<(String, ResolvedStorage)> {
+    match source {
+        // Direct storage reference - any backup set found there, in any
+        // date partition, may be selected
+        ResolvedBackupSource::Storage { storage, backup_id } => {
+            locate_backup_set(storage, None, backup_id.as_deref()).await
+        }
+        // Reference to a KafkaBackup resource - only the sets this backup
+        // wrote are considered
+        ResolvedBackupSource::BackupResource {
+            name,
+            namespace: backup_ns,
+            backup_id,
+        } => {
+            let storage = backup_resource_storage(client, backup_ns, name).await?;
+            locate_backup_set(&storage, Some(name), backup_id.as_deref()).await
+        }
+    }
+}
+
+/// Storage configured on a KafkaBackup resource
+async fn backup_resource_storage(
+    client: &Client,
+    backup_ns: &str,
+    name: &str,
+) -> Result<ResolvedStorage> {
+    let api: Api<KafkaBackup> = Api::namespaced(client.clone(), backup_ns);
+    let backup = api.get(name).await.map_err(|e| {
+        Error::BackupNotFound(format!("Failed to fetch KafkaBackup '{}': {}", name, e))
+    })?;
+    crate::adapters::build_storage_config(&backup.spec.storage, client, backup_ns).await
+}
+
+/// Probe the restore's backup locations without resolving a backup set in
+/// them: the primary location, then the fallbacks in order until one is
+/// reachable. If none is, the primary location's error is returned.
+async fn probe_backup_locations(
+    config: &ResolvedRestoreConfig,
+    client: &Client,
+    namespace: &str,
+    name: &str,
+) -> Result<()> {
+    let primary = match &config.backup_source {
+        ResolvedBackupSource::Storage { storage, .. } => Ok(storage.clone()),
+        ResolvedBackupSource::BackupResource {
+            name: backup_name,
+            namespace: backup_ns,
+            ..
+        } => backup_resource_storage(client, backup_ns, backup_name).await,
+    };
+    let primary_error = match primary {
+        Ok(storage) => {
+            match super::probe_storage(&storage, "KafkaRestore", namespace, name).await {
+                Ok(()) => return Ok(()),
+                Err(e) => e,
+            }
+        }
+        Err(e) => e,
+    };
+
+    for fallback in &config.storage_fallbacks {
+        let Ok(storage) = crate::adapters::build_storage_config(fallback, client, namespace).await
+        else {
+            continue;
+        };
+        if super::probe_storage(&storage, "KafkaRestore", namespace, name)
+            .await
+            .is_ok()
+        {
+            return Ok(());
+        }
+    }
+    Err(primary_error)
+}
+
+/// Resolve the backup ID against the backup sets in one storage location and
+/// scope the storage to the set's date partition
+async fn locate_backup_set(
+    storage: &ResolvedStorage,
+    backup_name: Option<&str>,
+    backup_id: Option<&str>,
+) -> Result<(String, ResolvedStorage)> {
+    let backup_sets = retention::list_backup_sets(storage, backup_name).await?;
+    let resolved_id = retention::select_backup_id(backup_id, &backup_sets)?;
+    let storage = retention::backup_set_storage(storage, &backup_sets, &resolved_id);
+    Ok((resolved_id, storage))
+}
+
+/// Whether a completed `rerunOnNewBackup` restore has a newer completed
+/// backup to restore. With a KafkaBackup reference, storage is only listed
+/// once that backup has completed a run since this restore started.
+pub async fn newer_backup_available(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+) -> Result<bool> {
+    let status = restore.status.as_ref();
+    let backup_ref = &restore.spec.backup_ref;
+    if backup_ref.storage.is_none() {
+        let backup_ns = backup_ref.namespace.as_deref().unwrap_or(namespace);
+        let api: Api<KafkaBackup> = Api::namespaced(client.clone(), backup_ns);
+        // Without the resource (e.g. a cross-cluster standby reading a
+        // replica bucket) only storage can tell
+        if let Ok(backup) = api.get(&backup_ref.name).await {
+            let completed_at = backup.status.and_then(|status| status.last_backup_time);
+            let started_at = status.and_then(|status| status.start_time);
+            let completed_since = completed_at
+                .zip(started_at)
+                .is_some_and(|(completed, started)| completed > started);
+            if !completed_since {
+                return Ok(false);
+            }
+        }
+    }
+
+    let resolved_config = build_restore_config(restore, client, namespace).await?;
+    let (backup_id, _, _) = resolve_backup_source(&resolved_config, client, namespace).await?;
+    let restored = status.and_then(|status| status.backup_id.as_deref());
+    Ok(restored != Some(backup_id.as_str()))
+}
+
+/// Names of the locked fields (backupRef, kafkaCluster, topics) that differ
+/// from those the restore started running with. Always empty in terminal and
+/// pre-run phases, where every field may change.
+pub fn changed_locked_fields(restore: &KafkaRestore) -> Vec<&'static str> {
+    let Some(status) = &restore.status else {
+        return Vec::new();
+    };
+    let in_flight = matches!(
+        status.phase.as_deref(),
+        Some("Running" | super::PHASE_TIMED_OUT | super::PHASE_STALLED | super::PHASE_INTERRUPTED)
+    );
+    let Some(locked) = status.locked_spec.as_ref().filter(|_| in_flight) else {
+        return Vec::new();
+    };
+
+    let spec = &restore.spec;
+    let mut changed = Vec::new();
+    if !same_json(&locked.backup_ref, &spec.backup_ref) {
+        changed.push("backupRef");
+    }
+    if !same_json(&locked.kafka_cluster, &spec.kafka_cluster) {
+        changed.push("kafkaCluster");
+    }
+    if locked.topics != spec.topics {
+        changed.push("topics");
+    }
+    changed
+}
+
+/// Whether two values serialize identically
+fn same_json<T: Serialize>(a: &T, b: &T) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+/// Leave an in-flight restore alone after its locked fields were edited,
+/// recording why in an ImmutableFieldsChanged condition
+pub async fn reject_locked_field_change(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    changed: &[&str],
+) -> Result<()> {
+    let name = restore.name_any();
+    let phase = restore
+        .status
+        .as_ref()
+        .and_then(|status| status.phase.as_deref())
+        .unwrap_or("Running");
+    let message = format!(
+        "{} cannot change once the restore has started running (phase {}). \
+         Revert the change, or delete and recreate the KafkaRestore.",
+        changed.join(", "),
+        phase
+    );
+    // Every status patch triggers another reconcile; report each edit once
+    let already_reported = restore.status.as_ref().is_some_and(|status| {
+        status.conditions.iter().any(|condition| {
+            condition.type_ == "ImmutableFieldsChanged"
+                && condition.message.as_deref() == Some(message.as_str())
+        })
+    });
+    if already_reported {
+        return Ok(());
+    }
+    warn!(name = %name, changed = ?changed, "Ignoring change to locked restore fields");
+    events::publish_warning(
+        client,
+        restore,
+        "ImmutableFieldChanged",
+        "Restore",
+        &message,
+    )
+    .await;
+
+    let mut conditions: Vec<serde_json::Value> = restore
+        .status
+        .as_ref()
+        .map(|status| {
+            status
+                .conditions
+                .iter()
+                .filter(|condition| condition.type_ != "ImmutableFieldsChanged")
+                .filter_map(|condition| serde_json::to_value(condition).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    conditions.push(json!({
+        "type": "ImmutableFieldsChanged",
+        "status": "True",
+        "lastTransitionTime": Utc::now(),
+        "reason": "ImmutableFieldChanged",
+        "message": message
+    }));
+
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+    api.patch_status(
+        &name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(json!({ "status": { "conditions": conditions } })),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Whether an in-flight restore carries an ImmutableFieldsChanged condition
+/// although its locked fields match the running ones again
+pub fn locked_field_change_reverted(restore: &KafkaRestore) -> bool {
+    let Some(status) = &restore.status else {
+        return false;
+    };
+    status.locked_spec.is_some()
+        && matches!(
+            status.phase.as_deref(),
+            Some(
+                "Running"
+                    | super::PHASE_TIMED_OUT
+                    | super::PHASE_STALLED
+                    | super::PHASE_INTERRUPTED
+            )
+        )
+        && status
+            .conditions
+            .iter()
+            .any(|condition| condition.type_ == "ImmutableFieldsChanged")
+        && changed_locked_fields(restore).is_empty()
+}
+
+/// Drop the ImmutableFieldsChanged condition once the edit was reverted. The
+/// reverted spec is the one in flight, so its generation counts as observed.
+pub async fn clear_locked_field_change(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+) -> Result<()> {
+    let name = restore.name_any();
+    info!(name = %name, "Locked restore fields reverted");
+    let conditions: Vec<serde_json::Value> = restore
+        .status
+        .as_ref()
+        .map(|status| {
+            status
+                .conditions
+                .iter()
+                .filter(|condition| condition.type_ != "ImmutableFieldsChanged")
+                .filter_map(|condition| serde_json::to_value(condition).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+    api.patch_status(
+        &name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(json!({
+            "status": {
+                "conditions": conditions,
+                "observedGeneration": restore.metadata.generation,
+            }
+        })),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Keep following an in-flight restore whose spec edit was ignored: a
+/// running engine is still monitored, while resuming one that is gone (or
+/// retrying a stopped run) waits until the edit is reverted
+pub async fn follow_in_flight(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    shutdown: &CancellationToken,
+) -> Result<Action> {
+    let running = restore
+        .status
+        .as_ref()
+        .is_some_and(|status| status.phase.as_deref() == Some("Running"));
+    if running && !restore_is_orphaned(restore, Utc::now()) {
+        return monitor_progress(restore, client, namespace, shutdown).await;
+    }
+    Ok(Action::await_change())
+}
+
+/// Update status to Failed
+pub async fn update_status_failed(
+    restore: &KafkaRestore,
+    client: &Client,
+    namespace: &str,
+    error_message: &str,
+) -> Result<()> {
+    let name = restore.name_any();
+    let api: Api<KafkaRestore> = Api::namespaced(client.clone(), namespace);
+
+    let recent_errors = super::record_recent_error(restore, "Failed", error_message);
+    let status = json!({
+        "status": {
+            "phase": "Failed",
+            "message": error_message,
+            "recentErrors": recent_errors,
+            "observedGeneration": restore.metadata.generation,
+            "conditions": [{
+                "type": "Ready",
+                "status": "False",
+                "lastTransitionTime": Utc::now(),
+                "reason": "ValidationFailed",
+                "message": error_message
+            }]
+        }
+    });
+
+    api.patch_status(
+        &name,
+        &PatchParams::apply("kafka-backup-operator"),
+        &Patch::Merge(status),
+    )
     .await?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod eta_tests {
+    use super::estimate_eta_ms;
+
+    #[test]
+    fn eta_is_unknown_without_progress() {
+        assert_eq!(estimate_eta_ms(0, 0.0, 0.0), None);
+        assert_eq!(estimate_eta_ms(1_000, 10.0, 0.0), None);
+    }
+
+    #[test]
+    fn eta_extrapolates_remaining_records() {
+        // 1,000 of 4,000 records done at 100 records/sec leaves 30 seconds.
+        assert_eq!(estimate_eta_ms(1_000, 25.0, 100.0), Some(30_000));
+    }
+
+    #[test]
+    fn eta_is_zero_when_complete() {
+        assert_eq!(estimate_eta_ms(4_000, 100.0, 100.0), Some(0));
+    }
+}
+
+#[cfg(test)]
+mod ramp_up_tests {
+    use super::*;
+
+    fn topics(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn ramp_up_climbs_linearly_from_ten_percent() {
+        let ramp_up = Duration::from_secs(600);
+        let rate = |secs| ramp_up_rate(1_000, ramp_up, Duration::from_secs(secs));
+
+        assert_eq!(rate(0), 100);
+        assert_eq!(rate(300), 550);
+        assert_eq!(rate(540), 910);
+        assert_eq!(rate(600), 1_000);
+        assert_eq!(rate(900), 1_000);
+        assert_eq!(ramp_up_rate(5, ramp_up, Duration::ZERO), 1);
+    }
+
+    #[test]
+    fn ramp_passes_take_one_topic_until_the_ramp_is_over() {
+        let pending = topics(&["orders", "payments", "refunds"]);
+        let ramp_up = Duration::from_secs(600);
+
+        assert_eq!(
+            next_ramp_pass(&pending, 1_000, ramp_up, Duration::ZERO),
+            (1, 100)
+        );
+        assert_eq!(
+            next_ramp_pass(&pending, 1_000, ramp_up, Duration::from_secs(300)),
+            (1, 550)
+        );
+        assert_eq!(
+            next_ramp_pass(&pending, 1_000, ramp_up, Duration::from_secs(600)),
+            (3, 1_000)
+        );
+    }
+}
+
+#[cfg(test)]
+mod offsets_only_tests {
+    use super::*;
+
+    #[test]
+    fn stored_offsets_are_renamed_and_scoped_to_restored_topics() {
+        let spec: KafkaRestoreSpec = serde_json::from_value(json!({
+            "backupRef": {"name": "nightly"},
+            "kafkaCluster": {"bootstrapServers": ["localhost:9092"]},
+            "consumerGroupPrefix": "dr-",
+        }))
+        .unwrap();
+        let group_offsets: crate::reconcilers::GroupOffsets = serde_json::from_value(json!({
+            "billing": {"orders": {"0": 42, "1": 7}, "audit": {"0": 3}},
+            "reporting": {"audit": {"0": 9}},
+        }))
+        .unwrap();
+        let topics = vec!["orders".to_string()];
+        let topic_mapping = HashMap::from([("orders".to_string(), "orders-dr".to_string())]);
+
+        let offsets = target_group_offsets(&spec, &topics, &topic_mapping, group_offsets);
+
+        assert_eq!(
+            offsets,
+            BTreeMap::from([(
+                "dr-billing".to_string(),
+                vec![
+                    ("orders-dr".to_string(), 0, 42, None),
+                    ("orders-dr".to_string(), 1, 7, None),
+                ]
+            )])
+        );
+    }
+}
+
+#[cfg(test)]
+mod snapshot_tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn snapshot_expires_after_retention_window() {
+        let taken = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        assert_eq!(
+            snapshot_expiry(taken, 24),
+            Utc.with_ymd_and_hms(2024, 1, 2, 12, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn snapshot_defaults_to_operator_snapshot_directory() {
+        let rollback = RollbackSpec {
+            snapshot_before_restore: true,
+            snapshot_retention_hours: 24,
+            auto_rollback_on_failure: false,
+            snapshot_storage: None,
+        };
+        let dir =
+            get_snapshot_storage_path(build_rollback_config(&rollback).snapshot_path.as_deref());
+        assert_eq!(
+            snapshot_file_path(&dir, "snap-1"),
+            PathBuf::from("/data/snapshots/snap-1.json")
+        );
+    }
+}
+
+#[cfg(test)]
+mod progress_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn stalled_watchdog_aborts_running_restore() {
+        let stalled = CancellationToken::new();
+        stalled.cancel();
+
+        let result = run_until_stalled(
+            &stalled,
+            Some(Duration::from_secs(600)),
+            "Restore demo",
+            std::future::pending::<()>(),
+        )
+        .await;
+        let err = result.unwrap_err();
+        assert!(matches!(err, Error::Stalled(_)));
+        assert!(err.to_string().contains("stallTimeoutSecs=600"));
+    }
+
+    #[tokio::test]
+    async fn stall_deadline_fires_only_with_a_stall_timeout() {
+        let last_advance = tokio::time::Instant::now();
+        let deadline = stall_deadline(last_advance, Some(Duration::from_millis(10)));
+        assert!(tokio::time::timeout(Duration::from_secs(5), deadline)
+            .await
+            .is_ok());
+
+        let no_timeout = stall_deadline(last_advance, None);
+        assert!(tokio::time::timeout(Duration::from_millis(50), no_timeout)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn persisted_progress_round_trips() {
+        let dir = std::env::temp_dir().join(format!("restore-progress-{}", std::process::id()));
+        let path = dir.join("ns-restore.json");
+        let progress = PersistedProgress {
+            records_restored: 1_500,
+            progress_percent: 37.5,
+            finished: false,
+            updated_at: Utc::now(),
+        };
+
+        write_progress(&path, &progress).await.unwrap();
+        assert_eq!(read_progress(&path).await, Some(progress));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn missing_progress_file_reads_as_none() {
+        let path = std::env::temp_dir().join("restore-progress-does-not-exist.json");
+        assert_eq!(read_progress(&path).await, None);
+    }
+
+    #[test]
+    fn progress_file_is_keyed_by_namespace_and_name() {
+        assert_eq!(
+            progress_file_path("kafka", "nightly"),
+            operator_work_dir().join("restores/kafka/nightly.json")
+        );
+        assert_ne!(
+            progress_file_path("a-b", "c"),
+            progress_file_path("a", "b-c")
+        );
+    }
+}
+
+#[cfg(test)]
+mod manifest_tests {
+    use super::*;
+    use kafka_backup_core::manifest::TopicBackup;
+
+    #[test]
+    fn manifest_without_version_is_v1() {
+        let manifest = json!({"backup_id": "nightly-20260101-000000", "topics": []});
+        assert_eq!(manifest_version(&manifest).unwrap(), 1);
+    }
+
+    #[test]
+    fn manifest_version_accepts_numbers_and_numeric_strings() {
+        assert_eq!(manifest_version(&json!({"version": 2})).unwrap(), 2);
+        assert_eq!(manifest_version(&json!({"version": "3"})).unwrap(), 3);
+        assert!(manifest_version(&json!({"version": "next"})).is_err());
+    }
+
+    fn manifest_with_topics(topics: &[&str]) -> BackupManifest {
+        let mut manifest = BackupManifest::new("nightly-20260101-000000".to_string());
+        for topic in topics {
+            manifest.topics.push(TopicBackup {
+                name: topic.to_string(),
+                original_partition_count: Some(3),
+                partitions: Vec::new(),
+            });
+        }
+        manifest
+    }
+
+    #[test]
+    fn restoring_all_topics_covers_the_whole_backup() {
+        let manifest = manifest_with_topics(&["orders", "payments"]);
+        assert_eq!(check_backup_topics(&[], &manifest, "nightly").unwrap(), 2);
+    }
+
+    #[test]
+    fn requested_topics_missing_from_backup_are_rejected() {
+        let manifest = manifest_with_topics(&["payments"]);
+        let requested = vec!["orders".to_string(), "payments".to_string()];
+
+        let err = check_backup_topics(&requested, &manifest, "nightly")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Topics not found in backup 'nightly': orders"));
+    }
+
+    #[test]
+    fn requested_topics_present_in_backup_are_counted() {
+        let manifest = manifest_with_topics(&["orders", "payments"]);
+        let requested = vec!["orders".to_string()];
+        assert_eq!(
+            check_backup_topics(&requested, &manifest, "nightly").unwrap(),
+            1
+        );
+    }
+
+    fn failed_status(backup_id: &str, completed: &[&str]) -> KafkaRestoreStatus {
+        KafkaRestoreStatus {
+            phase: Some("Failed".to_string()),
+            backup_id: Some(backup_id.to_string()),
+            completed_topics: completed.iter().map(|t| t.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn retry_skips_topics_completed_against_the_same_backup() {
+        let status = failed_status("nightly", &["orders"]);
+        assert_eq!(
+            completed_topics_to_skip(false, Some(&status), "nightly"),
+            vec!["orders".to_string()]
+        );
+        assert!(completed_topics_to_skip(true, Some(&status), "nightly").is_empty());
+        assert!(completed_topics_to_skip(false, Some(&status), "weekly").is_empty());
+
+        let completed = KafkaRestoreStatus {
+            phase: Some("Completed".to_string()),
+            ..status
+        };
+        assert!(completed_topics_to_skip(false, Some(&completed), "nightly").is_empty());
+    }
+
+    #[test]
+    fn resumed_run_keeps_the_backup_it_resolved() {
+        let interrupted = KafkaRestoreStatus {
+            phase: Some(crate::reconcilers::PHASE_INTERRUPTED.to_string()),
+            ..failed_status("nightly-20260101-000000", &[])
+        };
+        assert_eq!(
+            run_backup_id(Some(&interrupted)),
+            Some("nightly-20260101-000000")
+        );
+
+        let failed = failed_status("nightly-20260101-000000", &[]);
+        assert_eq!(run_backup_id(Some(&failed)), None);
+        assert_eq!(run_backup_id(None), None);
+    }
+
+    #[test]
+    fn remaining_topics_drop_completed_ones() {
+        let manifest = manifest_with_topics(&["orders", "payments", "refunds"]);
+        let completed = vec!["orders".to_string()];
+
+        assert_eq!(
+            remaining_topics(&[], &manifest, &completed),
+            Some(vec!["payments".to_string(), "refunds".to_string()])
+        );
+        assert_eq!(
+            remaining_topics(&["orders".to_string()], &manifest, &completed),
+            None
+        );
+    }
+
+    #[test]
+    fn replication_factor_must_fit_on_target_brokers() {
+        assert_eq!(applied_replication_factor(3, 3, false).unwrap(), 3);
+        let err = applied_replication_factor(3, 1, false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("defaultReplicationFactor 3 exceeds the 1 brokers"));
+    }
+
+    #[test]
+    fn replication_factor_downscales_to_broker_count_when_allowed() {
+        assert_eq!(applied_replication_factor(3, 1, true).unwrap(), 1);
+        assert_eq!(applied_replication_factor(2, 5, true).unwrap(), 2);
+        assert_eq!(
+            applied_replication_factor(BROKER_DEFAULT_REPLICATION_FACTOR, 1, false).unwrap(),
+            BROKER_DEFAULT_REPLICATION_FACTOR
+        );
+
+        let condition = replication_factor_reduced_condition(&["orders".to_string()], 3, 1);
+        assert_eq!(condition["type"], "ReplicationFactorReduced");
+        assert!(condition["message"]
+            .as_str()
+            .unwrap()
+            .contains("orders (requested RF 3 -> applied RF 1)"));
+    }
+
+    #[test]
+    fn header_based_offsets_need_offset_headers_in_backup() {
+        assert!(check_offset_headers_enabled("nightly", true).is_ok());
+        let err = check_offset_headers_enabled("nightly", false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("KafkaBackup 'nightly' to set includeOffsetHeaders: true"));
+    }
+
+    #[test]
+    fn denied_topic_operations_name_topic_and_operation() {
+        let describe_only = 1 << ACL_DESCRIBE;
+        let describe_write = describe_only | 1 << ACL_WRITE;
+        let authorized = AuthorizedOperations {
+            cluster: Some(describe_only),
+            topics: BTreeMap::from([
+                (
+                    "orders".to_string(),
+                    TopicAccess::Exists(Some(describe_write)),
+                ),
+                (
+                    "payments".to_string(),
+                    TopicAccess::Exists(Some(describe_only)),
+                ),
+                ("secret".to_string(), TopicAccess::DescribeDenied),
+                ("legacy".to_string(), TopicAccess::Exists(None)),
+                (
+                    "team-a.events".to_string(),
+                    TopicAccess::Missing(Some(describe_only | 1 << ACL_CREATE)),
+                ),
+            ]),
+        };
+        let topics: Vec<String> = [
+            "legacy",
+            "new",
+            "orders",
+            "payments",
+            "secret",
+            "team-a.events",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        assert_eq!(
+            denied_topic_operations(&topics, &authorized, false),
+            vec![
+                "Write on topic 'payments'".to_string(),
+                "Describe on topic 'secret'".to_string(),
+            ]
+        );
+        let denied = denied_topic_operations(&topics, &authorized, true);
+        assert!(denied.contains(&"Create on topic 'new'".to_string()));
+        // A prefixed topic ACL grants Create without cluster Create
+        assert!(!denied.contains(&"Create on topic 'team-a.events'".to_string()));
+    }
+
+    fn pitr(start: Option<i64>, end: Option<i64>) -> ResolvedPitrConfig {
+        ResolvedPitrConfig {
+            start_timestamp_ms: start,
+            end_timestamp_ms: end,
+        }
+    }
+
+    #[test]
+    fn pitr_window_inside_backup_data_passes() {
+        let window = pitr(Some(2_000), Some(3_000));
+        assert_eq!(
+            check_pitr_window(Some(&window), Some((1_000, 4_000))).unwrap(),
+            None
+        );
+        assert_eq!(check_pitr_window(Some(&window), None).unwrap(), None);
+    }
+
+    #[test]
+    fn pitr_window_outside_backup_data_is_rejected() {
+        let window = pitr(Some(5_000), Some(6_000));
+        let err = check_pitr_window(Some(&window), Some((1_000, 4_000)))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(
+            "PITR window [1970-01-01T00:00:05+00:00,1970-01-01T00:00:06+00:00] does not overlap \
+             backup data [1970-01-01T00:00:01+00:00,1970-01-01T00:00:04+00:00]"
+        ));
+    }
+
+    #[test]
+    fn pitr_window_partly_outside_backup_data_warns() {
+        let window = pitr(Some(3_000), None);
+        let note = check_pitr_window(Some(&window), Some((1_000, 4_000)))
+            .unwrap()
+            .unwrap();
+        assert!(note.contains("only partly overlaps"));
+    }
+
+    #[test]
+    fn backup_older_than_max_age_is_rejected() {
+        let now = DateTime::<Utc>::from_timestamp_millis(100 * 3_600_000).unwrap();
+        let created_at = 70 * 3_600_000;
+
+        assert!(check_backup_age("nightly", created_at, None, now).is_ok());
+        assert!(check_backup_age("nightly", created_at, Some(30), now).is_ok());
+        let err = check_backup_age("nightly", created_at, Some(24), now)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("is 30h old, exceeding maxBackupAgeHours 24"));
+    }
+
+    #[test]
+    fn segment_size_mismatch_names_the_segment() {
+        assert!(check_segment_size("nightly/topics/orders/partition=0/segment-1", 10, 10).is_ok());
+        let err = check_segment_size("nightly/topics/orders/partition=0/segment-1", 4, 10)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'nightly/topics/orders/partition=0/segment-1' is 4 bytes"));
+    }
+
+    #[test]
+    fn segment_checksum_mismatch_names_the_segment() {
+        let key = "nightly/topics/orders/partition=0/segment-1";
+        let recorded = super::super::backup::segment_checksum(b"segment-data");
+        assert!(check_segment_checksum(key, b"segment-data", &recorded).is_ok());
+
+        let err = check_segment_checksum(key, b"segment-dat4", &recorded)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(&format!("Segment '{}' has checksum", key)));
+        assert!(err.contains(&format!("but {} in the backup's checksums", recorded)));
+    }
+
+    #[test]
+    fn unsupported_manifest_version_is_rejected_with_supported_range() {
+        assert!(check_manifest_version(1).is_ok());
+        let err = check_manifest_version(2).unwrap_err().to_string();
+        assert!(err.contains("backup format v2 not supported by this operator (supports v1)"));
+    }
+}
+
+#[cfg(test)]
+mod partition_tests {
+    use super::*;
+
+    fn target_partitions(topics: &[(&str, usize)]) -> BTreeMap<String, usize> {
+        topics
+            .iter()
+            .map(|(topic, partitions)| (topic.to_string(), *partitions))
+            .collect()
+    }
+
+    #[test]
+    fn preview_shows_value_sizes_and_only_opted_in_values() {
+        let value = &b"{\"total\":12}"[..];
+        let record = preview_record(
+            2,
+            41,
+            1_700_000_000_000,
+            Some(&b"order-7"[..]),
+            Some(value),
+            false,
+            None,
+        );
+        assert_eq!(
+            record,
+            PreviewRecord {
+                partition: 2,
+                offset: 41,
+                timestamp: 1_700_000_000_000,
+                key: Some("order-7".to_string()),
+                target_partition: None,
+                value_bytes: 12,
+                value: None,
+            }
+        );
+
+        let record = preview_record(0, 0, 0, None, Some(value), true, Some(6));
+        assert_eq!(record.key, None);
+        assert_eq!(record.target_partition, None);
+        assert_eq!(record.value.as_deref(), Some("{\"total\":12}"));
+        assert_eq!(
+            preview_record(0, 0, 0, None, None, true, None).value_bytes,
+            0
+        );
+    }
+
+    #[test]
+    fn preview_routes_keys_like_the_producer() {
+        // The Java client puts "abc" on partition 3 of 6, "foobar" on 6 of 12
+        let route = |key: &[u8], partitions| {
+            preview_record(0, 0, 0, Some(key), None, false, Some(partitions)).target_partition
+        };
+        assert_eq!(route(b"abc", 6), Some(3));
+        assert_eq!(route(b"foobar", 12), Some(6));
+    }
+
+    #[test]
+    fn preview_text_is_truncated_and_escaped() {
+        assert_eq!(preview_text(b"line\nbreak"), "line\\nbreak");
+        assert_eq!(preview_text(&[0xff, b'a']), "\u{fffd}a");
+        let long = "x".repeat(PREVIEW_TEXT_CHARS + 1);
+        assert_eq!(
+            preview_text(long.as_bytes()),
+            format!("{}...", "x".repeat(PREVIEW_TEXT_CHARS))
+        );
+    }
+
+    #[tokio::test]
+    async fn preview_reads_segments_written_by_core() {
+        use crate::adapters::LocalStorageConfig;
+        use kafka_backup_core::config::CompressionType;
+        use kafka_backup_core::segment::SegmentWriter;
+
+        let dir = tempfile::tempdir().unwrap();
+        let storage = ResolvedStorage::Local(LocalStorageConfig {
+            path: dir.path().to_string_lossy().to_string(),
+        });
+        let backend = retention::create_backend(&storage).unwrap();
+
+        for (compression, key) in [
+            (
+                CompressionType::None,
+                "nightly/topics/orders/partition=0/segment-0001.bin",
+            ),
+            (
+                CompressionType::Lz4,
+                "nightly/topics/orders/partition=0/segment-0002.bin.lz4",
+            ),
+            (
+                CompressionType::Zstd,
+                "nightly/topics/orders/partition=0/segment-0003.bin.zst",
+            ),
+        ] {
+            let mut writer = SegmentWriter::new(compression);
+            for offset in 0..3 {
+                writer
+                    .write(&BackupRecord {
+                        key: Some(format!("order-{offset}").into_bytes()),
+                        value: Some(b"{\"total\":12}".to_vec()),
+                        headers: Vec::new(),
+                        timestamp: 1_700_000_000_000 + offset,
+                        offset,
+                    })
+                    .unwrap();
+            }
+            backend.put(key, writer.finish().unwrap()).await.unwrap();
+
+            let records = read_segment(backend.as_ref(), key).await.unwrap();
+            assert_eq!(
+                records
+                    .iter()
+                    .map(|record| record.offset)
+                    .collect::<Vec<_>>(),
+                vec![0, 1, 2],
+                "{key}"
+            );
+            let preview = preview_record(
+                0,
+                records[1].offset,
+                records[1].timestamp,
+                records[1].key.as_deref(),
+                records[1].value.as_deref(),
+                true,
+                None,
+            );
+            assert_eq!(preview.key.as_deref(), Some("order-1"));
+            assert_eq!(preview.value.as_deref(), Some("{\"total\":12}"));
+            assert_eq!(preview.value_bytes, 12);
+        }
+    }
+
+    #[test]
+    fn topic_drift_reports_missing_and_resized_topics() {
+        let topics = vec![
+            ("orders".to_string(), 6),
+            ("payments".to_string(), 3),
+            ("audit".to_string(), 1),
+        ];
+        let target = target_partitions(&[("orders", 3), ("payments", 3)]);
+
+        assert_eq!(
+            topic_drift(&topics, &target),
+            vec![
+                "orders: target partitions=3, backup=6".to_string(),
+                "audit: missing on target".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn matching_partition_counts_need_no_folds() {
+        let topics = vec![("orders".to_string(), 3)];
+        let folds = plan_partition_folds(
+            &topics,
+            &target_partitions(&[("orders", 3)]),
+            &HashMap::new(),
+            false,
+        )
+        .unwrap();
+        assert!(folds.is_empty());
+    }
+
+    #[test]
+    fn missing_target_partitions_fail_without_downscale() {
+        let topics = vec![("orders".to_string(), 6)];
+        let err = plan_partition_folds(
+            &topics,
+            &target_partitions(&[("orders", 4)]),
+            &HashMap::new(),
+            false,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("orders (has 4 partitions, restore writes up to partition 5)"));
+        assert!(err.contains("allowPartitionDownscale"));
+    }
+
+    #[test]
+    fn downscale_folds_extra_partitions_by_modulo() {
+        let topics = vec![("orders".to_string(), 6)];
+        let folds = plan_partition_folds(
+            &topics,
+            &target_partitions(&[("orders", 4)]),
+            &HashMap::new(),
+            true,
+        )
+        .unwrap();
+        assert_eq!(folds["orders"], BTreeMap::from([(4, 0), (5, 1)]));
+
+        let mapping = fold_partition_mapping(&topics, &folds, &HashMap::new()).unwrap();
+        assert_eq!(mapping, HashMap::from([(4, 0), (5, 1)]));
+    }
+
+    #[test]
+    fn partition_mapping_is_applied_before_the_check() {
+        let topics = vec![("orders".to_string(), 2)];
+        let folds = plan_partition_folds(
+            &topics,
+            &target_partitions(&[("orders", 2)]),
+            &HashMap::from([(1, 0)]),
+            false,
+        )
+        .unwrap();
+        assert!(folds.is_empty());
+    }
+
+    #[test]
+    fn topics_missing_from_target_are_skipped() {
+        let topics = vec![("orders".to_string(), 6)];
+        let folds =
+            plan_partition_folds(&topics, &BTreeMap::new(), &HashMap::new(), false).unwrap();
+        assert!(folds.is_empty());
+    }
+
+    #[test]
+    fn conflicting_folds_across_topics_are_rejected() {
+        let topics = vec![("orders".to_string(), 4), ("payments".to_string(), 4)];
+        let folds = plan_partition_folds(
+            &topics,
+            &target_partitions(&[("orders", 2), ("payments", 4)]),
+            &HashMap::new(),
+            true,
+        )
+        .unwrap();
+
+        let err = fold_partition_mapping(&topics, &folds, &HashMap::new())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("restore these topics separately"));
+    }
+}
+
+#[cfg(test)]
+mod locked_spec_tests {
+    use super::*;
+
+    fn restore(phase: &str) -> KafkaRestore {
+        let spec: KafkaRestoreSpec = serde_json::from_value(json!({
+            "backupRef": {"name": "nightly"},
+            "kafkaCluster": {"bootstrapServers": ["localhost:9092"]},
+            "topics": ["orders"],
+        }))
+        .unwrap();
+        let mut restore = KafkaRestore::new("restore", spec.clone());
+        restore.status = Some(KafkaRestoreStatus {
+            phase: Some(phase.to_string()),
+            locked_spec: Some(LockedRestoreSpec::from_spec(&spec)),
+            ..Default::default()
+        });
+        restore
+    }
+
+    #[test]
+    fn unchanged_spec_has_no_locked_changes() {
+        assert!(changed_locked_fields(&restore("Running")).is_empty());
+    }
+
+    #[test]
+    fn in_flight_restore_reports_changed_locked_fields() {
+        let mut restore = restore(crate::reconcilers::PHASE_TIMED_OUT);
+        restore.spec.backup_ref.name = "weekly".to_string();
+        restore.spec.topics.push("payments".to_string());
+        restore.spec.produce_acks = 1;
+
+        assert_eq!(changed_locked_fields(&restore), vec!["backupRef", "topics"]);
+    }
+
+    #[test]
+    fn terminal_restore_may_change_locked_fields() {
+        for phase in ["Completed", "Failed", "RolledBack", "Validating"] {
+            let mut restore = restore(phase);
+            restore.spec.kafka_cluster.bootstrap_servers = vec!["dr:9092".to_string()];
+            assert!(changed_locked_fields(&restore).is_empty(), "{}", phase);
+        }
+    }
+
+    #[test]
+    fn reverted_edit_is_detected_by_its_leftover_condition() {
+        let mut restore = restore("Running");
+        assert!(!locked_field_change_reverted(&restore));
+
+        let status = restore.status.as_mut().unwrap();
+        status.conditions.push(crate::crd::Condition {
+            type_: "ImmutableFieldsChanged".to_string(),
+            status: "True".to_string(),
+            last_transition_time: Utc::now(),
+            reason: Some("ImmutableFieldChanged".to_string()),
+            message: None,
+        });
+        assert!(locked_field_change_reverted(&restore));
+
+        restore.spec.topics.push("payments".to_string());
+        assert!(!locked_field_change_reverted(&restore));
+    }
+}
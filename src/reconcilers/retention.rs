@@ -4,7 +4,7 @@
 //! segments from a manifest because that can create PITR gaps that are hard to
 //! detect during restore.
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -14,7 +14,9 @@ use kafka_backup_core::storage::{
 };
 use tracing::{debug, info, warn};
 
-use crate::adapters::{AzureAuthMethod, ResolvedStorage};
+use crate::adapters::{
+    backup_date_partition, split_date_partition, AzureAuthMethod, ResolvedStorage,
+};
 use crate::crd::RetentionSpec;
 use crate::error::{Error, Result};
 
@@ -27,10 +29,21 @@ pub struct BackupSet {
     pub backup_id: String,
     /// Manifest creation timestamp in epoch milliseconds.
     pub created_at: i64,
+    /// Date partition (`YYYY/MM/DD/`) the set was written under, empty for
+    /// the flat layout.
+    pub partition: String,
     /// Storage keys that belong to this backup set.
     pub keys: Vec<String>,
-    /// Total bytes occupied by known keys in this backup set.
-    pub bytes: u64,
+}
+
+/// A KafkaRestore that still reads from this backup's storage.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RestoreReference {
+    /// `namespace/name` of the restore.
+    pub restore: String,
+    /// Backup set the restore reads. `None` until the restore has resolved one,
+    /// in which case every set is treated as in use.
+    pub backup_id: Option<String>,
 }
 
 /// Result from a retention pruning run.
@@ -48,6 +61,16 @@ pub struct RetentionReport {
     pub reclaimed_bytes: u64,
     /// Whether the run only reported what would be deleted.
     pub dry_run: bool,
+    /// Restores that kept eligible backup sets from being deleted.
+    pub blocked_by: Vec<String>,
+}
+
+impl RetentionReport {
+    /// Backup sets still in storage once the run finished. A dry run deletes
+    /// nothing, so every inspected set remains.
+    pub fn remaining_backups(&self) -> u64 {
+        self.inspected_backups.saturating_sub(self.deleted_backups)
+    }
 }
 
 /// Validate a retention spec. Disabled retention is always valid.
@@ -88,6 +111,7 @@ pub async fn apply_retention(
     retention: Option<&RetentionSpec>,
     storage: &ResolvedStorage,
     current_backup_id: Option<&str>,
+    in_use: &[RestoreReference],
 ) -> Result<Option<RetentionReport>> {
     let Some(retention) = retention else {
         return Ok(None);
@@ -104,9 +128,11 @@ pub async fn apply_retention(
     let now_ms = chrono::Utc::now().timestamp_millis();
     let report = apply_retention_with_backend(
         backend.as_ref(),
+        storage,
         backup_name,
         retention,
         current_backup_id,
+        in_use,
         now_ms,
         local_root.as_deref(),
     )
@@ -117,22 +143,27 @@ pub async fn apply_retention(
 
 async fn apply_retention_with_backend(
     backend: &dyn StorageBackend,
+    storage: &ResolvedStorage,
     backup_name: &str,
     retention: &RetentionSpec,
     current_backup_id: Option<&str>,
+    in_use: &[RestoreReference],
     now_ms: i64,
     local_root: Option<&Path>,
 ) -> Result<RetentionReport> {
-    let backup_sets = discover_backup_sets(backend, backup_name).await?;
+    let keys = list_backup_keys(backend, storage, backup_name).await?;
+    let backup_sets = discover_backup_sets(backend, &keys, Some(backup_name)).await?;
     let delete_ids = plan_deletions(&backup_sets, retention, current_backup_id, now_ms);
     let eligible_backups = delete_ids.len() as u64;
     let dry_run = retention.dry_run;
+    let (delete_ids, blocked_by) = exclude_in_use(delete_ids, in_use);
 
     let mut report = RetentionReport {
         inspected_backups: backup_sets.len() as u64,
         eligible_backups,
-        retained_backups: backup_sets.len().saturating_sub(delete_ids.len()) as u64,
+        retained_backups: backup_sets.len().saturating_sub(eligible_backups as usize) as u64,
         dry_run,
+        blocked_by,
         ..Default::default()
     };
 
@@ -141,20 +172,22 @@ async fn apply_retention_with_backend(
         .iter()
         .filter(|backup_set| delete_set.contains(backup_set.backup_id.as_str()))
     {
+        // Only sets picked for deletion are sized, not every set inspected
+        let bytes = measure_backup_set(backend, &backup_set.keys).await;
         if dry_run {
             info!(
                 backup_id = %backup_set.backup_id,
-                bytes = backup_set.bytes,
+                bytes,
                 "Retention dry run would delete backup set"
             );
-            report.reclaimed_bytes += backup_set.bytes;
+            report.reclaimed_bytes += bytes;
             continue;
         }
 
         info!(
             backup_id = %backup_set.backup_id,
             keys = backup_set.keys.len(),
-            bytes = backup_set.bytes,
+            bytes,
             "Deleting backup set due to retention policy"
         );
 
@@ -168,20 +201,56 @@ async fn apply_retention_with_backend(
         cleanup_local_backup_dir(local_root, &backup_set.backup_id)?;
 
         report.deleted_backups += 1;
-        report.reclaimed_bytes += backup_set.bytes;
+        report.reclaimed_bytes += bytes;
     }
 
     Ok(report)
 }
 
-fn local_storage_root(storage: &ResolvedStorage) -> Option<PathBuf> {
+/// Drop backup sets that restores still read from the deletion list. Returns
+/// the remaining IDs and the sorted names of the restores that held sets back.
+fn exclude_in_use(
+    delete_ids: Vec<String>,
+    in_use: &[RestoreReference],
+) -> (Vec<String>, Vec<String>) {
+    let mut blocked_by = BTreeSet::new();
+    let delete_ids = delete_ids
+        .into_iter()
+        .filter(|backup_id| {
+            let users: Vec<&str> = in_use
+                .iter()
+                .filter(|reference| {
+                    reference
+                        .backup_id
+                        .as_deref()
+                        .map_or(true, |id| id == backup_id)
+                })
+                .map(|reference| reference.restore.as_str())
+                .collect();
+            if users.is_empty() {
+                return true;
+            }
+            warn!(
+                backup_id = %backup_id,
+                restores = ?users,
+                "Keeping backup set still used by restores"
+            );
+            blocked_by.extend(users.into_iter().map(str::to_string));
+            false
+        })
+        .collect();
+
+    (delete_ids, blocked_by.into_iter().collect())
+}
+
+pub(super) fn local_storage_root(storage: &ResolvedStorage) -> Option<PathBuf> {
     match storage {
         ResolvedStorage::Local(local) => Some(PathBuf::from(&local.path)),
         _ => None,
     }
 }
 
-fn cleanup_local_backup_dir(local_root: Option<&Path>, backup_id: &str) -> Result<()> {
+pub(super) fn cleanup_local_backup_dir(local_root: Option<&Path>, backup_id: &str) -> Result<()> {
     let Some(local_root) = local_root else {
         return Ok(());
     };
@@ -258,15 +327,162 @@ pub fn plan_deletions(
         .collect()
 }
 
-async fn discover_backup_sets(
+/// Backup ID keyword selecting the most recent backup set in storage
+pub const LATEST_BACKUP_ID: &str = "latest";
+
+/// Backup ID keyword selecting the oldest backup set in storage
+pub const OLDEST_BACKUP_ID: &str = "oldest";
+
+/// List the completed backup sets in storage, oldest first. With a
+/// `backup_name`, only sets written by that KafkaBackup resource are returned.
+/// Only manifests are read; sets a size-limit abort marked are left out.
+pub(crate) async fn list_backup_sets(
+    storage: &ResolvedStorage,
+    backup_name: Option<&str>,
+) -> Result<Vec<BackupSet>> {
+    let backend = create_backend(storage)?;
+    let keys = list_backup_keys(backend.as_ref(), storage, backup_name.unwrap_or_default()).await?;
+    let backup_sets = discover_backup_sets(backend.as_ref(), &keys, backup_name).await?;
+    Ok(backup_sets
+        .into_iter()
+        .filter(|backup_set| {
+            let marker = format!(
+                "{}{}/{}",
+                backup_set.partition,
+                backup_set.backup_id,
+                super::gc::ABORTED_MARKER
+            );
+            !backup_set.keys.contains(&marker)
+        })
+        .collect())
+}
+
+/// Index of the date partitions backups were written to, one empty marker
+/// object per partition (e.g. `_date-partitions/2026/01/31`)
+const DATE_PARTITION_INDEX: &str = "_date-partitions/";
+
+/// Record the date partition `backup_id` is written under in the index, so
+/// listings visit that partition instead of the whole bucket. Nothing is
+/// recorded for the flat layout.
+pub(crate) async fn record_date_partition(
+    storage: &ResolvedStorage,
+    backup_id: &str,
+) -> Result<()> {
+    let Some(partition) = backup_date_partition(backup_id).filter(|_| storage.date_layout()) else {
+        return Ok(());
+    };
+    let marker = format!(
+        "{}{}",
+        DATE_PARTITION_INDEX,
+        partition.trim_end_matches('/')
+    );
+    create_backend(storage)?
+        .put(&marker, Vec::new().into())
+        .await
+        .map_err(|e| {
+            Error::storage(format!(
+                "Failed to record date partition '{}': {}",
+                marker, e
+            ))
+        })
+}
+
+/// Keys that include every set `backup_name` wrote, and possibly sets of
+/// other backups sharing the listing. Object stores list the flat layout
+/// below the backup's own name; with a date layout they also list each
+/// recorded partition in turn. Local directories are listed by path rather
+/// than key prefix, so they list everything.
+pub(super) async fn list_backup_keys(
     backend: &dyn StorageBackend,
+    storage: &ResolvedStorage,
     backup_name: &str,
-) -> Result<Vec<BackupSet>> {
-    let keys = backend
-        .list("")
+) -> Result<Vec<String>> {
+    if matches!(storage, ResolvedStorage::Local(_)) {
+        return list_keys(backend, "").await;
+    }
+
+    let mut keys = Vec::new();
+    if storage.date_layout() {
+        keys.extend(list_date_partitions(backend).await?);
+    }
+    // Sets written before dateLayout was enabled stay below the root; an
+    // unnamed listing has no prefix to narrow it, so it only sees partitions
+    if !backup_name.is_empty() || !storage.date_layout() {
+        keys.extend(list_keys(backend, backup_name).await?);
+    }
+    Ok(keys)
+}
+
+/// Keys in every date partition recorded in the index
+async fn list_date_partitions(backend: &dyn StorageBackend) -> Result<Vec<String>> {
+    let mut keys = Vec::new();
+    for marker in list_keys(backend, DATE_PARTITION_INDEX).await? {
+        let partition = match marker.strip_prefix(DATE_PARTITION_INDEX) {
+            Some(partition) if !partition.is_empty() => partition,
+            _ => continue,
+        };
+        keys.extend(list_keys(backend, &format!("{}/", partition)).await?);
+    }
+    Ok(keys)
+}
+
+pub(super) async fn list_keys(backend: &dyn StorageBackend, prefix: &str) -> Result<Vec<String>> {
+    backend
+        .list(prefix)
         .await
-        .map_err(|e| Error::storage(format!("Failed to list backup storage: {}", e)))?;
+        .map_err(|e| Error::storage(format!("Failed to list backup storage: {}", e)))
+}
+
+/// Resolve a requested backup ID against the backup sets in storage. No ID or
+/// `latest` picks the most recent set, `oldest` the earliest one; any other
+/// ID must name an existing set.
+pub fn select_backup_id(requested: Option<&str>, backup_sets: &[BackupSet]) -> Result<String> {
+    let requested = requested.unwrap_or(LATEST_BACKUP_ID);
+    let selected = match requested {
+        LATEST_BACKUP_ID => backup_sets
+            .iter()
+            .max_by_key(|backup_set| backup_set.created_at),
+        OLDEST_BACKUP_ID => backup_sets
+            .iter()
+            .min_by_key(|backup_set| backup_set.created_at),
+        backup_id => backup_sets
+            .iter()
+            .find(|backup_set| backup_set.backup_id == backup_id),
+    };
+
+    match selected {
+        Some(backup_set) => Ok(backup_set.backup_id.clone()),
+        None if backup_sets.is_empty() => Err(Error::BackupNotFound(
+            "no backups found in storage".to_string(),
+        )),
+        None => Err(Error::BackupNotFound(format!(
+            "backup '{}' not found in storage",
+            requested
+        ))),
+    }
+}
 
+/// Storage rooted where the backup set `backup_id` was written, searching
+/// across date partitions. Unchanged if no listed set has that ID.
+pub fn backup_set_storage(
+    storage: &ResolvedStorage,
+    backup_sets: &[BackupSet],
+    backup_id: &str,
+) -> ResolvedStorage {
+    match backup_sets
+        .iter()
+        .find(|backup_set| backup_set.backup_id == backup_id)
+    {
+        Some(backup_set) => storage.with_partition(&backup_set.partition),
+        None => storage.clone(),
+    }
+}
+
+async fn discover_backup_sets(
+    backend: &dyn StorageBackend,
+    keys: &[String],
+    backup_name: Option<&str>,
+) -> Result<Vec<BackupSet>> {
     let mut backup_sets = Vec::new();
 
     for manifest_key in keys.iter().filter(|key| key.ends_with("/manifest.json")) {
@@ -286,7 +502,9 @@ async fn discover_backup_sets(
             }
         };
 
-        if !is_owned_backup_id(backup_name, &manifest.backup_id) {
+        if let Some(backup_name) =
+            backup_name.filter(|backup_name| !is_owned_backup_id(backup_name, &manifest.backup_id))
+        {
             debug!(
                 backup_id = %manifest.backup_id,
                 backup_name = %backup_name,
@@ -295,31 +513,20 @@ async fn discover_backup_sets(
             continue;
         }
 
-        let prefix = format!("{}/", manifest.backup_id);
-        let offset_db = format!("{}-offsets.db", manifest.backup_id);
+        let (partition, _) = split_date_partition(manifest_key);
+        let prefix = format!("{}{}/", partition, manifest.backup_id);
+        let offset_db = format!("{}{}-offsets.db", partition, manifest.backup_id);
         let set_keys: Vec<String> = keys
             .iter()
             .filter(|key| key.starts_with(&prefix) || *key == &offset_db)
             .cloned()
             .collect();
 
-        let mut bytes = 0;
-        for key in &set_keys {
-            match backend.size(key).await {
-                Ok(size) => bytes += size,
-                Err(e) => warn!(
-                    key = %key,
-                    error = %e,
-                    "Could not determine object size during retention scan"
-                ),
-            }
-        }
-
         backup_sets.push(BackupSet {
             backup_id: manifest.backup_id,
             created_at: manifest.created_at,
+            partition: partition.to_string(),
             keys: set_keys,
-            bytes,
         });
     }
 
@@ -327,7 +534,23 @@ async fn discover_backup_sets(
     Ok(backup_sets)
 }
 
-fn is_owned_backup_id(backup_name: &str, backup_id: &str) -> bool {
+/// Total bytes occupied by `keys`, skipping objects whose size is unknown
+async fn measure_backup_set(backend: &dyn StorageBackend, keys: &[String]) -> u64 {
+    let mut bytes = 0;
+    for key in keys {
+        match backend.size(key).await {
+            Ok(size) => bytes += size,
+            Err(e) => warn!(
+                key = %key,
+                error = %e,
+                "Could not determine object size during retention scan"
+            ),
+        }
+    }
+    bytes
+}
+
+pub(super) fn is_owned_backup_id(backup_name: &str, backup_id: &str) -> bool {
     if backup_id == backup_name {
         return true;
     }
@@ -350,7 +573,8 @@ fn is_generated_backup_suffix(suffix: &str) -> bool {
             .all(|(index, byte)| index == 8 || byte.is_ascii_digit())
 }
 
-fn create_backend(storage: &ResolvedStorage) -> Result<Arc<dyn StorageBackend>> {
+/// Open the storage backend a resolved storage config points at
+pub(crate) fn create_backend(storage: &ResolvedStorage) -> Result<Arc<dyn StorageBackend>> {
     let config = match storage {
         ResolvedStorage::Local(local) => StorageBackendConfig::Filesystem {
             path: local.path.clone().into(),
@@ -362,12 +586,8 @@ fn create_backend(storage: &ResolvedStorage) -> Result<Arc<dyn StorageBackend>>
             access_key: Some(s3.access_key_id.clone()),
             secret_key: Some(s3.secret_access_key.clone()),
             prefix: s3.prefix.clone(),
-            path_style: false,
-            allow_http: s3
-                .endpoint
-                .as_ref()
-                .map(|endpoint| endpoint.starts_with("http://"))
-                .unwrap_or(false),
+            path_style: s3.path_style,
+            allow_http: s3.allow_http,
         },
         ResolvedStorage::Azure(azure) => {
             let (
@@ -445,8 +665,8 @@ mod tests {
         BackupSet {
             backup_id: backup_id.to_string(),
             created_at,
+            partition: String::new(),
             keys: Vec::new(),
-            bytes: 0,
         }
     }
 
@@ -633,6 +853,7 @@ mod tests {
             Some(&retention(None, Some(1))),
             &local_storage(dir.path()),
             Some("demo-20260103-000000"),
+            &[],
         )
         .await
         .unwrap()
@@ -641,6 +862,7 @@ mod tests {
         assert_eq!(report.inspected_backups, 3);
         assert_eq!(report.eligible_backups, 2);
         assert_eq!(report.deleted_backups, 2);
+        assert_eq!(report.remaining_backups(), 1);
         assert!(report.reclaimed_bytes > 0);
 
         assert!(!dir.path().join("demo-20260101-000000").exists());
@@ -652,6 +874,51 @@ mod tests {
             .exists());
     }
 
+    #[tokio::test]
+    async fn local_retention_keeps_backup_sets_used_by_restores() {
+        let dir = tempdir().unwrap();
+        write_backup_set(dir.path(), "demo-20260101-000000", 1);
+        write_backup_set(dir.path(), "demo-20260102-000000", 2);
+        write_backup_set(dir.path(), "demo-20260103-000000", 3);
+
+        let in_use = vec![RestoreReference {
+            restore: "default/restore-orders".to_string(),
+            backup_id: Some("demo-20260101-000000".to_string()),
+        }];
+        let report = apply_retention(
+            "demo",
+            Some(&retention(None, Some(1))),
+            &local_storage(dir.path()),
+            Some("demo-20260103-000000"),
+            &in_use,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(report.eligible_backups, 2);
+        assert_eq!(report.deleted_backups, 1);
+        assert_eq!(report.blocked_by, vec!["default/restore-orders"]);
+        assert!(dir
+            .path()
+            .join("demo-20260101-000000/manifest.json")
+            .exists());
+        assert!(!dir.path().join("demo-20260102-000000").exists());
+    }
+
+    #[test]
+    fn unresolved_restore_reference_blocks_every_set() {
+        let in_use = vec![RestoreReference {
+            restore: "default/restore-latest".to_string(),
+            backup_id: None,
+        }];
+        let (delete_ids, blocked_by) =
+            exclude_in_use(vec!["demo-1".to_string(), "demo-2".to_string()], &in_use);
+
+        assert!(delete_ids.is_empty());
+        assert_eq!(blocked_by, vec!["default/restore-latest"]);
+    }
+
     #[tokio::test]
     async fn local_retention_dry_run_reports_without_deleting() {
         let dir = tempdir().unwrap();
@@ -661,13 +928,14 @@ mod tests {
         let mut spec = retention(None, Some(1));
         spec.dry_run = true;
 
-        let report = apply_retention("demo", Some(&spec), &local_storage(dir.path()), None)
+        let report = apply_retention("demo", Some(&spec), &local_storage(dir.path()), None, &[])
             .await
             .unwrap()
             .unwrap();
 
         assert_eq!(report.eligible_backups, 1);
         assert_eq!(report.deleted_backups, 0);
+        assert_eq!(report.remaining_backups(), 2);
         assert!(report.dry_run);
         assert!(report.reclaimed_bytes > 0);
         assert!(dir
@@ -693,6 +961,7 @@ mod tests {
             Some(&retention(None, Some(1))),
             &local_storage(dir.path()),
             None,
+            &[],
         )
         .await
         .unwrap()
@@ -725,6 +994,7 @@ mod tests {
             Some(&retention(None, Some(1))),
             &local_storage(dir.path()),
             None,
+            &[],
         )
         .await
         .unwrap()
@@ -734,4 +1004,119 @@ mod tests {
         assert_eq!(report.deleted_backups, 0);
         assert!(invalid_dir.join("manifest.json").exists());
     }
+
+    #[test]
+    fn backup_selection_resolves_keywords_by_creation_time() {
+        let backups = vec![
+            backup_set("demo-20260102-000000", 2),
+            backup_set("demo-20260101-000000", 1),
+            backup_set("demo-20260103-000000", 3),
+        ];
+
+        assert_eq!(
+            select_backup_id(None, &backups).unwrap(),
+            "demo-20260103-000000"
+        );
+        assert_eq!(
+            select_backup_id(Some("latest"), &backups).unwrap(),
+            "demo-20260103-000000"
+        );
+        assert_eq!(
+            select_backup_id(Some("oldest"), &backups).unwrap(),
+            "demo-20260101-000000"
+        );
+        assert_eq!(
+            select_backup_id(Some("demo-20260102-000000"), &backups).unwrap(),
+            "demo-20260102-000000"
+        );
+    }
+
+    #[test]
+    fn backup_selection_rejects_unknown_ids_and_empty_storage() {
+        let backups = vec![backup_set("demo-20260101-000000", 1)];
+
+        let err = select_backup_id(Some("demo-20991231-000000"), &backups)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("backup 'demo-20991231-000000' not found in storage"));
+
+        let err = select_backup_id(None, &[]).unwrap_err().to_string();
+        assert!(err.contains("no backups found in storage"));
+    }
+
+    #[tokio::test]
+    async fn listing_backup_sets_filters_by_backup_name() {
+        let dir = tempdir().unwrap();
+        write_backup_set(dir.path(), "demo-20260101-000000", 1);
+        write_backup_set(dir.path(), "other-20260101-000000", 2);
+
+        let storage = local_storage(dir.path());
+        let owned = list_backup_sets(&storage, Some("demo")).await.unwrap();
+        let all = list_backup_sets(&storage, None).await.unwrap();
+
+        assert_eq!(owned.len(), 1);
+        assert_eq!(owned[0].backup_id, "demo-20260101-000000");
+        assert_eq!(all.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn listing_backup_sets_skips_aborted_sets() {
+        let dir = tempdir().unwrap();
+        write_backup_set(dir.path(), "demo-20260101-000000", 1);
+        write_backup_set(dir.path(), "demo-20260102-000000", 2);
+        fs::write(
+            dir.path()
+                .join("demo-20260102-000000")
+                .join(crate::reconcilers::gc::ABORTED_MARKER),
+            b"{}",
+        )
+        .unwrap();
+
+        let backup_sets = list_backup_sets(&local_storage(dir.path()), Some("demo"))
+            .await
+            .unwrap();
+
+        assert_eq!(backup_sets.len(), 1);
+        assert_eq!(backup_sets[0].backup_id, "demo-20260101-000000");
+    }
+
+    #[tokio::test]
+    async fn backup_sets_are_found_across_date_partitions() {
+        let dir = tempdir().unwrap();
+        let partition_dir = dir.path().join("2026/01/02");
+        write_backup_set(dir.path(), "demo-20260101-000000", 1);
+        write_backup_set(&partition_dir, "demo-20260102-000000", 2);
+
+        let storage = local_storage(dir.path());
+        let backup_sets = list_backup_sets(&storage, Some("demo")).await.unwrap();
+
+        assert_eq!(backup_sets.len(), 2);
+        assert_eq!(backup_sets[0].partition, "");
+        assert_eq!(backup_sets[1].partition, "2026/01/02/");
+        assert!(backup_sets[1]
+            .keys
+            .contains(&"2026/01/02/demo-20260102-000000-offsets.db".to_string()));
+
+        let ResolvedStorage::Local(scoped) =
+            backup_set_storage(&storage, &backup_sets, "demo-20260102-000000")
+        else {
+            panic!("expected local storage");
+        };
+        assert_eq!(std::path::Path::new(&scoped.path), partition_dir.as_path());
+    }
+
+    #[tokio::test]
+    async fn date_layout_lists_only_recorded_partitions() {
+        let dir = tempdir().unwrap();
+        write_backup_set(&dir.path().join("2026/01/02"), "demo-20260102-000000", 1);
+        write_backup_set(&dir.path().join("2026/01/03"), "demo-20260103-000000", 2);
+        fs::create_dir_all(dir.path().join("_date-partitions/2026/01")).unwrap();
+        fs::write(dir.path().join("_date-partitions/2026/01/02"), b"").unwrap();
+
+        let backend = create_backend(&local_storage(dir.path())).unwrap();
+        let keys = list_date_partitions(backend.as_ref()).await.unwrap();
+
+        assert!(keys.contains(&"2026/01/02/demo-20260102-000000/manifest.json".to_string()));
+        assert!(keys.iter().all(|key| key.starts_with("2026/01/02/")));
+    }
 }
@@ -0,0 +1,198 @@
+//! One-line status summaries.
+//!
+//! Every resource records a `status.summary` such as "Backed up 3 topics,
+//! 1.2M records, 450MB in 42s" when it completes. The helpers here keep
+//! counts, sizes and durations formatted the same way across resource kinds.
+
+use std::time::Duration;
+
+/// Shorten a count to at most one decimal: 950, 1.2K, 34M
+pub fn format_count(count: u64) -> String {
+    scale(count, &["", "K", "M", "B", "T"])
+}
+
+/// Shorten a byte count in decimal units: 512B, 1.2KB, 450MB
+pub fn format_bytes(bytes: u64) -> String {
+    scale(bytes, &["B", "KB", "MB", "GB", "TB", "PB"])
+}
+
+/// Format a duration in its two largest units: 42s, 3m12s, 2h05m
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m{:02}s", secs / 60, secs % 60),
+        _ => format!("{}h{:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+/// `count` followed by `noun`, pluralized with a trailing "s"
+fn counted(count: u64, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {}", noun)
+    } else {
+        format!("{} {}s", format_count(count), noun)
+    }
+}
+
+fn scale(value: u64, units: &[&str]) -> String {
+    let mut scaled = value as f64;
+    let mut unit = 0;
+    // Round before comparing so 999,950 reads 1.0M rather than 1000K
+    while (scaled * 10.0).round() / 10.0 >= 1000.0 && unit + 1 < units.len() {
+        scaled /= 1000.0;
+        unit += 1;
+    }
+    if unit == 0 || scaled >= 9.95 {
+        format!("{:.0}{}", scaled, units[unit])
+    } else {
+        format!("{:.1}{}", scaled, units[unit])
+    }
+}
+
+/// Summary of a completed backup run. `topics` is unknown when the manifest
+/// could not be read.
+pub fn backup_summary(topics: Option<u32>, records: u64, bytes: u64, duration: Duration) -> String {
+    let topics = topics
+        .map(|topics| format!("{}, ", counted(u64::from(topics), "topic")))
+        .unwrap_or_default();
+    format!(
+        "Backed up {}{}, {} in {}",
+        topics,
+        counted(records, "record"),
+        format_bytes(bytes),
+        format_duration(duration)
+    )
+}
+
+/// Summary of a completed restore. Bytes and duration are unknown when a
+/// detached engine finished without the operator watching.
+pub fn restore_summary(records: u64, bytes: Option<u64>, duration: Option<Duration>) -> String {
+    let mut summary = format!("Restored {}", counted(records, "record"));
+    if let Some(bytes) = bytes {
+        summary.push_str(&format!(", {}", format_bytes(bytes)));
+    }
+    if let Some(duration) = duration {
+        summary.push_str(&format!(" in {}", format_duration(duration)));
+    }
+    summary
+}
+
+/// Summary of a completed offsets-only restore
+pub fn offsets_only_restore_summary(groups: u64, duration: Duration) -> String {
+    format!(
+        "Restored offsets of {} in {}",
+        counted(groups, "consumer group"),
+        format_duration(duration)
+    )
+}
+
+/// Summary of a completed offset reset
+pub fn offset_reset_summary(
+    groups_reset: u64,
+    groups_total: u64,
+    groups_failed: u64,
+    duration: Duration,
+) -> String {
+    let mut summary = format!(
+        "Reset {} of {} in {}",
+        format_count(groups_reset),
+        counted(groups_total, "consumer group"),
+        format_duration(duration)
+    );
+    if groups_failed > 0 {
+        summary.push_str(&format!(", {} failed", format_count(groups_failed)));
+    }
+    summary
+}
+
+/// Summary of a completed offset rollback
+pub fn offset_rollback_summary(groups: u64, verified: bool, duration: Duration) -> String {
+    format!(
+        "Rolled back {}{} in {}",
+        counted(groups, "consumer group"),
+        if verified { " (verified)" } else { "" },
+        format_duration(duration)
+    )
+}
+
+/// Summary of a completed validation
+pub fn validation_summary(
+    result: &str,
+    passed: u32,
+    total: u32,
+    warned: u32,
+    duration: Duration,
+) -> String {
+    let mut summary = format!("{}: {}/{} checks passed", result, passed, total);
+    if warned > 0 {
+        summary.push_str(&format!(", {} warned", warned));
+    }
+    summary.push_str(&format!(" in {}", format_duration(duration)));
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_and_bytes_are_shortened() {
+        assert_eq!(format_count(950), "950");
+        assert_eq!(format_count(1_234), "1.2K");
+        assert_eq!(format_count(1_200_000), "1.2M");
+        assert_eq!(format_count(34_000_000), "34M");
+        assert_eq!(format_count(999_960), "1.0M");
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(450_000_000), "450MB");
+        assert_eq!(format_bytes(3_100_000_000), "3.1GB");
+    }
+
+    #[test]
+    fn durations_use_two_units() {
+        assert_eq!(format_duration(Duration::from_millis(42_900)), "42s");
+        assert_eq!(format_duration(Duration::from_secs(192)), "3m12s");
+        assert_eq!(format_duration(Duration::from_secs(7_500)), "2h05m");
+    }
+
+    #[test]
+    fn backup_summary_reads_as_one_line() {
+        assert_eq!(
+            backup_summary(Some(3), 1_200_000, 450_000_000, Duration::from_secs(42)),
+            "Backed up 3 topics, 1.2M records, 450MB in 42s"
+        );
+        assert_eq!(
+            backup_summary(None, 1, 0, Duration::ZERO),
+            "Backed up 1 record, 0B in 0s"
+        );
+    }
+
+    #[test]
+    fn restore_summary_omits_unknowns() {
+        assert_eq!(
+            restore_summary(5_000, Some(2_000_000), Some(Duration::from_secs(65))),
+            "Restored 5.0K records, 2.0MB in 1m05s"
+        );
+        assert_eq!(restore_summary(12, None, None), "Restored 12 records");
+        assert_eq!(
+            offsets_only_restore_summary(3, Duration::from_secs(2)),
+            "Restored offsets of 3 consumer groups in 2s"
+        );
+    }
+
+    #[test]
+    fn offset_summaries_name_consumer_groups() {
+        assert_eq!(
+            offset_reset_summary(3, 4, 1, Duration::from_secs(2)),
+            "Reset 3 of 4 consumer groups in 2s, 1 failed"
+        );
+        assert_eq!(
+            offset_rollback_summary(1, true, Duration::from_secs(1)),
+            "Rolled back 1 consumer group (verified) in 1s"
+        );
+        assert_eq!(
+            validation_summary("PASSED", 5, 6, 1, Duration::from_secs(9)),
+            "PASSED: 5/6 checks passed, 1 warned in 9s"
+        );
+    }
+}
@@ -58,17 +58,13 @@ pub fn validate(validation: &KafkaBackupValidation) -> Result<()> {
         ));
     }
 
+    if let Some(storage) = &validation.spec.backup_ref.storage {
+        super::validate_storage_credentials(storage)?;
+    }
+
     if let Some(kafka_cluster) = &validation.spec.kafka_cluster {
-        // Validate TLS configuration: SSL/SASL_SSL requires at least one TLS secret
-        let protocol = kafka_cluster.security_protocol.to_uppercase();
-        if (protocol == "SSL" || protocol == "SASL_SSL")
-            && kafka_cluster.tls_secret.is_none()
-            && kafka_cluster.ca_secret.is_none()
-        {
-            return Err(Error::validation(
-                "securityProtocol SSL/SASL_SSL requires either tlsSecret or caSecret to be configured",
-            ));
-        }
+        // Validate security: SSL needs certificates, SASL needs credentials
+        super::validate_kafka_security(kafka_cluster)?;
 
         if let Some(connection) = &kafka_cluster.connection {
             if connection.connections_per_broker == 0 {
@@ -182,7 +178,9 @@ pub async fn execute(
     .await?;
 
     // Execute validation
+    let start_time = std::time::Instant::now();
     let validation_result = execute_validation_internal(validation, client, namespace).await;
+    let duration = start_time.elapsed();
 
     match validation_result {
         Ok(result) => {
@@ -215,6 +213,13 @@ pub async fn execute(
                 "status": {
                     "phase": "Completed",
                     "message": format!("Validation completed: {}", result.overall_result),
+                    "summary": super::status::validation_summary(
+                        &result.overall_result,
+                        result.checks_passed,
+                        result.checks_total,
+                        result.checks_warned,
+                        duration,
+                    ),
                     "validationResult": result.overall_result,
                     "completionTime": Utc::now(),
                     "checksTotal": result.checks_total,
@@ -253,10 +258,13 @@ pub async fn execute(
                 .with_label_values(&["error", namespace, &name])
                 .inc();
 
+            let message = format!("Validation failed: {}", e);
+            let recent_errors = super::record_recent_error(validation, "Failed", &message);
             let failed_status = json!({
                 "status": {
                     "phase": "Failed",
-                    "message": format!("Validation failed: {}", e),
+                    "message": message,
+                    "recentErrors": recent_errors,
                     "observedGeneration": validation.metadata.generation,
                     "conditions": [{
                         "type": "Ready",
@@ -363,7 +371,7 @@ async fn execute_validation_internal(
         .unwrap();
     let target_router = kafka_backup_core::kafka::PartitionLeaderRouter::new(kafka_config)
         .await
-        .map_err(|e| Error::Core(format!("Failed to connect to target Kafka cluster: {}", e)))?;
+        .map_err(|e| Error::KafkaConnection(format!("target cluster: {}", e)))?;
 
     let ctx = kafka_backup_core::validation::ValidationContext {
         backup_id: backup_id.clone(),
@@ -438,10 +446,11 @@ async fn resolve_backup_source(
 ) -> Result<(String, ResolvedStorage)> {
     match source {
         ResolvedBackupSource::Storage { storage, backup_id } => {
-            let resolved_id = backup_id
-                .clone()
-                .unwrap_or_else(|| format!("validation-{}", Utc::now().format("%Y%m%d-%H%M%S")));
-            Ok((resolved_id, storage.clone()))
+            let backup_sets = super::retention::list_backup_sets(storage, None).await?;
+            let resolved_id =
+                super::retention::select_backup_id(backup_id.as_deref(), &backup_sets)?;
+            let storage = super::retention::backup_set_storage(storage, &backup_sets, &resolved_id);
+            Ok((resolved_id, storage))
         }
         ResolvedBackupSource::BackupResource {
             name,
@@ -453,18 +462,16 @@ async fn resolve_backup_source(
                 Error::BackupNotFound(format!("Failed to fetch KafkaBackup '{}': {}", name, e))
             })?;
 
-            let resolved_backup_id = backup_id.clone().unwrap_or_else(|| {
-                backup
-                    .status
-                    .as_ref()
-                    .and_then(|s| s.backup_id.clone())
-                    .unwrap_or_else(|| format!("{}-latest", name))
-            });
-
             let storage =
                 crate::adapters::build_storage_config(&backup.spec.storage, client, backup_ns)
                     .await?;
 
+            let backup_sets = super::retention::list_backup_sets(&storage, Some(name)).await?;
+            let resolved_backup_id =
+                super::retention::select_backup_id(backup_id.as_deref(), &backup_sets)?;
+            let storage =
+                super::retention::backup_set_storage(&storage, &backup_sets, &resolved_backup_id);
+
             Ok((resolved_backup_id, storage))
         }
     }
@@ -480,10 +487,12 @@ pub async fn update_status_failed(
     let name = validation.name_any();
     let api: Api<KafkaBackupValidation> = Api::namespaced(client.clone(), namespace);
 
+    let recent_errors = super::record_recent_error(validation, "Failed", error_message);
     let status = json!({
         "status": {
             "phase": "Failed",
             "message": error_message,
+            "recentErrors": recent_errors,
             "observedGeneration": validation.metadata.generation,
             "conditions": [{
                 "type": "Ready",
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
-use kafka_backup_operator::crd::{BackupRef, KafkaClusterSpec, KafkaRestore, KafkaRestoreSpec};
+use kafka_backup_operator::crd::{
+    BackupRef, KafkaClusterSpec, KafkaRestore, KafkaRestoreSpec, PartitionStrategy,
+};
 use kafka_backup_operator::reconcilers::restore;
 
 fn restore_spec() -> KafkaRestoreSpec {
@@ -10,22 +12,31 @@ fn restore_spec() -> KafkaRestoreSpec {
             name: "backup-issue48".to_string(),
             namespace: None,
             backup_id: Some("backup-issue48-20260501-120000".to_string()),
+            use_latest: false,
+            rerun_on_new_backup: false,
             storage: None,
+            storage_fallbacks: Vec::new(),
         },
         kafka_cluster: KafkaClusterSpec {
             bootstrap_servers: vec!["kafka:9092".to_string()],
             security_protocol: "PLAINTEXT".to_string(),
             tls_secret: None,
             ca_secret: None,
+            tls_cert_dir: None,
             sasl_secret: None,
             connection: None,
+            client_config: HashMap::new(),
         },
         topics: vec!["orders".to_string()],
         pitr: None,
         topic_mapping: HashMap::new(),
         partition_mapping: HashMap::new(),
         repartitioning: HashMap::new(),
+        partition_strategy: PartitionStrategy::Preserve,
         offset_reset: None,
+        offset_strategy: None,
+        consumer_group_mapping: HashMap::new(),
+        consumer_group_prefix: None,
         rollback: None,
         rate_limiting: None,
         circuit_breaker: None,
@@ -37,6 +48,17 @@ fn restore_spec() -> KafkaRestoreSpec {
         auto_consumer_groups: false,
         create_topics: false,
         default_replication_factor: None,
+        allow_replication_factor_downscale: false,
+        allow_partition_downscale: false,
+        max_backup_age_hours: None,
+        verify_checksums: false,
+        acl_preflight: false,
+        timeout_secs: None,
+        stall_timeout_secs: None,
+        force_full_restore: false,
+        offset_report: None,
+        preview: None,
+        restore_offsets_only: false,
     }
 }
 
@@ -6,8 +6,8 @@ use kafka_backup_operator::adapters::{
     build_restore_config, to_core_restore_config, ResolvedBackupSource,
 };
 use kafka_backup_operator::crd::{
-    BackupRef, KafkaClusterSpec, KafkaRestore, KafkaRestoreSpec, OffsetResetSpec, PvcStorageSpec,
-    StorageSpec,
+    BackupRef, KafkaClusterSpec, KafkaRestore, KafkaRestoreSpec, OffsetResetSpec,
+    PartitionStrategy, PvcStorageSpec, StorageSpec,
 };
 
 fn test_client() -> kube::Client {
@@ -43,22 +43,31 @@ fn restore_with_storage_ref() -> KafkaRestore {
                 name: String::new(),
                 namespace: None,
                 backup_id: Some("backup-issue49".to_string()),
+                use_latest: false,
+                rerun_on_new_backup: false,
                 storage: Some(storage_ref()),
+                storage_fallbacks: Vec::new(),
             },
             kafka_cluster: KafkaClusterSpec {
                 bootstrap_servers: vec!["kafka:9092".to_string()],
                 security_protocol: "PLAINTEXT".to_string(),
                 tls_secret: None,
                 ca_secret: None,
+                tls_cert_dir: None,
                 sasl_secret: None,
                 connection: None,
+                client_config: HashMap::new(),
             },
             topics: vec!["orders".to_string()],
             pitr: None,
             topic_mapping: HashMap::new(),
             partition_mapping: HashMap::new(),
             repartitioning: HashMap::new(),
+            partition_strategy: PartitionStrategy::Preserve,
             offset_reset: None,
+            offset_strategy: None,
+            consumer_group_mapping: HashMap::new(),
+            consumer_group_prefix: None,
             rollback: None,
             rate_limiting: None,
             circuit_breaker: None,
@@ -70,6 +79,17 @@ fn restore_with_storage_ref() -> KafkaRestore {
             auto_consumer_groups: false,
             create_topics: false,
             default_replication_factor: None,
+            allow_replication_factor_downscale: false,
+            allow_partition_downscale: false,
+            max_backup_age_hours: None,
+            verify_checksums: false,
+            acl_preflight: false,
+            timeout_secs: None,
+            stall_timeout_secs: None,
+            force_full_restore: false,
+            offset_report: None,
+            preview: None,
+            restore_offsets_only: false,
         },
         status: None,
     }
@@ -7,12 +7,14 @@ use std::collections::HashMap;
 
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 use kafka_backup_operator::crd::{
-    BackupRef, BackupValidationRef, CaSecretRef, ConsumerGroupCheckSpec, EvidenceSpec, KafkaBackup,
-    KafkaBackupSpec, KafkaBackupValidation, KafkaBackupValidationSpec, KafkaClusterSpec,
-    KafkaOffsetReset, KafkaOffsetResetSpec, KafkaRestore, KafkaRestoreSpec, MessageCountCheckSpec,
-    OffsetMappingRef, OffsetRangeCheckSpec, OffsetResetStrategy, PitrSpec, PvcStorageSpec,
-    RetentionSpec, SigningKeyRef, SigningSpec, StorageSpec, TlsSecretRef, TopicRepartitioningSpec,
-    ValidationChecksSpec, WebhookCheckSpec,
+    BackupRef, BackupValidationRef, CaSecretRef, CheckpointSpec, CompressionOverride,
+    ConsumerGroupCheckSpec, EvidenceSpec, KafkaBackup, KafkaBackupSpec, KafkaBackupValidation,
+    KafkaBackupValidationSpec, KafkaClusterSpec, KafkaOffsetReset, KafkaOffsetResetSpec,
+    KafkaRestore, KafkaRestoreSpec, MessageCountCheckSpec, OffsetMappingRef, OffsetRangeCheckSpec,
+    OffsetResetSpec, OffsetResetStrategy, OffsetStorageBackendKind, PartitionStrategy, PitrSpec,
+    PreviewSpec, PvcStorageSpec, RateLimitingSpec, RestoreOffsetStrategy, RetentionSpec, RetrySpec,
+    SaslSecretRef, SigningKeyRef, SigningSpec, StorageSpec, TlsSecretRef, TopicPartitions,
+    TopicRepartitioningSpec, ValidationChecksSpec, WebhookCheckSpec,
 };
 use kafka_backup_operator::reconcilers::{backup, offset_reset, restore, validation};
 
@@ -26,8 +28,21 @@ fn valid_kafka_cluster() -> KafkaClusterSpec {
         security_protocol: "PLAINTEXT".to_string(),
         tls_secret: None,
         ca_secret: None,
+        tls_cert_dir: None,
         sasl_secret: None,
         connection: None,
+        client_config: HashMap::new(),
+    }
+}
+
+fn sasl_secret(mechanism: &str) -> SaslSecretRef {
+    SaslSecretRef {
+        name: Some("kafka-sasl".to_string()),
+        mechanism: mechanism.to_string(),
+        username_key: "username".to_string(),
+        password_key: "password".to_string(),
+        username_env: None,
+        password_env: None,
     }
 }
 
@@ -64,21 +79,32 @@ fn valid_backup_spec() -> KafkaBackupSpec {
         storage: valid_pvc_storage(),
         compression: "zstd".to_string(),
         compression_level: 3,
+        topic_compression: HashMap::new(),
         segment_max_bytes: 128 * 1024 * 1024,
         segment_max_interval_ms: 60_000,
         continuous: false,
         stop_at_current_offsets: false,
         include_offset_headers: true,
         source_cluster_id: None,
+        labels: HashMap::new(),
+        segment_checksums: false,
         poll_interval_ms: 100,
         consumer_group_snapshot: false,
+        max_message_bytes: None,
         // cron crate uses 7-field format: sec min hour day_of_month month day_of_week year
         schedule: Some("0 0 0 * * * *".to_string()),
+        min_schedule_interval_secs: None,
+        allow_frequent_schedule: false,
+        max_requeue_secs: None,
         checkpoint: None,
         rate_limiting: None,
         circuit_breaker: None,
         retention: None,
         suspend: false,
+        suspend_until: None,
+        timeout_secs: None,
+        max_total_bytes: None,
+        offset_storage_backend: OffsetStorageBackendKind::Sqlite,
         metrics: None,
     }
 }
@@ -113,6 +139,20 @@ fn backup_empty_topics_fails_validation() {
     assert!(result.unwrap_err().to_string().contains("topic"));
 }
 
+#[test]
+fn backup_labels_must_follow_kubernetes_label_syntax() {
+    let mut spec = valid_backup_spec();
+    spec.labels = HashMap::from([("team".to_string(), "payments".to_string())]);
+    assert!(backup::validate(&create_backup(spec.clone())).is_ok());
+
+    spec.labels
+        .insert("owner".to_string(), "data platform".to_string());
+    let result = backup::validate(&create_backup(spec));
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("labels"));
+}
+
 #[test]
 fn backup_empty_bootstrap_servers_fails_validation() {
     let mut spec = valid_backup_spec();
@@ -172,6 +212,168 @@ fn backup_s3_storage_without_config_fails_validation() {
         .contains("s3"));
 }
 
+fn s3_storage(endpoint: Option<&str>, allow_http: bool) -> StorageSpec {
+    serde_json::from_value(serde_json::json!({
+        "storageType": "s3",
+        "s3": {
+            "bucket": "kafka-backups",
+            "region": "us-east-1",
+            "endpoint": endpoint,
+            "pathStyle": true,
+            "allowHttp": allow_http,
+            "credentialsSecret": {"name": "s3-credentials"},
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn restore_storage_fallback_credentials_are_validated() {
+    let mut spec = valid_restore_spec();
+    spec.backup_ref.storage_fallbacks = vec![s3_storage(None, false)];
+    assert!(restore::validate(&create_restore(spec.clone())).is_ok());
+
+    let mut replica = s3_storage(None, false);
+    replica
+        .s3
+        .as_mut()
+        .unwrap()
+        .credentials_secret
+        .access_key_id_env = Some("KAFKA_BACKUP_CRED_REPLICA_ACCESS_KEY_ID".to_string());
+    spec.backup_ref.storage_fallbacks.push(replica);
+    let err_msg = restore::validate(&create_restore(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err_msg.contains("s3.credentialsSecret"));
+}
+
+#[test]
+fn restore_s3_transport_is_validated_for_direct_and_fallback_storage() {
+    let mut spec = valid_restore_spec();
+    spec.backup_ref.storage = Some(s3_storage(Some("http://minio.storage.svc:9000"), true));
+    spec.backup_ref.storage_fallbacks = vec![s3_storage(
+        Some("https://s3.eu-west-1.amazonaws.com"),
+        false,
+    )];
+    assert!(restore::validate(&create_restore(spec.clone())).is_ok());
+
+    let mut direct = spec.clone();
+    direct.backup_ref.storage = Some(s3_storage(Some("https://minio.storage.svc:9000"), true));
+    let err_msg = restore::validate(&create_restore(direct))
+        .unwrap_err()
+        .to_string();
+    assert!(err_msg.contains("allowHttp"));
+
+    spec.backup_ref.storage_fallbacks = vec![s3_storage(Some("minio:9000/"), false)];
+    let err_msg = restore::validate(&create_restore(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err_msg.contains("s3.endpoint"));
+}
+
+#[test]
+fn backup_s3_allow_http_with_http_endpoint_passes_validation() {
+    let mut spec = valid_backup_spec();
+    spec.storage = s3_storage(Some("http://minio.storage.svc:9000"), true);
+
+    let backup = create_backup(spec);
+    assert!(backup::validate(&backup).is_ok());
+}
+
+#[test]
+fn backup_s3_allow_http_without_http_endpoint_fails_validation() {
+    for endpoint in [None, Some("https://minio.storage.svc:9000")] {
+        let mut spec = valid_backup_spec();
+        spec.storage = s3_storage(endpoint, true);
+
+        let backup = create_backup(spec);
+        let result = backup::validate(&backup);
+
+        assert!(
+            result.is_err(),
+            "endpoint {:?} should be rejected",
+            endpoint
+        );
+        assert!(result.unwrap_err().to_string().contains("allowHttp"));
+    }
+}
+
+#[test]
+fn backup_s3_malformed_endpoint_fails_validation() {
+    let mut spec = valid_backup_spec();
+    spec.storage = s3_storage(Some("minio:9000/"), false);
+
+    let backup = create_backup(spec);
+    let result = backup::validate(&backup);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("s3.endpoint"));
+}
+
+fn azure_storage(endpoint: Option<&str>) -> StorageSpec {
+    serde_json::from_value(serde_json::json!({
+        "storageType": "azure",
+        "azure": {
+            "container": "kafka-backups",
+            "accountName": "account",
+            "endpoint": endpoint,
+            "useWorkloadIdentity": true,
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn backup_azure_government_endpoint_passes_validation() {
+    let mut spec = valid_backup_spec();
+    spec.storage = azure_storage(Some("https://account.blob.core.usgovcloudapi.net"));
+
+    let backup = create_backup(spec);
+    assert!(backup::validate(&backup).is_ok());
+}
+
+#[test]
+fn backup_azure_malformed_endpoint_fails_validation() {
+    for endpoint in [
+        "blob.core.chinacloudapi.cn",
+        "ftp://account.blob.core.windows.net",
+    ] {
+        let mut spec = valid_backup_spec();
+        spec.storage = azure_storage(Some(endpoint));
+
+        let backup = create_backup(spec);
+        let result = backup::validate(&backup);
+
+        assert!(
+            result.is_err(),
+            "endpoint '{}' should be rejected",
+            endpoint
+        );
+        assert!(result.unwrap_err().to_string().contains("azure.endpoint"));
+    }
+}
+
+#[test]
+fn backup_azure_multiple_auth_methods_fail_validation() {
+    let mut spec = valid_backup_spec();
+    spec.storage = serde_json::from_value(serde_json::json!({
+        "storageType": "azure",
+        "azure": {
+            "container": "kafka-backups",
+            "accountName": "account",
+            "useWorkloadIdentity": true,
+            "credentialsSecret": {"name": "azure-creds"},
+        }
+    }))
+    .unwrap();
+
+    let err = backup::validate(&create_backup(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("mutually exclusive"));
+    assert!(err.contains("useWorkloadIdentity, credentialsSecret"));
+}
+
 #[test]
 fn backup_invalid_cron_schedule_fails_validation() {
     let mut spec = valid_backup_spec();
@@ -212,19 +414,204 @@ fn backup_valid_cron_schedules_pass_validation() {
 }
 
 #[test]
-fn backup_invalid_compression_fails_validation() {
+fn backup_sub_minute_schedule_fails_validation() {
+    let mut spec = valid_backup_spec();
+    spec.schedule = Some("* * * * * * *".to_string());
+
+    let err = backup::validate(&create_backup(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("fires every 1s"));
+    assert!(err.contains("allowFrequentSchedule"));
+}
+
+#[test]
+fn backup_sub_minute_schedule_passes_when_allowed() {
+    let mut spec = valid_backup_spec();
+    spec.schedule = Some("*/10 * * * * * *".to_string());
+    spec.allow_frequent_schedule = true;
+
+    assert!(backup::validate(&create_backup(spec)).is_ok());
+}
+
+#[test]
+fn backup_schedule_below_custom_floor_fails_validation() {
     let mut spec = valid_backup_spec();
-    spec.compression = "gzip".to_string(); // Not supported
+    spec.schedule = Some("0 */5 * * * * *".to_string());
+    spec.min_schedule_interval_secs = Some(3600);
+
+    let result = backup::validate(&create_backup(spec));
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("(3600s)"));
+}
+
+#[test]
+fn backup_zero_max_requeue_secs_fails_validation() {
+    let mut spec = valid_backup_spec();
+    spec.max_requeue_secs = Some(0);
 
     let backup = create_backup(spec);
     let result = backup::validate(&backup);
 
     assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("maxRequeueSecs"));
+}
+
+fn unbounded_rate_limiting(max_concurrent_partitions: usize) -> RateLimitingSpec {
+    RateLimitingSpec {
+        records_per_sec: 0,
+        bytes_per_sec: 0,
+        max_concurrent_partitions,
+        ramp_up_secs: None,
+    }
+}
+
+#[test]
+fn backup_zero_max_concurrent_partitions_fails_validation() {
+    let mut spec = valid_backup_spec();
+    spec.rate_limiting = Some(unbounded_rate_limiting(0));
+
+    let result = backup::validate(&create_backup(spec));
     assert!(result
         .unwrap_err()
         .to_string()
-        .to_lowercase()
-        .contains("compression"));
+        .contains("maxConcurrentPartitions"));
+
+    let mut spec = valid_backup_spec();
+    spec.rate_limiting = Some(unbounded_rate_limiting(4));
+    assert!(backup::validate(&create_backup(spec)).is_ok());
+}
+
+#[test]
+fn restore_zero_max_concurrent_partitions_fails_validation() {
+    let mut spec = valid_restore_spec();
+    spec.rate_limiting = Some(unbounded_rate_limiting(0));
+
+    let result = restore::validate(&create_restore(spec));
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("maxConcurrentPartitions"));
+}
+
+#[test]
+fn restore_ramp_up_requires_a_records_limit() {
+    let mut spec = valid_restore_spec();
+    spec.rate_limiting = Some(RateLimitingSpec {
+        ramp_up_secs: Some(300),
+        ..unbounded_rate_limiting(4)
+    });
+
+    let err_msg = restore::validate(&create_restore(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err_msg.contains("requires rateLimiting.recordsPerSec"));
+}
+
+#[test]
+fn restore_ramp_up_validation() {
+    let ramped = |ramp_up_secs| RateLimitingSpec {
+        records_per_sec: 5_000,
+        ramp_up_secs: Some(ramp_up_secs),
+        ..unbounded_rate_limiting(4)
+    };
+
+    let mut spec = valid_restore_spec();
+    spec.rate_limiting = Some(ramped(300));
+    assert!(restore::validate(&create_restore(spec)).is_ok());
+
+    let mut spec = valid_restore_spec();
+    spec.rate_limiting = Some(ramped(30));
+    let err_msg = restore::validate(&create_restore(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err_msg.contains("rampUpSecs must be at least 60"));
+
+    let mut spec = valid_restore_spec();
+    spec.rate_limiting = Some(ramped(300));
+    spec.auto_consumer_groups = true;
+    let err_msg = restore::validate(&create_restore(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err_msg.contains("cannot be combined"));
+}
+
+#[test]
+fn backup_ramp_up_fails_validation() {
+    let mut spec = valid_backup_spec();
+    spec.rate_limiting = Some(RateLimitingSpec {
+        ramp_up_secs: Some(300),
+        ..unbounded_rate_limiting(4)
+    });
+
+    let err_msg = backup::validate(&create_backup(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err_msg.contains("only applies to restores"));
+}
+
+#[test]
+fn backup_max_message_bytes_validation() {
+    let mut spec = valid_backup_spec();
+    spec.max_message_bytes = Some(0);
+    let result = backup::validate(&create_backup(spec));
+    assert!(result.unwrap_err().to_string().contains("maxMessageBytes"));
+
+    let mut spec = valid_backup_spec();
+    spec.max_message_bytes = Some(spec.segment_max_bytes + 1);
+    let result = backup::validate(&create_backup(spec));
+    assert!(result.unwrap_err().to_string().contains("segmentMaxBytes"));
+
+    let mut spec = valid_backup_spec();
+    spec.max_message_bytes = Some(1024 * 1024);
+    assert!(backup::validate(&create_backup(spec)).is_ok());
+}
+
+#[test]
+fn backup_max_total_bytes_validation() {
+    let mut spec = valid_backup_spec();
+    spec.max_total_bytes = Some(0);
+    let result = backup::validate(&create_backup(spec));
+    assert!(result.unwrap_err().to_string().contains("maxTotalBytes"));
+
+    let mut spec = valid_backup_spec();
+    spec.max_total_bytes = Some(10 * 1024 * 1024 * 1024);
+    assert!(backup::validate(&create_backup(spec)).is_ok());
+}
+
+#[test]
+fn backup_memory_offset_storage_requires_checkpointing_disabled() {
+    // Checkpointing defaults to enabled
+    let mut spec = valid_backup_spec();
+    spec.offset_storage_backend = OffsetStorageBackendKind::Memory;
+    let result = backup::validate(&create_backup(spec));
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("offsetStorageBackend"));
+
+    let mut spec = valid_backup_spec();
+    spec.offset_storage_backend = OffsetStorageBackendKind::Memory;
+    spec.checkpoint = Some(CheckpointSpec {
+        enabled: false,
+        interval_secs: 30,
+        storage: None,
+    });
+    assert!(backup::validate(&create_backup(spec)).is_ok());
+}
+
+#[test]
+fn backup_invalid_compression_fails_validation() {
+    let mut spec = valid_backup_spec();
+    spec.compression = "brotli".to_string(); // Not supported
+
+    let backup = create_backup(spec);
+    let result = backup::validate(&backup);
+
+    assert!(result.is_err());
+    let message = result.unwrap_err().to_string();
+    assert!(message.to_lowercase().contains("compression"));
+    assert!(message.contains(&backup::SUPPORTED_COMPRESSIONS.join(", ")));
 }
 
 #[test]
@@ -244,6 +631,68 @@ fn backup_valid_compressions_pass_validation() {
     }
 }
 
+#[test]
+fn backup_topic_compression_overrides_are_validated() {
+    let mut spec = valid_backup_spec();
+    spec.topics = vec!["images".to_string()];
+    spec.topic_compression.insert(
+        "images".to_string(),
+        CompressionOverride {
+            compression: "none".to_string(),
+            compression_level: 3,
+        },
+    );
+    assert!(backup::validate(&create_backup(spec.clone())).is_ok());
+
+    spec.topic_compression.insert(
+        "logs".to_string(),
+        CompressionOverride {
+            compression: "zstd".to_string(),
+            compression_level: 30,
+        },
+    );
+    let err = backup::validate(&create_backup(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("topicCompression for 'logs'"));
+    assert!(err.contains("must be between 1 and 22"));
+}
+
+#[test]
+fn backup_topic_compression_overrides_must_agree() {
+    let mut spec = valid_backup_spec();
+    spec.topics = vec!["images".to_string(), "videos".to_string()];
+    spec.topic_compression.insert(
+        "images".to_string(),
+        CompressionOverride {
+            compression: "none".to_string(),
+            compression_level: 3,
+        },
+    );
+    let err = backup::validate(&create_backup(spec.clone()))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("does not cover topic 'videos'"));
+
+    spec.topic_compression.insert(
+        "videos".to_string(),
+        CompressionOverride {
+            compression: "lz4".to_string(),
+            compression_level: 3,
+        },
+    );
+    let err = backup::validate(&create_backup(spec.clone()))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("differs from the other overrides"));
+
+    spec.topic_compression
+        .get_mut("videos")
+        .unwrap()
+        .compression = "none".to_string();
+    assert!(backup::validate(&create_backup(spec)).is_ok());
+}
+
 #[test]
 fn backup_invalid_zstd_compression_level_fails_validation() {
     let invalid_levels = vec![0, 23, -1, 100];
@@ -280,6 +729,19 @@ fn backup_valid_zstd_compression_levels_pass_validation() {
     }
 }
 
+#[test]
+fn backup_gzip_and_snappy_compression_fail_validation() {
+    for compression in ["gzip", "snappy"] {
+        let mut spec = valid_backup_spec();
+        spec.compression = compression.to_string();
+
+        let err = backup::validate(&create_backup(spec))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(&format!("Invalid compression '{}'", compression)));
+    }
+}
+
 #[test]
 fn backup_no_schedule_passes_validation() {
     let mut spec = valid_backup_spec();
@@ -492,11 +954,66 @@ fn backup_sasl_ssl_with_ca_secret_only_passes_validation() {
         name: "cluster-ca-cert".to_string(),
         ca_key: "ca.crt".to_string(),
     });
+    spec.kafka_cluster.sasl_secret = Some(sasl_secret("SCRAM-SHA-512"));
+
+    let backup = create_backup(spec);
+    assert!(backup::validate(&backup).is_ok());
+}
+
+#[test]
+fn backup_sasl_without_sasl_secret_fails_validation() {
+    for protocol in ["SASL_PLAINTEXT", "SASL_SSL"] {
+        let mut spec = valid_backup_spec();
+        spec.kafka_cluster.security_protocol = protocol.to_string();
+        spec.kafka_cluster.ca_secret = Some(CaSecretRef {
+            name: "cluster-ca-cert".to_string(),
+            ca_key: "ca.crt".to_string(),
+        });
+
+        let backup = create_backup(spec);
+        let result = backup::validate(&backup);
+
+        assert!(
+            result.is_err(),
+            "{} without saslSecret should fail",
+            protocol
+        );
+        assert!(result.unwrap_err().to_string().contains("saslSecret"));
+    }
+}
+
+#[test]
+fn backup_ssl_with_tls_cert_dir_passes_validation() {
+    let mut spec = valid_backup_spec();
+    spec.kafka_cluster.security_protocol = "SSL".to_string();
+    spec.kafka_cluster.tls_cert_dir = Some("/etc/kafka/certs".to_string());
 
     let backup = create_backup(spec);
     assert!(backup::validate(&backup).is_ok());
 }
 
+#[test]
+fn backup_tls_cert_dir_with_tls_secret_fails_validation() {
+    let mut spec = valid_backup_spec();
+    spec.kafka_cluster.security_protocol = "SSL".to_string();
+    spec.kafka_cluster.tls_cert_dir = Some("/etc/kafka/certs".to_string());
+    spec.kafka_cluster.tls_secret = Some(TlsSecretRef {
+        name: "kafka-tls".to_string(),
+        ca_key: "ca.crt".to_string(),
+        cert_key: None,
+        key_key: None,
+    });
+
+    let backup = create_backup(spec);
+    let result = backup::validate(&backup);
+
+    assert!(result.is_err());
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("mutually exclusive"));
+}
+
 #[test]
 fn backup_plaintext_with_ca_secret_passes_validation() {
     let mut spec = valid_backup_spec();
@@ -510,25 +1027,163 @@ fn backup_plaintext_with_ca_secret_passes_validation() {
     assert!(backup::validate(&backup).is_ok());
 }
 
-// ============================================================================
-// Restore Validation Tests
-// ============================================================================
+#[test]
+fn backup_supported_sasl_mechanisms_pass_validation() {
+    for mechanism in ["PLAIN", "SCRAM-SHA-256", "SCRAM-SHA-512", "scram-sha-512"] {
+        let mut spec = valid_backup_spec();
+        spec.kafka_cluster.security_protocol = "SASL_PLAINTEXT".to_string();
+        spec.kafka_cluster.sasl_secret = Some(sasl_secret(mechanism));
 
-fn valid_restore_spec() -> KafkaRestoreSpec {
-    KafkaRestoreSpec {
-        backup_ref: BackupRef {
-            name: "my-backup".to_string(),
-            namespace: None,
-            backup_id: None,
-            storage: None,
-        },
-        kafka_cluster: valid_kafka_cluster(),
-        topics: vec![],
-        pitr: None,
+        let backup = create_backup(spec);
+        assert!(
+            backup::validate(&backup).is_ok(),
+            "Mechanism '{}' should be valid",
+            mechanism
+        );
+    }
+}
+
+#[test]
+fn backup_unsupported_sasl_mechanism_fails_validation() {
+    let mut spec = valid_backup_spec();
+    spec.kafka_cluster.security_protocol = "SASL_PLAINTEXT".to_string();
+    spec.kafka_cluster.sasl_secret = Some(sasl_secret("OAUTHBEARER"));
+
+    let backup = create_backup(spec);
+    let err_msg = backup::validate(&backup).unwrap_err().to_string();
+
+    assert!(err_msg.contains("OAUTHBEARER"));
+    assert!(err_msg.contains("SCRAM-SHA-512"));
+}
+
+#[test]
+fn backup_env_sourced_sasl_credentials_pass_validation() {
+    let mut spec = valid_backup_spec();
+    spec.kafka_cluster.security_protocol = "SASL_PLAINTEXT".to_string();
+    spec.kafka_cluster.sasl_secret = Some(SaslSecretRef {
+        name: None,
+        username_env: Some("KAFKA_BACKUP_CRED_KAFKA_USERNAME".to_string()),
+        password_env: Some("KAFKA_BACKUP_CRED_KAFKA_PASSWORD".to_string()),
+        ..sasl_secret("SCRAM-SHA-512")
+    });
+
+    let backup = create_backup(spec);
+    assert!(backup::validate(&backup).is_ok());
+}
+
+#[test]
+fn backup_env_sourced_credentials_outside_the_credential_prefix_fail_validation() {
+    let mut spec = valid_backup_spec();
+    spec.kafka_cluster.security_protocol = "SASL_PLAINTEXT".to_string();
+    spec.kafka_cluster.sasl_secret = Some(SaslSecretRef {
+        name: None,
+        username_env: Some("KAFKA_BACKUP_CRED_KAFKA_USERNAME".to_string()),
+        password_env: Some("KUBERNETES_SERVICE_HOST".to_string()),
+        ..sasl_secret("SCRAM-SHA-512")
+    });
+
+    let backup = create_backup(spec);
+    let err_msg = backup::validate(&backup).unwrap_err().to_string();
+
+    assert!(err_msg.contains("saslSecret.passwordEnv 'KUBERNETES_SERVICE_HOST'"));
+}
+
+#[test]
+fn backup_sasl_credentials_with_secret_and_env_fail_validation() {
+    let mut spec = valid_backup_spec();
+    spec.kafka_cluster.security_protocol = "SASL_PLAINTEXT".to_string();
+    spec.kafka_cluster.sasl_secret = Some(SaslSecretRef {
+        password_env: Some("KAFKA_BACKUP_CRED_KAFKA_PASSWORD".to_string()),
+        ..sasl_secret("SCRAM-SHA-512")
+    });
+
+    let backup = create_backup(spec);
+    let err_msg = backup::validate(&backup).unwrap_err().to_string();
+
+    assert!(err_msg.contains("saslSecret sets both name and passwordEnv"));
+}
+
+#[test]
+fn backup_sasl_credentials_without_any_source_fail_validation() {
+    let mut spec = valid_backup_spec();
+    spec.kafka_cluster.security_protocol = "SASL_PLAINTEXT".to_string();
+    spec.kafka_cluster.sasl_secret = Some(SaslSecretRef {
+        name: None,
+        password_env: Some("KAFKA_BACKUP_CRED_KAFKA_PASSWORD".to_string()),
+        ..sasl_secret("SCRAM-SHA-512")
+    });
+
+    let backup = create_backup(spec);
+    let err_msg = backup::validate(&backup).unwrap_err().to_string();
+
+    assert!(err_msg.contains("missing usernameEnv"));
+}
+
+#[test]
+fn backup_client_config_cannot_override_security_settings() {
+    let mut spec = valid_backup_spec();
+    spec.kafka_cluster.client_config = HashMap::from([
+        ("sasl.password".to_string(), "hunter2".to_string()),
+        ("security.protocol".to_string(), "PLAINTEXT".to_string()),
+        ("fetch.max.bytes".to_string(), "52428800".to_string()),
+    ]);
+
+    let err_msg = backup::validate(&create_backup(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err_msg.contains("clientConfig must not set sasl.password, security.protocol"));
+}
+
+#[test]
+fn backup_client_config_tuning_passes_validation() {
+    let mut spec = valid_backup_spec();
+    spec.kafka_cluster.client_config = HashMap::from([
+        ("socket.nagle.disable".to_string(), "false".to_string()),
+        ("fetch.max.bytes".to_string(), "52428800".to_string()),
+        ("queue.buffering.max.ms".to_string(), "5".to_string()),
+    ]);
+
+    assert!(backup::validate(&create_backup(spec)).is_ok());
+}
+
+#[test]
+fn backup_client_config_booleans_are_checked() {
+    let mut spec = valid_backup_spec();
+    spec.kafka_cluster.client_config =
+        HashMap::from([("socket.keepalive.enable".to_string(), "yes".to_string())]);
+
+    let err_msg = backup::validate(&create_backup(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err_msg.contains("clientConfig socket.keepalive.enable must be \"true\" or \"false\""));
+}
+
+// ============================================================================
+// Restore Validation Tests
+// ============================================================================
+
+fn valid_restore_spec() -> KafkaRestoreSpec {
+    KafkaRestoreSpec {
+        backup_ref: BackupRef {
+            name: "my-backup".to_string(),
+            namespace: None,
+            backup_id: None,
+            use_latest: false,
+            rerun_on_new_backup: false,
+            storage: None,
+            storage_fallbacks: Vec::new(),
+        },
+        kafka_cluster: valid_kafka_cluster(),
+        topics: vec![],
+        pitr: None,
         topic_mapping: HashMap::new(),
         partition_mapping: HashMap::new(),
         repartitioning: HashMap::new(),
+        partition_strategy: PartitionStrategy::Preserve,
         offset_reset: None,
+        offset_strategy: None,
+        consumer_group_mapping: HashMap::new(),
+        consumer_group_prefix: None,
         rollback: None,
         rate_limiting: None,
         circuit_breaker: None,
@@ -540,6 +1195,17 @@ fn valid_restore_spec() -> KafkaRestoreSpec {
         auto_consumer_groups: false,
         create_topics: false,
         default_replication_factor: None,
+        allow_replication_factor_downscale: false,
+        allow_partition_downscale: false,
+        max_backup_age_hours: None,
+        verify_checksums: false,
+        acl_preflight: false,
+        timeout_secs: None,
+        stall_timeout_secs: None,
+        force_full_restore: false,
+        offset_report: None,
+        preview: None,
+        restore_offsets_only: false,
     }
 }
 
@@ -569,6 +1235,142 @@ fn restore_invalid_produce_acks_fails_validation() {
     assert!(result.unwrap_err().to_string().contains("produceAcks"));
 }
 
+#[test]
+fn restore_latest_offset_strategy_without_groups_fails_validation() {
+    let mut spec = valid_restore_spec();
+    spec.offset_strategy = Some(RestoreOffsetStrategy::Latest);
+
+    let result = restore::validate(&create_restore(spec));
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("offsetReset.consumerGroups"));
+}
+
+#[test]
+fn restore_earliest_offset_strategy_with_groups_passes_validation() {
+    let mut spec = valid_restore_spec();
+    spec.offset_strategy = Some(RestoreOffsetStrategy::Earliest);
+    spec.offset_reset = Some(OffsetResetSpec {
+        enabled: false,
+        consumer_groups: vec!["orders-app".to_string()],
+        strategy: "manual".to_string(),
+    });
+
+    assert!(restore::validate(&create_restore(spec)).is_ok());
+}
+
+#[test]
+fn restore_consumer_group_prefix_maps_group_names() {
+    let mut spec = valid_restore_spec();
+    spec.consumer_group_prefix = Some("dr-".to_string());
+    spec.consumer_group_mapping =
+        HashMap::from([("billing".to_string(), "billing-standby".to_string())]);
+
+    assert_eq!(restore::target_consumer_group(&spec, "app"), "dr-app");
+    assert_eq!(
+        restore::target_consumer_group(&spec, "billing"),
+        "billing-standby"
+    );
+    assert!(restore::validate(&create_restore(spec)).is_ok());
+}
+
+#[test]
+fn restore_consumer_group_mapping_collision_fails_validation() {
+    let mut spec = valid_restore_spec();
+    spec.consumer_group_prefix = Some("dr-".to_string());
+    spec.consumer_group_mapping = HashMap::from([("legacy".to_string(), "dr-app".to_string())]);
+    spec.offset_strategy = Some(RestoreOffsetStrategy::Latest);
+    spec.offset_reset = Some(OffsetResetSpec {
+        enabled: false,
+        consumer_groups: vec!["app".to_string()],
+        strategy: "manual".to_string(),
+    });
+
+    let err = restore::validate(&create_restore(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("dr-app <- [app, legacy]"), "{}", err);
+}
+
+#[test]
+fn restore_consumer_group_prefix_with_engine_offset_reset_fails_validation() {
+    let mut spec = valid_restore_spec();
+    spec.consumer_group_prefix = Some("dr-".to_string());
+    spec.offset_reset = Some(OffsetResetSpec {
+        enabled: true,
+        consumer_groups: vec!["app".to_string()],
+        strategy: "auto".to_string(),
+    });
+
+    let err = restore::validate(&create_restore(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("can't rename groups reset by the restore engine"));
+}
+
+#[test]
+fn restore_stall_timeout_with_three_phase_restore_fails_validation() {
+    let mut spec = valid_restore_spec();
+    spec.stall_timeout_secs = Some(600);
+    assert!(restore::validate(&create_restore(spec.clone())).is_ok());
+
+    spec.auto_consumer_groups = true;
+    let err = restore::validate(&create_restore(spec.clone()))
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("stallTimeoutSecs cannot be combined"),
+        "{}",
+        err
+    );
+
+    spec.auto_consumer_groups = false;
+    spec.offset_reset = Some(OffsetResetSpec {
+        enabled: true,
+        consumer_groups: vec!["app".to_string()],
+        strategy: "auto".to_string(),
+    });
+    assert!(restore::validate(&create_restore(spec)).is_err());
+}
+
+#[test]
+fn restore_zero_default_replication_factor_fails_validation() {
+    let mut spec = valid_restore_spec();
+    spec.create_topics = true;
+    spec.default_replication_factor = Some(0);
+
+    let result = restore::validate(&create_restore(spec));
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("defaultReplicationFactor"));
+}
+
+#[test]
+fn restore_broker_default_replication_factor_passes_validation() {
+    let mut spec = valid_restore_spec();
+    spec.create_topics = true;
+    spec.default_replication_factor = Some(-1);
+
+    assert!(restore::validate(&create_restore(spec)).is_ok());
+}
+
+#[test]
+fn restore_produce_batch_size_out_of_range_fails_validation() {
+    for batch_size in [0, restore::MAX_PRODUCE_BATCH_SIZE + 1] {
+        let mut spec = valid_restore_spec();
+        spec.produce_batch_size = batch_size;
+
+        let result = restore::validate(&create_restore(spec));
+        assert!(result.unwrap_err().to_string().contains("produceBatchSize"));
+    }
+
+    let mut spec = valid_restore_spec();
+    spec.produce_batch_size = restore::MAX_PRODUCE_BATCH_SIZE;
+    assert!(restore::validate(&create_restore(spec)).is_ok());
+}
+
 #[test]
 fn restore_valid_repartitioning_passes_validation() {
     let mut spec = valid_restore_spec();
@@ -710,6 +1512,81 @@ fn restore_specific_topics_with_purge_passes_validation() {
     assert!(restore::validate(&restore).is_ok());
 }
 
+#[test]
+fn restore_offsets_only_needs_no_purge_for_original_topics() {
+    let mut spec = valid_restore_spec();
+    spec.purge_topics = false;
+    spec.restore_offsets_only = true;
+
+    let restore = create_restore(spec);
+    assert!(restore::validate(&restore).is_ok());
+}
+
+#[test]
+fn restore_offsets_only_rejects_record_options() {
+    let mut spec = valid_restore_spec();
+    spec.restore_offsets_only = true;
+    spec.create_topics = true;
+
+    let restore = create_restore(spec);
+    let err = restore::validate(&restore).unwrap_err().to_string();
+
+    assert!(err.contains("restoreOffsetsOnly"));
+    assert!(err.contains("purgeTopics, createTopics"));
+}
+
+#[test]
+fn restore_rerun_on_new_backup_requires_use_latest_and_purge() {
+    let mut spec = valid_restore_spec();
+    spec.backup_ref.rerun_on_new_backup = true;
+    spec.purge_topics = true;
+    let err = restore::validate(&create_restore(spec.clone()))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("rerunOnNewBackup requires backupRef.useLatest"));
+
+    spec.backup_ref.use_latest = true;
+    assert!(restore::validate(&create_restore(spec.clone())).is_ok());
+
+    spec.purge_topics = false;
+    let err = restore::validate(&create_restore(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("rerunOnNewBackup requires purgeTopics=true"));
+}
+
+#[test]
+fn restore_key_hash_routing_rejects_partition_mapping() {
+    let mut spec = valid_restore_spec();
+    spec.partition_strategy = PartitionStrategy::KeyHash;
+    assert!(restore::validate(&create_restore(spec.clone())).is_ok());
+
+    spec.partition_mapping = HashMap::from([(0, 1)]);
+    let err = restore::validate(&create_restore(spec))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("partitionMapping cannot be combined with partitionStrategy key_hash"));
+}
+
+#[test]
+fn restore_preview_requires_dry_run() {
+    let mut spec = valid_restore_spec();
+    spec.preview = Some(PreviewSpec {
+        topic: "orders".to_string(),
+        max_records: 10,
+        include_values: false,
+    });
+    let result = restore::validate(&create_restore(spec.clone()));
+    assert!(result.unwrap_err().to_string().contains("dryRun"));
+
+    spec.dry_run = true;
+    assert!(restore::validate(&create_restore(spec.clone())).is_ok());
+
+    spec.preview.as_mut().unwrap().max_records = 101;
+    let result = restore::validate(&create_restore(spec));
+    assert!(result.unwrap_err().to_string().contains("maxRecords"));
+}
+
 #[test]
 fn restore_topic_mapping_passes_validation() {
     let mut spec = valid_restore_spec();
@@ -719,6 +1596,59 @@ fn restore_topic_mapping_passes_validation() {
     assert!(restore::validate(&restore).is_ok());
 }
 
+#[test]
+fn restore_topic_mapping_with_duplicate_targets_fails_validation() {
+    let mut spec = valid_restore_spec();
+    spec.topic_mapping = HashMap::from([
+        ("orders".to_string(), "merged".to_string()),
+        ("payments".to_string(), "merged".to_string()),
+    ]);
+
+    let restore = create_restore(spec);
+    let err_msg = restore::validate(&restore).unwrap_err().to_string();
+
+    assert!(err_msg.contains("merged"));
+    assert!(err_msg.contains("orders"));
+    assert!(err_msg.contains("payments"));
+}
+
+#[test]
+fn restore_topic_mapping_target_colliding_with_unmapped_topic_fails_validation() {
+    let mut spec = valid_restore_spec();
+    spec.topics = vec!["orders".to_string(), "payments".to_string()];
+    spec.topic_mapping = HashMap::from([("orders".to_string(), "payments".to_string())]);
+
+    let restore = create_restore(spec);
+    let err_msg = restore::validate(&restore).unwrap_err().to_string();
+
+    assert!(err_msg.contains("orders -> payments"));
+}
+
+#[test]
+fn restore_topic_mapping_swap_passes_validation() {
+    let mut spec = valid_restore_spec();
+    spec.topics = vec!["orders".to_string(), "payments".to_string()];
+    spec.topic_mapping = HashMap::from([
+        ("orders".to_string(), "payments".to_string()),
+        ("payments".to_string(), "orders".to_string()),
+    ]);
+
+    let restore = create_restore(spec);
+    assert!(restore::validate(&restore).is_ok());
+}
+
+#[test]
+fn restore_sasl_without_sasl_secret_fails_validation() {
+    let mut spec = valid_restore_spec();
+    spec.kafka_cluster.security_protocol = "SASL_PLAINTEXT".to_string();
+
+    let restore = create_restore(spec);
+    let result = restore::validate(&restore);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("saslSecret"));
+}
+
 #[test]
 fn restore_dry_run_mode_passes_validation() {
     let mut spec = valid_restore_spec();
@@ -736,15 +1666,20 @@ fn valid_offset_reset_spec() -> KafkaOffsetResetSpec {
     KafkaOffsetResetSpec {
         kafka_cluster: valid_kafka_cluster(),
         consumer_groups: vec!["test-group".to_string()],
+        all_groups: false,
         reset_strategy: OffsetResetStrategy::ToEarliest,
         reset_timestamp: None,
         reset_offset: None,
         topics: vec![],
+        partitions: vec![],
         parallelism: 50,
         dry_run: false,
         continue_on_error: false,
         offset_mapping_ref: None,
         snapshot_before_reset: true,
+        snapshot_retention_hours: 24,
+        retry: None,
+        verify_after_reset: false,
     }
 }
 
@@ -786,6 +1721,98 @@ fn offset_reset_empty_consumer_groups_fails_validation() {
     assert!(result.unwrap_err().to_string().contains("consumer group"));
 }
 
+#[test]
+fn offset_reset_duplicate_consumer_groups_fail_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.consumer_groups = vec!["app".to_string(), "billing".to_string(), "app".to_string()];
+
+    let reset = create_offset_reset(spec);
+    let result = offset_reset::validate(&reset);
+
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("consumerGroups lists 'app' more than once"));
+}
+
+#[test]
+fn offset_reset_duplicate_topics_fail_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.topics = vec!["orders".to_string(), "orders".to_string()];
+
+    let reset = create_offset_reset(spec);
+    let result = offset_reset::validate(&reset);
+
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("topics lists 'orders' more than once"));
+}
+
+#[test]
+fn offset_reset_partitions_for_unlisted_topic_fails_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.topics = vec!["orders".to_string()];
+    spec.partitions = vec![TopicPartitions {
+        topic: "payments".to_string(),
+        partitions: vec![3],
+    }];
+
+    let reset = create_offset_reset(spec);
+    let result = offset_reset::validate(&reset);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("payments"));
+}
+
+#[test]
+fn offset_reset_partitions_for_listed_topic_passes_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.topics = vec!["orders".to_string()];
+    spec.partitions = vec![TopicPartitions {
+        topic: "orders".to_string(),
+        partitions: vec![3],
+    }];
+
+    let reset = create_offset_reset(spec);
+    assert!(offset_reset::validate(&reset).is_ok());
+}
+
+#[test]
+fn offset_reset_partitions_entry_without_partitions_passes_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.topics = vec!["orders".to_string(), "payments".to_string()];
+    spec.partitions = vec![TopicPartitions {
+        topic: "orders".to_string(),
+        partitions: vec![],
+    }];
+
+    let reset = create_offset_reset(spec);
+    assert!(offset_reset::validate(&reset).is_ok());
+}
+
+#[test]
+fn offset_reset_all_groups_without_consumer_groups_passes_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.consumer_groups = vec![];
+    spec.all_groups = true;
+
+    let reset = create_offset_reset(spec);
+    assert!(offset_reset::validate(&reset).is_ok());
+}
+
+#[test]
+fn offset_reset_all_groups_with_consumer_groups_fails_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.all_groups = true;
+
+    let reset = create_offset_reset(spec);
+    let result = offset_reset::validate(&reset);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("allGroups"));
+}
+
 #[test]
 fn offset_reset_to_timestamp_without_timestamp_fails_validation() {
     let mut spec = valid_offset_reset_spec();
@@ -918,6 +1945,52 @@ fn offset_reset_valid_parallelism_passes_validation() {
     }
 }
 
+#[test]
+fn offset_reset_retry_zero_attempts_fails_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.retry = Some(RetrySpec {
+        max_attempts: 0,
+        ..Default::default()
+    });
+
+    let result = offset_reset::validate(&create_offset_reset(spec));
+
+    assert!(result.is_err());
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("retry.maxAttempts"));
+}
+
+#[test]
+fn offset_reset_retry_short_timeout_fails_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.retry = Some(RetrySpec {
+        request_timeout_ms: 999,
+        ..Default::default()
+    });
+
+    let result = offset_reset::validate(&create_offset_reset(spec));
+
+    assert!(result.is_err());
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("retry.requestTimeoutMs must be at least 1000"));
+}
+
+#[test]
+fn offset_reset_retry_minimums_pass_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.retry = Some(RetrySpec {
+        max_attempts: 1,
+        base_delay_ms: 0,
+        request_timeout_ms: 1000,
+    });
+
+    assert!(offset_reset::validate(&create_offset_reset(spec)).is_ok());
+}
+
 #[test]
 fn offset_reset_multiple_consumer_groups_passes_validation() {
     let mut spec = valid_offset_reset_spec();
@@ -931,6 +2004,31 @@ fn offset_reset_multiple_consumer_groups_passes_validation() {
     assert!(offset_reset::validate(&reset).is_ok());
 }
 
+#[test]
+fn offset_reset_unsupported_sasl_mechanism_fails_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.kafka_cluster.security_protocol = "SASL_PLAINTEXT".to_string();
+    spec.kafka_cluster.sasl_secret = Some(sasl_secret("GSSAPI"));
+
+    let reset = create_offset_reset(spec);
+    let result = offset_reset::validate(&reset);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("GSSAPI"));
+}
+
+#[test]
+fn offset_reset_sasl_without_sasl_secret_fails_validation() {
+    let mut spec = valid_offset_reset_spec();
+    spec.kafka_cluster.security_protocol = "SASL_PLAINTEXT".to_string();
+
+    let reset = create_offset_reset(spec);
+    let result = offset_reset::validate(&reset);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("saslSecret"));
+}
+
 #[test]
 fn offset_reset_dry_run_mode_passes_validation() {
     let mut spec = valid_offset_reset_spec();